@@ -0,0 +1,34 @@
+//! Rates every word of a word list and saves the ratings next to it.
+//!
+//! ```text
+//! cargo run --release -p wordle-engine --example rate_words -- data/words.txt
+//! ```
+
+use std::collections::HashSet;
+use std::env;
+use std::path::Path;
+use std::sync::Arc;
+
+use wordle_engine::difficulty::Ratings;
+use wordle_engine::normalize::fold_word;
+use wordle_engine::Game;
+
+fn main() -> std::io::Result<()> {
+    let Some(words_file) = env::args().nth(1) else {
+        eprintln!("usage: rate_words <words file>");
+        std::process::exit(2);
+    };
+
+    let mut seen = HashSet::new();
+    let words: Arc<[String]> = Game::load_words_from_file(&words_file)
+        .iter()
+        .map(|word| fold_word(word))
+        .filter(|word| seen.insert(word.clone()))
+        .collect();
+
+    let ratings = Ratings::compute(words);
+    let path = Ratings::path_for(Path::new(&words_file));
+    ratings.save(&path)?;
+    println!("{} words rated into {}", ratings.len(), path.display());
+    Ok(())
+}
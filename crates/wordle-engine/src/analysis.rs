@@ -0,0 +1,55 @@
+//! Post-game breakdown of how much each guess narrowed the answer down, and
+//! how it compares with the solver's choice.
+
+use crate::candidates::CandidateSet;
+use crate::solver::{self, Suggestion};
+use crate::Feedback;
+
+/// What one guess achieved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuessAnalysis {
+    pub feedback: Feedback,
+    /// Words that could be the answer before the guess.
+    pub before: usize,
+    /// Words that could be the answer after it.
+    pub after: usize,
+    /// Expected information of the guess, in bits, given `before`.
+    pub entropy: f64,
+    /// The solver's best guess in the same position.
+    pub best: Option<Suggestion>,
+}
+
+/// Analyzes a finished game.
+///
+/// `before` holds the candidate set as it was right before each guess of
+/// `guesses` (the history kept while playing); guesses without a recorded
+/// set are skipped.
+pub fn analyze(guesses: &[Feedback], before: &[CandidateSet]) -> Vec<GuessAnalysis> {
+    guesses
+        .iter()
+        .zip(before)
+        .map(|(feedback, set)| {
+            let mut after = set.clone();
+            after.apply(&feedback.letters, &feedback.statuses);
+
+            let candidates: Vec<&str> = set.iter().collect();
+            let answers: Vec<&[u8]> = candidates.iter().map(|word| word.as_bytes()).collect();
+            let word = feedback.word();
+            let entropy = if word.is_ascii() {
+                solver::entropy(word.as_bytes(), &answers)
+            } else {
+                0.0
+            };
+
+            GuessAnalysis {
+                feedback: feedback.clone(),
+                before: set.len(),
+                after: after.len(),
+                entropy,
+                best: solver::rank_guesses(set.word_list(), &candidates, 1)
+                    .into_iter()
+                    .next(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,137 @@
+//! The set of words that can still be the answer.
+//!
+//! A [`CandidateSet`] starts with a whole word list and is narrowed one
+//! guess at a time with [`CandidateSet::apply`]. Membership is a bitset over
+//! the indices of the shared list, so cloning a set (to keep its history, or
+//! to try "what if" guesses) copies a few hundred bytes, never the words.
+
+use std::sync::Arc;
+
+use crate::solver::pattern;
+use crate::{evaluate, Counts, LetterStatus};
+
+#[derive(Debug, Clone)]
+pub struct CandidateSet {
+    words: Arc<[String]>,
+    bits: Vec<u64>,
+    len: usize,
+}
+
+impl CandidateSet {
+    /// Every word of `words` is a candidate.
+    pub fn new(words: Arc<[String]>) -> Self {
+        let len = words.len();
+        let mut bits = vec![u64::MAX; len.div_ceil(64)];
+        // Clear the bits past the end of the list
+        if !len.is_multiple_of(64) {
+            if let Some(last) = bits.last_mut() {
+                *last = (1u64 << (len % 64)) - 1;
+            }
+        }
+        CandidateSet { words, bits, len }
+    }
+
+    /// The whole list the set was started with, candidates or not.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Keeps only the words that would have produced `statuses` for `guess`.
+    pub fn apply(&mut self, guess: &[char], statuses: &[LetterStatus]) {
+        // Fast path: compare base-3 pattern codes on ASCII bytes
+        let guess_word: String = guess.iter().collect();
+        let code = guess_word
+            .is_ascii()
+            .then(|| pattern_code(statuses))
+            .flatten();
+
+        for index in self.indices().collect::<Vec<_>>() {
+            let word = &self.words[index];
+            let keep = match code {
+                Some(code) if word.is_ascii() && word.len() == guess.len() => {
+                    pattern(guess_word.as_bytes(), word.as_bytes()) == code
+                }
+                _ => evaluate(guess, word) == statuses,
+            };
+            if !keep {
+                self.remove(index);
+            }
+        }
+    }
+
+    /// Keeps only the words that would have produced `counts` for `guess`,
+    /// for games answered with counts only.
+    pub fn apply_counts(&mut self, guess: &[char], counts: Counts) {
+        for index in self.indices().collect::<Vec<_>>() {
+            if Counts::of(&evaluate(guess, &self.words[index])) != counts {
+                self.remove(index);
+            }
+        }
+    }
+
+    /// Number of candidates left.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether the word at `index` of the list is still a candidate.
+    pub fn contains(&self, index: usize) -> bool {
+        index < self.words.len() && self.bits[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Whether `word` is still a candidate.
+    pub fn contains_word(&self, word: &str) -> bool {
+        self.iter().any(|candidate| candidate == word)
+    }
+
+    /// Indices (in the word list) of the candidates, in list order.
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bits.iter().enumerate().flat_map(|(block, &bits)| {
+            let mut bits = bits;
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+                let offset = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                Some(block * 64 + offset)
+            })
+        })
+    }
+
+    /// The candidates, in list order.
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.indices().map(|index| self.words[index].as_str())
+    }
+
+    /// The full word list this set was built from.
+    pub fn word_list(&self) -> &Arc<[String]> {
+        &self.words
+    }
+
+    fn remove(&mut self, index: usize) {
+        let mask = 1 << (index % 64);
+        if self.bits[index / 64] & mask != 0 {
+            self.bits[index / 64] &= !mask;
+            self.len -= 1;
+        }
+    }
+}
+
+// Statuses as the base-3 code produced by `solver::pattern`, or `None` if
+// they contain `Unused` (feedback for an incomplete guess)
+fn pattern_code(statuses: &[LetterStatus]) -> Option<u32> {
+    statuses.iter().try_fold(0, |code, status| {
+        let digit = match status {
+            LetterStatus::Absent => 0,
+            LetterStatus::Present => 1,
+            LetterStatus::Correct => 2,
+            LetterStatus::Unused => return None,
+        };
+        Some(code * 3 + digit)
+    })
+}
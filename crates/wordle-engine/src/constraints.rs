@@ -0,0 +1,93 @@
+//! What the feedback so far says about the answer, slot by slot.
+//!
+//! [`Constraints::deduce`] folds every submitted guess into a template:
+//! the letters known for each slot, the letters ruled out of each slot,
+//! the letters that must still go somewhere and the ones not in the word
+//! at all.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::LetterStatus;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Constraints {
+    /// Letter known to be in each slot.
+    pub known: Vec<Option<char>>,
+    /// Letters that are in the word but not in each slot, alphabetically.
+    /// Empty for known slots.
+    pub excluded: Vec<Vec<char>>,
+    /// Letters in the word whose slot isn't known yet, repeated when the
+    /// word has several of them.
+    pub elsewhere: Vec<char>,
+    /// Letters not in the word, alphabetically.
+    pub absent: Vec<char>,
+}
+
+impl Constraints {
+    /// Deduces the constraints on a `word_length` letter answer from
+    /// submitted guesses and their statuses.
+    pub fn deduce<'a>(
+        word_length: usize,
+        guesses: impl IntoIterator<Item = (&'a [char], &'a [LetterStatus])>,
+    ) -> Constraints {
+        let mut known = vec![None; word_length];
+        let mut excluded = vec![BTreeSet::new(); word_length];
+        let mut absent = BTreeSet::new();
+        // Fewest copies of each letter the word is known to have
+        let mut least = BTreeMap::new();
+
+        for (guess, statuses) in guesses {
+            // A forfeited row tells nothing about the word
+            if guess.iter().all(|&letter| letter == crate::FORFEITED) {
+                continue;
+            }
+            let mut marked = BTreeMap::new();
+            for (&letter, &status) in guess.iter().zip(statuses) {
+                if let LetterStatus::Correct | LetterStatus::Present = status {
+                    *marked.entry(letter).or_insert(0usize) += 1;
+                }
+            }
+            for (slot, (&letter, &status)) in guess.iter().zip(statuses).enumerate() {
+                match status {
+                    LetterStatus::Correct => known[slot] = Some(letter),
+                    LetterStatus::Present => {
+                        excluded[slot].insert(letter);
+                    }
+                    // An extra copy of a letter the word has: just not here
+                    LetterStatus::Absent if marked.contains_key(&letter) => {
+                        excluded[slot].insert(letter);
+                    }
+                    LetterStatus::Absent => {
+                        absent.insert(letter);
+                    }
+                    LetterStatus::Unused => {}
+                }
+            }
+            for (letter, count) in marked {
+                let least = least.entry(letter).or_insert(0);
+                *least = count.max(*least);
+            }
+        }
+
+        let mut elsewhere = Vec::new();
+        for (letter, count) in least {
+            let placed = known.iter().filter(|&&slot| slot == Some(letter)).count();
+            elsewhere.extend(std::iter::repeat_n(letter, count.saturating_sub(placed)));
+        }
+        let excluded = known
+            .iter()
+            .zip(excluded)
+            .map(|(slot, letters)| match slot {
+                Some(_) => Vec::new(),
+                None => letters.into_iter().collect(),
+            })
+            .collect();
+
+        Constraints {
+            known,
+            excluded,
+            elsewhere,
+            absent: absent.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! A word list with the difficulty tier of each word, for drawing the word
+//! to find.
+//!
+//! Words are easy, medium or hard by how common they are (see
+//! [`Frequencies`]): by a frequency list when the word list has one, or by
+//! how common their letters are. A game of a tier is drawn like any other,
+//! from the whole list: when the word of a seed isn't of the tier, the seeds
+//! after it are tried in turn, and the game keeps the first that draws one.
+//! Its seed still gives its word to anyone with the list, whatever the tier.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::normalize::fold_word;
+use crate::rarity::{Frequencies, Rarity};
+use crate::Game;
+
+/// How hard a word is to find.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tier {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Tier {
+    pub const ALL: [Tier; 3] = [Tier::Easy, Tier::Medium, Tier::Hard];
+}
+
+impl From<Rarity> for Tier {
+    fn from(rarity: Rarity) -> Tier {
+        match rarity {
+            Rarity::Common => Tier::Easy,
+            Rarity::Uncommon => Tier::Medium,
+            Rarity::Rare => Tier::Hard,
+        }
+    }
+}
+
+/// Words to draw from, as spelled in the word list, with their tiers.
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    spellings: Arc<[String]>,
+    // By word, accents folded
+    tiers: HashMap<String, Tier>,
+}
+
+impl Dictionary {
+    /// Tiers `spellings` by how `frequencies` ranks them. Without a
+    /// ranking no word has a tier.
+    pub fn new(spellings: Arc<[String]>, frequencies: &Frequencies) -> Dictionary {
+        let tiers = spellings
+            .iter()
+            .map(|word| fold_word(word))
+            .filter_map(|word| {
+                let tier = Tier::from(frequencies.rarity(&word)?);
+                Some((word, tier))
+            })
+            .collect();
+        Dictionary { spellings, tiers }
+    }
+
+    /// The words, accents included, in the order of the list.
+    pub fn spellings(&self) -> &Arc<[String]> {
+        &self.spellings
+    }
+
+    pub fn len(&self) -> usize {
+        self.spellings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spellings.is_empty()
+    }
+
+    /// The tier of `word`, accents or not.
+    pub fn tier(&self, word: &str) -> Option<Tier> {
+        self.tiers.get(&fold_word(word)).copied()
+    }
+
+    /// How many words are of `tier`.
+    pub fn count(&self, tier: Tier) -> usize {
+        self.tiers.values().filter(|&&other| other == tier).count()
+    }
+
+    /// The game `seed` draws, of `tier` if there is one (see the module
+    /// documentation).
+    pub fn draw(&self, seed: u64, tier: Option<Tier>) -> Game {
+        self.draw_where(seed, |game| {
+            tier.is_none_or(|tier| self.tier(&game.target_word) == Some(tier))
+        })
+    }
+
+    /// The game `seed` draws, or the first of the seeds after it to draw a
+    /// game `accept` takes. When no word of the list is taken, the game of
+    /// `seed`.
+    pub fn draw_where(&self, seed: u64, accept: impl Fn(&Game) -> bool) -> Game {
+        let first = Game::from_seed(&self.spellings, seed);
+        if accept(&first) {
+            return first;
+        }
+        // Bounded, for a list with nothing to take
+        let mut next = seed;
+        for _ in 1..self.spellings.len() {
+            next = next.wrapping_add(1);
+            let game = Game::from_seed(&self.spellings, next);
+            if accept(&game) {
+                return game;
+            }
+        }
+        first
+    }
+}
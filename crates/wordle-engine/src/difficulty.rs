@@ -0,0 +1,177 @@
+//! How hard each word of a list is as an answer, on a 1-5 scale.
+//!
+//! A rating mixes how many guesses a simple entropy bot needs to find the
+//! word with how rare its letters are in the list. Playing a bot game per
+//! word is slow, so ratings are computed once per word list (see the
+//! `rate_words` example) and shipped next to it as a `.difficulty` file of
+//! `WORD rating` lines.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::candidates::CandidateSet;
+use crate::{evaluate, solver, LetterStatus};
+
+pub const MIN_RATING: f32 = 1.0;
+pub const MAX_RATING: f32 = 5.0;
+/// Share of the rating that comes from the bot's guess count; the rest is
+/// letter rarity.
+const GUESS_WEIGHT: f32 = 0.7;
+/// The bot gives up after this many guesses.
+const BOT_LIMIT: usize = 10;
+
+/// Difficulty ratings of the words of a list.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ratings {
+    ratings: HashMap<String, f32>,
+}
+
+impl Ratings {
+    /// Rates every word of `words` (uppercase, accents folded). Plays one
+    /// bot game per word, so this takes a while on large lists.
+    pub fn compute(words: Arc<[String]>) -> Ratings {
+        let everything = CandidateSet::new(words.clone());
+        let all: Vec<&str> = everything.iter().collect();
+        let Some(opener) = solver::rank_guesses(&words, &all, 1).pop() else {
+            return Ratings::default();
+        };
+
+        let frequencies = letter_frequencies(&words);
+        let rarity: Vec<f32> = words
+            .iter()
+            .map(|word| letter_rarity(word, &frequencies))
+            .collect();
+        let guesses: Vec<f32> = words
+            .iter()
+            .map(|word| bot_guesses(&words, &opener.word, word) as f32)
+            .collect();
+
+        // Both measures are spread over the whole scale, so every list has
+        // easy and hard words whatever its size
+        let rarity = spread_out(&rarity);
+        let guesses = spread_out(&guesses);
+        let ratings = words
+            .iter()
+            .zip(guesses.iter().zip(&rarity))
+            .map(|(word, (&guesses, &rarity))| {
+                let score = GUESS_WEIGHT * guesses + (1.0 - GUESS_WEIGHT) * rarity;
+                let rating = MIN_RATING + score * (MAX_RATING - MIN_RATING);
+                (word.clone(), (rating * 10.0).round() / 10.0)
+            })
+            .collect();
+        Ratings { ratings }
+    }
+
+    /// Reads `WORD rating` lines, skipping any that don't parse.
+    pub fn parse(text: &str) -> Ratings {
+        let ratings = text
+            .lines()
+            .filter_map(|line| {
+                let (word, rating) = line.trim().split_once(' ')?;
+                let rating: f32 = rating.trim().parse().ok()?;
+                Some((word.to_string(), rating.clamp(MIN_RATING, MAX_RATING)))
+            })
+            .collect();
+        Ratings { ratings }
+    }
+
+    /// The ratings as `WORD rating` lines, in alphabetical order.
+    pub fn to_text(&self) -> String {
+        let mut words: Vec<_> = self.ratings.iter().collect();
+        words.sort_by(|a, b| a.0.cmp(b.0));
+        words
+            .into_iter()
+            .map(|(word, rating)| format!("{} {:.1}\n", word, rating))
+            .collect()
+    }
+
+    /// Reads the ratings saved for a word list.
+    pub fn load(path: &Path) -> io::Result<Ratings> {
+        Ok(Self::parse(&fs::read_to_string(path)?))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    /// Where the ratings of the word list at `words_file` are kept.
+    pub fn path_for(words_file: &Path) -> PathBuf {
+        words_file.with_extension("difficulty")
+    }
+
+    /// Rating of `word` (uppercase, accents folded), if it was rated.
+    pub fn get(&self, word: &str) -> Option<f32> {
+        self.ratings.get(word).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ratings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ratings.is_empty()
+    }
+}
+
+/// Guesses a bot needs to find `answer`: it opens with `opener`, then
+/// always plays the candidate that gives the most information. Returns
+/// more than [`crate::MAX_ATTEMPTS`] when it would have lost.
+pub fn bot_guesses(words: &Arc<[String]>, opener: &str, answer: &str) -> usize {
+    let mut candidates = CandidateSet::new(words.clone());
+    let mut guess: Vec<char> = opener.chars().collect();
+    for attempt in 1..=BOT_LIMIT {
+        let statuses = evaluate(&guess, answer);
+        if statuses.iter().all(|&s| s == LetterStatus::Correct) {
+            return attempt;
+        }
+        candidates.apply(&guess, &statuses);
+
+        let remaining: Vec<&str> = candidates.iter().collect();
+        let pool: Vec<String> = remaining.iter().map(|word| word.to_string()).collect();
+        match solver::rank_guesses(&pool, &remaining, 1).pop() {
+            Some(best) => guess = best.word.chars().collect(),
+            None => break,
+        }
+    }
+    BOT_LIMIT + 1
+}
+
+// Rescales values to 0..1, from the smallest to the largest
+fn spread_out(values: &[f32]) -> Vec<f32> {
+    let (least, most) = values
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+    let spread = (most - least).max(f32::EPSILON);
+    values.iter().map(|v| (v - least) / spread).collect()
+}
+
+// Share of the words of the list each letter appears in
+pub(crate) fn letter_frequencies(words: &[String]) -> HashMap<char, f32> {
+    let mut counts = HashMap::new();
+    for word in words {
+        let mut seen: Vec<char> = word.chars().collect();
+        seen.sort_unstable();
+        seen.dedup();
+        for letter in seen {
+            *counts.entry(letter).or_insert(0usize) += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .map(|(letter, count)| (letter, count as f32 / words.len() as f32))
+        .collect()
+}
+
+// Average rarity of the letters of `word`: 0 when each is in every word of
+// the list, towards 1 when they are in almost none
+pub(crate) fn letter_rarity(word: &str, frequencies: &HashMap<char, f32>) -> f32 {
+    let letters = word.chars().count().max(1);
+    let total: f32 = word
+        .chars()
+        .map(|letter| 1.0 - frequencies.get(&letter).copied().unwrap_or(0.0))
+        .sum();
+    total / letters as f32
+}
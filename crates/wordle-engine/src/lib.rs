@@ -0,0 +1,953 @@
+//! Headless Wordle engine.
+//!
+//! [`Game`] holds the state of one game and applies the rules; [`evaluate`]
+//! scores a guess against a target word; [`candidates`] tracks which words
+//! can still be the answer and [`constraints`] what is known of it slot by
+//! slot, [`solver`] ranks guesses by the information they give (faster
+//! with a [`patterns`] cache), [`analysis`] reviews a finished game,
+//! [`difficulty`] rates how hard each answer is and [`rarity`] how common,
+//! [`dictionary`] draws answers of an easy, medium or hard tier, [`timing`]
+//! tells typed games from scripted ones, [`sheet`] lays out puzzles to play
+//! on paper and [`seed`] draws words the same way everywhere. Nothing here
+//! depends on a terminal, so the engine can be driven by tests, bots or any
+//! frontend.
+//!
+//! ```
+//! use wordle_engine::{Game, GameStatus, LetterStatus};
+//!
+//! let mut game = Game::with_word("CRANE");
+//! let feedback = game.guess("react").unwrap();
+//! assert_eq!(feedback.statuses[0], LetterStatus::Present);
+//! assert_eq!(feedback.statuses[2], LetterStatus::Correct);
+//!
+//! game.guess("CRANE").unwrap();
+//! assert_eq!(game.status, GameStatus::Won);
+//! ```
+//!
+//! # Stability
+//!
+//! The crate follows semantic versioning. [`Feedback`], [`LetterStatus`],
+//! [`GameConfig`] and [`GuessError`] are the stable scoring API;
+//! `GameConfig` and `GuessError` are `#[non_exhaustive]` so fields and
+//! variants can be added in minor releases.
+
+pub mod analysis;
+pub mod candidates;
+pub mod constraints;
+pub mod dictionary;
+pub mod difficulty;
+pub mod normalize;
+pub mod patterns;
+pub mod rarity;
+pub mod seed;
+pub mod sheet;
+pub mod solver;
+pub mod timing;
+
+use rand::seq::SliceRandom;
+use std::fmt;
+
+pub use seed::{seed_code, seed_from_code, seeded_index, word_list_checksum};
+use std::fs;
+use std::path::Path;
+
+/// Number of guesses a player gets before the game is lost, by default.
+pub const MAX_ATTEMPTS: usize = 6;
+/// Length of target words and guesses, by default.
+pub const WORD_LENGTH: usize = 5;
+/// Tries in a game of Letterle, one per letter of the alphabet.
+pub const LETTERLE_ATTEMPTS: usize = 26;
+/// Word list used by [`Game::new`].
+pub const DEFAULT_WORDS_FILE: &str = "./data/words.txt";
+/// Letter filling every slot of a forfeited row (see [`Game::forfeit_row`]).
+pub const FORFEITED: char = ' ';
+const MESSAGE_TICKS: u8 = 8; // How long temporary messages stay visible
+
+/// Feedback for a single letter of a submitted guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LetterStatus {
+    Correct, // Correct letter in correct position
+    Present, // Correct letter in wrong position
+    Absent,  // Letter is not in the word
+    Unused,  // Letter not yet used
+}
+
+/// How much the player is told about a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeedbackStyle {
+    /// The status of every letter, in place.
+    #[default]
+    Letters,
+    /// Only how many letters are right, Mastermind style (see [`Counts`]).
+    Counts,
+}
+
+/// Rules of a game: how long the words are, how many tries the player
+/// gets and how guesses are answered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct GameConfig {
+    pub word_length: usize,
+    pub max_attempts: usize,
+    pub feedback: FeedbackStyle,
+    /// Whether a row is added after every miss instead of the game being
+    /// lost: `max_attempts` is then only the rows to start with.
+    pub endless: bool,
+    /// Whether submitted guesses can be taken back with [`Game::undo`],
+    /// for practice.
+    pub undo: bool,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            word_length: WORD_LENGTH,
+            max_attempts: MAX_ATTEMPTS,
+            feedback: FeedbackStyle::Letters,
+            endless: false,
+            undo: false,
+        }
+    }
+}
+
+impl GameConfig {
+    pub fn new(word_length: usize, max_attempts: usize) -> Self {
+        GameConfig {
+            word_length,
+            max_attempts,
+            feedback: FeedbackStyle::Letters,
+            endless: false,
+            undo: false,
+        }
+    }
+
+    /// The same rules, with guesses answered in `feedback` style.
+    pub fn with_feedback(self, feedback: FeedbackStyle) -> Self {
+        GameConfig { feedback, ..self }
+    }
+
+    /// The same rules, with no end but finding the word.
+    pub fn with_endless(self, endless: bool) -> Self {
+        GameConfig { endless, ..self }
+    }
+
+    /// The same rules, with guesses that can be taken back.
+    pub fn with_undo(self, undo: bool) -> Self {
+        GameConfig { undo, ..self }
+    }
+}
+
+/// Feedback on a guess without positions: how many of its letters are in
+/// the right place, and how many are in the word but elsewhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Counts {
+    pub correct: usize,
+    pub present: usize,
+}
+
+impl Counts {
+    /// Counts the statuses of a scored guess.
+    pub fn of(statuses: &[LetterStatus]) -> Counts {
+        let count = |wanted| statuses.iter().filter(|&&status| status == wanted).count();
+        Counts {
+            correct: count(LetterStatus::Correct),
+            present: count(LetterStatus::Present),
+        }
+    }
+}
+
+/// A scored guess: the letters played and the status of each of them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Feedback {
+    pub letters: Vec<char>,
+    pub statuses: Vec<LetterStatus>,
+}
+
+impl Feedback {
+    /// Scores `guess` against `target`, see [`evaluate`].
+    pub fn new(guess: &[char], target: &str) -> Self {
+        Feedback {
+            letters: guess.to_vec(),
+            statuses: evaluate(guess, target),
+        }
+    }
+
+    /// The guessed word.
+    pub fn word(&self) -> String {
+        self.letters.iter().collect()
+    }
+
+    /// Whether every letter is in the right spot.
+    pub fn is_solved(&self) -> bool {
+        !self.statuses.is_empty() && self.statuses.iter().all(|s| *s == LetterStatus::Correct)
+    }
+}
+
+/// Overall state of a game.
+///
+/// The status only moves along the transitions [`GameStatus::can_become`]
+/// allows, through [`Game::transition`]:
+///
+/// ```text
+/// Playing ──▶ Won
+///    │ ▲ ───▶ Lost ──▶ Playing (keep trying)
+///    ▼ │
+/// Quitting
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameStatus {
+    /// Guesses are being accepted.
+    Playing,
+    /// The target word was guessed.
+    Won,
+    /// All attempts were used without finding the word.
+    Lost,
+    /// The player asked to exit and has to confirm.
+    Quitting,
+}
+
+impl GameStatus {
+    /// Whether letters can be typed and guesses submitted.
+    pub fn accepts_input(self) -> bool {
+        self == GameStatus::Playing
+    }
+
+    /// Whether the game ended, won or lost.
+    pub fn is_over(self) -> bool {
+        matches!(self, GameStatus::Won | GameStatus::Lost)
+    }
+
+    /// Whether a game can go from this status straight to `next`.
+    pub fn can_become(self, next: GameStatus) -> bool {
+        use GameStatus::*;
+        matches!(
+            (self, next),
+            (Playing, Won | Lost | Quitting) | (Quitting, Playing) | (Lost, Playing)
+        )
+    }
+}
+
+/// A status change [`Game::transition`] refused.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransitionError {
+    pub from: GameStatus,
+    pub to: GameStatus,
+}
+
+impl fmt::Display for TransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a {:?} game can't become {:?}", self.from, self.to)
+    }
+}
+
+impl std::error::Error for TransitionError {}
+
+/// A single Wordle game: the hidden word, every attempt typed so far and the
+/// feedback for the submitted ones.
+///
+/// The board is read through [`Game::row`], [`Game::letter`],
+/// [`Game::status_at`] and [`Game::current_row`], which give `None` past
+/// its edges rather than panicking: once the game is lost no row is being
+/// typed, and rows and words need not be the default size.
+pub struct Game {
+    pub config: GameConfig,
+    /// Letters of each attempt, one row per attempt (rows after
+    /// `current_attempt` are empty).
+    pub attempts: Vec<Vec<char>>,
+    /// Feedback for each letter of each submitted attempt.
+    pub letter_statuses: Vec<Vec<LetterStatus>>,
+    /// Index of the row currently being typed.
+    pub current_attempt: usize,
+    /// The word to guess, in uppercase with accents folded away: what
+    /// guesses are compared with.
+    pub target_word: String,
+    /// The word to guess as spelled in the word list (uppercase, accents
+    /// kept), for revealing it at the end of the game.
+    pub display_word: String,
+    pub status: GameStatus,
+    pub should_quit: bool,
+    /// Temporary message shown to the player, cleared by `on_tick`.
+    pub message: Option<String>,
+    pub message_timer: u8,
+    /// How many hints the player asked for in this game.
+    pub hints_used: usize,
+    /// The game was lost and the player kept guessing with
+    /// [`Game::keep_trying`]. Whatever happens next is practice, and
+    /// shouldn't count in stats or leaderboards.
+    pub continued: bool,
+    /// Seed the word was picked from the list with, when it was picked
+    /// that way, to check later which word a seed gives.
+    pub seed: Option<u64>,
+    // The board before each submitted guess, when guesses can be undone
+    undo_stack: Vec<Snapshot>,
+}
+
+/// The board at one point of a game, with the row being typed: what
+/// [`Game::undo`] puts back, and what [`Game::at`] shows again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    attempts: Vec<Vec<char>>,
+    letter_statuses: Vec<Vec<LetterStatus>>,
+    current_attempt: usize,
+    status: GameStatus,
+    continued: bool,
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Game {
+    /// Starts a game with a random word from `./data/words.txt`.
+    pub fn new() -> Self {
+        Self::from_words(&Self::load_words_from_file(DEFAULT_WORDS_FILE))
+    }
+
+    /// Starts a game with a random word from `words`, or a fallback word if
+    /// the list is empty. The seed it was drawn with is kept in
+    /// [`Game::seed`].
+    pub fn from_words(words: &[String]) -> Self {
+        Self::from_seed(words, rand::random())
+    }
+
+    /// Starts a game with the word of `words` that `seed` picks (see
+    /// [`seeded_index`]), so the same seed and list always give the same
+    /// word.
+    pub fn from_seed(words: &[String], seed: u64) -> Self {
+        let Some(index) = seeded_index(words.len(), seed) else {
+            let fallback_words = [
+                "PROVA",
+                // "OLHAR", "SORTE", "TEMPO", "PULAR", "FALAR",
+                // "JOGAR", "QUERO", "MUNDO", "LIVRO", "VIVER",
+            ];
+            return Self::with_word(fallback_words.choose(&mut rand::thread_rng()).unwrap());
+        };
+
+        Game {
+            seed: Some(seed),
+            ..Self::with_word(words.get(index).map_or("PROVA", String::as_str))
+        }
+    }
+
+    /// Starts the daily game: everyone playing with the same `words` on
+    /// the same `day` (counted from the Unix epoch) gets the same word.
+    pub fn for_day(words: &[String], day: u64) -> Self {
+        Self::from_seed(words, day)
+    }
+
+    /// Starts the Letterle of `day`, a warmup where the word is a single
+    /// letter found in up to [`LETTERLE_ATTEMPTS`] tries. Like
+    /// [`Game::for_day`], everyone gets the same letter on the same day.
+    pub fn letterle_for_day(day: u64) -> Self {
+        let letters = letterle_letters();
+        let letter = seeded_index(letters.len(), day).map_or("A", |index| &letters[index]);
+        Game {
+            seed: Some(day),
+            ..Self::with_config(GameConfig::new(1, LETTERLE_ATTEMPTS), letter)
+        }
+    }
+
+    /// Starts a game with a known target word, without touching the word
+    /// list. The word is uppercased and its accents folded; it should be
+    /// `WORD_LENGTH` letters long.
+    pub fn with_word(word: &str) -> Self {
+        Self::with_config(GameConfig::default(), word)
+    }
+
+    /// Starts a game with custom rules. `word` should have
+    /// `config.word_length` letters.
+    pub fn with_config(config: GameConfig, word: &str) -> Self {
+        Game {
+            config,
+            attempts: vec![Vec::new(); config.max_attempts],
+            letter_statuses: vec![
+                vec![LetterStatus::Unused; config.word_length];
+                config.max_attempts
+            ],
+            current_attempt: 0,
+            target_word: normalize::fold_word(word),
+            display_word: word.to_uppercase(),
+            status: GameStatus::Playing,
+            should_quit: false,
+            message: None,
+            message_timer: 0,
+            hints_used: 0,
+            continued: false,
+            seed: None,
+            undo_stack: Vec::new(),
+        }
+    }
+
+    /// Reads a word list, one word per line, keeping the `WORD_LENGTH`
+    /// letter words in uppercase (accents included). A missing file gives an
+    /// empty list.
+    pub fn load_words_from_file(filename: &str) -> Vec<String> {
+        Self::load_words(filename, WORD_LENGTH)
+    }
+
+    /// Like [`Game::load_words_from_file`], for words of any length.
+    pub fn load_words(filename: &str, word_length: usize) -> Vec<String> {
+        // Return empty vector if the file can't be read
+        let Ok(bytes) = fs::read(Path::new(filename)) else {
+            return Vec::new();
+        };
+
+        // A line that isn't UTF-8 is skipped, and the rest still read
+        let lines = bytes
+            .split(|&byte| byte == b'\n')
+            .filter_map(|line| std::str::from_utf8(line).ok());
+        filter_words(lines, word_length)
+    }
+
+    /// Like [`Game::load_words`], for a word list already in memory (for
+    /// instance one embedded in the binary).
+    pub fn parse_words(text: &str, word_length: usize) -> Vec<String> {
+        filter_words(text.lines(), word_length)
+    }
+
+    /// Moves the game to `next`, if [`GameStatus::can_become`] allows it
+    /// and the board agrees: a game is only won with the target on the
+    /// current row, only lost with every row used, and only played with a
+    /// row left to type in.
+    pub fn transition(&mut self, next: GameStatus) -> Result<(), TransitionError> {
+        let refused = TransitionError {
+            from: self.status,
+            to: next,
+        };
+        if !self.status.can_become(next) {
+            return Err(refused);
+        }
+        let board_agrees = match next {
+            GameStatus::Won => self
+                .attempts
+                .get(self.current_attempt)
+                .is_some_and(|row| row.iter().collect::<String>() == self.target_word),
+            GameStatus::Lost => self.current_attempt >= self.attempts.len(),
+            GameStatus::Playing => self.current_attempt < self.attempts.len(),
+            GameStatus::Quitting => true,
+        };
+        if !board_agrees {
+            return Err(refused);
+        }
+        self.status = next;
+        Ok(())
+    }
+
+    // A transition the rules make, which can only fail through a bug
+    fn enter(&mut self, next: GameStatus) {
+        let result = self.transition(next);
+        debug_assert!(result.is_ok(), "{}", result.unwrap_err());
+    }
+
+    /// Letters typed in row `row`, or `None` past the last row.
+    pub fn row(&self, row: usize) -> Option<&[char]> {
+        self.attempts.get(row).map(Vec::as_slice)
+    }
+
+    /// Feedback of row `row`, all [`LetterStatus::Unused`] until it is
+    /// submitted, or `None` past the last row.
+    pub fn row_statuses(&self, row: usize) -> Option<&[LetterStatus]> {
+        self.letter_statuses.get(row).map(Vec::as_slice)
+    }
+
+    /// Letter typed at `column` of `row`, if there is one.
+    pub fn letter(&self, row: usize, column: usize) -> Option<char> {
+        self.row(row)?.get(column).copied()
+    }
+
+    /// Feedback for `column` of `row`, if the board has that cell.
+    pub fn status_at(&self, row: usize, column: usize) -> Option<LetterStatus> {
+        self.row_statuses(row)?.get(column).copied()
+    }
+
+    /// Letters of the row being typed, or `None` once every row is used.
+    /// After a win it is the winning row.
+    pub fn current_row(&self) -> Option<&[char]> {
+        self.row(self.current_attempt)
+    }
+
+    fn current_row_mut(&mut self) -> Option<&mut Vec<char>> {
+        self.attempts.get_mut(self.current_attempt)
+    }
+
+    /// Appends a letter to the current attempt, if there is room.
+    pub fn input_letter(&mut self, c: char) {
+        if !self.status.accepts_input() {
+            return;
+        }
+
+        let word_length = self.config.word_length;
+        if let Some(row) = self.current_row_mut() {
+            if row.len() < word_length {
+                row.push(c);
+            }
+        }
+    }
+
+    /// Removes the last letter of the current attempt.
+    pub fn delete_letter(&mut self) {
+        if !self.status.accepts_input() {
+            return;
+        }
+
+        if let Some(row) = self.current_row_mut() {
+            row.pop();
+        }
+    }
+
+    /// Scores the current attempt and moves to the next row, updating
+    /// `status` when the game is won or lost. Incomplete attempts are ignored.
+    pub fn submit_guess(&mut self) {
+        if !self.status.accepts_input() {
+            return;
+        }
+
+        if self.current_row().map(<[char]>::len) != Some(self.config.word_length) {
+            return; // Incomplete word, or no row to submit
+        }
+
+        // Removed the check if the word is in the list to allow
+        // any attempt of the right length
+        // let current_word: String = self.attempts[self.current_attempt].iter().collect();
+        // if !WORDS.contains(&current_word.as_str()) {
+        //     return; // Word is not in the list
+        // }
+
+        self.checkpoint();
+
+        // Evaluate the guess
+        self.evaluate_guess();
+
+        // Check if won
+        if self
+            .current_row()
+            .is_some_and(|row| row.iter().collect::<String>() == self.target_word)
+        {
+            self.enter(GameStatus::Won);
+            return;
+        }
+
+        // Move to next attempt
+        self.current_attempt += 1;
+        self.out_of_rows();
+    }
+
+    // Loses the game once every row is used, or adds one in endless games
+    fn out_of_rows(&mut self) {
+        if self.current_attempt < self.attempts.len() {
+            return;
+        }
+        if self.config.endless {
+            self.add_row();
+        } else {
+            self.enter(GameStatus::Lost);
+        }
+    }
+
+    fn add_row(&mut self) {
+        self.attempts.push(Vec::new());
+        self.letter_statuses
+            .push(vec![LetterStatus::Unused; self.config.word_length]);
+    }
+
+    /// Gives up the current row, whatever was typed in it: it is filled
+    /// with [`FORFEITED`] letters, all absent, and counts as a guess. Used
+    /// by timed modes when a guess comes too late.
+    pub fn forfeit_row(&mut self) {
+        if !self.status.accepts_input() {
+            return;
+        }
+
+        let row = self.current_attempt;
+        if row >= self.attempts.len() || row >= self.letter_statuses.len() {
+            return;
+        }
+        self.checkpoint();
+        self.attempts[row] = vec![FORFEITED; self.config.word_length];
+        self.letter_statuses[row] = vec![LetterStatus::Absent; self.config.word_length];
+        self.current_attempt += 1;
+        self.out_of_rows();
+    }
+
+    // Remembers the board before a guess, in games where it can be undone
+    fn checkpoint(&mut self) {
+        if !self.config.undo {
+            return;
+        }
+        let snapshot = self.snapshot();
+        self.undo_stack.push(snapshot);
+    }
+
+    /// The board as it is now.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            attempts: self.attempts.clone(),
+            letter_statuses: self.letter_statuses.clone(),
+            current_attempt: self.current_attempt,
+            status: self.status,
+            continued: self.continued,
+        }
+    }
+
+    /// A copy of the game with the board of `snapshot`, to look at: the
+    /// same word and rules, without a message or guesses to undo.
+    pub fn at(&self, snapshot: &Snapshot) -> Game {
+        Game {
+            config: self.config,
+            attempts: snapshot.attempts.clone(),
+            letter_statuses: snapshot.letter_statuses.clone(),
+            current_attempt: snapshot.current_attempt,
+            target_word: self.target_word.clone(),
+            display_word: self.display_word.clone(),
+            status: snapshot.status,
+            should_quit: false,
+            message: None,
+            message_timer: 0,
+            hints_used: self.hints_used,
+            continued: snapshot.continued,
+            seed: self.seed,
+            undo_stack: Vec::new(),
+        }
+    }
+
+    /// Takes back the last submitted guess, in games configured with
+    /// [`GameConfig::undo`]: the board, keyboard and status go back to how
+    /// they were before it, with the guess typed in the current row again.
+    /// Winning or losing guesses can be taken back too. Returns whether
+    /// there was a guess to take back.
+    pub fn undo(&mut self) -> bool {
+        if self.status == GameStatus::Quitting {
+            return false;
+        }
+        let Some(snapshot) = self.undo_stack.pop() else {
+            return false;
+        };
+        self.attempts = snapshot.attempts;
+        self.letter_statuses = snapshot.letter_statuses;
+        self.current_attempt = snapshot.current_attempt;
+        // A rewind rather than a move of the game, so not a transition
+        self.status = snapshot.status;
+        self.continued = snapshot.continued;
+        true
+    }
+
+    /// How many guesses [`Game::undo`] can take back.
+    pub fn undo_depth(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Types and submits a whole word in one go, for frontends that don't
+    /// work key by key (bots, tests, scripts).
+    ///
+    /// Returns the feedback for the guess. Input is normalized like typed
+    /// letters, so lowercase and look-alike characters are accepted.
+    pub fn guess(&mut self, word: &str) -> Result<Feedback, GuessError> {
+        if !self.status.accepts_input() {
+            return Err(GuessError::GameOver);
+        }
+
+        let letters = normalize::normalize_input(word).map_err(GuessError::InvalidCharacter)?;
+        if letters.len() != self.config.word_length {
+            return Err(GuessError::WrongLength {
+                expected: self.config.word_length,
+                found: letters.len(),
+            });
+        }
+
+        let row = self.current_attempt;
+        let Some(current) = self.current_row_mut() else {
+            return Err(GuessError::GameOver);
+        };
+        *current = letters;
+        self.submit_guess();
+        Ok(Feedback {
+            letters: self.row(row).unwrap_or_default().to_vec(),
+            statuses: self.row_statuses(row).unwrap_or_default().to_vec(),
+        })
+    }
+
+    /// Letters and feedback of every submitted attempt, oldest first.
+    pub fn submitted(&self) -> impl Iterator<Item = (&[char], &[LetterStatus])> {
+        let submitted = match self.status {
+            // The winning row is not followed by a new attempt
+            GameStatus::Won => self.current_attempt + 1,
+            _ => self.current_attempt.min(self.attempts.len()),
+        };
+        self.attempts
+            .iter()
+            .zip(self.letter_statuses.iter())
+            .take(submitted)
+            .map(|(attempt, statuses)| (attempt.as_slice(), statuses.as_slice()))
+    }
+
+    /// Greens the submitted guess of row `row` found that no guess before
+    /// it had. `None` for a row not submitted yet, and when the feedback is
+    /// only counts, which don't say where the greens are.
+    pub fn new_greens(&self, row: usize) -> Option<usize> {
+        if self.config.feedback == FeedbackStyle::Counts {
+            return None;
+        }
+        let submitted: Vec<&[LetterStatus]> =
+            self.submitted().map(|(_, statuses)| statuses).collect();
+        let (statuses, earlier) = submitted.get(..=row)?.split_last()?;
+        let found = statuses
+            .iter()
+            .enumerate()
+            .filter(|&(slot, &status)| {
+                status == LetterStatus::Correct
+                    && !earlier
+                        .iter()
+                        .any(|before| before.get(slot) == Some(&LetterStatus::Correct))
+            })
+            .count();
+        Some(found)
+    }
+
+    /// Momentum: how many guesses in a row, up to the last one, each found
+    /// a new green.
+    pub fn momentum(&self) -> usize {
+        let rows = self.submitted().count();
+        (0..rows)
+            .rev()
+            .take_while(|&row| self.new_greens(row).is_some_and(|found| found > 0))
+            .count()
+    }
+
+    /// The longest momentum the game reached.
+    pub fn best_momentum(&self) -> usize {
+        let mut best = 0;
+        let mut run = 0;
+        for row in 0..self.submitted().count() {
+            run = match self.new_greens(row) {
+                Some(found) if found > 0 => run + 1,
+                _ => 0,
+            };
+            best = best.max(run);
+        }
+        best
+    }
+
+    /// Owned copy of the feedback for every submitted attempt.
+    pub fn history(&self) -> Vec<Feedback> {
+        self.submitted()
+            .map(|(letters, statuses)| Feedback {
+                letters: letters.to_vec(),
+                statuses: statuses.to_vec(),
+            })
+            .collect()
+    }
+
+    /// What the feedback so far says about the target, slot by slot.
+    pub fn constraints(&self) -> constraints::Constraints {
+        match self.config.feedback {
+            FeedbackStyle::Letters => {
+                constraints::Constraints::deduce(self.config.word_length, self.submitted())
+            }
+            // Counts don't say which letters they are about
+            FeedbackStyle::Counts => constraints::Constraints::deduce(self.config.word_length, []),
+        }
+    }
+
+    /// Whether `word` could still be the target: it would have produced
+    /// exactly the feedback received for every submitted attempt.
+    pub fn is_consistent(&self, word: &str) -> bool {
+        self.submitted()
+            .all(|(attempt, statuses)| match self.config.feedback {
+                FeedbackStyle::Letters => evaluate(attempt, word) == statuses,
+                FeedbackStyle::Counts => {
+                    Counts::of(&evaluate(attempt, word)) == Counts::of(statuses)
+                }
+            })
+    }
+
+    /// Suggests a random word from `words` that fits everything revealed so
+    /// far and hasn't been guessed yet, counting it as a hint.
+    ///
+    /// Returns `None` (and counts nothing) when the game is over or no word
+    /// fits.
+    pub fn hint<'w>(&mut self, words: &'w [String]) -> Option<&'w str> {
+        if !self.status.accepts_input() {
+            return None;
+        }
+
+        let guessed: Vec<String> = self
+            .submitted()
+            .map(|(attempt, _)| attempt.iter().collect())
+            .collect();
+        let candidates: Vec<&String> = words
+            .iter()
+            .filter(|word| !guessed.contains(word) && self.is_consistent(word))
+            .collect();
+
+        let word = candidates.choose(&mut rand::thread_rng())?;
+        self.hints_used += 1;
+        Some(word.as_str())
+    }
+
+    fn evaluate_guess(&mut self) {
+        let Some(row) = self.current_row() else {
+            return;
+        };
+        let statuses = evaluate(row, &self.target_word);
+        if let Some(current) = self.letter_statuses.get_mut(self.current_attempt) {
+            *current = statuses;
+        }
+    }
+
+    /// After a loss, adds one more row to keep guessing the same word, as
+    /// practice. Can be repeated after every further miss.
+    pub fn keep_trying(&mut self) {
+        if self.status != GameStatus::Lost {
+            return;
+        }
+        self.add_row();
+        self.enter(GameStatus::Playing);
+        self.continued = true;
+    }
+
+    /// Asks for exit confirmation, while playing.
+    pub fn quit(&mut self) {
+        let _ = self.transition(GameStatus::Quitting);
+    }
+
+    /// Goes back to the game after [`Game::quit`].
+    pub fn resume(&mut self) {
+        let _ = self.transition(GameStatus::Playing);
+    }
+
+    /// Shows a temporary message in the instructions area.
+    pub fn show_message(&mut self, text: impl Into<String>) {
+        self.message = Some(text.into());
+        self.message_timer = MESSAGE_TICKS;
+    }
+
+    /// Advances timers; called by the frontend at a fixed rate.
+    pub fn on_tick(&mut self) {
+        // Update the temporary message timer
+        if self.message_timer > 0 {
+            self.message_timer -= 1;
+            if self.message_timer == 0 {
+                self.message = None;
+            }
+        }
+    }
+
+    /// Best status seen so far for each letter A-Z, used to color the
+    /// virtual keyboard.
+    pub fn get_keyboard_status(&self) -> [LetterStatus; 26] {
+        let mut keyboard_status = [LetterStatus::Unused; 26];
+        // With counts only, no letter is known to be in or out
+        if self.config.feedback == FeedbackStyle::Counts {
+            return keyboard_status;
+        }
+
+        for (attempt, statuses) in self.submitted() {
+            for (letter, &current_status) in attempt.iter().zip(statuses) {
+                if !letter.is_ascii_uppercase() {
+                    continue;
+                }
+                let Some(best) = keyboard_status.get_mut((*letter as u8 - b'A') as usize) else {
+                    continue;
+                };
+                // Only update if the status is "better" than the current one
+                match (*best, current_status) {
+                    (LetterStatus::Unused, _) => *best = current_status,
+                    (LetterStatus::Absent, LetterStatus::Present | LetterStatus::Correct) => {
+                        *best = current_status
+                    }
+                    (LetterStatus::Present, LetterStatus::Correct) => *best = current_status,
+                    _ => {}
+                }
+            }
+        }
+
+        keyboard_status
+    }
+}
+
+/// Reason a guess passed to [`Game::guess`] was rejected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum GuessError {
+    /// The game is already won, lost or being quit.
+    GameOver,
+    /// The guess doesn't have the configured number of letters.
+    WrongLength { expected: usize, found: usize },
+    /// The guess contains a character that isn't a letter.
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuessError::GameOver => write!(f, "the game is over"),
+            GuessError::WrongLength { expected, found } => {
+                write!(f, "guess has {} letters, expected {}", found, expected)
+            }
+            GuessError::InvalidCharacter(c) => write!(f, "'{}' is not a letter", c),
+        }
+    }
+}
+
+impl std::error::Error for GuessError {}
+
+// Uppercases the lines and keeps the words of `word_length` letters (not
+// bytes: accented letters take more than one)
+fn filter_words<S: AsRef<str>>(lines: impl Iterator<Item = S>, word_length: usize) -> Vec<String> {
+    lines
+        .map(|line| line.as_ref().trim().to_uppercase())
+        .filter(|word| word.chars().count() == word_length)
+        .collect()
+}
+
+/// Scores a guess against the target word.
+///
+/// Letters in the right spot are `Correct`, letters that appear elsewhere in
+/// the target are `Present` (each target letter can only be claimed once, so
+/// duplicated guess letters are not over-reported), everything else is
+/// `Absent`. The result has one status per target letter; positions missing
+/// from a short guess stay `Unused`.
+pub fn evaluate(guess: &[char], target: &str) -> Vec<LetterStatus> {
+    let target: Vec<char> = target.chars().collect();
+    let length = target.len();
+    let mut statuses = vec![LetterStatus::Unused; length];
+    let mut used = vec![false; length];
+
+    // First step: mark correct letters
+    for (i, letter) in guess.iter().enumerate().take(length) {
+        if target.get(i) == Some(letter) {
+            statuses[i] = LetterStatus::Correct;
+            used[i] = true;
+        }
+    }
+
+    // Second step: mark letters present in another position
+    for (i, letter) in guess.iter().enumerate().take(length) {
+        if statuses[i] == LetterStatus::Correct {
+            continue;
+        }
+
+        let mut found = false;
+        for (j, target_letter) in target.iter().enumerate() {
+            if !used[j] && letter == target_letter {
+                statuses[i] = LetterStatus::Present;
+                used[j] = true;
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            statuses[i] = LetterStatus::Absent;
+        }
+    }
+
+    statuses
+}
+
+/// The "word list" of Letterle: the letters of the alphabet.
+pub fn letterle_letters() -> Vec<String> {
+    ('A'..='Z').map(String::from).collect()
+}
@@ -0,0 +1,120 @@
+//! Input normalization shared by every path a guess can take into the game
+//! (single keystrokes and bracketed pastes alike).
+//!
+//! Text copied from web pages often contains letters that look like A-Z but
+//! are different code points: full-width forms (Ａ), Cyrillic (А) or Greek (Α)
+//! capitals. Without folding them first they are either dropped or end up as
+//! guesses that can never match the target word.
+//!
+//! Accents are folded the same way (Á, Ã and À all become A, Ç becomes C),
+//! so players type plain letters and still match accented words like ÁGUAS
+//! or AVIÃO; the word list keeps the accented spelling for display.
+
+/// Folds a single character into an uppercase ASCII letter.
+///
+/// Returns `None` when the character has no sensible A-Z equivalent.
+pub fn normalize_letter(c: char) -> Option<char> {
+    if c.is_ascii_alphabetic() {
+        return Some(c.to_ascii_uppercase());
+    }
+
+    // Full-width Latin letters (U+FF21..U+FF3A, U+FF41..U+FF5A)
+    if ('\u{FF21}'..='\u{FF3A}').contains(&c) {
+        return char::from_u32(c as u32 - 0xFF21 + 'A' as u32);
+    }
+    if ('\u{FF41}'..='\u{FF5A}').contains(&c) {
+        return char::from_u32(c as u32 - 0xFF41 + 'A' as u32);
+    }
+
+    accented(c).or_else(|| homoglyph(c))
+}
+
+/// Folds a word into the plain uppercase letters guesses are compared
+/// with. Characters without an A-Z equivalent are kept, uppercased.
+pub fn fold_word(word: &str) -> String {
+    word.chars()
+        .flat_map(|c| match normalize_letter(c) {
+            Some(letter) => vec![letter],
+            None => c.to_uppercase().collect(),
+        })
+        .collect()
+}
+
+/// Normalizes a whole chunk of input (typically a paste).
+///
+/// Whitespace is ignored. On the first character that cannot be mapped to
+/// a letter, that character is returned as the error so the caller can tell
+/// the player exactly what was rejected.
+pub fn normalize_input(text: &str) -> Result<Vec<char>, char> {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| normalize_letter(c).ok_or(c))
+        .collect()
+}
+
+// Latin letters with diacritics, as used in Portuguese, Spanish and French
+fn accented(c: char) -> Option<char> {
+    let mapped = match c {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'A',
+        'Ç' | 'ç' => 'C',
+        'È' | 'É' | 'Ê' | 'Ë' | 'è' | 'é' | 'ê' | 'ë' => 'E',
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'ì' | 'í' | 'î' | 'ï' => 'I',
+        'Ñ' | 'ñ' => 'N',
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'O',
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'ù' | 'ú' | 'û' | 'ü' => 'U',
+        'Ý' | 'Ÿ' | 'ý' | 'ÿ' => 'Y',
+        _ => return None,
+    };
+    Some(mapped)
+}
+
+// Cyrillic and Greek letters that are visually identical to Latin ones
+fn homoglyph(c: char) -> Option<char> {
+    let mapped = match c {
+        // Cyrillic capitals
+        'А' => 'A',
+        'В' => 'B',
+        'Е' | 'Ё' => 'E',
+        'К' => 'K',
+        'М' => 'M',
+        'Н' => 'H',
+        'О' => 'O',
+        'Р' => 'P',
+        'С' => 'C',
+        'Т' => 'T',
+        'Х' => 'X',
+        'Ѕ' => 'S',
+        'І' => 'I',
+        'Ј' => 'J',
+        // Cyrillic small letters
+        'а' => 'A',
+        'е' | 'ё' => 'E',
+        'о' => 'O',
+        'р' => 'P',
+        'с' => 'C',
+        'у' => 'Y',
+        'х' => 'X',
+        'ѕ' => 'S',
+        'і' => 'I',
+        'ј' => 'J',
+        // Greek capitals
+        'Α' => 'A',
+        'Β' => 'B',
+        'Ε' => 'E',
+        'Ζ' => 'Z',
+        'Η' => 'H',
+        'Ι' => 'I',
+        'Κ' => 'K',
+        'Μ' => 'M',
+        'Ν' => 'N',
+        'Ο' => 'O',
+        'Ρ' => 'P',
+        'Τ' => 'T',
+        'Υ' => 'Y',
+        'Χ' => 'X',
+        // Greek small letters
+        'ο' => 'O',
+        _ => return None,
+    };
+    Some(mapped)
+}
@@ -0,0 +1,164 @@
+//! Precomputed feedback patterns for a word list.
+//!
+//! A [`PatternMatrix`] stores [`pattern`] for every guess × answer pair of a
+//! list, so the solver looks patterns up instead of scoring words over and
+//! over. Building it for a full dictionary takes seconds; the result is
+//! saved next to the word list and reused as long as the list is unchanged.
+//!
+//! File layout, integers little-endian: the magic `WPAT`, a format version
+//! byte, the checksum of the word list (`u64`), the number of words (`u32`),
+//! then one pattern byte per pair, row by row (guess-major).
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::solver::pattern;
+use crate::word_list_checksum;
+
+const MAGIC: &[u8; 4] = b"WPAT";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 4 + 1 + 8 + 4;
+/// Longest word whose patterns (3^len codes) fit in a byte.
+const MAX_WORD_LENGTH: usize = 5;
+
+pub struct PatternMatrix {
+    checksum: u64,
+    len: usize,
+    data: Vec<u8>,
+}
+
+impl PatternMatrix {
+    /// Scores every word of `words` against every other. Returns `None` if
+    /// the words are not uppercase ASCII of the same length, up to 5 letters.
+    pub fn build(words: &[String]) -> Option<Self> {
+        let word_length = words.first().map_or(0, String::len);
+        let valid = word_length <= MAX_WORD_LENGTH
+            && words.iter().all(|word| {
+                word.len() == word_length && word.bytes().all(|b| b.is_ascii_uppercase())
+            });
+        if !valid {
+            return None;
+        }
+
+        let mut data = vec![0u8; words.len() * words.len()];
+        if !words.is_empty() {
+            let fill_row = |(guess, row): (usize, &mut [u8])| {
+                for (cell, answer) in row.iter_mut().zip(words) {
+                    *cell = pattern(words[guess].as_bytes(), answer.as_bytes()) as u8;
+                }
+            };
+            #[cfg(feature = "parallel")]
+            {
+                use rayon::prelude::*;
+                data.par_chunks_mut(words.len())
+                    .enumerate()
+                    .for_each(fill_row);
+            }
+            #[cfg(not(feature = "parallel"))]
+            data.chunks_mut(words.len()).enumerate().for_each(fill_row);
+        }
+
+        Some(PatternMatrix {
+            checksum: word_list_checksum(words),
+            len: words.len(),
+            data,
+        })
+    }
+
+    /// Reads a matrix saved by [`PatternMatrix::save`]. Returns `Ok(None)`
+    /// if the file is missing, from another format version, or was built
+    /// for a different word list.
+    pub fn load(path: &Path, words: &[String]) -> io::Result<Option<Self>> {
+        let mut file = match fs::File::open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let mut header = [0u8; HEADER_LEN];
+        if file.read_exact(&mut header).is_err() {
+            return Ok(None);
+        }
+        let stored_checksum = u64::from_le_bytes(header[5..13].try_into().unwrap());
+        let stored_len = u32::from_le_bytes(header[13..17].try_into().unwrap()) as usize;
+        if &header[..4] != MAGIC
+            || header[4] != VERSION
+            || stored_len != words.len()
+            || stored_checksum != word_list_checksum(words)
+        {
+            return Ok(None);
+        }
+
+        let mut data = Vec::with_capacity(stored_len * stored_len);
+        file.read_to_end(&mut data)?;
+        if data.len() != stored_len * stored_len {
+            return Ok(None);
+        }
+
+        Ok(Some(PatternMatrix {
+            checksum: stored_checksum,
+            len: stored_len,
+            data,
+        }))
+    }
+
+    /// Writes the matrix to `path`, through a temporary file so a reader
+    /// never sees a partial matrix.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let tmp = path.with_extension("tmp");
+        {
+            let mut file = io::BufWriter::new(fs::File::create(&tmp)?);
+            file.write_all(MAGIC)?;
+            file.write_all(&[VERSION])?;
+            file.write_all(&self.checksum.to_le_bytes())?;
+            file.write_all(&(self.len as u32).to_le_bytes())?;
+            file.write_all(&self.data)?;
+            file.flush()?;
+        }
+        fs::rename(tmp, path)
+    }
+
+    /// Loads the cached matrix for `words` from `path`, or builds it and
+    /// caches it there. A cache that can't be written is not an error: the
+    /// matrix is still returned.
+    pub fn load_or_build(path: &Path, words: &[String]) -> Option<Self> {
+        if let Ok(Some(matrix)) = Self::load(path, words) {
+            return Some(matrix);
+        }
+        let matrix = Self::build(words)?;
+        let _ = matrix.save(path);
+        Some(matrix)
+    }
+
+    /// Where the cache for the word list at `words_file` lives: next to it,
+    /// with a `.patterns` extension.
+    pub fn cache_path(words_file: &Path) -> PathBuf {
+        words_file.with_extension("patterns")
+    }
+
+    /// Number of words in the list the matrix was built for.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether the matrix was built for exactly `words`.
+    pub fn is_for(&self, words: &[String]) -> bool {
+        self.len == words.len() && self.checksum == word_list_checksum(words)
+    }
+
+    /// Pattern of the word at index `guess` against the word at `answer`,
+    /// as returned by [`pattern`].
+    pub fn get(&self, guess: usize, answer: usize) -> u8 {
+        self.data[guess * self.len + answer]
+    }
+
+    /// Patterns of the word at index `guess` against every word.
+    pub fn row(&self, guess: usize) -> &[u8] {
+        &self.data[guess * self.len..(guess + 1) * self.len]
+    }
+}
@@ -0,0 +1,115 @@
+//! How common each word of a list is, as a badge: common, uncommon or rare.
+//!
+//! Words are ranked by a frequency list shipped next to the word list as a
+//! `.frequency` file, one word per line from the most used down (anything
+//! after the word, like a count, is ignored). Without one, words are ranked
+//! by how common their letters are in the list, which tells `CRANE` from
+//! `JUMPY` well enough.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::difficulty::{letter_frequencies, letter_rarity};
+use crate::normalize::fold_word;
+
+/// Share of a ranking, from the top, whose words are common.
+const COMMON_SHARE: f32 = 0.4;
+/// Share of a ranking, from the top, whose words are common or uncommon;
+/// the rest are rare.
+const UNCOMMON_SHARE: f32 = 0.8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+}
+
+impl Rarity {
+    /// How many times over a win on a word this rare counts in a score.
+    pub fn weight(self) -> u32 {
+        match self {
+            Rarity::Common => 1,
+            Rarity::Uncommon => 2,
+            Rarity::Rare => 3,
+        }
+    }
+}
+
+/// Words ranked from the most common.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Frequencies {
+    ranks: HashMap<String, usize>,
+}
+
+impl Frequencies {
+    /// Reads a frequency list, the most used word first. Words are folded
+    /// like guesses; a word listed twice keeps its first rank.
+    pub fn parse(text: &str) -> Frequencies {
+        let mut ranks = HashMap::new();
+        for word in text
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+        {
+            let rank = ranks.len();
+            ranks.entry(fold_word(word)).or_insert(rank);
+        }
+        Frequencies { ranks }
+    }
+
+    /// Ranks `words` (uppercase, accents folded) by how common their
+    /// letters are among them, for lists without a frequency list.
+    pub fn from_letters(words: &[String]) -> Frequencies {
+        let frequencies = letter_frequencies(words);
+        let mut ranked: Vec<(&String, f32)> = words
+            .iter()
+            .map(|word| (word, letter_rarity(word, &frequencies)))
+            .collect();
+        ranked.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+        let ranks = ranked
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (word, _))| (word.clone(), rank))
+            .collect();
+        Frequencies { ranks }
+    }
+
+    pub fn load(path: &Path) -> io::Result<Frequencies> {
+        Ok(Self::parse(&fs::read_to_string(path)?))
+    }
+
+    /// Where the frequency list of the word list at `words_file` is kept.
+    pub fn path_for(words_file: &Path) -> PathBuf {
+        words_file.with_extension("frequency")
+    }
+
+    /// How rare `word` (uppercase, accents folded) is: by where it ranks,
+    /// and rare when it isn't ranked at all. `None` without a ranking.
+    pub fn rarity(&self, word: &str) -> Option<Rarity> {
+        if self.ranks.is_empty() {
+            return None;
+        }
+        let Some(&rank) = self.ranks.get(word) else {
+            return Some(Rarity::Rare);
+        };
+        let share = rank as f32 / self.ranks.len() as f32;
+        let rarity = if share < COMMON_SHARE {
+            Rarity::Common
+        } else if share < UNCOMMON_SHARE {
+            Rarity::Uncommon
+        } else {
+            Rarity::Rare
+        };
+        Some(rarity)
+    }
+
+    pub fn len(&self) -> usize {
+        self.ranks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranks.is_empty()
+    }
+}
@@ -0,0 +1,80 @@
+//! Drawing words from seeds, the same way on every platform and in every
+//! release.
+//!
+//! A word is drawn from a list by its seed alone: [`seeded_index`] mixes
+//! the seed with SplitMix64 and takes it modulo the length of the list,
+//! all in `u64`, so neither the platform's `usize` nor the `rand` crate's
+//! algorithms play a part. The word of the day is the draw with the day
+//! number (days since 1970-01-01, UTC) as seed. Which list a word was
+//! drawn from is told by [`word_list_checksum`], an FNV-1a hash of the
+//! list: the same seed, word length and checksum always give the same
+//! word.
+//!
+//! These functions are pinned by the vectors in
+//! `tests/vectors/determinism.txt`; changing what they return breaks games
+//! logged, shared or printed before, so it needs a new scheme rather than
+//! an edit.
+
+/// Index of the word of a `len` word list that `seed` picks, the same on
+/// every platform and release. `None` for an empty list.
+pub fn seeded_index(len: usize, seed: u64) -> Option<usize> {
+    // Mixing the seed keeps consecutive seeds (days) far apart in the list
+    (len > 0).then(|| (splitmix64(seed) % len as u64) as usize)
+}
+
+/// Checksum of a word list, to tell whether a seed is replayed against the
+/// same list it was drawn from.
+pub fn word_list_checksum(words: &[String]) -> u64 {
+    // The words and their separators
+    fnv1a(words.iter().flat_map(|word| word.bytes().chain([b'\n'])))
+}
+
+/// A seed written for people to share: base 36, in uppercase.
+pub fn seed_code(seed: u64) -> String {
+    let mut digits = Vec::new();
+    let mut rest = seed;
+    loop {
+        digits.push(
+            char::from_digit((rest % 36) as u32, 36)
+                .unwrap()
+                .to_ascii_uppercase(),
+        );
+        rest /= 36;
+        if rest == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+/// The seed a shared code stands for: the seed [`seed_code`] wrote, in any
+/// case, or for any other text (`friday-race`) a seed made from it, so
+/// friends can agree on a word of their own. `None` for blank codes.
+pub fn seed_from_code(code: &str) -> Option<u64> {
+    let code = code.trim();
+    if code.is_empty() {
+        return None;
+    }
+    u64::from_str_radix(code, 36)
+        .ok()
+        .or_else(|| Some(fnv1a(code.to_lowercase().bytes())))
+}
+
+// FNV-1a: a hash that stays the same on every platform and release
+fn fnv1a(bytes: impl Iterator<Item = u8>) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+// SplitMix64 finalizer: spreads nearby inputs over the whole range, the
+// same on every platform and release
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
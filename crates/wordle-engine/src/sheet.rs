@@ -0,0 +1,59 @@
+//! Printable puzzle sheets, for playing on paper: one blank grid per
+//! puzzle with the alphabet under it to cross letters out, and the answers
+//! at the bottom in ROT13 so they can't be read at a glance.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::normalize::fold_word;
+
+/// Picks `count` different answers from `words`, accents folded. Fewer
+/// come back if the list is shorter.
+pub fn draw_answers(words: &[String], count: usize, rng: &mut impl Rng) -> Vec<String> {
+    words
+        .choose_multiple(rng, count)
+        .map(|word| fold_word(word))
+        .collect()
+}
+
+/// The sheet for `answers`, with `max_attempts` rows per grid, as plain
+/// text.
+pub fn render(answers: &[String], max_attempts: usize) -> String {
+    let mut sheet = String::new();
+    for (number, answer) in answers.iter().enumerate() {
+        let slots = answer.chars().count();
+        let border = format!("     {}+", "+---".repeat(slots));
+        let row = format!("     {}|", "|   ".repeat(slots));
+
+        sheet.push_str(&format!("{:>3}.\n", number + 1));
+        sheet.push_str(&border);
+        sheet.push('\n');
+        for _ in 0..max_attempts {
+            sheet.push_str(&row);
+            sheet.push('\n');
+            sheet.push_str(&border);
+            sheet.push('\n');
+        }
+        let alphabet: Vec<String> = ('A'..='Z').map(String::from).collect();
+        sheet.push_str(&format!("     {}\n\n", alphabet.join(" ")));
+    }
+
+    let answers: Vec<String> = answers
+        .iter()
+        .enumerate()
+        .map(|(number, answer)| format!("{}. {}", number + 1, rot13(answer)))
+        .collect();
+    sheet.push_str(&format!("Answers (ROT13): {}\n", answers.join("  ")));
+    sheet
+}
+
+/// Shifts every ASCII letter 13 places, which both hides and reveals.
+pub fn rot13(word: &str) -> String {
+    word.chars()
+        .map(|c| match c {
+            'A'..='Z' => (((c as u8 - b'A') + 13) % 26 + b'A') as char,
+            'a'..='z' => (((c as u8 - b'a') + 13) % 26 + b'a') as char,
+            _ => c,
+        })
+        .collect()
+}
@@ -0,0 +1,230 @@
+//! Guess suggestions ranked by expected information gain.
+//!
+//! The words that can still be the answer come from a
+//! [`CandidateSet`](crate::candidates::CandidateSet) narrowed by the feedback
+//! so far. Every possible guess is then scored by the entropy of the
+//! feedback patterns it would produce over those candidates:
+//! a guess that splits the candidates into many small groups tells the
+//! player more, on average, than one that leaves them in a big lump.
+
+use std::collections::HashSet;
+
+use crate::candidates::CandidateSet;
+use crate::patterns::PatternMatrix;
+
+/// Guesses scored per ranking when the word list is large. The pool is made
+/// of the words whose letters are most common among the candidates, which is
+/// where the best guesses are almost always found.
+const GUESS_POOL: usize = 500;
+/// Candidates used to estimate the entropy of a guess. Larger candidate sets
+/// are sampled evenly, which keeps the ranking fast on the opening move.
+const ANSWER_SAMPLE: usize = 2000;
+
+/// A ranked guess.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub word: String,
+    /// Expected information gained by playing this word, in bits.
+    pub entropy: f64,
+    /// Whether the word itself can still be the answer.
+    pub candidate: bool,
+}
+
+/// Ranks guesses from `words` by the expected information they give about
+/// `candidates`, best first, returning at most `limit` of them.
+///
+/// Ties are broken in favor of words that can still be the answer, since
+/// those also have a chance of winning outright.
+pub fn rank_guesses(words: &[String], candidates: &[&str], limit: usize) -> Vec<Suggestion> {
+    if candidates.is_empty() {
+        return Vec::new();
+    }
+    if candidates.len() <= 2 {
+        // Nothing to learn: just guess one of them
+        return candidates
+            .iter()
+            .take(limit)
+            .map(|word| Suggestion {
+                word: word.to_string(),
+                entropy: if candidates.len() == 2 { 1.0 } else { 0.0 },
+                candidate: true,
+            })
+            .collect();
+    }
+
+    let step = candidates.len().div_ceil(ANSWER_SAMPLE);
+    let answers: Vec<&[u8]> = candidates
+        .iter()
+        .step_by(step)
+        .map(|word| word.as_bytes())
+        .collect();
+    let candidate_set: HashSet<&str> = candidates.iter().copied().collect();
+
+    let pool = guess_pool(words, candidates);
+    let suggestions = score_each(pool.len(), |i| Suggestion {
+        word: pool[i].to_string(),
+        entropy: entropy(pool[i].as_bytes(), &answers),
+        candidate: candidate_set.contains(pool[i]),
+    });
+    best(suggestions, limit)
+}
+
+/// Like [`rank_guesses`], but with the patterns looked up in `matrix`,
+/// which must have been built for the candidates' word list. Lookups are
+/// cheap enough to score every word of the list, not just a pool.
+pub fn rank_guesses_cached(
+    matrix: &PatternMatrix,
+    candidates: &CandidateSet,
+    limit: usize,
+) -> Vec<Suggestion> {
+    let words = candidates.word_list();
+    debug_assert!(matrix.is_for(words));
+    if candidates.len() <= 2 {
+        let candidates: Vec<&str> = candidates.iter().collect();
+        return rank_guesses(words, &candidates, limit);
+    }
+
+    let step = candidates.len().div_ceil(ANSWER_SAMPLE);
+    let answers: Vec<usize> = candidates.indices().step_by(step).collect();
+
+    let suggestions = score_each(words.len(), |guess| {
+        let mut histogram = [0u32; 256];
+        let row = matrix.row(guess);
+        for &answer in &answers {
+            histogram[row[answer] as usize] += 1;
+        }
+        Suggestion {
+            word: words[guess].clone(),
+            entropy: histogram_entropy(&histogram, answers.len()),
+            candidate: candidates.contains(guess),
+        }
+    });
+    best(suggestions, limit)
+}
+
+// Scores guesses 0..count, spread over every core with the `parallel`
+// feature
+fn score_each<F>(count: usize, score: F) -> Vec<Suggestion>
+where
+    F: Fn(usize) -> Suggestion + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        (0..count).into_par_iter().map(score).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        (0..count).map(score).collect()
+    }
+}
+
+// The `limit` best suggestions, best first
+fn best(mut suggestions: Vec<Suggestion>, limit: usize) -> Vec<Suggestion> {
+    suggestions.sort_by(|a, b| {
+        b.entropy
+            .total_cmp(&a.entropy)
+            .then(b.candidate.cmp(&a.candidate))
+            .then(a.word.cmp(&b.word))
+    });
+    suggestions.truncate(limit);
+    suggestions
+}
+
+/// Feedback pattern of `guess` against `answer` as a base-3 number (0 for
+/// absent, 1 for present, 2 for correct, first letter most significant).
+/// Both words must be uppercase ASCII of the same length.
+pub fn pattern(guess: &[u8], answer: &[u8]) -> u32 {
+    let mut unmatched = [0u8; 26];
+    let mut digits = [0u8; 16];
+
+    for (i, (&g, &a)) in guess.iter().zip(answer).enumerate() {
+        if g == a {
+            digits[i] = 2;
+        } else if a.is_ascii_uppercase() {
+            unmatched[(a - b'A') as usize] += 1;
+        }
+    }
+    for (i, &g) in guess.iter().enumerate() {
+        if digits[i] == 0 && g.is_ascii_uppercase() {
+            let idx = (g - b'A') as usize;
+            if unmatched[idx] > 0 {
+                unmatched[idx] -= 1;
+                digits[i] = 1;
+            }
+        }
+    }
+
+    digits[..guess.len()]
+        .iter()
+        .fold(0, |code, &digit| code * 3 + digit as u32)
+}
+
+/// Entropy in bits of the feedback patterns `guess` produces over `answers`.
+pub fn entropy(guess: &[u8], answers: &[&[u8]]) -> f64 {
+    let mut histogram = vec![0u32; 3usize.pow(guess.len() as u32)];
+    for answer in answers {
+        histogram[pattern(guess, answer) as usize] += 1;
+    }
+    histogram_entropy(&histogram, answers.len())
+}
+
+// Entropy of the distribution of `total` answers over pattern counts
+fn histogram_entropy(histogram: &[u32], total: usize) -> f64 {
+    let total = total as f64;
+    histogram
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+// Guesses worth scoring: everything when the list is small, otherwise the
+// words covering the most frequent letters among the candidates
+fn guess_pool<'w>(words: &'w [String], candidates: &[&'w str]) -> Vec<&'w str> {
+    if words.len() <= GUESS_POOL {
+        return words.iter().map(String::as_str).collect();
+    }
+
+    let mut frequency = [0u32; 26];
+    for word in candidates {
+        let mut seen = [false; 26];
+        for idx in word.chars().filter_map(letter_index) {
+            if !seen[idx] {
+                frequency[idx] += 1;
+                seen[idx] = true;
+            }
+        }
+    }
+
+    let coverage = |word: &str| {
+        let mut seen = [false; 26];
+        word.chars()
+            .filter_map(letter_index)
+            .filter(|&idx| !std::mem::replace(&mut seen[idx], true))
+            .map(|idx| frequency[idx])
+            .sum::<u32>()
+    };
+
+    let mut pool: Vec<&str> = words.iter().map(String::as_str).collect();
+    pool.sort_by_cached_key(|word| std::cmp::Reverse(coverage(word)));
+    pool.truncate(GUESS_POOL);
+
+    // Candidates are always worth a look, they can win outright
+    let mut in_pool: HashSet<&str> = pool.iter().copied().collect();
+    for &word in candidates.iter().take(GUESS_POOL) {
+        if in_pool.insert(word) {
+            pool.push(word);
+        }
+    }
+    pool
+}
+
+fn letter_index(letter: char) -> Option<usize> {
+    letter
+        .is_ascii_uppercase()
+        .then(|| (letter as u8 - b'A') as usize)
+}
@@ -0,0 +1,107 @@
+//! Keystroke timing of a game, to tell people typing from scripts and
+//! pastes when a result goes to a leaderboard.
+//!
+//! The frontend records how long passed between the letters of each guess;
+//! [`TimingSignature::assess`] then looks for what people can't do: typing
+//! faster than a key repeat, or with a metronome's regularity. The same
+//! check can run again wherever the signature is sent, so a modified client
+//! can't just claim to be human.
+
+use std::time::Duration;
+
+/// Gaps between letters below this are not typed by hand.
+const INSTANT_MS: u32 = 15;
+/// Coefficient of variation below which gaps are too regular to be human.
+const UNIFORM_VARIATION: f64 = 0.1;
+/// Gaps needed before regularity means anything.
+const MIN_SAMPLES: usize = 8;
+
+/// How a guess was entered.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GuessTiming {
+    /// Milliseconds between consecutive letters.
+    pub intervals_ms: Vec<u32>,
+    /// Whether any of its letters came from a paste.
+    pub pasted: bool,
+}
+
+/// Timing of every submitted guess of a game.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimingSignature {
+    pub guesses: Vec<GuessTiming>,
+    current: GuessTiming,
+}
+
+/// How much a signature looks like a person typing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Confidence {
+    /// Nothing unusual.
+    Human,
+    /// Pasted guesses or very regular typing: fine to play with, worth
+    /// filtering out of competitive rankings.
+    Suspicious,
+    /// Letters entered faster than anyone types.
+    Automated,
+}
+
+impl TimingSignature {
+    /// Records a letter typed `since_previous` after the previous one of
+    /// the same guess (`None` for the first letter).
+    pub fn letter(&mut self, since_previous: Option<Duration>) {
+        if let Some(gap) = since_previous {
+            let ms = gap.as_millis().min(u32::MAX as u128) as u32;
+            self.current.intervals_ms.push(ms);
+        }
+    }
+
+    /// Marks the guess being typed as (partly) pasted.
+    pub fn paste(&mut self) {
+        self.current.pasted = true;
+    }
+
+    /// Closes the guess being typed, once it has been submitted.
+    pub fn submit(&mut self) {
+        self.guesses.push(std::mem::take(&mut self.current));
+    }
+
+    /// Judges the submitted guesses.
+    pub fn assess(&self) -> Confidence {
+        let intervals: Vec<u32> = self
+            .guesses
+            .iter()
+            .flat_map(|guess| guess.intervals_ms.iter().copied())
+            .collect();
+
+        if !intervals.is_empty() && median(&intervals) < INSTANT_MS {
+            return Confidence::Automated;
+        }
+        if self.guesses.iter().any(|guess| guess.pasted) {
+            return Confidence::Suspicious;
+        }
+        if intervals.len() >= MIN_SAMPLES && variation(&intervals) < UNIFORM_VARIATION {
+            return Confidence::Suspicious;
+        }
+        Confidence::Human
+    }
+}
+
+fn median(values: &[u32]) -> u32 {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    sorted[sorted.len() / 2]
+}
+
+// Standard deviation over mean
+fn variation(values: &[u32]) -> f64 {
+    let n = values.len() as f64;
+    let mean = values.iter().map(|&v| v as f64).sum::<f64>() / n;
+    if mean == 0.0 {
+        return 0.0;
+    }
+    let variance = values
+        .iter()
+        .map(|&v| (v as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n;
+    variance.sqrt() / mean
+}
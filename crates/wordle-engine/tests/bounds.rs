@@ -0,0 +1,74 @@
+use wordle_engine::{Game, GameConfig, GameStatus, GuessError, LetterStatus};
+
+fn lost(word: &str) -> Game {
+    let mut game = Game::with_config(GameConfig::new(5, 2), word);
+    game.guess("SLATE").unwrap();
+    game.guess("PRICE").unwrap();
+    assert_eq!(game.status, GameStatus::Lost);
+    game
+}
+
+#[test]
+fn the_board_reads_none_past_its_edges() {
+    let mut game = Game::with_word("CRANE");
+    game.guess("SLATE").unwrap();
+    assert_eq!(game.row(0), Some(&['S', 'L', 'A', 'T', 'E'][..]));
+    assert_eq!(game.letter(0, 4), Some('E'));
+    assert_eq!(game.status_at(0, 2), Some(LetterStatus::Correct));
+    assert_eq!(game.status_at(1, 0), Some(LetterStatus::Unused));
+
+    assert_eq!(game.letter(0, 5), None);
+    assert_eq!(game.letter(1, 0), None);
+    assert_eq!(game.status_at(0, 5), None);
+    assert_eq!(game.row(6), None);
+    assert_eq!(game.row_statuses(6), None);
+}
+
+#[test]
+fn a_lost_game_has_no_row_to_type_in() {
+    let mut game = lost("CRANE");
+    assert_eq!(game.current_row(), None);
+
+    // None of these has a row to touch, so nothing happens
+    game.input_letter('A');
+    game.delete_letter();
+    game.submit_guess();
+    game.forfeit_row();
+    assert_eq!(game.guess("CRANE"), Err(GuessError::GameOver));
+    assert_eq!(game.status, GameStatus::Lost);
+    assert_eq!(game.history().len(), 2);
+}
+
+#[test]
+fn a_won_game_keeps_its_winning_row() {
+    let mut game = Game::with_word("CRANE");
+    game.guess("SLATE").unwrap();
+    game.guess("CRANE").unwrap();
+    assert_eq!(game.current_row(), Some(&['C', 'R', 'A', 'N', 'E'][..]));
+    // The winning row counts on the keyboard too
+    assert_eq!(
+        game.get_keyboard_status()[(b'C' - b'A') as usize],
+        LetterStatus::Correct
+    );
+    game.input_letter('A');
+    assert_eq!(game.current_row().map(<[char]>::len), Some(5));
+}
+
+#[test]
+fn rows_of_the_wrong_length_are_scored_without_panicking() {
+    // A board edited from outside, as a frontend or a test might
+    let mut game = Game::with_word("CRANE");
+    game.attempts[0] = vec!['C', 'R', 'A', 'N', 'E', 'S', 'X'];
+    game.letter_statuses[0] = vec![LetterStatus::Correct];
+    game.current_attempt = 1;
+    let keyboard = game.get_keyboard_status();
+    assert_eq!(keyboard[(b'C' - b'A') as usize], LetterStatus::Correct);
+    assert_eq!(keyboard[(b'R' - b'A') as usize], LetterStatus::Unused);
+    assert_eq!(game.status_at(0, 3), None);
+
+    let mut game = Game::with_word("CRANE");
+    game.current_attempt = 9;
+    game.input_letter('A');
+    game.submit_guess();
+    assert_eq!(game.current_row(), None);
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use wordle_engine::candidates::CandidateSet;
+use wordle_engine::constraints::Constraints;
+use wordle_engine::{evaluate, LetterStatus};
+
+fn word_list(words: &[&str]) -> Arc<[String]> {
+    words.iter().map(|w| w.to_string()).collect()
+}
+
+fn letters(word: &str) -> Vec<char> {
+    word.chars().collect()
+}
+
+#[test]
+fn starts_with_every_word() {
+    let words = word_list(&["CRANE", "SLATE", "TRACE", "CRATE"]);
+    let set = CandidateSet::new(words);
+    assert_eq!(set.len(), 4);
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        ["CRANE", "SLATE", "TRACE", "CRATE"]
+    );
+}
+
+#[test]
+fn apply_keeps_only_consistent_words() {
+    let words = word_list(&["CRANE", "SLATE", "TRACE", "CRATE", "GRACE"]);
+    let mut set = CandidateSet::new(words);
+
+    let guess = letters("CRATE");
+    set.apply(&guess, &evaluate(&guess, "TRACE"));
+
+    // Every remaining word gives the same feedback the target gave
+    assert!(set.contains_word("TRACE"));
+    for word in set.iter() {
+        assert_eq!(evaluate(&guess, word), evaluate(&guess, "TRACE"));
+    }
+    assert!(!set.contains_word("CRATE"));
+    assert!(!set.contains_word("SLATE"));
+}
+
+#[test]
+fn apply_handles_repeated_letters() {
+    let words = word_list(&["SPEED", "ABIDE", "STEEP", "EERIE"]);
+    let mut set = CandidateSet::new(words);
+
+    // Second E of the guess is gray: the target has exactly one E
+    let guess = letters("EERIE");
+    set.apply(&guess, &evaluate(&guess, "ABIDE"));
+
+    assert_eq!(set.iter().collect::<Vec<_>>(), ["ABIDE"]);
+}
+
+#[test]
+fn narrowing_is_incremental() {
+    let words = word_list(&["CRANE", "SLATE", "TRACE", "CRATE", "GRACE", "BRACE"]);
+    let mut set = CandidateSet::new(words);
+
+    let first = letters("SLATE");
+    set.apply(&first, &evaluate(&first, "BRACE"));
+    let after_first = set.len();
+
+    let second = letters("GRACE");
+    set.apply(&second, &evaluate(&second, "BRACE"));
+
+    assert!(set.len() <= after_first);
+    assert_eq!(set.iter().collect::<Vec<_>>(), ["BRACE"]);
+}
+
+#[test]
+fn clones_are_independent() {
+    let words = word_list(&["CRANE", "SLATE", "TRACE"]);
+    let full = CandidateSet::new(words);
+    let mut narrowed = full.clone();
+
+    let guess = letters("SLATE");
+    narrowed.apply(&guess, &evaluate(&guess, "SLATE"));
+
+    assert_eq!(full.len(), 3);
+    assert_eq!(narrowed.len(), 1);
+    assert!(Arc::ptr_eq(full.word_list(), narrowed.word_list()));
+}
+
+#[test]
+fn bitset_covers_lists_longer_than_a_block() {
+    let words: Arc<[String]> = (0..150).map(|i| format!("W{:04}", i)).collect();
+    let mut set = CandidateSet::new(words);
+    assert_eq!(set.len(), 150);
+    assert_eq!(set.indices().last(), Some(149));
+    assert!(!set.contains(150));
+
+    let guess = letters("W0149");
+    set.apply(&guess, &[LetterStatus::Correct; 5]);
+    assert_eq!(set.indices().collect::<Vec<_>>(), [149]);
+}
+
+#[test]
+fn incomplete_feedback_falls_back_to_full_evaluation() {
+    let words = word_list(&["CRANE", "CRATE"]);
+    let mut set = CandidateSet::new(words);
+
+    let guess = letters("CRA");
+    set.apply(&guess, &evaluate(&guess, "CRANE"));
+
+    assert_eq!(set.iter().collect::<Vec<_>>(), ["CRANE", "CRATE"]);
+}
+
+#[test]
+fn every_candidate_fits_the_constraints_shown() {
+    let words = word_list(&[
+        "CRANE", "SLATE", "TRACE", "CRATE", "GRACE", "LEVEL", "EERIE", "STEEL", "SHEET",
+    ]);
+    for target in words.iter() {
+        let mut set = CandidateSet::new(words.clone());
+        let guesses: Vec<(Vec<char>, Vec<LetterStatus>)> = ["EERIE", "CRATE"]
+            .iter()
+            .map(|guess| (letters(guess), evaluate(&letters(guess), target)))
+            .collect();
+        for (guess, statuses) in &guesses {
+            set.apply(guess, statuses);
+        }
+        let constraints = Constraints::deduce(
+            5,
+            guesses
+                .iter()
+                .map(|(guess, statuses)| (guess.as_slice(), statuses.as_slice())),
+        );
+        assert!(set.contains_word(target));
+        for word in set.iter() {
+            let word = letters(word);
+            for (slot, &letter) in word.iter().enumerate() {
+                assert!(constraints.known[slot].is_none_or(|known| known == letter));
+                assert!(!constraints.excluded[slot].contains(&letter));
+                assert!(!constraints.absent.contains(&letter));
+            }
+            for letter in &constraints.elsewhere {
+                let needed = constraints
+                    .elsewhere
+                    .iter()
+                    .filter(|&l| l == letter)
+                    .count()
+                    + constraints
+                        .known
+                        .iter()
+                        .filter(|&&k| k == Some(*letter))
+                        .count();
+                assert!(word.iter().filter(|&l| l == letter).count() >= needed);
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Checks the seed functions against the conformance vectors in
+//! `tests/vectors/determinism.txt`.
+
+use wordle_engine::{seed_code, seed_from_code, seeded_index, word_list_checksum, Game};
+
+const VECTORS: &str = include_str!("vectors/determinism.txt");
+
+#[test]
+fn seeds_draw_what_the_vectors_pin() {
+    let mut checked = 0;
+    for (number, line) in VECTORS.lines().enumerate() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let at = format!("vectors/determinism.txt:{}", number + 1);
+        match fields.as_slice() {
+            [] => continue,
+            [comment, ..] if comment.starts_with('#') => continue,
+            ["index", len, seed, index] => assert_eq!(
+                seeded_index(len.parse().unwrap(), seed.parse().unwrap()),
+                Some(index.parse().unwrap()),
+                "{}",
+                at
+            ),
+            ["checksum", words, checksum] => {
+                let words: Vec<String> = match *words {
+                    "-" => Vec::new(),
+                    words => words.split(',').map(String::from).collect(),
+                };
+                assert_eq!(
+                    word_list_checksum(&words),
+                    checksum.parse::<u64>().unwrap(),
+                    "{}",
+                    at
+                );
+            }
+            ["code", seed, code] => {
+                let seed: u64 = seed.parse().unwrap();
+                assert_eq!(seed_code(seed), *code, "{}", at);
+                assert_eq!(seed_from_code(code), Some(seed), "{}", at);
+            }
+            ["text", text, seed] => assert_eq!(
+                seed_from_code(&text.replace('_', " ")),
+                Some(seed.parse().unwrap()),
+                "{}",
+                at
+            ),
+            ["letterle", day, letter] => assert_eq!(
+                Game::letterle_for_day(day.parse().unwrap()).target_word,
+                *letter,
+                "{}",
+                at
+            ),
+            _ => panic!("{}: unknown vector '{}'", at, line),
+        }
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
+
+#[test]
+fn no_list_has_nothing_to_draw() {
+    assert_eq!(seeded_index(0, 20454), None);
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use wordle_engine::dictionary::{Dictionary, Tier};
+use wordle_engine::rarity::{Frequencies, Rarity};
+use wordle_engine::Game;
+
+fn words() -> Arc<[String]> {
+    include_str!("../../../data/words.txt")
+        .lines()
+        .map(str::to_uppercase)
+        .collect()
+}
+
+#[test]
+fn words_are_tiered_by_how_common_they_are() {
+    let words = words();
+    let dictionary = Dictionary::new(words.clone(), &Frequencies::from_letters(&words));
+    assert_eq!(Tier::from(Rarity::Common), Tier::Easy);
+    assert_eq!(dictionary.tier("AROSE"), Some(Tier::Easy));
+    assert_eq!(dictionary.tier("jazzy"), Some(Tier::Hard));
+    assert_eq!(dictionary.tier("NOTAWORD"), None);
+    let counted: usize = Tier::ALL.iter().map(|&tier| dictionary.count(tier)).sum();
+    assert_eq!(counted, words.len());
+    assert!(Tier::ALL.iter().all(|&tier| dictionary.count(tier) > 0));
+
+    // A frequency list ranks the words instead of their letters
+    let list = Dictionary::new(
+        ["THE".to_string(), "OF".to_string(), "ZEBRA".to_string()].into(),
+        &Frequencies::parse("the\nof\nand\nsour\nvoilà"),
+    );
+    assert_eq!(list.tier("THE"), Some(Tier::Easy));
+    assert_eq!(list.tier("ZEBRA"), Some(Tier::Hard));
+    assert_eq!(
+        Dictionary::new(words, &Frequencies::default()).tier("AROSE"),
+        None
+    );
+}
+
+#[test]
+fn a_tier_moves_the_seed_to_a_word_of_it() {
+    let words = words();
+    let dictionary = Dictionary::new(words.clone(), &Frequencies::from_letters(&words));
+    for seed in 0..50 {
+        assert_eq!(
+            dictionary.draw(seed, None).target_word,
+            Game::from_seed(&words, seed).target_word
+        );
+        for tier in Tier::ALL {
+            let game = dictionary.draw(seed, Some(tier));
+            assert_eq!(dictionary.tier(&game.target_word), Some(tier));
+            // The seed the game keeps draws its word from the whole list
+            let moved = game.seed.unwrap();
+            assert!(moved >= seed);
+            assert_eq!(Game::from_seed(&words, moved).target_word, game.target_word);
+        }
+    }
+}
+
+#[test]
+fn nothing_to_take_falls_back_to_the_seed() {
+    let words = words();
+    let dictionary = Dictionary::new(words.clone(), &Frequencies::default());
+    let game = dictionary.draw(7, Some(Tier::Hard));
+    assert_eq!(game.seed, Some(7));
+    assert_eq!(game.target_word, Game::from_seed(&words, 7).target_word);
+    assert_eq!(dictionary.draw_where(7, |_| false).seed, Some(7));
+}
+
+#[test]
+fn a_line_that_isnt_utf8_is_skipped_not_the_rest() {
+    let path = std::env::temp_dir().join(format!("wordle-words-{}.txt", std::process::id()));
+    std::fs::write(&path, b"crane\nsl\xffte\nslate\r\nvoil\xc3\xa0\n").unwrap();
+    let words = Game::load_words(&path.to_string_lossy(), 5);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(words, ["CRANE", "SLATE", "VOILÀ"]);
+}
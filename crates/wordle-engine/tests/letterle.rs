@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use wordle_engine::candidates::CandidateSet;
+use wordle_engine::{letterle_letters, Game, GameStatus, LetterStatus, LETTERLE_ATTEMPTS};
+
+#[test]
+fn the_letter_of_the_day_is_the_same_for_everyone() {
+    let game = Game::letterle_for_day(20_000);
+    assert_eq!(game.config.word_length, 1);
+    assert_eq!(game.config.max_attempts, LETTERLE_ATTEMPTS);
+    assert_eq!(game.target_word, Game::letterle_for_day(20_000).target_word);
+    assert!(letterle_letters().contains(&game.target_word));
+}
+
+#[test]
+fn every_letter_but_one_can_be_missed() {
+    let mut game = Game::letterle_for_day(20_000);
+    let target = game.target_word.clone();
+    let letters = letterle_letters();
+    let mut candidates = CandidateSet::new(Arc::from(letters.clone()));
+
+    for letter in letters.iter().filter(|&letter| *letter != target) {
+        let feedback = game.guess(letter).unwrap();
+        assert_eq!(feedback.statuses, [LetterStatus::Absent]);
+        candidates.apply(&feedback.letters, &feedback.statuses);
+    }
+    assert_eq!(game.status, GameStatus::Playing);
+    assert_eq!(candidates.iter().collect::<Vec<_>>(), [target.as_str()]);
+    assert_eq!(game.constraints().absent.len(), 25);
+    let keyboard = game.get_keyboard_status();
+    assert_eq!(
+        keyboard
+            .iter()
+            .filter(|&&status| status == LetterStatus::Absent)
+            .count(),
+        25
+    );
+
+    assert_eq!(
+        game.guess(&target).unwrap().statuses,
+        [LetterStatus::Correct]
+    );
+    assert_eq!(game.status, GameStatus::Won);
+    assert_eq!(game.current_attempt, LETTERLE_ATTEMPTS - 1);
+}
+
+#[test]
+fn a_guess_is_a_single_letter() {
+    let mut game = Game::letterle_for_day(1);
+    assert!(game.guess("AB").is_err());
+    game.input_letter('A');
+    game.input_letter('B');
+    assert_eq!(game.attempts[0], ['A']);
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use wordle_engine::candidates::CandidateSet;
+use wordle_engine::{Counts, FeedbackStyle, Game, LetterStatus};
+
+fn mastermind(word: &str) -> Game {
+    let mut game = Game::with_word(word);
+    game.config = game.config.with_feedback(FeedbackStyle::Counts);
+    game
+}
+
+#[test]
+fn counts_leave_out_the_positions() {
+    let mut game = mastermind("CRANE");
+    let feedback = game.guess("TRACE").unwrap();
+    assert_eq!(
+        Counts::of(&feedback.statuses),
+        Counts {
+            correct: 3,
+            present: 1,
+        }
+    );
+
+    // Nothing is given away letter by letter
+    assert!(game
+        .get_keyboard_status()
+        .iter()
+        .all(|&status| status == LetterStatus::Unused));
+    let constraints = game.constraints();
+    assert!(constraints.known.iter().all(Option::is_none));
+    assert!(constraints.absent.is_empty());
+}
+
+#[test]
+fn words_with_the_same_counts_stay_candidates() {
+    let words: Arc<[String]> = ["CRANE", "CRATE", "GRATE", "SLOTH"]
+        .iter()
+        .map(|word| word.to_string())
+        .collect();
+    let mut game = mastermind("CRANE");
+    game.guess("CRATE").unwrap();
+    let (guess, statuses) = game.submitted().next().unwrap();
+
+    // GRATE also has 4 letters in place against CRATE; the colors would
+    // tell it apart by its G, the counts can't
+    let mut by_counts = CandidateSet::new(words.clone());
+    by_counts.apply_counts(guess, Counts::of(statuses));
+    assert_eq!(by_counts.iter().collect::<Vec<_>>(), ["CRANE", "GRATE"]);
+    let mut by_colors = CandidateSet::new(words);
+    by_colors.apply(guess, statuses);
+    assert_eq!(by_colors.iter().collect::<Vec<_>>(), ["CRANE"]);
+
+    assert!(game.is_consistent("GRATE"));
+    assert!(!game.is_consistent("SLOTH"));
+}
@@ -0,0 +1,49 @@
+use wordle_engine::{FeedbackStyle, Game, GameConfig};
+
+#[test]
+fn each_guess_counts_the_greens_it_found_first() {
+    let mut game = Game::with_word("CRANE");
+    assert_eq!(game.new_greens(0), None);
+    game.guess("CLOTH").unwrap();
+    game.guess("CRIMP").unwrap();
+    // Greens already found don't count again
+    game.guess("CRUST").unwrap();
+    game.guess("BRANE").unwrap();
+    assert_eq!(game.new_greens(0), Some(1));
+    assert_eq!(game.new_greens(1), Some(1));
+    assert_eq!(game.new_greens(2), Some(0));
+    assert_eq!(game.new_greens(3), Some(3));
+    assert_eq!(game.new_greens(4), None);
+}
+
+#[test]
+fn momentum_is_the_run_of_guesses_finding_greens() {
+    let mut game = Game::with_word("CRANE");
+    assert_eq!(game.momentum(), 0);
+    game.guess("CLOTH").unwrap();
+    game.guess("CRIMP").unwrap();
+    assert_eq!(game.momentum(), 2);
+    game.guess("CRUST").unwrap();
+    assert_eq!(game.momentum(), 0);
+    game.guess("CRANK").unwrap();
+    game.guess("CRANE").unwrap();
+    assert_eq!(game.momentum(), 2);
+    assert_eq!(game.best_momentum(), 2);
+
+    let mut game = Game::with_word("CRANE");
+    for guess in ["CLOTH", "CRIMP", "CRAMP", "CRANE"] {
+        game.guess(guess).unwrap();
+    }
+    assert_eq!(game.best_momentum(), 4);
+}
+
+#[test]
+fn counts_have_no_momentum() {
+    let config = GameConfig::default().with_feedback(FeedbackStyle::Counts);
+    let mut game = Game::with_config(config, "CRANE");
+    game.guess("CLOTH").unwrap();
+    game.guess("CRIMP").unwrap();
+    assert_eq!(game.new_greens(0), None);
+    assert_eq!(game.momentum(), 0);
+    assert_eq!(game.best_momentum(), 0);
+}
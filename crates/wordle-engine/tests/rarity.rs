@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use wordle_engine::rarity::{Frequencies, Rarity};
+
+#[test]
+fn a_frequency_list_ranks_words_from_the_most_used() {
+    let list = "the 500\nof\nand\nsour\nvoilà 3\nTHE\n\nzebra";
+    let frequencies = Frequencies::parse(list);
+    // Blank lines are skipped, repeats keep their first rank
+    assert_eq!(frequencies.len(), 6);
+    assert_eq!(frequencies.rarity("THE"), Some(Rarity::Common));
+    assert_eq!(frequencies.rarity("SOUR"), Some(Rarity::Uncommon));
+    assert_eq!(frequencies.rarity("AND"), Some(Rarity::Common));
+    assert_eq!(frequencies.rarity("VOILA"), Some(Rarity::Uncommon));
+    assert_eq!(frequencies.rarity("ZEBRA"), Some(Rarity::Rare));
+    // Words the list doesn't know are rare
+    assert_eq!(frequencies.rarity("QUAFF"), Some(Rarity::Rare));
+
+    assert_eq!(Frequencies::default().rarity("THE"), None);
+    assert!(Rarity::Common.weight() < Rarity::Uncommon.weight());
+    assert!(Rarity::Uncommon.weight() < Rarity::Rare.weight());
+}
+
+#[test]
+fn without_a_list_common_letters_make_common_words() {
+    let words: Arc<[String]> = include_str!("../../../data/words.txt")
+        .lines()
+        .map(str::to_uppercase)
+        .collect();
+    let frequencies = Frequencies::from_letters(&words);
+    assert_eq!(frequencies.len(), words.len());
+    assert_eq!(frequencies.rarity("AROSE"), Some(Rarity::Common));
+    assert_eq!(frequencies.rarity("JAZZY"), Some(Rarity::Rare));
+    assert_eq!(frequencies.rarity("NOTAWORD"), Some(Rarity::Rare));
+}
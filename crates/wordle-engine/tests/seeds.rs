@@ -0,0 +1,33 @@
+use wordle_engine::{seed_code, seed_from_code, Game};
+
+#[test]
+fn seed_codes_give_back_their_seed() {
+    for seed in [0, 1, 35, 36, 20_250_101, u64::MAX] {
+        let code = seed_code(seed);
+        assert_eq!(seed_from_code(&code), Some(seed), "{}", code);
+        assert_eq!(seed_from_code(&code.to_lowercase()), Some(seed));
+    }
+    assert_eq!(seed_code(0), "0");
+    assert_eq!(seed_code(35), "Z");
+    assert_eq!(seed_code(u64::MAX), "3W5E11264SGSF");
+}
+
+#[test]
+fn any_text_is_a_seed_but_a_blank_one() {
+    let race = seed_from_code("friday-race").unwrap();
+    assert_eq!(seed_from_code(" Friday-Race "), Some(race));
+    assert_ne!(seed_from_code("saturday-race"), Some(race));
+    assert_eq!(seed_from_code("  "), None);
+}
+
+#[test]
+fn the_same_code_draws_the_same_word() {
+    let words: Vec<String> = ["CRANE", "SLATE", "TRACE", "BRINE", "PLUMB"]
+        .map(String::from)
+        .to_vec();
+    let seed = seed_from_code("friday-race").unwrap();
+    let first = Game::from_seed(&words, seed);
+    let second = Game::from_seed(&words, seed);
+    assert_eq!(first.target_word, second.target_word);
+    assert_eq!(first.seed, Some(seed));
+}
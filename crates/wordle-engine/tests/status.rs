@@ -0,0 +1,188 @@
+use wordle_engine::{Game, GameStatus, TransitionError, FORFEITED};
+
+#[test]
+fn only_the_allowed_transitions_are_possible() {
+    use GameStatus::*;
+    let all = [Playing, Won, Lost, Quitting];
+    let allowed = [
+        (Playing, Won),
+        (Playing, Lost),
+        (Playing, Quitting),
+        (Quitting, Playing),
+        (Lost, Playing),
+    ];
+    for from in all {
+        for to in all {
+            assert_eq!(
+                from.can_become(to),
+                allowed.contains(&(from, to)),
+                "{:?} -> {:?}",
+                from,
+                to
+            );
+        }
+    }
+}
+
+#[test]
+fn a_game_is_only_won_with_the_target_on_the_board() {
+    let mut game = Game::with_word("CRANE");
+    assert_eq!(
+        game.transition(GameStatus::Won),
+        Err(TransitionError {
+            from: GameStatus::Playing,
+            to: GameStatus::Won,
+        })
+    );
+    assert_eq!(
+        game.transition(GameStatus::Lost).unwrap_err().to,
+        GameStatus::Lost
+    );
+    assert_eq!(game.status, GameStatus::Playing);
+
+    game.guess("crane").unwrap();
+    assert_eq!(game.status, GameStatus::Won);
+}
+
+#[test]
+fn nothing_is_typed_or_submitted_after_a_win() {
+    let mut game = Game::with_word("CRANE");
+    game.guess("crane").unwrap();
+
+    game.input_letter('S');
+    game.delete_letter();
+    assert_eq!(game.submitted().count(), 1);
+    assert!(game.guess("slate").is_err());
+    assert!(game.transition(GameStatus::Playing).is_err());
+
+    // Nor can a finished game be quit
+    game.quit();
+    assert_eq!(game.status, GameStatus::Won);
+}
+
+#[test]
+fn nothing_is_typed_while_quitting() {
+    let mut game = Game::with_word("CRANE");
+    game.input_letter('S');
+    game.quit();
+    assert_eq!(game.status, GameStatus::Quitting);
+    assert!(!game.status.accepts_input());
+
+    game.input_letter('L');
+    game.delete_letter();
+    game.submit_guess();
+    assert_eq!(game.attempts[0], ['S']);
+    assert!(game.guess("slate").is_err());
+
+    game.resume();
+    assert_eq!(game.status, GameStatus::Playing);
+    game.input_letter('L');
+    assert_eq!(game.attempts[0], ['S', 'L']);
+}
+
+#[test]
+fn a_lost_game_is_played_again_only_with_a_new_row() {
+    let mut game = Game::with_word("CRANE");
+    for _ in 0..game.config.max_attempts {
+        game.guess("slate").unwrap();
+    }
+    assert_eq!(game.status, GameStatus::Lost);
+    assert!(game.status.is_over());
+
+    // No row left to type in
+    assert!(game.transition(GameStatus::Playing).is_err());
+
+    game.keep_trying();
+    assert_eq!(game.status, GameStatus::Playing);
+    assert!(game.continued);
+    game.guess("crane").unwrap();
+    assert_eq!(game.status, GameStatus::Won);
+}
+
+#[test]
+fn forfeited_rows_count_as_guesses_but_tell_nothing() {
+    let mut game = Game::with_word("CRANE");
+    game.input_letter('S');
+    game.forfeit_row();
+    assert_eq!(game.current_attempt, 1);
+    assert!(game.attempts[0].iter().all(|&letter| letter == FORFEITED));
+    assert!(game.constraints().absent.is_empty());
+
+    for _ in 1..game.config.max_attempts {
+        game.forfeit_row();
+    }
+    assert_eq!(game.status, GameStatus::Lost);
+}
+
+#[test]
+fn an_endless_game_adds_rows_instead_of_being_lost() {
+    let mut game = Game::with_word("CRANE");
+    game.config = game.config.with_endless(true);
+    for _ in 0..10 {
+        game.guess("SLOTH").unwrap();
+    }
+    assert_eq!(game.status, GameStatus::Playing);
+    assert_eq!(game.current_attempt, 10);
+    assert!(!game.continued);
+    game.guess("CRANE").unwrap();
+    assert_eq!(game.status, GameStatus::Won);
+}
+
+#[test]
+fn practice_guesses_can_be_taken_back() {
+    let mut game = Game::with_word("CRANE");
+    game.guess("SLATE").unwrap();
+    // Only games set up for it keep the board of every guess
+    assert!(!game.undo());
+    assert_eq!(game.current_attempt, 1);
+
+    let mut game = Game::with_word("CRANE");
+    game.config = game.config.with_undo(true);
+    game.guess("SLATE").unwrap();
+    let keyboard = game.get_keyboard_status();
+    game.guess("TRACE").unwrap();
+    game.guess("CRANE").unwrap();
+    assert_eq!(game.status, GameStatus::Won);
+    assert_eq!(game.undo_depth(), 3);
+
+    // Back from the win, then one guess further back
+    assert!(game.undo());
+    assert_eq!(game.status, GameStatus::Playing);
+    assert_eq!(game.current_attempt, 2);
+    assert_eq!(game.attempts[2], ['C', 'R', 'A', 'N', 'E']);
+    assert!(game.undo());
+    assert_eq!(game.current_attempt, 1);
+    assert_eq!(game.get_keyboard_status(), keyboard);
+    assert_eq!(game.submitted().count(), 1);
+
+    game.guess("CRANE").unwrap();
+    assert_eq!(game.status, GameStatus::Won);
+    assert_eq!(game.current_attempt, 1);
+}
+
+#[test]
+fn snapshots_show_the_board_as_it_was() {
+    let mut game = Game::with_word("CRANE");
+    game.input_letter('S');
+    let typing = game.snapshot();
+    game.delete_letter();
+    game.guess("SLATE").unwrap();
+    let guessed = game.snapshot();
+    game.guess("CRANE").unwrap();
+    game.show_message("Won");
+
+    let then = game.at(&typing);
+    assert_eq!(then.current_row(), Some(&['S'][..]));
+    assert_eq!(then.submitted().count(), 0);
+    assert_eq!(then.status, GameStatus::Playing);
+    assert_eq!(then.target_word, "CRANE");
+    assert_eq!(then.message, None);
+
+    let then = game.at(&guessed);
+    assert_eq!(then.submitted().count(), 1);
+    assert_eq!(then.row(0), Some(&['S', 'L', 'A', 'T', 'E'][..]));
+    assert_eq!(then.snapshot(), guessed);
+    // The game looked at is left as it is
+    assert_eq!(game.status, GameStatus::Won);
+    assert_ne!(game.snapshot(), guessed);
+}
@@ -0,0 +1,135 @@
+//! Lines of the history of games played, `history.jsonl` in the game's
+//! data directory: one JSON object per line, tagged by its `event`.
+//!
+//! A `start` says how the word of a game was chosen, so it can be drawn
+//! again from the seed ([`Start::verify`]); a `finish` has the guesses and
+//! the result, enough to replay the game.
+//!
+//! ```json
+//! {"event":"start","id":7,"at":1760000000,"source":"daily","language":"en","word":"CRANE","seed":20378,"words":2315,"checksum":1234}
+//! {"event":"finish","id":7,"at":1760000090,"word":"CRANE","guesses":["REACT","CRANE"],"won":true,"seconds":90}
+//! ```
+
+use serde::{Deserialize, Serialize};
+use wordle_engine::normalize::fold_word;
+use wordle_engine::{seeded_index, word_list_checksum};
+
+/// Where the word of a game came from.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    /// Drawn at random from the word list.
+    Random,
+    /// The word of the day, drawn with the day as seed.
+    Daily,
+    /// The community puzzle of the week, from its feed.
+    Community,
+    /// Typed in by the owner for a guest to find.
+    Challenge,
+    /// Chosen by a friend, who sent it in a challenge file.
+    Sent,
+    /// Drawn at random for the next round of a blitz.
+    Blitz,
+    /// Drawn at random for a game without a limit on guesses.
+    Zen,
+    /// Drawn at random for a practice game, whose guesses can be undone.
+    Practice,
+    /// Drawn at random for a round between two players on one terminal.
+    Hotseat,
+    /// The letter of the day's warmup, drawn from the alphabet with the
+    /// day as seed.
+    Warmup,
+    /// The word of a past day, played late to catch up with the days
+    /// missed.
+    Archive,
+}
+
+/// One line of the history, tagged by its `event`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum Entry {
+    Start(Start),
+    Finish(Finish),
+}
+
+impl Entry {
+    /// Id of the game the entry is about.
+    pub fn id(&self) -> u64 {
+        match self {
+            Entry::Start(start) => start.id,
+            Entry::Finish(finish) => finish.id,
+        }
+    }
+}
+
+/// A game started.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Start {
+    pub id: u64,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+    pub source: Source,
+    /// Code of the language, which decides the word list.
+    pub language: String,
+    /// The answer, accents folded.
+    pub word: String,
+    /// Seed the word was drawn with, for drawn words.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Size and checksum of the word list the word was drawn from.
+    pub words: usize,
+    pub checksum: u64,
+}
+
+impl Start {
+    /// Draws the word again from the seed, with `words` as the word list
+    /// of the game's language.
+    pub fn verify(&self, words: &[String]) -> Verification {
+        let Some(seed) = self.seed else {
+            return Verification::NotDrawn;
+        };
+        if words.len() != self.words || word_list_checksum(words) != self.checksum {
+            return Verification::ListChanged;
+        }
+        let drawn = seeded_index(words.len(), seed).map(|index| fold_word(&words[index]));
+        match drawn {
+            Some(drawn) if drawn == self.word => Verification::Verified,
+            drawn => Verification::Mismatch {
+                drawn: drawn.unwrap_or_default(),
+            },
+        }
+    }
+}
+
+/// A game was won or lost.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Finish {
+    pub id: u64,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+    /// The answer, accents folded.
+    pub word: String,
+    /// The guesses submitted, in order.
+    pub guesses: Vec<String>,
+    pub won: bool,
+    /// Time from the start of the game to its end.
+    pub seconds: u64,
+    /// The guess dictionary that took each guess, when they are checked
+    /// (`wordle::guesses` in the terminal game).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accepted_by: Vec<String>,
+}
+
+/// What drawing a logged game's word again gave.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verification {
+    /// The seed gives the logged word.
+    Verified,
+    /// The seed gives another word from the same list.
+    Mismatch { drawn: String },
+    /// The word list isn't the one the word was drawn from, so the seed
+    /// can't be checked.
+    ListChanged,
+    /// The word wasn't drawn with a seed.
+    NotDrawn,
+}
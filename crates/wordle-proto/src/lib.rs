@@ -0,0 +1,20 @@
+//! What Wordle frontends and services write and send, in one place so they
+//! all speak the same formats.
+//!
+//! [`net`] has the messages of races, rooms and pairs over the network,
+//! [`history`] the lines of the history games are listed and replayed
+//! from, [`save`] a game saved in the middle of it and [`share`] the short
+//! codes a word is shared as. Only the formats are here: where the files
+//! go, and the connections, are up to each program.
+//!
+//! # Stability
+//!
+//! Every format has been written to disk or sent to other players by
+//! released games, so changes keep reading what was written before: new
+//! fields come with a default, and [`net::PROTOCOL_VERSION`] goes up when
+//! messages can't be understood by an older game.
+
+pub mod history;
+pub mod net;
+pub mod save;
+pub mod share;
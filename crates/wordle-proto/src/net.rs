@@ -0,0 +1,172 @@
+//! Messages of the races, the rooms of a server and the pairs, between
+//! players on the local network or through `wordle serve`.
+//!
+//! The protocol is one JSON message per line over TCP. The joining player
+//! says which room it wants and under what name, which a host ignores;
+//! the host or server answers with `start`, giving the seed and the word
+//! list. Then each side sends a `row` per submitted guess and `done` once
+//! its game is over:
+//!
+//! ```json
+//! {"type":"join","version":1,"room":"lobby","name":"ana","language":"en"}
+//! {"type":"start","version":1,"seed":8231,"language":"en","checksum":1234}
+//! {"type":"row","feedback":"APACC"}
+//! {"type":"done","won":true,"guesses":4}
+//! ```
+//!
+//! In a row, `C` is a letter in the right place, `P` one elsewhere in the
+//! word and `A` one not in it. A server passes rows and ends on to the
+//! rest of the room with the name of their player, sends the `scores`
+//! after each round and starts the next one with another `start`. Closing
+//! the connection leaves the race.
+//!
+//! A spectator says `watch` instead of `join`, naming a room someone plays
+//! in (`wordle watch <address>`). It sends nothing more, and hears what the
+//! players of the room do: first the round so far, then every row, end,
+//! score and start as they come.
+//!
+//! ```json
+//! {"type":"watch","version":1,"room":"lobby"}
+//! ```
+//!
+//! A pair (`wordle pair host` and `wordle pair join <address>`) plays one
+//! board together instead: the host's `start` says `"pair":true`, and a
+//! race and a pair can't join each other. Each side sends the letters of
+//! the row it is typing as they change, so the other sees them, and the
+//! guess it submits, letters and all, for the other to play too. Only the
+//! side holding the turn submits, and the turn goes to the other with each
+//! guess; the host has it first.
+//!
+//! ```json
+//! {"type":"typing","row":2,"letters":"CRA"}
+//! {"type":"guess","word":"CRANE"}
+//! ```
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+use serde::{Deserialize, Serialize};
+use wordle_engine::LetterStatus;
+
+/// Port the host or server listens on unless told otherwise.
+pub const DEFAULT_PORT: u16 = 4747;
+/// Room joined on a server unless told otherwise.
+pub const DEFAULT_ROOM: &str = "lobby";
+/// Version of the messages below; both sides must speak the same.
+pub const PROTOCOL_VERSION: u32 = 1;
+/// Longest line a message is read from, newline included.
+pub const MAX_LINE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Message {
+    /// The word to race on: the one `seed` draws from the word list of
+    /// `language`, identified by its checksum.
+    Start {
+        version: u32,
+        seed: u64,
+        language: String,
+        checksum: u64,
+        /// The room, when the race is on a server.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        room: Option<String>,
+        /// Whether the word is played as a pair rather than raced.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        pair: bool,
+    },
+    /// The colors of a submitted guess, from `player` when a server
+    /// passes it on.
+    Row {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        player: Option<String>,
+        feedback: String,
+    },
+    /// The game of the sender, or of `player`, is over.
+    Done {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        player: Option<String>,
+        won: bool,
+        guesses: usize,
+    },
+    /// The first message of the joining player: the room to play in and
+    /// the name to show the others.
+    Join {
+        version: u32,
+        room: String,
+        name: String,
+        language: String,
+    },
+    /// The first message of a spectator: the room to watch.
+    Watch { version: u32, room: String },
+    /// A player left the room.
+    Left { player: String },
+    /// Points of everyone in the room, after a round or a player joining.
+    Scores { scores: Vec<Score> },
+    /// The server turned the joining player away.
+    Refused { reason: String },
+    /// The letters typed so far in `row` of a pair's board.
+    Typing { row: usize, letters: String },
+    /// A guess submitted on a pair's board, to play on the other side too.
+    Guess { word: String },
+}
+
+/// Rounds a player of a room won.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Score {
+    pub name: String,
+    pub points: u32,
+}
+
+/// Reads the messages of `stream`, handing each to `deliver`, until it
+/// returns `false` or the connection ends. Lines that aren't messages are
+/// skipped; one longer than [`MAX_LINE`] ends the connection, rather than
+/// being kept in memory until its end comes.
+pub fn read_messages(stream: impl Read, mut deliver: impl FnMut(Message) -> bool) {
+    let mut reader = BufReader::new(stream);
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        let limit = MAX_LINE as u64 + 1;
+        match reader.by_ref().take(limit).read_until(b'\n', &mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) if line.len() > MAX_LINE => break,
+            Ok(_) => {}
+        }
+        if let Ok(message) = serde_json::from_slice(&line) {
+            if !deliver(message) {
+                break;
+            }
+        }
+    }
+}
+
+/// Writes `message` as a line.
+pub fn write_message(writer: &mut impl Write, message: &Message) -> io::Result<()> {
+    let mut line = serde_json::to_string(message)?;
+    line.push('\n');
+    writer.write_all(line.as_bytes())
+}
+
+/// The colors of a guess as sent in a `row` message.
+pub fn encode_row(statuses: &[LetterStatus]) -> String {
+    statuses
+        .iter()
+        .map(|status| match status {
+            LetterStatus::Correct => 'C',
+            LetterStatus::Present => 'P',
+            LetterStatus::Absent | LetterStatus::Unused => 'A',
+        })
+        .collect()
+}
+
+/// Reads the colors of a `row` message; `None` if they aren't colors.
+pub fn decode_row(feedback: &str) -> Option<Vec<LetterStatus>> {
+    feedback
+        .chars()
+        .map(|c| match c {
+            'C' => Some(LetterStatus::Correct),
+            'P' => Some(LetterStatus::Present),
+            'A' => Some(LetterStatus::Absent),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,93 @@
+//! A game saved in the middle of it, as the terminal game keeps it in
+//! `autosave.json` to pick it up at the next start.
+//!
+//! Only games that can be played again from their word and guesses are
+//! saved: [`Saved::of`] refuses the others.
+
+use serde::{Deserialize, Serialize};
+use wordle_engine::{FeedbackStyle, Game, GameConfig, GameStatus};
+
+use crate::history::Source;
+
+/// A game saved in the middle of it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Saved {
+    /// Id of the game in the history, so its end is logged with its start.
+    pub game_id: Option<u64>,
+    pub source: Source,
+    pub language: String,
+    /// The answer, as spelled in the word list.
+    pub word: String,
+    pub seed: Option<u64>,
+    pub word_length: usize,
+    pub max_attempts: usize,
+    /// Guesses answered with counts only.
+    pub counts: bool,
+    pub endless: bool,
+    pub undo: bool,
+    /// Guesses submitted, accents folded.
+    pub guesses: Vec<String>,
+    /// Letters of the row being typed.
+    pub typed: String,
+    pub hints_used: usize,
+}
+
+impl Saved {
+    /// `game`, if it is still being played and can be saved.
+    pub fn of(game: &Game, source: Source, language: &str, game_id: Option<u64>) -> Option<Saved> {
+        let playable = matches!(
+            source,
+            Source::Random | Source::Daily | Source::Zen | Source::Practice
+        );
+        if !playable || game.status != GameStatus::Playing {
+            return None;
+        }
+        Some(Saved {
+            game_id,
+            source,
+            language: language.to_string(),
+            word: game.display_word.clone(),
+            seed: game.seed,
+            word_length: game.config.word_length,
+            max_attempts: game.config.max_attempts,
+            counts: game.config.feedback == FeedbackStyle::Counts,
+            endless: game.config.endless,
+            undo: game.config.undo,
+            guesses: game
+                .submitted()
+                .map(|(guess, _)| guess.iter().collect())
+                .collect(),
+            typed: game
+                .attempts
+                .get(game.current_attempt)
+                .map_or_else(String::new, |row| row.iter().collect()),
+            hints_used: game.hints_used,
+        })
+    }
+
+    /// The game as it was saved, its guesses played again.
+    pub fn game(&self) -> Game {
+        let feedback = if self.counts {
+            FeedbackStyle::Counts
+        } else {
+            FeedbackStyle::Letters
+        };
+        let config = GameConfig::new(self.word_length, self.max_attempts)
+            .with_feedback(feedback)
+            .with_endless(self.endless)
+            .with_undo(self.undo);
+        let mut game = Game::with_config(config, &self.word);
+        game.seed = self.seed;
+        for guess in &self.guesses {
+            for letter in guess.chars() {
+                game.input_letter(letter);
+            }
+            game.submit_guess();
+        }
+        for letter in self.typed.chars() {
+            game.input_letter(letter);
+        }
+        game.hints_used = self.hints_used;
+        game
+    }
+}
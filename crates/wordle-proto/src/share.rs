@@ -0,0 +1,175 @@
+//! A word shared as a short code, such as `7QX2-KM4D`, to be played
+//! without it showing in a chat or a shell history.
+//!
+//! The code holds 40 bits: a random salt, so the same word gives different
+//! codes, the word's letters and language masked by a key made from the
+//! salt, and a check that catches most mistyped codes. It hides the word
+//! from the eyes only.
+
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+use wordle_engine::normalize::fold_word;
+use wordle_engine::WORD_LENGTH;
+
+/// Codes of the languages a share code can be in, by the number it writes.
+pub const LANGUAGES: [&str; 4] = ["en", "pt", "es", "fr"];
+
+/// Letters and digits of share codes: Crockford's base 32, without the
+/// letters that pass for digits.
+const CODE_DIGITS: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// A word and its language, as a share code writes them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShareCode {
+    letters: [u8; WORD_LENGTH],
+    // Index in `LANGUAGES`
+    language: usize,
+}
+
+impl ShareCode {
+    /// The code of `word` in `language`, one of [`LANGUAGES`]. The word has
+    /// to be of [`WORD_LENGTH`] letters once its accents are folded.
+    pub fn new(word: &str, language: &str) -> Result<ShareCode, CodeError> {
+        let language = LANGUAGES
+            .iter()
+            .position(|&code| code == language)
+            .ok_or_else(|| CodeError::UnknownLanguage(language.to_string()))?;
+        let folded = fold_word(word);
+        let letters: Vec<u8> = folded.bytes().collect();
+        let Ok(letters) = <[u8; WORD_LENGTH]>::try_from(letters) else {
+            return Err(CodeError::WordLength);
+        };
+        if !letters.iter().all(u8::is_ascii_uppercase) {
+            return Err(CodeError::Unwritable(word.to_string()));
+        }
+        Ok(ShareCode { letters, language })
+    }
+
+    /// Reads a code, in any case, with or without its dash; `I`, `L` and
+    /// `O` are taken for the digits they look like.
+    pub fn decode(code: &str) -> Result<ShareCode, CodeError> {
+        let mut value: u64 = 0;
+        let mut digits = 0;
+        for c in code.chars().filter(|c| !c.is_whitespace() && *c != '-') {
+            let c = match c.to_ascii_uppercase() {
+                'I' | 'L' => '1',
+                'O' => '0',
+                c => c,
+            };
+            let digit = CODE_DIGITS
+                .iter()
+                .position(|&digit| digit as char == c)
+                .ok_or_else(|| CodeError::NotACode(code.trim().to_string()))?;
+            value = value << 5 | digit as u64;
+            digits += 1;
+        }
+        if digits != 8 {
+            return Err(CodeError::CodeLength);
+        }
+        let salt = (value >> 34) as u8;
+        let payload = (value >> 7 & 0x7ff_ffff) as u32 ^ code_mask(salt);
+        let language = (payload >> 25) as usize & 3;
+        let mut letters = [0; WORD_LENGTH];
+        for (slot, letter) in letters.iter_mut().enumerate() {
+            let index = (payload >> (5 * (WORD_LENGTH - 1 - slot)) & 0x1f) as u8;
+            *letter = b'A' + index;
+        }
+        let decoded = ShareCode { letters, language };
+        if !letters.iter().all(u8::is_ascii_uppercase)
+            || decoded.check(salt) != (value & 0x7f) as u8
+        {
+            return Err(CodeError::Mistyped(code.trim().to_string()));
+        }
+        Ok(decoded)
+    }
+
+    /// A new code for the word, with a fresh salt.
+    pub fn encode(&self) -> String {
+        self.encode_with(rand::random::<u8>() & 0x3f)
+    }
+
+    /// The code for the word with `salt`, of which the low 6 bits count.
+    pub fn encode_with(&self, salt: u8) -> String {
+        let salt = salt & 0x3f;
+        let payload = self
+            .letters
+            .iter()
+            .fold(self.language as u32, |payload, letter| {
+                payload << 5 | (letter - b'A') as u32
+            });
+        let value = (salt as u64) << 34
+            | ((payload ^ code_mask(salt)) as u64) << 7
+            | self.check(salt) as u64;
+        let code: String = (0..8)
+            .rev()
+            .map(|digit| CODE_DIGITS[(value >> (5 * digit) & 0x1f) as usize] as char)
+            .collect();
+        format!("{}-{}", &code[..4], &code[4..])
+    }
+
+    /// The word, in uppercase with accents folded.
+    pub fn word(&self) -> String {
+        self.letters.iter().map(|&letter| letter as char).collect()
+    }
+
+    /// Code of the word's language, one of [`LANGUAGES`].
+    pub fn language(&self) -> &'static str {
+        LANGUAGES[self.language]
+    }
+
+    // Seven bits of a hash of everything the code holds
+    fn check(&self, salt: u8) -> u8 {
+        let hash = Sha256::digest(format!(
+            "wordle code {} {} {}",
+            self.word(),
+            self.language(),
+            salt
+        ));
+        hash[0] & 0x7f
+    }
+}
+
+// The 27 bits the word and language of a code are masked with
+fn code_mask(salt: u8) -> u32 {
+    let hash = Sha256::digest(format!("wordle code key {}", salt));
+    u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) & 0x7ff_ffff
+}
+
+/// Reason a word can't be written as a share code, or a code read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CodeError {
+    /// The word doesn't have [`WORD_LENGTH`] letters.
+    WordLength,
+    /// The word has letters a code can't write.
+    Unwritable(String),
+    /// The language isn't one of [`LANGUAGES`].
+    UnknownLanguage(String),
+    /// The code has something else than letters and digits.
+    NotACode(String),
+    /// The code doesn't have eight letters and digits.
+    CodeLength,
+    /// The code fails its check.
+    Mistyped(String),
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CodeError::WordLength => write!(f, "a challenge word has {} letters", WORD_LENGTH),
+            CodeError::Unwritable(word) => {
+                write!(f, "'{}' can't be written as a challenge code", word)
+            }
+            CodeError::UnknownLanguage(language) => {
+                write!(f, "a challenge code can't be in language '{}'", language)
+            }
+            CodeError::NotACode(code) => write!(f, "'{}' is not a challenge code", code),
+            CodeError::CodeLength => write!(f, "a challenge code has 8 letters and digits"),
+            CodeError::Mistyped(code) => {
+                write!(f, "'{}' is not a challenge code (mistyped?)", code)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CodeError {}
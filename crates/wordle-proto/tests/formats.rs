@@ -0,0 +1,121 @@
+//! The formats as released games wrote and sent them: these lines must
+//! keep reading the same.
+
+use wordle_engine::{Game, LetterStatus};
+use wordle_proto::history::{Entry, Source};
+use wordle_proto::net::{decode_row, encode_row, read_messages, write_message, Message, MAX_LINE};
+use wordle_proto::save::Saved;
+use wordle_proto::share::{CodeError, ShareCode};
+
+#[test]
+fn messages_are_json_lines_tagged_by_type() {
+    let lines = concat!(
+        "{\"type\":\"join\",\"version\":1,\"room\":\"lobby\",\"name\":\"ana\",\"language\":\"en\"}\n",
+        "not a message\n",
+        "{\"type\":\"row\",\"feedback\":\"APACC\"}\n",
+        "{\"type\":\"done\",\"won\":true,\"guesses\":4}\n",
+    );
+    let mut messages = Vec::new();
+    read_messages(lines.as_bytes(), |message| {
+        messages.push(message);
+        true
+    });
+    assert_eq!(messages.len(), 3);
+    assert_eq!(
+        messages[1],
+        Message::Row {
+            player: None,
+            feedback: "APACC".to_string()
+        }
+    );
+
+    let mut written = Vec::new();
+    for message in &messages {
+        write_message(&mut written, message).unwrap();
+    }
+    let expected: String = lines
+        .lines()
+        .filter(|line| *line != "not a message")
+        .map(|line| format!("{}\n", line))
+        .collect();
+    assert_eq!(String::from_utf8(written).unwrap(), expected);
+
+    let statuses = decode_row("APACC").unwrap();
+    assert_eq!(statuses[1], LetterStatus::Present);
+    assert_eq!(encode_row(&statuses), "APACC");
+    assert_eq!(decode_row("APXCC"), None);
+}
+
+#[test]
+fn a_line_too_long_ends_the_connection() {
+    let done = "{\"type\":\"done\",\"won\":true,\"guesses\":4}\n";
+    let lines = format!("{}{}\n{}", done, "x".repeat(MAX_LINE), done);
+    let mut messages = Vec::new();
+    read_messages(lines.as_bytes(), |message| {
+        messages.push(message);
+        true
+    });
+    assert_eq!(messages.len(), 1);
+}
+
+#[test]
+fn history_lines_read_with_and_without_optional_fields() {
+    let start: Entry = serde_json::from_str(
+        r#"{"event":"start","id":7,"at":1760000000,"source":"daily","language":"en","word":"CRANE","seed":20378,"words":2315,"checksum":1234}"#,
+    )
+    .unwrap();
+    let Entry::Start(start) = start else {
+        panic!("not a start: {:?}", start);
+    };
+    assert_eq!(start.source, Source::Daily);
+    assert_eq!(start.seed, Some(20378));
+
+    let finish: Entry = serde_json::from_str(
+        r#"{"event":"finish","id":7,"at":1760000090,"word":"CRANE","guesses":["REACT","CRANE"],"won":true,"seconds":90}"#,
+    )
+    .unwrap();
+    assert_eq!(finish.id(), 7);
+    let Entry::Finish(finish) = finish else {
+        panic!("not a finish: {:?}", finish);
+    };
+    assert!(finish.accepted_by.is_empty());
+    assert!(!serde_json::to_string(&finish)
+        .unwrap()
+        .contains("accepted_by"));
+}
+
+#[test]
+fn a_saved_game_plays_back_to_where_it_was() {
+    let mut game = Game::with_word("CRANE");
+    game.guess("REACT").unwrap();
+    game.input_letter('C');
+    let saved = Saved::of(&game, Source::Random, "en", Some(3)).unwrap();
+    let json = serde_json::to_string(&saved).unwrap();
+    let back: Saved = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, saved);
+    let restored = back.game();
+    assert_eq!(restored.current_attempt, 1);
+    assert_eq!(restored.attempts[1][0], 'C');
+
+    // Games that can't be played again from their guesses aren't saved
+    assert!(Saved::of(&game, Source::Blitz, "en", None).is_none());
+}
+
+#[test]
+fn share_codes_keep_their_word_and_language() {
+    let code = ShareCode::new("avião", "pt").unwrap();
+    assert_eq!(code.word(), "AVIAO");
+    for salt in 0..64 {
+        let written = code.encode_with(salt);
+        assert_eq!(written.len(), 9);
+        let read = ShareCode::decode(&written.to_lowercase().replace('-', "")).unwrap();
+        assert_eq!(read, code);
+        assert_eq!(read.language(), "pt");
+    }
+    assert_eq!(ShareCode::new("cat", "en"), Err(CodeError::WordLength));
+    assert!(matches!(
+        ShareCode::new("crane", "xx"),
+        Err(CodeError::UnknownLanguage(_))
+    ));
+    assert_eq!(ShareCode::decode("ABCD"), Err(CodeError::CodeLength));
+}
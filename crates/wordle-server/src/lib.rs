@@ -0,0 +1,478 @@
+//! A server without a terminal, for races of any number of players, run
+//! by `wordle serve`. Players join a room by name; each
+//! room draws the seed of its rounds, passes every player's rows and ends
+//! on to the others, and keeps the score. A round is over once everyone in
+//! the room is done with it: its winner is whoever found the word in the
+//! fewest guesses, the first to get there on a tie, and scores a point.
+//! The next round starts right away.
+//!
+//! Spectators watch a room without playing in it: they hear everything its
+//! players do, starting with the round so far, and the room stays open
+//! while any of them is left.
+//!
+//! The messages are those of [`wordle_proto::net`]. The server knows no
+//! word list of its own: it is given a [`Languages`] to look up the list
+//! of the language a room plays in, whose checksum every `start` carries.
+//!
+//! [`Server`] holds the rooms and decides what to send to whom; [`serve`]
+//! runs it over TCP.
+//!
+//! What strangers can make it hold is bounded: [`MAX_CONNECTIONS`] at
+//! once, [`MAX_ROOMS`] rooms, names of [`MAX_NAME`] characters and lines of
+//! [`net::MAX_LINE`] bytes.
+
+use std::collections::HashMap;
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+use anyhow::Context;
+use wordle_proto::net::{self, Message, Score, PROTOCOL_VERSION};
+
+/// Most connections served at once; the next are refused.
+pub const MAX_CONNECTIONS: usize = 256;
+
+/// Most rooms open at once.
+pub const MAX_ROOMS: usize = 64;
+
+/// Longest name of a player or a room, in characters.
+pub const MAX_NAME: usize = 32;
+
+/// A connection to the server, numbered in the order they came.
+pub type ClientId = u64;
+
+/// The word list of a language rounds are drawn from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WordList {
+    /// Code of the language, as `start` messages give it.
+    pub language: &'static str,
+    pub checksum: u64,
+}
+
+/// The word list of the language a player asks for by its code, or `None`
+/// for a language the server can't play in.
+pub type Languages = fn(&str) -> Option<WordList>;
+
+// How a player's game of the round ended
+#[derive(Debug, Clone, Copy)]
+struct Ended {
+    won: bool,
+    guesses: usize,
+}
+
+#[derive(Debug)]
+struct Player {
+    client: ClientId,
+    name: String,
+    // Colors of their guesses of the round, for spectators coming in
+    rows: Vec<String>,
+    // How their game of the round ended, and how many ended before it
+    result: Option<(Ended, usize)>,
+}
+
+#[derive(Debug)]
+struct Room {
+    name: String,
+    words: WordList,
+    seed: u64,
+    players: Vec<Player>,
+    spectators: Vec<ClientId>,
+    // By player name, so a player coming back finds their points
+    points: HashMap<String, u32>,
+    // Games of the round over so far
+    finished: usize,
+}
+
+impl Room {
+    fn start(&self) -> Message {
+        Message::Start {
+            version: PROTOCOL_VERSION,
+            seed: self.seed,
+            language: self.words.language.to_string(),
+            checksum: self.words.checksum,
+            room: Some(self.name.clone()),
+            pair: false,
+        }
+    }
+
+    // Scores of the players in the room, the best first
+    fn scores(&self) -> Message {
+        let mut scores: Vec<Score> = self
+            .players
+            .iter()
+            .map(|player| Score {
+                name: player.name.clone(),
+                points: self.points.get(&player.name).copied().unwrap_or(0),
+            })
+            .collect();
+        scores.sort_by(|a, b| b.points.cmp(&a.points).then_with(|| a.name.cmp(&b.name)));
+        Message::Scores { scores }
+    }
+
+    // `message` for everyone in the room but `except`, spectators included
+    fn broadcast(&self, message: &Message, except: Option<ClientId>) -> Vec<(ClientId, Message)> {
+        self.players
+            .iter()
+            .map(|player| player.client)
+            .chain(self.spectators.iter().copied())
+            .filter(|client| Some(*client) != except)
+            .map(|client| (client, message.clone()))
+            .collect()
+    }
+
+    // What a spectator coming in needs to catch up: the round, the scores
+    // and everyone's rows and ends so far
+    fn snapshot(&self) -> Vec<Message> {
+        let mut messages = vec![self.start(), self.scores()];
+        for player in &self.players {
+            let name = Some(player.name.clone());
+            messages.extend(player.rows.iter().map(|feedback| Message::Row {
+                player: name.clone(),
+                feedback: feedback.clone(),
+            }));
+            if let Some((result, _)) = player.result {
+                messages.push(Message::Done {
+                    player: name,
+                    won: result.won,
+                    guesses: result.guesses,
+                });
+            }
+        }
+        messages
+    }
+
+    // Scores the round if everyone is done with it, then starts the next
+    fn settle(&mut self) -> Vec<(ClientId, Message)> {
+        if self.players.is_empty() || self.players.iter().any(|p| p.result.is_none()) {
+            return Vec::new();
+        }
+        let winner = self
+            .players
+            .iter()
+            .filter_map(|player| Some((player, player.result?)))
+            .filter(|(_, (result, _))| result.won)
+            .min_by_key(|(_, (result, order))| (result.guesses, *order))
+            .map(|(player, _)| player.name.clone());
+        if let Some(winner) = winner {
+            *self.points.entry(winner).or_insert(0) += 1;
+        }
+        for player in &mut self.players {
+            player.rows.clear();
+            player.result = None;
+        }
+        self.finished = 0;
+        self.seed = rand::random();
+        let mut outgoing = self.broadcast(&self.scores(), None);
+        outgoing.extend(self.broadcast(&self.start(), None));
+        outgoing
+    }
+}
+
+/// The rooms of a server and who is in them.
+#[derive(Debug)]
+pub struct Server {
+    languages: Languages,
+    rooms: Vec<Room>,
+}
+
+impl Server {
+    /// A server with no room yet, playing in the `languages`.
+    pub fn new(languages: Languages) -> Server {
+        Server {
+            languages,
+            rooms: Vec::new(),
+        }
+    }
+
+    /// Number of rooms with someone in them, playing or watching.
+    pub fn room_count(&self) -> usize {
+        self.rooms.len()
+    }
+
+    /// Handles `message` from `client`, returning the messages to send and
+    /// who to.
+    pub fn handle(&mut self, client: ClientId, message: Message) -> Vec<(ClientId, Message)> {
+        match message {
+            Message::Join {
+                version,
+                room,
+                name,
+                language,
+            } => self.join(client, version, room, name, &language),
+            Message::Watch { version, room } => self.watch(client, version, &room),
+            Message::Row { feedback, .. } => {
+                let Some((room, index)) = self.find(client) else {
+                    return Vec::new();
+                };
+                let room = &mut self.rooms[room];
+                let player = &mut room.players[index];
+                if player.result.is_some() {
+                    return Vec::new();
+                }
+                player.rows.push(feedback.clone());
+                let row = Message::Row {
+                    player: Some(player.name.clone()),
+                    feedback,
+                };
+                room.broadcast(&row, Some(client))
+            }
+            Message::Done { won, guesses, .. } => {
+                let Some((room, index)) = self.find(client) else {
+                    return Vec::new();
+                };
+                let room = &mut self.rooms[room];
+                let player = &mut room.players[index];
+                if player.result.is_some() {
+                    return Vec::new();
+                }
+                player.result = Some((Ended { won, guesses }, room.finished));
+                room.finished += 1;
+                let done = Message::Done {
+                    player: Some(player.name.clone()),
+                    won,
+                    guesses,
+                };
+                let mut outgoing = room.broadcast(&done, Some(client));
+                outgoing.extend(room.settle());
+                outgoing
+            }
+            // Only the server sends those
+            Message::Start { .. }
+            | Message::Left { .. }
+            | Message::Scores { .. }
+            | Message::Refused { .. } => Vec::new(),
+            // Pairs play without a server
+            Message::Typing { .. } | Message::Guess { .. } => Vec::new(),
+        }
+    }
+
+    /// Takes `client` out of its room, which is closed once empty.
+    pub fn leave(&mut self, client: ClientId) -> Vec<(ClientId, Message)> {
+        if let Some(index) = self.find_spectator(client) {
+            let room = &mut self.rooms[index];
+            room.spectators.retain(|spectator| *spectator != client);
+            if room.players.is_empty() && room.spectators.is_empty() {
+                self.rooms.remove(index);
+            }
+            return Vec::new();
+        }
+        let Some((index, player)) = self.find(client) else {
+            return Vec::new();
+        };
+        let room = &mut self.rooms[index];
+        let player = room.players.remove(player);
+        if room.players.is_empty() && room.spectators.is_empty() {
+            self.rooms.remove(index);
+            return Vec::new();
+        }
+        let mut outgoing = room.broadcast(
+            &Message::Left {
+                player: player.name,
+            },
+            None,
+        );
+        outgoing.extend(room.broadcast(&room.scores(), None));
+        // The round may have been waiting on them only
+        outgoing.extend(room.settle());
+        outgoing
+    }
+
+    fn join(
+        &mut self,
+        client: ClientId,
+        version: u32,
+        room: String,
+        name: String,
+        language: &str,
+    ) -> Vec<(ClientId, Message)> {
+        let refuse = |reason: String| vec![(client, Message::Refused { reason })];
+        if self.find(client).is_some() || self.find_spectator(client).is_some() {
+            return Vec::new();
+        }
+        if version != PROTOCOL_VERSION {
+            return refuse(format!(
+                "the server speaks version {} of the protocol, this game version {}",
+                PROTOCOL_VERSION, version
+            ));
+        }
+        let Some(words) = (self.languages)(language) else {
+            return refuse(format!(
+                "the server doesn't know the language '{}'",
+                language
+            ));
+        };
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return refuse("a player needs a name".to_string());
+        }
+        if name.chars().count() > MAX_NAME || room.chars().count() > MAX_NAME {
+            return refuse(format!(
+                "names of players and rooms are at most {} characters long",
+                MAX_NAME
+            ));
+        }
+
+        let index = match self.rooms.iter().position(|r| r.name == room) {
+            Some(index) => index,
+            None => {
+                if self.rooms.len() >= MAX_ROOMS {
+                    return refuse("the server has no room for another room".to_string());
+                }
+                self.rooms.push(Room {
+                    name: room,
+                    words,
+                    seed: rand::random(),
+                    players: Vec::new(),
+                    spectators: Vec::new(),
+                    points: HashMap::new(),
+                    finished: 0,
+                });
+                self.rooms.len() - 1
+            }
+        };
+        let room = &mut self.rooms[index];
+        if room.words.language != words.language {
+            return refuse(format!(
+                "room '{}' plays in '{}': join with --lang {}",
+                room.name, room.words.language, room.words.language
+            ));
+        }
+        if room.players.iter().any(|player| player.name == name) {
+            return refuse(format!(
+                "someone called {} is already in room '{}'",
+                name, room.name
+            ));
+        }
+        room.players.push(Player {
+            client,
+            name,
+            rows: Vec::new(),
+            result: None,
+        });
+        let mut outgoing = vec![(client, room.start())];
+        outgoing.extend(room.broadcast(&room.scores(), None));
+        outgoing
+    }
+
+    fn watch(&mut self, client: ClientId, version: u32, room: &str) -> Vec<(ClientId, Message)> {
+        let refuse = |reason: String| vec![(client, Message::Refused { reason })];
+        if self.find(client).is_some() || self.find_spectator(client).is_some() {
+            return Vec::new();
+        }
+        if version != PROTOCOL_VERSION {
+            return refuse(format!(
+                "the server speaks version {} of the protocol, this game version {}",
+                PROTOCOL_VERSION, version
+            ));
+        }
+        // A room is only there while someone plays in it
+        let Some(room) = self
+            .rooms
+            .iter_mut()
+            .find(|r| r.name == room && !r.players.is_empty())
+        else {
+            return refuse(format!("nobody is playing in room '{}'", room));
+        };
+        room.spectators.push(client);
+        room.snapshot()
+            .into_iter()
+            .map(|message| (client, message))
+            .collect()
+    }
+
+    // The room `client` watches
+    fn find_spectator(&self, client: ClientId) -> Option<usize> {
+        self.rooms
+            .iter()
+            .position(|room| room.spectators.contains(&client))
+    }
+
+    // The room and place in it of `client`
+    fn find(&self, client: ClientId) -> Option<(usize, usize)> {
+        self.rooms.iter().enumerate().find_map(|(index, room)| {
+            let player = room.players.iter().position(|p| p.client == client)?;
+            Some((index, player))
+        })
+    }
+}
+
+// What the connections tell the thread running the server
+enum Event {
+    Connected(ClientId, TcpStream),
+    Received(ClientId, Message),
+    Closed(ClientId),
+}
+
+/// Runs a server in the `languages` on `listener`, logging who comes and
+/// goes. Only returns once it stops taking connections.
+pub fn serve(listener: TcpListener, languages: Languages) -> anyhow::Result<()> {
+    let (sender, events) = mpsc::channel();
+    let accepting = listener.try_clone().context("listening for players")?;
+    let connections = Arc::new(AtomicUsize::new(0));
+    thread::spawn(move || {
+        for (client, stream) in (0..).zip(accepting.incoming()) {
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+            if connections.load(Ordering::SeqCst) >= MAX_CONNECTIONS {
+                if let Ok(address) = stream.peer_addr() {
+                    println!("Refused {}: the server is full", address);
+                }
+                let reason = "the server is full".to_string();
+                let _ = net::write_message(&mut stream, &Message::Refused { reason });
+                let _ = stream.shutdown(Shutdown::Both);
+                continue;
+            }
+            let Ok(reader) = stream.try_clone() else {
+                continue;
+            };
+            if sender.send(Event::Connected(client, stream)).is_err() {
+                break;
+            }
+            connections.fetch_add(1, Ordering::SeqCst);
+            let sender = sender.clone();
+            let connections = Arc::clone(&connections);
+            thread::spawn(move || {
+                net::read_messages(reader, |message| {
+                    sender.send(Event::Received(client, message)).is_ok()
+                });
+                connections.fetch_sub(1, Ordering::SeqCst);
+                let _ = sender.send(Event::Closed(client));
+            });
+        }
+    });
+
+    let mut server = Server::new(languages);
+    let mut writers: HashMap<ClientId, TcpStream> = HashMap::new();
+    for event in events {
+        let outgoing = match event {
+            Event::Connected(client, stream) => {
+                if let Ok(address) = stream.peer_addr() {
+                    println!("Player {} connected from {}", client, address);
+                }
+                writers.insert(client, stream);
+                Vec::new()
+            }
+            Event::Received(client, message) => server.handle(client, message),
+            Event::Closed(client) => {
+                println!("Player {} left", client);
+                writers.remove(&client);
+                server.leave(client)
+            }
+        };
+        for (client, message) in outgoing {
+            let Some(writer) = writers.get_mut(&client) else {
+                continue;
+            };
+            // A failed write ends the connection, and its reader sees it
+            // close; a refused player is let go once told why
+            if net::write_message(writer, &message).is_err() {
+                let _ = writer.shutdown(Shutdown::Both);
+            } else if matches!(message, Message::Refused { .. }) {
+                let _ = writer.shutdown(Shutdown::Write);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Rooms on the word lists the server is given, whatever they are.
+
+use wordle_proto::net::{Message, PROTOCOL_VERSION};
+use wordle_server::{ClientId, Server, WordList};
+
+// English under any of its codes, and nothing else
+fn english(code: &str) -> Option<WordList> {
+    code.to_ascii_lowercase()
+        .starts_with("en")
+        .then_some(WordList {
+            language: "en",
+            checksum: 42,
+        })
+}
+
+fn join(server: &mut Server, client: ClientId, name: &str, language: &str) -> Message {
+    let mut outgoing = server.handle(
+        client,
+        Message::Join {
+            version: PROTOCOL_VERSION,
+            room: "lobby".to_string(),
+            name: name.to_string(),
+            language: language.to_string(),
+        },
+    );
+    outgoing.remove(0).1
+}
+
+#[test]
+fn rounds_start_on_the_list_of_the_language() {
+    let mut server = Server::new(english);
+    let Message::Start {
+        language, checksum, ..
+    } = join(&mut server, 1, "ana", "en")
+    else {
+        panic!("the player wasn't started");
+    };
+    assert_eq!((language.as_str(), checksum), ("en", 42));
+
+    // Another code of the same language is the same room's
+    assert!(matches!(
+        join(&mut server, 2, "bia", "EN-gb"),
+        Message::Start { .. }
+    ));
+    let Message::Refused { reason } = join(&mut server, 3, "caio", "pt") else {
+        panic!("a language the server can't play in was let in");
+    };
+    assert!(reason.contains("'pt'"), "{}", reason);
+    assert_eq!(server.room_count(), 1);
+}
@@ -0,0 +1,67 @@
+//! How much strangers can make a server hold.
+
+use wordle_proto::net::{Message, PROTOCOL_VERSION};
+use wordle_server::{ClientId, Server, WordList, MAX_NAME, MAX_ROOMS};
+
+fn english(_: &str) -> Option<WordList> {
+    Some(WordList {
+        language: "en",
+        checksum: 42,
+    })
+}
+
+fn join(server: &mut Server, client: ClientId, room: &str, name: &str) -> Message {
+    let mut outgoing = server.handle(
+        client,
+        Message::Join {
+            version: PROTOCOL_VERSION,
+            room: room.to_string(),
+            name: name.to_string(),
+            language: "en".to_string(),
+        },
+    );
+    outgoing.remove(0).1
+}
+
+#[test]
+fn overlong_names_are_refused() {
+    let mut server = Server::new(english);
+    let long = "a".repeat(MAX_NAME + 1);
+    assert!(matches!(
+        join(&mut server, 1, "lobby", &long),
+        Message::Refused { .. }
+    ));
+    assert!(matches!(
+        join(&mut server, 2, &long, "ana"),
+        Message::Refused { .. }
+    ));
+    assert_eq!(server.room_count(), 0);
+
+    // Characters are counted, not bytes
+    assert!(matches!(
+        join(&mut server, 3, "lobby", &"é".repeat(MAX_NAME)),
+        Message::Start { .. }
+    ));
+}
+
+#[test]
+fn rooms_past_the_cap_are_refused() {
+    let mut server = Server::new(english);
+    for client in 0..MAX_ROOMS as ClientId {
+        let room = format!("room {}", client);
+        assert!(matches!(
+            join(&mut server, client, &room, "ana"),
+            Message::Start { .. }
+        ));
+    }
+    assert!(matches!(
+        join(&mut server, 100, "one more", "ana"),
+        Message::Refused { .. }
+    ));
+    // The rooms open still take players
+    assert!(matches!(
+        join(&mut server, 101, "room 0", "bia"),
+        Message::Start { .. }
+    ));
+    assert_eq!(server.room_count(), MAX_ROOMS);
+}
@@ -0,0 +1,130 @@
+//! Badges earned across sessions, kept in `achievements.json` in the data
+//! directory with when each was earned and the current streak of wins.
+//!
+//! The engine has no hard mode, so the hard-mode badge goes to a win in
+//! the Mastermind mode, where guesses are only answered with counts.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::game::{FeedbackStyle, Game, GameStatus};
+
+/// Wins in a row for [`Achievement::Streak`].
+pub const STREAK: usize = 10;
+/// Guesses in a row finding new greens for [`Achievement::Momentum`].
+pub const MOMENTUM: usize = 3;
+
+/// A badge, earned once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Achievement {
+    /// Any game won.
+    FirstWin,
+    /// A word found in two guesses, or one.
+    InTwo,
+    /// [`STREAK`] games won in a row.
+    Streak,
+    /// A game won without asking for a hint.
+    NoHints,
+    /// A game won with counts for feedback.
+    HardMode,
+    /// A game won with [`MOMENTUM`] guesses in a row that each found a
+    /// new green.
+    Momentum,
+}
+
+impl Achievement {
+    /// Every badge, in the order they are listed.
+    pub const ALL: [Achievement; 6] = [
+        Achievement::FirstWin,
+        Achievement::InTwo,
+        Achievement::Streak,
+        Achievement::NoHints,
+        Achievement::HardMode,
+        Achievement::Momentum,
+    ];
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Saved {
+    // When each badge was earned, in seconds since the Unix epoch
+    unlocked: BTreeMap<Achievement, u64>,
+    streak: usize,
+}
+
+/// The badges earned so far, and what goes towards the others.
+#[derive(Debug, Default)]
+pub struct Achievements {
+    saved: Saved,
+}
+
+impl Achievements {
+    /// Reads the badges, starting afresh if there are none.
+    pub fn load() -> Achievements {
+        let saved = Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+        Achievements { saved }
+    }
+
+    /// When `achievement` was earned, if it was.
+    pub fn unlocked(&self, achievement: Achievement) -> Option<u64> {
+        self.saved.unlocked.get(&achievement).copied()
+    }
+
+    /// Wins in a row, up to the last game counted.
+    pub fn streak(&self) -> usize {
+        self.saved.streak
+    }
+
+    /// Counts the finished `game`, at `at`, and returns the badges it
+    /// earned, saving them. Unfinished games don't count.
+    pub fn record(&mut self, game: &Game, at: u64) -> io::Result<Vec<Achievement>> {
+        if !game.status.is_over() {
+            return Ok(Vec::new());
+        }
+        let won = game.status == GameStatus::Won;
+        self.saved.streak = if won { self.saved.streak + 1 } else { 0 };
+        let earned: Vec<Achievement> = Achievement::ALL
+            .into_iter()
+            .filter(|&achievement| {
+                won && self.unlocked(achievement).is_none()
+                    && match achievement {
+                        Achievement::FirstWin => true,
+                        Achievement::InTwo => game.submitted().count() <= 2,
+                        Achievement::Streak => self.saved.streak >= STREAK,
+                        Achievement::NoHints => game.hints_used == 0,
+                        Achievement::HardMode => game.config.feedback == FeedbackStyle::Counts,
+                        Achievement::Momentum => game.best_momentum() >= MOMENTUM,
+                    }
+            })
+            .collect();
+        for &achievement in &earned {
+            self.saved.unlocked.insert(achievement, at);
+        }
+        self.save()?;
+        Ok(earned)
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string_pretty(&self.saved).map_err(io::Error::other)?;
+        fs::write(path, text + "\n")
+    }
+
+    fn path() -> Option<PathBuf> {
+        Some(Config::data_dir()?.join("achievements.json"))
+    }
+}
@@ -0,0 +1,82 @@
+//! Short visual effects on the board. Each is a start time: the interface
+//! asks how far along an effect is when drawing, and the main loop keeps
+//! drawing frames while any is running.
+//!
+//! With reduced motion nothing is ever started, so the board only changes
+//! when the game does.
+
+use std::time::{Duration, Instant};
+
+/// How long the current row shakes after a guess is refused.
+pub const SHAKE_DURATION: Duration = Duration::from_millis(400);
+/// How long a typed letter stays highlighted.
+pub const POP_DURATION: Duration = Duration::from_millis(120);
+
+#[derive(Debug, Default)]
+pub struct Animations {
+    reduced_motion: bool,
+    // When the last guess was refused
+    shake: Option<Instant>,
+    // Column of the last typed letter, and when it was typed
+    pop: Option<(usize, Instant)>,
+}
+
+impl Animations {
+    pub fn new(reduced_motion: bool) -> Self {
+        Animations {
+            reduced_motion,
+            ..Animations::default()
+        }
+    }
+
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+
+    /// Turns the effects off (stopping any running) or back on.
+    pub fn set_reduced_motion(&mut self, reduced: bool) {
+        self.reduced_motion = reduced;
+        if reduced {
+            self.clear();
+        }
+    }
+
+    /// Shakes the row being typed.
+    pub fn reject(&mut self) {
+        if !self.reduced_motion {
+            self.shake = Some(Instant::now());
+        }
+    }
+
+    /// Highlights the letter just typed in `column`.
+    pub fn typed(&mut self, column: usize) {
+        if !self.reduced_motion {
+            self.pop = Some((column, Instant::now()));
+        }
+    }
+
+    /// Stops every effect, for when the board they were on goes away.
+    pub fn clear(&mut self) {
+        self.shake = None;
+        self.pop = None;
+    }
+
+    /// Time since the last guess was refused, while its row still shakes.
+    pub fn shake(&self) -> Option<Duration> {
+        self.shake
+            .map(|at| at.elapsed())
+            .filter(|elapsed| *elapsed < SHAKE_DURATION)
+    }
+
+    /// Column of the letter just typed, while it is highlighted.
+    pub fn pop(&self) -> Option<usize> {
+        self.pop
+            .filter(|(_, at)| at.elapsed() < POP_DURATION)
+            .map(|(column, _)| column)
+    }
+
+    /// Whether any effect still needs frames.
+    pub fn running(&self) -> bool {
+        self.shake().is_some() || self.pop().is_some()
+    }
+}
@@ -0,0 +1,2722 @@
+//! Frontend state: the current game plus everything the interface needs
+//! that isn't part of the game itself (open panels, language, ...).
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use ratatui::layout::Rect;
+
+use crate::achievements::{Achievement, Achievements};
+use crate::animation::Animations;
+use crate::autosave::{self, Saved};
+use crate::card::{self, Card, Palette, Shared};
+use crate::challenge::{Challenge, ChallengeCode, Ledger, Playing, Replies, Reply};
+use crate::changelog::{self, Release};
+use crate::clock;
+use crate::community::{self, Completed, Puzzle};
+use crate::config::{
+    BoardLayout, Config, KeyboardLayout, QuitConfirm, ShareConfig, StatusStyles, ThemeName,
+    ThemeSchedule, Toggle, WordFilterMode,
+};
+use crate::definitions::{self, Definitions};
+use crate::dvr::Dvr;
+use crate::filter::WordFilter;
+use crate::game::analysis::{self, GuessAnalysis};
+use crate::game::candidates::CandidateSet;
+use crate::game::dictionary::{Dictionary, Tier};
+use crate::game::difficulty::Ratings;
+use crate::game::normalize::{fold_word, normalize_input, normalize_letter};
+use crate::game::patterns::PatternMatrix;
+use crate::game::rarity::Frequencies;
+use crate::game::solver::{self, Suggestion};
+use crate::game::timing::TimingSignature;
+use crate::game::{
+    letterle_letters, seed_code, Counts, FeedbackStyle, Game, GameStatus, WORD_LENGTH,
+};
+use crate::guesses::{GuessChain, GuessSource};
+use crate::history::{Finish, History, Source};
+use crate::hotseat::{Hotseat, Phase, Seat};
+use crate::i18n::{Language, Texts};
+use crate::keymap::Keymap;
+use crate::leaderboard::{Category, Leaderboard, Sort};
+use crate::mode::{
+    Blitz, CatchUp, Countdown, Mode, Polyglot, Speedrun, BLITZ_DURATION, CATCH_UP_DAYS,
+    GUESS_DURATION,
+};
+use crate::net::{Pair, Race, Watch};
+use crate::online::{Online, Score, Submission, Typed};
+use crate::overlay::{self, Overlay};
+use crate::paths;
+use crate::records::Records;
+use crate::replay::Replay;
+use crate::sound::{Cue, Sound};
+use crate::stats::{Played, Stats};
+use crate::typos::Typos;
+use crate::ui::assistant::ASSISTANT_ROWS;
+use crate::ui::definition::{self, DEFINITION_ROWS};
+use crate::ui::theme::Theme;
+use crate::ui::Fit;
+use crate::word_themes::{self, WordTheme};
+
+/// Top-level screens: the menu the game starts on, the games themselves
+/// and the screens reached from the menu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum View {
+    /// Main menu, with the highlighted entry.
+    Menu(usize),
+    /// The open games, each showing its own [`Screen`].
+    Game,
+    Stats,
+    /// Badges, earned or not.
+    Achievements,
+    /// Personal bests, sorted as chosen.
+    Leaderboard(Sort),
+    /// Settings, with the highlighted one.
+    Settings(usize),
+    /// The owner typing the secret word of a guest challenge.
+    Challenge,
+    /// Past games, with the highlighted one.
+    History(usize),
+    /// Replay of the past game at this place in the history.
+    Replay(usize),
+    /// Changes of the release at this place in the releases shown.
+    WhatsNew(usize),
+    /// A room of a server, watched without playing.
+    Spectate,
+}
+
+/// Entries of the main menu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MenuItem {
+    /// Back to the game in progress.
+    Resume,
+    NewGame,
+    /// The word of the day, the same for everyone.
+    Daily,
+    /// The words of the days missed, back to back.
+    CatchUp,
+    /// A single letter to find before the word of the day.
+    Warmup,
+    /// Words back to back against the clock.
+    Blitz,
+    /// A word with a clock on every guess.
+    Countdown,
+    /// A word against the stopwatch and the best time so far.
+    Speedrun,
+    /// A word whose guesses are only answered with counts.
+    Mastermind,
+    /// A word with as many guesses as it takes.
+    Zen,
+    /// A word whose guesses can be taken back.
+    Practice,
+    /// Two players taking turns at the keyboard.
+    Hotseat,
+    /// A word in each language in turn.
+    Polyglot,
+    /// The community puzzle of the week.
+    Community,
+    /// A word typed by the owner for a guest to find.
+    Challenge,
+    Stats,
+    /// Badges earned across sessions.
+    Achievements,
+    /// Personal bests by mode and word length.
+    Leaderboard,
+    History,
+    Settings,
+    /// Changes of every release.
+    WhatsNew,
+    Quit,
+}
+
+/// What can be changed from the settings screen, for this session.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Setting {
+    Theme,
+    HighContrast,
+    StatusMarkers,
+    ReducedMotion,
+    KeyboardLayout,
+    WordTheme,
+    WordFilter,
+    QuitConfirm,
+}
+
+impl Setting {
+    pub const ALL: [Setting; 8] = [
+        Setting::Theme,
+        Setting::HighContrast,
+        Setting::StatusMarkers,
+        Setting::ReducedMotion,
+        Setting::KeyboardLayout,
+        Setting::WordTheme,
+        Setting::WordFilter,
+        Setting::QuitConfirm,
+    ];
+}
+
+/// What can be done once a game is over, from the menu under its board.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EndAction {
+    /// Another game of the same mode, or the next word of a run.
+    PlayAgain,
+    /// Back to the main menu, to pick another mode.
+    ChangeMode,
+    Analysis,
+    Share,
+    Quit,
+}
+
+impl EndAction {
+    pub const ALL: [EndAction; 5] = [
+        EndAction::PlayAgain,
+        EndAction::ChangeMode,
+        EndAction::Analysis,
+        EndAction::Share,
+        EndAction::Quit,
+    ];
+
+    /// The letter that picks the action.
+    pub fn key(self) -> char {
+        match self {
+            EndAction::PlayAgain => 'p',
+            EndAction::ChangeMode => 'm',
+            EndAction::Analysis => 'a',
+            EndAction::Share => 's',
+            EndAction::Quit => 'q',
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&action| action == self)
+            .unwrap_or(0)
+    }
+}
+
+/// What fills the screen of a game.
+pub enum Screen {
+    /// The board of the current game.
+    Board,
+    /// The board of a game just over, with the menu of what to do next
+    /// and the entry selected (see [`EndAction::ALL`]).
+    Finished(usize),
+    /// Review of the finished game, one entry per guess.
+    Analysis(Vec<GuessAnalysis>),
+    /// The result card of the finished game, to share.
+    Card(Box<Shared>),
+    /// A board of the DVR, this many changes before the latest, read-only.
+    Dvr(usize),
+}
+
+/// The screen locked after a while without input, hiding the game until
+/// it is unlocked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lock {
+    /// Passphrase typed so far.
+    pub typed: String,
+    /// The last passphrase entered was wrong.
+    pub wrong: bool,
+    /// Whether the game in progress was saved, when there was one to save.
+    pub saved: Option<bool>,
+}
+
+/// A game kept open in a tab other than the active one.
+pub struct Tab {
+    pub screen: Screen,
+    pub game: Game,
+    pub candidates: CandidateSet,
+    pub candidate_history: Vec<CandidateSet>,
+    pub timing: TimingSignature,
+    pub puzzle: Option<String>,
+    pub source: Source,
+    pub mode: Mode,
+    pub game_id: Option<u64>,
+}
+
+pub struct App {
+    pub view: View,
+    // Whether a game was entered from the menu, which can then resume it
+    started: bool,
+    pub screen: Screen,
+    pub game: Game,
+    /// Word list as spelled in the dictionary file, accents included, with
+    /// the tier of each word. New games draw their word from it.
+    pub dictionary: Dictionary,
+    /// Tier of the words drawn for new games, or any word.
+    pub difficulty: Option<Tier>,
+    /// The same words with accents folded, the way guesses are typed. Hints
+    /// and the solver work on this list.
+    pub words: Arc<[String]>,
+    /// Words that can still be the answer of the current game.
+    pub candidates: CandidateSet,
+    /// Candidates as they were before each submitted guess, for the
+    /// post-game analysis.
+    pub candidate_history: Vec<CandidateSet>,
+    /// How the guesses of the current game were typed, to flag scripted
+    /// input when the result is submitted to a leaderboard.
+    pub timing: TimingSignature,
+    /// Id of the community puzzle the current game plays, if it is one.
+    pub puzzle: Option<String>,
+    /// Where the word of the current game came from.
+    pub source: Source,
+    /// How the current game is played.
+    pub mode: Mode,
+    /// Id of the current game in the history, once recorded there.
+    pub game_id: Option<u64>,
+    // When the last letter of the guess being typed was entered
+    last_letter: Option<Instant>,
+    /// Effects running on the board.
+    pub animations: Animations,
+    /// The last boards of the game, to scrub back through.
+    pub dvr: Dvr,
+    /// Where the virtual keyboard's keys were last drawn, so mouse clicks
+    /// can type them. Updated by the interface on every frame.
+    pub keyboard_keys: RefCell<Vec<(char, Rect)>>,
+    /// Whether the terminal shows Sixel graphics, for the result card.
+    pub sixel: bool,
+    /// File the status line for streaming overlays is kept in, with
+    /// `--overlay-file`.
+    pub overlay: Option<Overlay>,
+    /// Where the image of the result card goes: the interface leaves it
+    /// blank, and the main loop draws the image there after the frame.
+    pub card_area: RefCell<Option<Rect>>,
+    /// How result cards are written as text.
+    pub share: ShareConfig,
+    /// Text to put on the system clipboard, which the main loop hands to
+    /// the terminal.
+    pub clipboard: Option<String>,
+    /// Sounds to play, which the main loop plays.
+    pub sound: Sound,
+    /// Games of the other tabs, in tab order. The active game is the one
+    /// in the fields above; its tab sits at `active_tab` among these.
+    pub background_tabs: Vec<Tab>,
+    pub active_tab: usize,
+    /// Language of the interface and of the word list in use.
+    pub language: Language,
+    /// Language the game started in, which games outside the polyglot
+    /// mode are played in.
+    pub home_language: Language,
+    /// Languages of the polyglot mode, from the config.
+    pub polyglot: Polyglot,
+    pub board_layout: BoardLayout,
+    pub keyboard_layout: KeyboardLayout,
+    /// Color and symbol of each letter status, over the theme.
+    pub status_styles: StatusStyles,
+    /// Whether every tile and key shows a symbol of its status.
+    pub status_markers: bool,
+    // `status_styles` with a symbol for every status
+    marked_styles: StatusStyles,
+    /// Letters typed on the keyboard are read through it.
+    pub keymap: Keymap,
+    pub theme_name: ThemeName,
+    /// What the `auto` theme shows when.
+    pub theme_schedule: ThemeSchedule,
+    /// The theme of the schedule at the start of the game; it only changes
+    /// with the next game, not in the middle of one.
+    pub scheduled_theme: ThemeName,
+    /// Colorblind-friendly colors, overriding the theme.
+    pub high_contrast: bool,
+    /// Show where keyboard input goes in the footer.
+    pub keyboard_audit: bool,
+    pub show_legend: bool,
+    /// Show what the feedback says about each slot of the answer.
+    pub show_constraints: bool,
+    /// Guess suggestions, present while the assistant panel is open.
+    pub assistant: Option<Assistant>,
+    /// How hard each word of the list is.
+    pub ratings: Ratings,
+    /// How common each word of the list is.
+    pub frequencies: Frequencies,
+    /// Definitions of the words of the list, from its dictionary.
+    pub definitions: Definitions,
+    // Definitions fetched from the dictionary API before
+    definitions_cache: definitions::Cache,
+    // Address of the dictionary API, with the word left to fill in
+    definitions_api: Option<String>,
+    /// Definition of the word of the last game finished.
+    pub definition: Option<Lookup>,
+    // Pending API answer for `definition`
+    definition_loading: Option<Receiver<Option<String>>>,
+    /// Words of the language kept out of the game when `word_filter` says
+    /// so.
+    pub filter: WordFilter,
+    pub word_filter: WordFilterMode,
+    /// Lists guesses are looked up in, by language code.
+    guess_sources: HashMap<String, Vec<GuessSource>>,
+    /// The lists of the language, loaded; guesses none has are refused.
+    pub guesses: GuessChain,
+    /// Themed word lists that can be played.
+    pub word_themes: Vec<WordTheme>,
+    /// The themed list played instead of the whole word list, if one is.
+    pub word_theme: Option<WordTheme>,
+    /// Games finished this session.
+    pub stats: Stats,
+    /// Record of the games played, across sessions.
+    pub history: History,
+    /// Days whose word of the day wasn't played, oldest first, for the
+    /// catch-up.
+    pub missed_dailies: Vec<u64>,
+    /// Whether the word of the day is still to be played to the end, which
+    /// puts a dot next to it in the menu.
+    pub daily_pending: bool,
+    /// Games the history screen lists, most recent first. Read from the
+    /// history when the screen opens.
+    pub past_games: Vec<Finish>,
+    /// Past game being watched again, on the replay screen.
+    pub replay: Option<Replay>,
+    /// Room of a server being watched, on the spectator screen.
+    pub watching: Option<Watch>,
+    /// Community puzzle of the week, once fetched.
+    pub community: Option<Puzzle>,
+    // Pending download of `community`
+    community_loading: Option<Receiver<Option<Puzzle>>>,
+    /// Community puzzles solved so far, this session or before.
+    pub community_completed: Completed,
+    /// Secret word being typed for a guest challenge, accents folded.
+    pub secret: String,
+    /// Results of the guest challenges played on this machine.
+    pub challenges: Ledger,
+    /// The challenge file played, when the game was started on one.
+    pub sent: Option<Playing>,
+    /// Replies to challenge files, played here or imported.
+    pub replies: Replies,
+    /// Best speedrun times.
+    pub records: Records,
+    /// Personal bests by mode and word length.
+    pub leaderboard: Leaderboard,
+    /// Badges earned across sessions.
+    pub achievements: Achievements,
+    /// Letters typed and deleted across sessions.
+    pub typos: Typos,
+    /// Badges just earned, the first shown over the screen until its time
+    /// is up.
+    pub toasts: VecDeque<Achievement>,
+    // When the first of `toasts` started showing
+    toast_since: Instant,
+    /// Online leaderboard daily results are shared with, when the
+    /// configuration opts in.
+    pub online: Option<Online>,
+    /// Best results of the last daily game shared, once fetched.
+    pub online_top: Option<Vec<Score>>,
+    // Pending submission of a daily result
+    online_loading: Option<Receiver<Option<Vec<Score>>>>,
+    /// Releases the "What's new" screen goes through, newest first.
+    pub releases: Vec<Release>,
+    /// Feedback patterns of the word list, once loaded.
+    pub patterns: Option<Arc<PatternMatrix>>,
+    // Pending background load of `patterns`
+    patterns_loading: Option<Receiver<Option<PatternMatrix>>>,
+    // Whether to load `patterns` at all
+    use_patterns: bool,
+    /// Time without input after which the screen locks, if it ever does.
+    pub idle_timeout: Option<Duration>,
+    // What unlocks the screen, if not any key
+    passphrase: Option<String>,
+    // When the player last did anything
+    last_input: Instant,
+    /// The lock over the screen, while it is locked.
+    pub lock: Option<Lock>,
+    /// Whether the game of a timed mode is paused, its clock stopped and
+    /// its board hidden until a key is pressed.
+    pub paused: bool,
+    /// When quitting asks first.
+    pub quit_confirm: QuitConfirm,
+    /// Quitting waits for Enter, outside of a game in progress (which
+    /// asks through its own status).
+    pub confirming_quit: bool,
+    /// The terminal is too small to show the game, which waits for it to
+    /// grow: nothing but the quit shortcut goes through meanwhile.
+    pub too_small: bool,
+    pub should_quit: bool,
+}
+
+/// How long the toast of an earned badge shows.
+pub const TOAST_DURATION: Duration = Duration::from_secs(3);
+
+/// The definition of a word, as looked up once its game ended.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lookup {
+    /// The word looked up, as spelled in the word list.
+    pub word: String,
+    /// `None` when there is none, or while the API is asked.
+    pub text: Option<String>,
+    pub loading: bool,
+    /// Lines of the definition scrolled past.
+    pub scroll: u16,
+}
+
+/// Solver output for the assistant panel, computed once per submitted guess.
+pub struct Assistant {
+    /// Number of submitted attempts the suggestions account for.
+    pub computed_for: usize,
+    /// How many words can still be the answer.
+    pub remaining: usize,
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl App {
+    pub fn new(config: &Config) -> Self {
+        let language = Language::detect(config.language.as_deref());
+        let use_patterns = match config.solver.pattern_cache {
+            Toggle::On => true,
+            Toggle::Off => false,
+            Toggle::Auto => !cfg!(debug_assertions),
+        };
+        let word_themes = word_themes::available();
+        let word_theme = config
+            .word_theme
+            .as_ref()
+            .and_then(|id| word_themes.iter().find(|theme| &theme.id == id))
+            .cloned();
+        let Assets {
+            dictionary,
+            words,
+            ratings,
+            frequencies,
+            definitions,
+            filter,
+            guesses,
+            patterns_loading,
+        } = Assets::load(
+            language,
+            use_patterns,
+            word_theme.as_ref(),
+            guess_sources(&config.guesses, language),
+        );
+        let word_filter = config.word_filter;
+        let difficulty = config.difficulty.tier();
+        // After an upgrade the game opens on what it brought
+        let releases = changelog::unseen();
+        let mut app = App {
+            view: if releases.is_empty() {
+                View::Menu(0)
+            } else {
+                View::WhatsNew(0)
+            },
+            started: false,
+            screen: Screen::Board,
+            game: draw(
+                &dictionary,
+                word_filter,
+                &filter,
+                difficulty,
+                rand::random(),
+            ),
+            candidates: CandidateSet::new(words.clone()),
+            candidate_history: Vec::new(),
+            timing: TimingSignature::default(),
+            puzzle: None,
+            source: Source::Random,
+            mode: Mode::Classic,
+            game_id: None,
+            last_letter: None,
+            animations: Animations::new(config.display.reduced_motion()),
+            dvr: Dvr::default(),
+            keyboard_keys: RefCell::new(Vec::new()),
+            sixel: false,
+            overlay: None,
+            card_area: RefCell::new(None),
+            share: config.share.clone(),
+            clipboard: None,
+            sound: Sound::new(config.sound.clone()),
+            background_tabs: Vec::new(),
+            active_tab: 0,
+            dictionary,
+            difficulty,
+            words,
+            language,
+            home_language: language,
+            polyglot: Polyglot::from_codes(&config.polyglot),
+            board_layout: config.display.board_layout,
+            keyboard_layout: config.display.keyboard_layout,
+            status_styles: config.display.status.clone(),
+            status_markers: config.display.status_markers,
+            marked_styles: config.display.status.marked(),
+            keymap: Keymap::new(&config.keymap),
+            theme_name: config.display.theme,
+            theme_schedule: config.display.theme_schedule.clone(),
+            scheduled_theme: config
+                .display
+                .theme_schedule
+                .theme_at(clock::local_minutes()),
+            high_contrast: config.display.high_contrast,
+            keyboard_audit: config.display.keyboard_audit,
+            show_legend: false,
+            show_constraints: false,
+            assistant: None,
+            ratings,
+            frequencies,
+            definitions,
+            definitions_cache: definitions::Cache::load(),
+            definitions_api: config.definitions.api_url.clone(),
+            definition: None,
+            definition_loading: None,
+            filter,
+            word_filter,
+            guess_sources: config.guesses.clone(),
+            guesses,
+            word_themes,
+            word_theme,
+            stats: Stats::default(),
+            history: History::open(),
+            missed_dailies: Vec::new(),
+            daily_pending: false,
+            past_games: Vec::new(),
+            replay: None,
+            watching: None,
+            community: None,
+            community_loading: config
+                .community
+                .feed_url
+                .clone()
+                .map(community::fetch_in_background),
+            community_completed: Completed::load(),
+            secret: String::new(),
+            challenges: Ledger::load(),
+            sent: None,
+            replies: Replies::load(),
+            records: Records::load(),
+            leaderboard: Leaderboard::load(),
+            achievements: Achievements::load(),
+            typos: Typos::load(),
+            toasts: VecDeque::new(),
+            toast_since: Instant::now(),
+            online: Online::from_config(&config.online),
+            online_top: None,
+            online_loading: None,
+            releases,
+            patterns: None,
+            patterns_loading,
+            use_patterns,
+            idle_timeout: (config.lock.idle_minutes > 0)
+                .then(|| Duration::from_secs(60 * config.lock.idle_minutes)),
+            passphrase: config.lock.passphrase.clone(),
+            last_input: Instant::now(),
+            lock: None,
+            paused: false,
+            quit_confirm: config.quit_confirm,
+            confirming_quit: false,
+            too_small: false,
+            should_quit: false,
+        };
+        app.missed_dailies = app.history.missed_dailies(today(), language, CATCH_UP_DAYS);
+        app.daily_pending = !app.history.daily_finished(today(), language);
+        if let Some(saved) = autosave::load() {
+            app.restore(saved);
+        }
+        app
+    }
+
+    // Plays again the game saved when the screen last locked, straight
+    // away unless the game opens on something else
+    fn restore(&mut self, saved: Saved) {
+        if let Some(language) = Language::from_code(&saved.language) {
+            self.switch_language(language);
+        }
+        self.replace_game(saved.game(), None);
+        self.mode = Mode::Classic;
+        self.source = saved.source;
+        self.game_id = saved.game_id;
+        self.started = true;
+        if self.view == View::Menu(0) {
+            self.view = View::Game;
+        }
+        self.sync_candidates();
+        self.game.show_message(self.texts().lock_restored);
+        autosave::clear();
+    }
+
+    /// Saves the game in progress, when it can be saved, and locks the
+    /// screen over it. The games of other tabs aren't saved.
+    pub fn lock_screen(&mut self) {
+        self.pause();
+        let saved = self.save_in_progress();
+        self.animations.clear();
+        self.lock = Some(Lock {
+            typed: String::new(),
+            wrong: false,
+            saved,
+        });
+    }
+
+    // Saves the game in progress to be played again at the next start, if
+    // there is one that can be saved; whether it was saved
+    fn save_in_progress(&self) -> Option<bool> {
+        Saved::of(&self.game, self.source, self.language.code(), self.game_id)
+            .filter(|_| self.started && matches!(self.mode, Mode::Classic))
+            .map(|saved| autosave::save(&saved).is_ok())
+    }
+
+    /// Quits, or asks first if the quit policy says so.
+    pub fn request_quit(&mut self) {
+        let in_progress = self.started && self.game.status == GameStatus::Playing;
+        if self.quit_confirm.asks(in_progress) {
+            self.confirming_quit = true;
+        } else {
+            self.should_quit = true;
+        }
+    }
+
+    /// Quits without asking, saving the game in progress first.
+    pub fn hard_quit(&mut self) {
+        self.save_in_progress();
+        self.should_quit = true;
+    }
+
+    /// Whether the screen unlocks with a passphrase rather than any key.
+    pub fn has_passphrase(&self) -> bool {
+        self.passphrase.is_some()
+    }
+
+    // Takes the lock off, forgetting the game saved on the way: it is
+    // back on screen
+    fn unlock(&mut self) {
+        if let Some(Lock {
+            saved: Some(true), ..
+        }) = self.lock.take()
+        {
+            autosave::clear();
+        }
+    }
+
+    // Any key unlocks the screen, or the passphrase followed by Enter when
+    // there is one
+    fn handle_lock_key(&mut self, key: KeyEvent) {
+        let Some(passphrase) = &self.passphrase else {
+            self.unlock();
+            return;
+        };
+        let Some(lock) = &mut self.lock else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(c) => {
+                lock.typed.push(c);
+                lock.wrong = false;
+            }
+            KeyCode::Backspace => {
+                lock.typed.pop();
+            }
+            KeyCode::Esc => lock.typed.clear(),
+            KeyCode::Enter if lock.typed == *passphrase => self.unlock(),
+            KeyCode::Enter => {
+                lock.typed.clear();
+                lock.wrong = true;
+            }
+            _ => {}
+        }
+    }
+
+    /// Stops the clock of a timed game in progress and hides its board.
+    /// Games without a clock have nothing to pause.
+    pub fn pause(&mut self) {
+        let can_pause = !self.paused
+            && self.started
+            && self.view == View::Game
+            && matches!(self.screen, Screen::Board)
+            && self.mode.is_timed()
+            && self.game.status.accepts_input();
+        if !can_pause {
+            return;
+        }
+        self.mode.pause();
+        if let Some(id) = self.game_id {
+            self.history.pause(id);
+        }
+        self.animations.clear();
+        self.paused = true;
+    }
+
+    // Starts the clock of the paused game again, leaving the pause out of
+    // its time
+    fn resume(&mut self) {
+        self.mode.resume();
+        if let Some(id) = self.game_id {
+            self.history.resume(id);
+        }
+        self.paused = false;
+    }
+
+    // Locks the screen once nothing happened for the idle timeout
+    fn run_idle_lock(&mut self) {
+        let Some(timeout) = self.idle_timeout else {
+            return;
+        };
+        if self.lock.is_none() && self.last_input.elapsed() >= timeout {
+            self.lock_screen();
+        }
+    }
+
+    pub fn texts(&self) -> &'static Texts {
+        self.language.texts()
+    }
+
+    /// How letter statuses are shown over the theme: with a symbol for
+    /// each if status markers are on.
+    pub fn shown_status_styles(&self) -> &StatusStyles {
+        if self.status_markers {
+            &self.marked_styles
+        } else {
+            &self.status_styles
+        }
+    }
+
+    pub fn theme(&self) -> &'static Theme {
+        if self.high_contrast {
+            Theme::high_contrast()
+        } else {
+            Theme::named(self.shown_theme())
+        }
+    }
+
+    /// The theme on screen: the one chosen, or for `auto` the one its
+    /// schedule picked when the game started.
+    pub fn shown_theme(&self) -> ThemeName {
+        match self.theme_name {
+            ThemeName::Auto => self.scheduled_theme,
+            name => name,
+        }
+    }
+
+    /// The chosen theme as the settings show it, with the one `auto` shows.
+    pub fn theme_label(&self) -> String {
+        match self.theme_name {
+            ThemeName::Auto => format!("auto ({})", self.scheduled_theme.as_str()),
+            name => name.as_str().to_string(),
+        }
+    }
+
+    // Moves to the next theme; `auto` picks its theme for now straight away
+    fn next_theme(&mut self) {
+        self.theme_name = self.theme_name.next();
+        self.follow_schedule();
+    }
+
+    // Catches up with the theme schedule, between games
+    fn follow_schedule(&mut self) {
+        self.scheduled_theme = self.theme_schedule.theme_at(clock::local_minutes());
+    }
+
+    /// Where keyboard input currently goes, outermost first: the screen,
+    /// then the prompt or state that handles the keys within it.
+    pub fn focus_path(&self) -> Vec<&'static str> {
+        let texts = self.texts();
+        if self.lock.is_some() {
+            return vec![texts.lock_title];
+        }
+        if self.paused {
+            return vec![texts.pause_title];
+        }
+        match self.view {
+            View::Menu(_) => return vec![texts.menu_title],
+            View::Stats => return vec![texts.menu_stats],
+            View::Achievements => return vec![texts.menu_achievements],
+            View::Leaderboard(_) => return vec![texts.menu_leaderboard],
+            View::Settings(_) => return vec![texts.menu_settings],
+            View::Challenge => return vec![texts.menu_challenge],
+            View::History(_) => return vec![texts.menu_history],
+            View::Replay(_) => return vec![texts.menu_history, texts.replay_label],
+            View::WhatsNew(_) => return vec![texts.menu_whats_new],
+            View::Spectate => return vec![texts.spectate_title],
+            View::Game => {}
+        }
+        match self.screen {
+            Screen::Analysis(_) => vec![texts.analysis_title],
+            Screen::Card(_) => vec![texts.card_title],
+            Screen::Dvr(_) => vec![texts.focus_board, texts.dvr_label],
+            Screen::Finished(_) => vec![texts.focus_board, texts.focus_game_over],
+            Screen::Board if self.mode.is_over() => vec![texts.focus_board, texts.focus_game_over],
+            Screen::Board => match self.game.status {
+                GameStatus::Playing => vec![texts.focus_board],
+                GameStatus::Won | GameStatus::Lost => {
+                    vec![texts.focus_board, texts.focus_game_over]
+                }
+                GameStatus::Quitting => vec![texts.focus_board, texts.focus_quit],
+            },
+        }
+    }
+
+    /// Entries of the main menu, as they are listed.
+    pub fn menu_items(&self) -> Vec<MenuItem> {
+        let mut items = Vec::new();
+        if self.started {
+            items.push(MenuItem::Resume);
+        }
+        items.extend([MenuItem::NewGame, MenuItem::Daily]);
+        if !self.missed_dailies.is_empty() {
+            items.push(MenuItem::CatchUp);
+        }
+        items.extend([
+            MenuItem::Warmup,
+            MenuItem::Blitz,
+            MenuItem::Countdown,
+            MenuItem::Speedrun,
+            MenuItem::Mastermind,
+            MenuItem::Zen,
+            MenuItem::Practice,
+            MenuItem::Hotseat,
+            MenuItem::Polyglot,
+        ]);
+        if self.community.is_some() {
+            items.push(MenuItem::Community);
+        }
+        items.extend([
+            MenuItem::Challenge,
+            MenuItem::Stats,
+            MenuItem::Achievements,
+            MenuItem::Leaderboard,
+            MenuItem::History,
+            MenuItem::Settings,
+            MenuItem::WhatsNew,
+            MenuItem::Quit,
+        ]);
+        items
+    }
+
+    /// Number of open tabs, the active one included.
+    pub fn tab_count(&self) -> usize {
+        self.background_tabs.len() + 1
+    }
+
+    /// The game of every tab, in tab order.
+    pub fn tab_games(&self) -> impl Iterator<Item = &Game> {
+        let (before, after) = self.background_tabs.split_at(self.active_tab);
+        before
+            .iter()
+            .map(|tab| &tab.game)
+            .chain([&self.game])
+            .chain(after.iter().map(|tab| &tab.game))
+    }
+
+    /// Opens a tab with a new game right after the active one, and switches
+    /// to it.
+    pub fn open_tab(&mut self) {
+        self.open_game_tab(self.draw_word(), None);
+        self.record_start(Source::Random);
+    }
+
+    /// Plays the community puzzle of the week, with a word about who made
+    /// it.
+    pub fn play_community(&mut self) {
+        let texts = self.texts();
+        let Some(puzzle) = self.community.clone() else {
+            self.game.show_message(texts.community_missing);
+            return;
+        };
+        self.start_game(
+            Game::with_word(&puzzle.word),
+            Source::Community,
+            Some(puzzle.id),
+        );
+        self.game.show_message(format!(
+            "{} {} {}",
+            texts.community_title, texts.community_by, puzzle.author
+        ));
+    }
+
+    /// Plays `game` in the active tab if nothing was played there yet,
+    /// otherwise in a new tab, and shows it.
+    pub fn start_game(&mut self, game: Game, source: Source, puzzle: Option<String>) {
+        let untouched = self.game.status == GameStatus::Playing
+            && self.game.current_attempt == 0
+            && typed_letters(&self.game) == 0;
+        if untouched {
+            self.replace_game(game, puzzle);
+        } else {
+            self.open_game_tab(game, puzzle);
+        }
+        self.mode = Mode::Classic;
+        self.record_start(source);
+        self.view = View::Game;
+        self.started = true;
+    }
+
+    // A game on a word drawn at random, of the chosen difficulty
+    fn draw_word(&self) -> Game {
+        let seed = rand::random();
+        draw(
+            &self.dictionary,
+            self.word_filter,
+            &self.filter,
+            self.difficulty,
+            seed,
+        )
+    }
+
+    // The game `seed` draws, unless the word filter keeps its word out
+    // (see `draw`). The difficulty leaves it alone, so a seed gives the
+    // same word to everyone.
+    fn draw_seeded(&self, seed: u64) -> Game {
+        draw(&self.dictionary, self.word_filter, &self.filter, None, seed)
+    }
+
+    /// Chooses the difficulty after (or before) the one chosen, wrapping
+    /// around through any word.
+    pub fn cycle_difficulty(&mut self, forward: bool) {
+        let choices = [None, Some(Tier::Easy), Some(Tier::Medium), Some(Tier::Hard)];
+        let index = choices
+            .iter()
+            .position(|&choice| choice == self.difficulty)
+            .unwrap_or(0);
+        let step = if forward { 1 } else { choices.len() - 1 };
+        self.difficulty = choices[(index + step) % choices.len()];
+    }
+
+    /// Plays a word drawn at random, of the chosen difficulty.
+    pub fn play_random(&mut self) {
+        let game = self.draw_word();
+        self.start_game(game, Source::Random, None);
+    }
+
+    /// Plays the word of the day.
+    pub fn play_daily(&mut self) {
+        let game = self.draw_seeded(today());
+        self.start_game(game, Source::Daily, None);
+        self.online_top = None;
+    }
+
+    /// Plays the words of the days missed one after another, as archive
+    /// games.
+    pub fn start_catch_up(&mut self) {
+        let Some(&day) = self.missed_dailies.first() else {
+            return;
+        };
+        let catch_up = CatchUp::new(self.missed_dailies.clone());
+        let game = self.draw_seeded(day);
+        self.start_game(game, Source::Archive, None);
+        self.mode = Mode::CatchUp(catch_up);
+    }
+
+    /// Plays the word `seed` draws, which anyone with the seed and the
+    /// same word list gets too.
+    pub fn play_seed(&mut self, seed: u64) {
+        let game = self.draw_seeded(seed);
+        self.start_game(game, Source::Random, None);
+    }
+
+    /// Starts a guest challenge on `word`, set by another player.
+    pub fn play_challenge(&mut self, word: &str) {
+        self.start_game(Game::with_word(word), Source::Challenge, None);
+        self.game.show_message(self.texts().challenge_start);
+    }
+
+    /// Starts a guest challenge on the word of `code`, in its language and
+    /// spelled as in its word list when it is there.
+    pub fn play_code(&mut self, code: ChallengeCode) {
+        self.switch_language(code.language);
+        let word = code.word();
+        let spelling = self.spelling(&word).unwrap_or(word);
+        self.secret.clear();
+        self.play_challenge(&spelling);
+    }
+
+    // How the word list spells `word`, given with its accents folded
+    fn spelling(&self, word: &str) -> Option<String> {
+        self.dictionary
+            .spellings()
+            .iter()
+            .find(|spelling| fold_word(spelling) == word)
+            .cloned()
+    }
+
+    /// Races the player at the other end of `race` on the word its seed
+    /// draws.
+    pub fn play_race(&mut self, race: Race) {
+        let game = self.draw_seeded(race.seed);
+        self.start_game(game, Source::Random, None);
+        self.mode = Mode::Race(Box::new(race));
+    }
+
+    /// Plays the word the seed of `pair` draws on one board with the
+    /// partner at its other end.
+    pub fn play_pair(&mut self, pair: Pair) {
+        let game = self.draw_seeded(pair.seed);
+        self.start_game(game, Source::Random, None);
+        self.mode = Mode::Pair(Box::new(pair));
+    }
+
+    /// Plays the challenge file of `playing`, with a word about who sent
+    /// it. The reply is written once the game is over.
+    pub fn play_sent(&mut self, playing: Playing) {
+        let game = Game::with_config(playing.challenge.config(), &playing.word);
+        let message = format!("{} {}", self.texts().sent_from, playing.challenge.from);
+        self.sent = Some(playing);
+        self.start_game(game, Source::Sent, None);
+        self.game.show_message(message);
+    }
+
+    /// Watches the room at the other end of `watch`, without playing.
+    pub fn watch(&mut self, watch: Watch) {
+        self.watching = Some(watch);
+        self.view = View::Spectate;
+    }
+
+    /// Starts the polyglot mode on its first language.
+    pub fn start_polyglot(&mut self) {
+        let polyglot = self.polyglot.clone();
+        self.switch_language(polyglot.language());
+        let game = self.draw_word();
+        self.start_game(game, Source::Random, None);
+        self.mode = Mode::Polyglot(polyglot);
+    }
+
+    /// Plays with the word list and interface of `language` from now on:
+    /// the word list, its ratings, frequencies and pattern matrix are
+    /// loaded again.
+    /// The game being played isn't changed.
+    pub fn switch_language(&mut self, language: Language) {
+        if language == self.language {
+            return;
+        }
+        self.language = language;
+        self.reload_words();
+    }
+
+    /// Plays new games with the themed list `theme`, or the whole word
+    /// list for `None`.
+    pub fn set_word_theme(&mut self, theme: Option<WordTheme>) {
+        self.word_theme = theme;
+        self.reload_words();
+    }
+
+    // The theme after the one played among those of the language, then
+    // none, wrapping around
+    fn next_word_theme(&self) -> Option<WordTheme> {
+        let fitting: Vec<&WordTheme> = self
+            .word_themes
+            .iter()
+            .filter(|theme| theme.fits(self.language))
+            .collect();
+        let next = match &self.word_theme {
+            None => 0,
+            Some(current) => fitting
+                .iter()
+                .position(|theme| theme.id == current.id)
+                .map_or(0, |index| index + 1),
+        };
+        fitting.get(next).map(|&theme| theme.clone())
+    }
+
+    // Loads the word list of the language, or the theme played if it
+    // fits the language, with what goes with it
+    fn reload_words(&mut self) {
+        let assets = Assets::load(
+            self.language,
+            self.use_patterns,
+            self.word_theme.as_ref(),
+            guess_sources(&self.guess_sources, self.language),
+        );
+        self.dictionary = assets.dictionary;
+        self.words = assets.words;
+        self.ratings = assets.ratings;
+        self.frequencies = assets.frequencies;
+        self.definitions = assets.definitions;
+        self.filter = assets.filter;
+        self.guesses = assets.guesses;
+        self.patterns = None;
+        self.patterns_loading = assets.patterns_loading;
+        if let Some(assistant) = &mut self.assistant {
+            assistant.computed_for = usize::MAX;
+        }
+    }
+
+    /// Starts a blitz: a first word, and the clock.
+    pub fn start_blitz(&mut self) {
+        let game = self.draw_word();
+        self.start_game(game, Source::Blitz, None);
+        self.mode = Mode::Blitz(Blitz::start(BLITZ_DURATION));
+    }
+
+    /// Starts a game where every guess must be in before its clock runs
+    /// out.
+    pub fn start_countdown(&mut self) {
+        let game = self.draw_word();
+        self.start_game(game, Source::Random, None);
+        self.mode = Mode::Countdown(Countdown::start(GUESS_DURATION));
+    }
+
+    /// Starts a speedrun: a word, and a stopwatch that starts with the
+    /// first letter.
+    pub fn start_speedrun(&mut self) {
+        let game = self.draw_word();
+        self.start_game(game, Source::Random, None);
+        let best = self.records.best(self.game.config.word_length);
+        self.mode = Mode::Speedrun(Speedrun::new(best));
+    }
+
+    /// Starts a versus game between two players on this terminal, each on
+    /// a word of their own until they agree on sharing one.
+    pub fn start_hotseat(&mut self) {
+        let game = self.draw_word();
+        self.start_game(game, Source::Hotseat, None);
+        let waiting = Seat::new(self.draw_word(), self.words.clone());
+        self.mode = Mode::Hotseat(Box::new(Hotseat::new(waiting, false)));
+    }
+
+    // Passes the keyboard once the player at it made their guess, and
+    // scores the round once it is decided
+    fn run_hotseat(&mut self) {
+        let Mode::Hotseat(hotseat) = &mut self.mode else {
+            return;
+        };
+        if hotseat.phase != Phase::Playing {
+            return;
+        }
+        let guesses = self.game.submitted().count();
+        if guesses == hotseat.turn_start {
+            return;
+        }
+        if hotseat.settle(&self.game) {
+            return;
+        }
+        if hotseat.should_pass() {
+            let seat = &mut hotseat.waiting;
+            std::mem::swap(&mut self.game, &mut seat.game);
+            std::mem::swap(&mut self.candidates, &mut seat.candidates);
+            std::mem::swap(&mut self.candidate_history, &mut seat.candidate_history);
+            std::mem::swap(&mut self.timing, &mut seat.timing);
+            hotseat.turn = 1 - hotseat.turn;
+            hotseat.phase = Phase::Handover;
+            self.last_letter = None;
+            self.animations.clear();
+        }
+        hotseat.turn_start = self.game.submitted().count();
+    }
+
+    // Keys between the turns of a versus game: Enter when the next player
+    // is at the keyboard, S to play the same word before the first guess,
+    // and once a round is over Enter for the next one. Returns whether the
+    // key was taken.
+    fn handle_hotseat_key(&mut self, key: KeyEvent) -> bool {
+        let Mode::Hotseat(hotseat) = &mut self.mode else {
+            return false;
+        };
+        match hotseat.phase {
+            Phase::Handover => match key.code {
+                KeyCode::Enter => hotseat.phase = Phase::Playing,
+                KeyCode::Char('s') | KeyCode::Char('S') if hotseat.untouched(&self.game) => {
+                    hotseat.same_word = !hotseat.same_word;
+                    hotseat.waiting.game = if hotseat.same_word {
+                        match self.game.seed {
+                            Some(seed) => Game::from_seed(self.dictionary.spellings(), seed),
+                            None => Game::with_word(&self.game.display_word),
+                        }
+                    } else {
+                        draw(
+                            &self.dictionary,
+                            self.word_filter,
+                            &self.filter,
+                            self.difficulty,
+                            rand::random(),
+                        )
+                    };
+                }
+                KeyCode::Esc => self.view = View::Menu(0),
+                _ => {}
+            },
+            // The next round is started by the other player
+            Phase::Over(_) => match key.code {
+                KeyCode::Enter => {
+                    let (dictionary, filter) = (&self.dictionary, &self.filter);
+                    let (mode, tier) = (self.word_filter, self.difficulty);
+                    let game = draw(dictionary, mode, filter, tier, rand::random());
+                    let rival = match game.seed {
+                        Some(seed) if hotseat.same_word => {
+                            Game::from_seed(dictionary.spellings(), seed)
+                        }
+                        _ => draw(dictionary, mode, filter, tier, rand::random()),
+                    };
+                    hotseat.waiting = Seat::new(rival, self.words.clone());
+                    hotseat.starter = hotseat.next_starter();
+                    hotseat.turn = hotseat.starter;
+                    hotseat.turn_start = 0;
+                    hotseat.phase = Phase::Handover;
+                    self.replace_game(game, None);
+                }
+                KeyCode::Esc => self.view = View::Menu(0),
+                _ => {}
+            },
+            Phase::Playing => return false,
+        }
+        true
+    }
+
+    /// Whether a clock on screen changes faster than the main loop ticks,
+    /// so frames should keep coming.
+    pub fn clock_running(&self) -> bool {
+        match &self.mode {
+            Mode::Speedrun(run) => run.is_running(),
+            _ => false,
+        }
+    }
+
+    // Forfeits the current row once its clock ran out, and restarts the
+    // clock on every new row
+    fn run_countdown(&mut self) {
+        let Mode::Countdown(countdown) = &mut self.mode else {
+            return;
+        };
+        countdown.follow(self.game.current_attempt);
+        if !countdown.is_up() || !self.game.status.accepts_input() {
+            return;
+        }
+        self.game.forfeit_row();
+        countdown.follow(self.game.current_attempt);
+        self.game.show_message(self.texts().countdown_forfeit);
+        self.sync_candidates();
+    }
+
+    // Tells the rival of a race about the guesses made, and hears about
+    // theirs
+    fn run_race(&mut self) {
+        let Mode::Race(race) = &mut self.mode else {
+            return;
+        };
+        race.send_progress(&self.game);
+        race.poll();
+    }
+
+    // The guess dictionary that takes each guess of the game, for the
+    // history; nothing when guesses aren't checked
+    fn accepted_by(&self) -> Vec<String> {
+        if self.guesses.is_empty() {
+            return Vec::new();
+        }
+        self.game
+            .submitted()
+            .map(|(guess, _)| {
+                let guess: String = guess.iter().collect();
+                self.guesses
+                    .find(&guess)
+                    .map_or_else(|| "-".to_string(), GuessSource::label)
+            })
+            .collect()
+    }
+
+    // Tells the partner of a pair what was typed and submitted, and plays
+    // the guesses they submitted
+    fn run_pair(&mut self) {
+        let Mode::Pair(pair) = &mut self.mode else {
+            return;
+        };
+        pair.send_progress(&self.game);
+        pair.poll();
+        let mut played = false;
+        while let Some(word) = pair.next_guess() {
+            played |= self.game.guess(&word).is_ok();
+        }
+        if played {
+            self.sync_candidates();
+        }
+    }
+
+    // Writes the reply to the challenge file played, for its author, and
+    // keeps it with the others
+    fn reply_sent(&mut self) {
+        let Some(playing) = &self.sent else {
+            return;
+        };
+        let texts = self.language.texts();
+        let reply = Reply::new(&playing.challenge, &playing.player, &self.game);
+        let path = playing.reply_path();
+        let message = match reply.save(&path) {
+            Ok(()) => format!(
+                "{} {}: {}",
+                texts.sent_reply_saved,
+                playing.challenge.from,
+                path.display()
+            ),
+            Err(_) => texts.sent_reply_unsaved.to_string(),
+        };
+        let _ = self.replies.record(reply);
+        self.game.show_message(message);
+    }
+
+    // Hears what the players of the room watched did
+    fn run_watch(&mut self) {
+        if let Some(watch) = &mut self.watching {
+            watch.poll();
+        }
+    }
+
+    // Moves a race on a server to the round it started, if it did
+    fn next_race_round(&mut self) -> bool {
+        let Mode::Race(race) = &mut self.mode else {
+            return false;
+        };
+        let Some(seed) = race.next_round() else {
+            return false;
+        };
+        self.replace_game(self.draw_seeded(seed), None);
+        self.record_start(Source::Random);
+        true
+    }
+
+    // Moves the polyglot mode on to a word in its next language
+    fn next_polyglot_word(&mut self) -> bool {
+        let Mode::Polyglot(polyglot) = &mut self.mode else {
+            return false;
+        };
+        let language = polyglot.advance();
+        self.switch_language(language);
+        self.replace_game(self.draw_word(), None);
+        self.record_start(Source::Random);
+        true
+    }
+
+    // Moves a catch-up on to the word of its next day
+    fn next_catch_up_day(&mut self) -> bool {
+        let Mode::CatchUp(catch_up) = &mut self.mode else {
+            return false;
+        };
+        let Some(day) = catch_up.advance() else {
+            return false;
+        };
+        self.replace_game(self.draw_seeded(day), None);
+        self.record_start(Source::Archive);
+        true
+    }
+
+    // Moves a blitz on to its next word, keeping the clock and the score
+    fn next_blitz_word(&mut self) {
+        let answer = self.game.display_word.clone();
+        self.replace_game(self.draw_word(), None);
+        self.record_start(Source::Blitz);
+        self.game
+            .show_message(format!("{} {}", self.texts().blitz_next, answer));
+    }
+
+    // Opens a tab playing `game` right after the active one, and switches
+    // to it
+    fn open_game_tab(&mut self, game: Game, puzzle: Option<String>) {
+        let fresh = Tab {
+            screen: Screen::Board,
+            game,
+            candidates: CandidateSet::new(self.words.clone()),
+            candidate_history: Vec::new(),
+            timing: TimingSignature::default(),
+            puzzle,
+            source: Source::Random,
+            mode: Mode::Classic,
+            game_id: None,
+        };
+        let previous = self.swap_active(fresh);
+        self.background_tabs.insert(self.active_tab, previous);
+        self.active_tab += 1;
+    }
+
+    /// Closes the active tab and shows the next one (or the previous, for
+    /// the last tab). The last open tab can't be closed.
+    pub fn close_tab(&mut self) {
+        if self.background_tabs.is_empty() {
+            return;
+        }
+        let index = self.active_tab.min(self.background_tabs.len() - 1);
+        let next = self.background_tabs.remove(index);
+        self.swap_active(next);
+        self.active_tab = index;
+    }
+
+    /// Switches to the tab `offset` places away, wrapping around.
+    pub fn cycle_tab(&mut self, offset: isize) {
+        let count = self.tab_count() as isize;
+        let target = (self.active_tab as isize + offset).rem_euclid(count) as usize;
+        if target == self.active_tab {
+            return;
+        }
+        // Take the target out of the background, then park the active game
+        // where its tab is among the others
+        let index = if target < self.active_tab {
+            target
+        } else {
+            target - 1
+        };
+        let next = self.background_tabs.remove(index);
+        let previous = self.swap_active(next);
+        let parked = if target < self.active_tab {
+            self.active_tab - 1
+        } else {
+            self.active_tab
+        };
+        self.background_tabs.insert(parked, previous);
+        self.active_tab = target;
+    }
+
+    /// Applies a terminal event to the app.
+    pub fn handle_event(&mut self, event: Event) {
+        if let Event::Key(_) | Event::Paste(_) | Event::Mouse(_) = event {
+            self.last_input = Instant::now();
+        }
+        match event {
+            // Nothing gets past the lock but what takes it off
+            Event::Key(key) if key.kind == KeyEventKind::Press && self.lock.is_some() => {
+                self.handle_lock_key(self.keymap.apply(key))
+            }
+            _ if self.lock.is_some() => {}
+            Event::Resize(columns, rows) => self.resize(columns, rows),
+            // Any key resumes a paused game, and nothing else reaches it
+            Event::Key(key) if key.kind == KeyEventKind::Press && self.paused => self.resume(),
+            _ if self.paused => {}
+            // Keys and clicks on a board that can't be seen would change it
+            // unseen
+            Event::Key(key)
+                if key.kind == KeyEventKind::Press
+                    && self.too_small
+                    && self.keymap.is_quit(key) =>
+            {
+                self.hard_quit()
+            }
+            _ if self.too_small => {}
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                self.handle_key(self.keymap.apply(key))
+            }
+            Event::Paste(text) => self.handle_paste(&text),
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
+            _ => {}
+        }
+        self.sync_candidates();
+        self.run_hotseat();
+        self.run_race();
+        self.run_pair();
+        self.run_watch();
+        self.run_countdown();
+        self.poll_community();
+        self.poll_definition();
+        self.poll_online();
+        self.poll_patterns();
+        self.refresh_assistant();
+        self.dvr.record(&self.game);
+        self.write_overlay();
+    }
+
+    /// Takes the new size of the terminal, `columns` by `rows`. Where
+    /// things were drawn is forgotten until the next frame draws them
+    /// again, and while the game doesn't fit, input is held.
+    pub fn resize(&mut self, columns: u16, rows: u16) {
+        let size = Rect::new(0, 0, columns, rows);
+        self.too_small = Fit::for_size(size, self.board_layout).is_none();
+        self.keyboard_keys.borrow_mut().clear();
+        *self.card_area.borrow_mut() = None;
+    }
+
+    /// Replaces the current game with a fresh one.
+    pub fn new_game(&mut self) {
+        self.replace_game(self.draw_word(), None);
+        self.mode = Mode::Classic;
+        self.record_start(Source::Random);
+    }
+
+    // Logs how the word of the game just started was chosen. A game that
+    // can't be logged is still played, it just has no id.
+    fn record_start(&mut self, source: Source) {
+        self.follow_schedule();
+        self.source = source;
+        // Versus rounds are between the players, not for the history
+        if source == Source::Hotseat {
+            self.game_id = None;
+            return;
+        }
+        // The warmup letter is drawn from the alphabet, not the word list
+        let words = match source {
+            Source::Warmup => letterle_letters().into(),
+            _ => self.dictionary.spellings().clone(),
+        };
+        self.game_id = self
+            .history
+            .start(&self.game, source, self.language, &words)
+            .ok();
+        if let (Source::Daily | Source::Archive, Some(day)) = (source, self.game.seed) {
+            self.missed_dailies.retain(|&missed| missed != day);
+        }
+    }
+
+    // Plays `game` in the active tab, dropping the one there
+    fn replace_game(&mut self, game: Game, puzzle: Option<String>) {
+        self.screen = Screen::Board;
+        self.game = game;
+        self.candidates = CandidateSet::new(self.words.clone());
+        self.candidate_history.clear();
+        self.timing = TimingSignature::default();
+        self.puzzle = puzzle;
+        self.game_id = None;
+        self.last_letter = None;
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        if self.keymap.is_quit(key) {
+            self.hard_quit();
+            return;
+        }
+        // Enter quits, anything else stays
+        if self.confirming_quit {
+            self.confirming_quit = false;
+            self.should_quit = key.code == KeyCode::Enter;
+            return;
+        }
+        // Display keys work on every screen
+        if key.code == KeyCode::F(2) {
+            self.next_theme();
+            let texts = self.texts();
+            self.game
+                .show_message(format!("{}: {}", texts.theme_label, self.theme_label()));
+            return;
+        }
+        if key.code == KeyCode::F(3) {
+            self.high_contrast = !self.high_contrast;
+            let texts = self.texts();
+            self.game.show_message(if self.high_contrast {
+                texts.high_contrast_on
+            } else {
+                texts.high_contrast_off
+            });
+            return;
+        }
+
+        match self.view {
+            View::Menu(selected) => self.handle_menu_key(key, selected),
+            View::Stats | View::Achievements => {
+                if let KeyCode::Esc | KeyCode::Enter = key.code {
+                    self.view = View::Menu(0);
+                }
+            }
+            View::Leaderboard(sort) => self.handle_leaderboard_key(key, sort),
+            View::Settings(selected) => self.handle_settings_key(key, selected),
+            View::Challenge => self.handle_challenge_key(key),
+            View::History(selected) => self.handle_history_key(key, selected),
+            View::Replay(selected) => self.handle_replay_key(key, selected),
+            View::WhatsNew(shown) => self.handle_whats_new_key(key, shown),
+            View::Spectate => {
+                if key.code == KeyCode::Esc {
+                    self.watching = None;
+                    self.view = View::Menu(0);
+                }
+            }
+            View::Game => self.handle_game_key(key),
+        }
+    }
+
+    fn handle_menu_key(&mut self, key: KeyEvent, selected: usize) {
+        let items = self.menu_items();
+        match key.code {
+            KeyCode::Up => self.view = View::Menu((selected + items.len() - 1) % items.len()),
+            KeyCode::Down => self.view = View::Menu((selected + 1) % items.len()),
+            KeyCode::Esc if self.started => self.view = View::Game,
+            KeyCode::Left | KeyCode::Right if items.get(selected) == Some(&MenuItem::NewGame) => {
+                self.cycle_difficulty(key.code == KeyCode::Right)
+            }
+            KeyCode::Enter => {
+                let item = items[selected.min(items.len() - 1)];
+                // Games of the other modes are in the usual language again
+                let other_game = !matches!(
+                    item,
+                    MenuItem::Resume
+                        | MenuItem::Polyglot
+                        | MenuItem::Stats
+                        | MenuItem::Achievements
+                        | MenuItem::Leaderboard
+                        | MenuItem::History
+                        | MenuItem::Settings
+                        | MenuItem::WhatsNew
+                        | MenuItem::Quit
+                );
+                if other_game {
+                    self.switch_language(self.home_language);
+                }
+                self.open_menu_item(item);
+            }
+            _ => {}
+        }
+    }
+
+    fn open_menu_item(&mut self, item: MenuItem) {
+        match item {
+            MenuItem::Resume => self.view = View::Game,
+            MenuItem::NewGame => self.play_random(),
+            MenuItem::Daily => self.play_daily(),
+            MenuItem::CatchUp => self.start_catch_up(),
+            MenuItem::Warmup => {
+                let game = Game::letterle_for_day(today());
+                self.start_game(game, Source::Warmup, None);
+                self.candidates = CandidateSet::new(letterle_letters().into());
+            }
+            MenuItem::Blitz => self.start_blitz(),
+            MenuItem::Countdown => self.start_countdown(),
+            MenuItem::Speedrun => self.start_speedrun(),
+            MenuItem::Mastermind => {
+                let mut game = self.draw_word();
+                game.config = game.config.with_feedback(FeedbackStyle::Counts);
+                self.start_game(game, Source::Random, None);
+            }
+            MenuItem::Zen => {
+                let mut game = self.draw_word();
+                game.config = game.config.with_endless(true);
+                self.start_game(game, Source::Zen, None);
+            }
+            MenuItem::Practice => {
+                let mut game = self.draw_word();
+                game.config = game.config.with_undo(true);
+                self.start_game(game, Source::Practice, None);
+            }
+            MenuItem::Hotseat => self.start_hotseat(),
+            MenuItem::Polyglot => self.start_polyglot(),
+            MenuItem::Community => self.play_community(),
+            MenuItem::Challenge => {
+                self.secret.clear();
+                self.view = View::Challenge;
+            }
+            MenuItem::Stats => self.view = View::Stats,
+            MenuItem::Achievements => self.view = View::Achievements,
+            MenuItem::Leaderboard => self.view = View::Leaderboard(Sort::default()),
+            MenuItem::History => {
+                self.past_games = self.history.finished();
+                self.view = View::History(0);
+            }
+            MenuItem::Settings => self.view = View::Settings(0),
+            MenuItem::WhatsNew => {
+                self.releases = changelog::releases();
+                self.view = View::WhatsNew(0);
+            }
+            MenuItem::Quit => self.request_quit(),
+        }
+    }
+
+    fn handle_settings_key(&mut self, key: KeyEvent, selected: usize) {
+        let count = Setting::ALL.len();
+        match key.code {
+            KeyCode::Up => self.view = View::Settings((selected + count - 1) % count),
+            KeyCode::Down => self.view = View::Settings((selected + 1) % count),
+            KeyCode::Enter | KeyCode::Left | KeyCode::Right | KeyCode::Char(' ') => {
+                self.change_setting(Setting::ALL[selected])
+            }
+            KeyCode::Esc => self.view = View::Menu(0),
+            _ => {}
+        }
+    }
+
+    // The owner types the secret word; nothing of it is shown but how many
+    // letters are in
+    fn handle_challenge_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char(c) => {
+                if let Some(letter) = normalize_letter(c) {
+                    if self.secret.chars().count() < WORD_LENGTH {
+                        self.secret.push(letter);
+                    }
+                }
+            }
+            KeyCode::Backspace => {
+                self.secret.pop();
+            }
+            KeyCode::Enter => self.hand_over(),
+            KeyCode::Esc => {
+                self.secret.clear();
+                self.view = View::Menu(0);
+            }
+            _ => {}
+        }
+    }
+
+    // Starts the guest's game on the secret word, if it's one of the list
+    fn hand_over(&mut self) {
+        let texts = self.texts();
+        let Some(spelling) = self.spelling(&self.secret) else {
+            self.game.show_message(texts.challenge_unknown);
+            self.animations.reject();
+            self.sound.cue(Cue::Invalid);
+            return;
+        };
+        self.secret.clear();
+        self.play_challenge(&spelling);
+    }
+
+    fn handle_leaderboard_key(&mut self, key: KeyEvent, sort: Sort) {
+        match key.code {
+            KeyCode::Left => {
+                self.view = View::Leaderboard(Sort {
+                    column: sort.column.previous(),
+                    reversed: false,
+                })
+            }
+            KeyCode::Right | KeyCode::Tab => {
+                self.view = View::Leaderboard(Sort {
+                    column: sort.column.next(),
+                    reversed: false,
+                })
+            }
+            KeyCode::Char(' ') => {
+                self.view = View::Leaderboard(Sort {
+                    reversed: !sort.reversed,
+                    ..sort
+                })
+            }
+            KeyCode::Esc | KeyCode::Enter => self.view = View::Menu(0),
+            _ => {}
+        }
+    }
+
+    fn handle_history_key(&mut self, key: KeyEvent, selected: usize) {
+        let last = self.past_games.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Up => self.view = View::History(selected.saturating_sub(1)),
+            KeyCode::Down => self.view = View::History((selected + 1).min(last)),
+            KeyCode::Enter => {
+                if let Some(finish) = self.past_games.get(selected) {
+                    self.replay = Some(Replay::new(finish.clone()));
+                    self.animations.clear();
+                    self.view = View::Replay(selected);
+                }
+            }
+            KeyCode::Esc => self.view = View::Menu(0),
+            _ => {}
+        }
+    }
+
+    fn handle_replay_key(&mut self, key: KeyEvent, selected: usize) {
+        let Some(replay) = &mut self.replay else {
+            return;
+        };
+        match key.code {
+            KeyCode::Char(' ') => replay.paused = !replay.paused,
+            KeyCode::Right => replay.skip_guess(),
+            KeyCode::Esc => {
+                self.replay = None;
+                self.animations.clear();
+                self.view = View::History(selected);
+            }
+            _ => {}
+        }
+    }
+
+    // Older releases to the right, newer to the left; closing keeps them
+    // for the next start unless they are dismissed for good
+    fn handle_whats_new_key(&mut self, key: KeyEvent, shown: usize) {
+        let last = self.releases.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Left => self.view = View::WhatsNew(shown.saturating_sub(1)),
+            KeyCode::Right => self.view = View::WhatsNew((shown + 1).min(last)),
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                // If it can't be saved the screen just shows again next time
+                let _ = changelog::mark_seen();
+                self.view = View::Menu(0);
+            }
+            KeyCode::Esc | KeyCode::Enter => self.view = View::Menu(0),
+            _ => {}
+        }
+    }
+
+    // Moves a setting to its next value
+    fn change_setting(&mut self, setting: Setting) {
+        match setting {
+            Setting::Theme => self.next_theme(),
+            Setting::HighContrast => self.high_contrast = !self.high_contrast,
+            Setting::StatusMarkers => self.status_markers = !self.status_markers,
+            Setting::ReducedMotion => {
+                let reduced = !self.animations.reduced_motion();
+                self.animations.set_reduced_motion(reduced);
+            }
+            Setting::KeyboardLayout => self.keyboard_layout = self.keyboard_layout.next(),
+            Setting::WordTheme => self.set_word_theme(self.next_word_theme()),
+            Setting::WordFilter => self.word_filter = self.word_filter.next(),
+            Setting::QuitConfirm => self.quit_confirm = self.quit_confirm.next(),
+        }
+    }
+
+    fn handle_game_key(&mut self, key: KeyEvent) {
+        // Nothing reaches the game from the DVR, which only looks
+        if let Screen::Dvr(back) = self.screen {
+            let oldest = self.dvr.len().saturating_sub(1);
+            self.screen = match key.code {
+                KeyCode::Left => Screen::Dvr((back + 1).min(oldest)),
+                KeyCode::Right => Screen::Dvr(back.saturating_sub(1)),
+                KeyCode::Home => Screen::Dvr(oldest),
+                KeyCode::End => Screen::Dvr(0),
+                KeyCode::Esc | KeyCode::Enter => self.board_screen(EndAction::PlayAgain),
+                KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.board_screen(EndAction::PlayAgain)
+                }
+                _ => Screen::Dvr(back),
+            };
+            return;
+        }
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            match key.code {
+                KeyCode::Char('r') if !self.dvr.is_empty() => {
+                    self.animations.clear();
+                    self.screen = Screen::Dvr(0);
+                }
+                KeyCode::Char('l') => self.show_legend = !self.show_legend,
+                KeyCode::Char('a') => self.toggle_assistant(),
+                KeyCode::Char('k') => self.show_constraints = !self.show_constraints,
+                KeyCode::Char('t') => self.open_tab(),
+                KeyCode::Char('w') => self.close_tab(),
+                KeyCode::Char('z') => self.undo_guess(),
+                KeyCode::Char('p') => self.pause(),
+                // Most terminals keep Ctrl+Tab to themselves, hence the
+                // page keys
+                KeyCode::Tab | KeyCode::PageDown => self.cycle_tab(1),
+                KeyCode::BackTab | KeyCode::PageUp => self.cycle_tab(-1),
+                _ => {}
+            }
+            return;
+        }
+
+        if key.code == KeyCode::F(4) {
+            self.play_community();
+            return;
+        }
+        if key.code == KeyCode::F(10) {
+            self.view = View::Menu(0);
+            return;
+        }
+
+        // Back to the menu of the finished game, on the action that led
+        // here
+        if let Screen::Analysis(_) | Screen::Card(_) = self.screen {
+            let action = match self.screen {
+                Screen::Card(_) => EndAction::Share,
+                _ => EndAction::Analysis,
+            };
+            match key.code {
+                KeyCode::Esc | KeyCode::Enter => self.screen = self.board_screen(action),
+                KeyCode::Char('c') | KeyCode::Char('C') => self.copy_card(),
+                _ => {}
+            }
+            return;
+        }
+
+        if let Screen::Finished(selected) = self.screen {
+            let count = EndAction::ALL.len();
+            match key.code {
+                KeyCode::Left => self.screen = Screen::Finished((selected + count - 1) % count),
+                KeyCode::Right => self.screen = Screen::Finished((selected + 1) % count),
+                KeyCode::Up => self.scroll_definition(-1),
+                KeyCode::Down => self.scroll_definition(1),
+                KeyCode::Enter => self.end_action(EndAction::ALL[selected]),
+                KeyCode::Esc => self.end_action(EndAction::PlayAgain),
+                KeyCode::Char(' ') if self.game.status == GameStatus::Lost => {
+                    self.game.keep_trying();
+                    self.screen = Screen::Board;
+                }
+                KeyCode::Char(c) => {
+                    let c = c.to_ascii_lowercase();
+                    if let Some(&action) = EndAction::ALL.iter().find(|action| action.key() == c) {
+                        self.end_action(action);
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        if self.handle_hotseat_key(key) {
+            return;
+        }
+
+        // Once the clock of a timed mode runs out, the board is only
+        // looked at
+        if self.mode.is_over() {
+            if key.code == KeyCode::Esc {
+                self.next_blitz_word();
+                self.mode = Mode::Blitz(Blitz::start(BLITZ_DURATION));
+            }
+            return;
+        }
+
+        let game = &mut self.game;
+        match key.code {
+            KeyCode::Esc => {
+                if game.status == GameStatus::Playing {
+                    // The game asks through its own status
+                    if self.quit_confirm.asks(true) {
+                        game.quit();
+                    } else {
+                        self.should_quit = true;
+                    }
+                } else if game.status == GameStatus::Quitting {
+                    // Cancel quitting and go back to the game
+                    game.resume();
+                } else if game.status.is_over() {
+                    // Games without the menu of a finished one
+                    self.play_again();
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(letter) = normalize_letter(c) {
+                    if game.status.accepts_input() {
+                        let now = Instant::now();
+                        self.timing.letter(self.last_letter.map(|last| now - last));
+                        self.last_letter = Some(now);
+                        if let Mode::Speedrun(run) = &mut self.mode {
+                            run.start();
+                        }
+                    }
+                    let before = typed_letters(game);
+                    game.input_letter(letter);
+                    if typed_letters(game) > before {
+                        self.animations.typed(before);
+                        if self.source != Source::Hotseat {
+                            self.typos.typed(letter);
+                        }
+                    }
+                }
+            }
+            KeyCode::Tab => self.request_hint(),
+            KeyCode::Backspace => {
+                let deleting = game.current_row().and_then(|row| row.last().copied());
+                let before = typed_letters(game);
+                game.delete_letter();
+                if let Some(letter) = deleting.filter(|_| typed_letters(game) < before) {
+                    if self.source != Source::Hotseat {
+                        self.typos.deleted(letter);
+                    }
+                }
+            }
+            KeyCode::Enter => {
+                if game.status.accepts_input() && typed_letters(game) != game.config.word_length {
+                    // Nothing would happen otherwise, so say why
+                    game.show_message(self.language.texts().incomplete_guess);
+                    self.animations.reject();
+                    self.sound.cue(Cue::Invalid);
+                } else if self.word_filter == WordFilterMode::Guesses
+                    && game.status.accepts_input()
+                    && self.filter.blocks(
+                        &game
+                            .current_row()
+                            .unwrap_or_default()
+                            .iter()
+                            .collect::<String>(),
+                    )
+                {
+                    game.show_message(self.language.texts().filtered_guess);
+                    self.animations.reject();
+                    self.sound.cue(Cue::Invalid);
+                    return;
+                } else if game.status.accepts_input()
+                    && !self.guesses.is_empty()
+                    && self
+                        .guesses
+                        .find(
+                            &game
+                                .current_row()
+                                .unwrap_or_default()
+                                .iter()
+                                .collect::<String>(),
+                        )
+                        .is_none()
+                {
+                    game.show_message(self.language.texts().guess_unknown);
+                    self.animations.reject();
+                    self.sound.cue(Cue::Invalid);
+                    return;
+                } else if let (Mode::Pair(pair), true) = (&self.mode, game.status.accepts_input()) {
+                    // Only the holder of the turn submits
+                    if !pair.our_turn {
+                        game.show_message(self.language.texts().pair_partner_submits);
+                        return;
+                    }
+                }
+                let (was, before) = (game.status, game.submitted().count());
+                game.submit_guess();
+                match (was, game.status) {
+                    (GameStatus::Playing, GameStatus::Won) => self.sound.cue(Cue::Win),
+                    (GameStatus::Playing, GameStatus::Lost) => self.sound.cue(Cue::Loss),
+                    _ if game.submitted().count() > before => self.sound.cue(Cue::Guess),
+                    _ => {}
+                }
+                // If in quitting state and user presses Enter, exit
+                if game.status == GameStatus::Quitting {
+                    self.should_quit = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Runs `action` of the menu of a finished game
+    fn end_action(&mut self, action: EndAction) {
+        match action {
+            EndAction::PlayAgain => self.play_again(),
+            EndAction::ChangeMode => self.view = View::Menu(0),
+            EndAction::Analysis => self.open_analysis(),
+            EndAction::Share => self.share_card(),
+            EndAction::Quit => self.request_quit(),
+        }
+    }
+
+    // The next game of the mode just played: the next word of a run, the
+    // word of the day after the warmup, or a new game with the same rules
+    fn play_again(&mut self) {
+        if self.next_race_round() || self.next_polyglot_word() || self.next_catch_up_day() {
+            return;
+        }
+        let item = match (&self.mode, self.source) {
+            (_, Source::Warmup) => MenuItem::Daily,
+            (Mode::Countdown(_), _) => MenuItem::Countdown,
+            (Mode::Speedrun(_), _) => MenuItem::Speedrun,
+            (_, Source::Zen) => MenuItem::Zen,
+            (_, Source::Practice) => MenuItem::Practice,
+            _ if self.game.config.feedback == FeedbackStyle::Counts => MenuItem::Mastermind,
+            _ => MenuItem::NewGame,
+        };
+        self.open_menu_item(item);
+    }
+
+    // The screen of the board: with the menu of a finished game, `action`
+    // selected, unless the mode has its own way on
+    fn board_screen(&self, action: EndAction) -> Screen {
+        let own_way = matches!(self.mode, Mode::Blitz(_) | Mode::Hotseat(_));
+        if self.game.status.is_over() && !own_way {
+            Screen::Finished(action.index())
+        } else {
+            Screen::Board
+        }
+    }
+
+    // Makes `tab` the active game, returning the one it replaces
+    fn swap_active(&mut self, tab: Tab) -> Tab {
+        self.last_letter = None;
+        self.animations.clear();
+        if let Some(assistant) = &mut self.assistant {
+            assistant.computed_for = usize::MAX;
+        }
+        Tab {
+            screen: std::mem::replace(&mut self.screen, tab.screen),
+            game: std::mem::replace(&mut self.game, tab.game),
+            candidates: std::mem::replace(&mut self.candidates, tab.candidates),
+            candidate_history: std::mem::replace(
+                &mut self.candidate_history,
+                tab.candidate_history,
+            ),
+            timing: std::mem::replace(&mut self.timing, tab.timing),
+            puzzle: std::mem::replace(&mut self.puzzle, tab.puzzle),
+            source: std::mem::replace(&mut self.source, tab.source),
+            mode: std::mem::replace(&mut self.mode, tab.mode),
+            game_id: std::mem::replace(&mut self.game_id, tab.game_id),
+        }
+    }
+
+    fn toggle_assistant(&mut self) {
+        self.assistant = match self.assistant {
+            Some(_) => None,
+            None => Some(Assistant {
+                // Forces the first computation
+                computed_for: usize::MAX,
+                remaining: 0,
+                suggestions: Vec::new(),
+            }),
+        };
+    }
+
+    // Narrow the candidates with the guesses submitted since the last call,
+    // remembering the set each guess was played against
+    fn sync_candidates(&mut self) {
+        let applied = self.candidate_history.len();
+        for (guess, statuses) in self.game.submitted().skip(applied) {
+            self.candidate_history.push(self.candidates.clone());
+            match self.game.config.feedback {
+                FeedbackStyle::Letters => self.candidates.apply(guess, statuses),
+                FeedbackStyle::Counts => self.candidates.apply_counts(guess, Counts::of(statuses)),
+            }
+            self.timing.submit();
+            self.last_letter = None;
+        }
+        // The guess that ended the game brings up what to do next
+        if applied < self.candidate_history.len() && matches!(self.screen, Screen::Board) {
+            self.screen = self.board_screen(EndAction::PlayAgain);
+        }
+        // The guess that ended the game goes through here once; guesses
+        // after a loss don't count
+        if applied == self.candidate_history.len() || self.game.continued {
+            return;
+        }
+        if !self.game.status.is_over() {
+            return;
+        }
+        let time = self.game_id.and_then(|id| self.history.elapsed(id));
+        // Practice games can end more than once, so none is logged
+        if let Some(id) = self.game_id.filter(|_| self.source != Source::Practice) {
+            let accepted_by = self.accepted_by();
+            if self.history.finish(id, &self.game, accepted_by).is_err() {
+                self.game.show_message(self.texts().history_unsaved);
+            }
+        }
+        if matches!(self.source, Source::Daily | Source::Archive) && self.game.seed == Some(today())
+        {
+            self.daily_pending = false;
+        }
+        if self.typos.save().is_err() {
+            self.game.show_message(self.texts().typos_unsaved);
+        }
+        self.look_up_definition();
+        let word = self.game.target_word.clone();
+        let won = self.game.status == GameStatus::Won;
+        let guesses = self.game.submitted().count();
+        let category = Category::of(&self.mode, self.source, self.game.config.feedback);
+        if let (Some(category), true) = (category, won) {
+            let length = self.game.config.word_length;
+            if self
+                .leaderboard
+                .submit(category, length, guesses, time)
+                .is_err()
+            {
+                self.game.show_message(self.texts().leaderboard_unsaved);
+            }
+        }
+        // Versus rounds are the players', and warmups and practice games
+        // too easy to earn badges with
+        if !matches!(
+            self.source,
+            Source::Warmup | Source::Practice | Source::Hotseat
+        ) {
+            self.record_achievements();
+        }
+        if let Mode::Speedrun(run) = &mut self.mode {
+            let time = run.stop();
+            if won {
+                match self.records.submit(self.game.config.word_length, time) {
+                    Ok(new_record) => run.new_record = new_record,
+                    Err(_) => self
+                        .game
+                        .show_message(self.language.texts().records_unsaved),
+                }
+            }
+        }
+        match self.source {
+            // Community puzzles and guest challenges are tracked on their
+            // own
+            Source::Community => {
+                let Some(id) = self.puzzle.clone() else {
+                    return;
+                };
+                if won && self.community_completed.insert(&id).is_err() {
+                    self.game.show_message(self.texts().community_unsaved);
+                }
+            }
+            Source::Challenge => {
+                let challenge = Challenge::now(word, won, guesses);
+                if self.challenges.record(challenge).is_err() {
+                    self.game.show_message(self.texts().challenge_unsaved);
+                }
+            }
+            Source::Sent => self.reply_sent(),
+            Source::Random | Source::Daily | Source::Archive => {
+                if let (Source::Daily, Some(online), Some(puzzle)) =
+                    (self.source, &self.online, self.game.seed)
+                {
+                    let submission = Submission {
+                        puzzle,
+                        language: self.language.code().to_string(),
+                        name: online.name.clone(),
+                        won,
+                        guesses,
+                        time_ms: time.map(|time| time.as_millis() as u64),
+                        typing: Typed::all(&self.timing),
+                    };
+                    self.online_loading = Some(online.share_in_background(submission));
+                }
+                if let Mode::CatchUp(catch_up) = &mut self.mode {
+                    catch_up.record(won, guesses);
+                }
+                self.stats.record(Played {
+                    difficulty: self.ratings.get(&word),
+                    rarity: self.frequencies.rarity(&word),
+                    language: self.language,
+                    momentum: self.game.best_momentum(),
+                    won,
+                    guesses,
+                    word,
+                })
+            }
+            // Only a warmup, or a game that can't be lost: they don't count
+            Source::Warmup | Source::Zen | Source::Practice | Source::Hotseat => {}
+            Source::Blitz => {
+                if let Mode::Blitz(blitz) = &mut self.mode {
+                    if won {
+                        blitz.solved += 1;
+                    } else {
+                        blitz.missed += 1;
+                    }
+                    if !blitz.is_over() {
+                        self.next_blitz_word();
+                    }
+                }
+            }
+        }
+    }
+
+    // Counts the game just finished towards the badges, queueing a toast
+    // for each one it earned
+    fn record_achievements(&mut self) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        match self.achievements.record(&self.game, now) {
+            Ok(earned) => {
+                if self.toasts.is_empty() {
+                    self.toast_since = Instant::now();
+                }
+                self.toasts.extend(earned);
+            }
+            Err(_) => self.game.show_message(self.texts().achievements_unsaved),
+        }
+    }
+
+    // Takes back the last guess of a practice game, and what the candidates
+    // learnt from it
+    fn undo_guess(&mut self) {
+        if !self.game.config.undo || !matches!(self.screen, Screen::Board | Screen::Finished(_)) {
+            return;
+        }
+        let texts = self.texts();
+        if !self.game.undo() {
+            self.game.show_message(texts.practice_no_undo);
+            return;
+        }
+        let submitted = self.game.submitted().count();
+        if self.candidate_history.len() > submitted {
+            self.candidates = self.candidate_history[submitted].clone();
+            self.candidate_history.truncate(submitted);
+        }
+        self.screen = Screen::Board;
+        self.game.show_message(texts.practice_undone);
+    }
+
+    // Brings the overlay line up to date with the game. Only the number
+    // of the word of the day goes in the title: a seed would give the word
+    // away to anyone watching
+    fn write_overlay(&mut self) {
+        let Some(file) = &mut self.overlay else {
+            return;
+        };
+        let title = match self.game.seed {
+            Some(day) if matches!(self.source, Source::Daily | Source::Archive) => {
+                format!("Daily #{}", day)
+            }
+            _ => "Wordle".to_string(),
+        };
+        // A file gone missing mid-stream isn't worth stopping the game for
+        let _ = file.update(overlay::line(&title, &self.game, self.high_contrast));
+    }
+
+    // Opens the result card of the finished game, saving it as a PNG on
+    // the way
+    fn share_card(&mut self) {
+        let (title, name) = match self.game.seed {
+            Some(day) if matches!(self.source, Source::Daily | Source::Archive) => {
+                (format!("WORDLE #{}", day), format!("wordle-{}", day))
+            }
+            Some(seed) => (
+                format!("WORDLE {}", seed_code(seed)),
+                format!("wordle-{}", seed_code(seed)),
+            ),
+            None => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_secs());
+                ("WORDLE".to_string(), format!("wordle-{}", now))
+            }
+        };
+        let time = self.game_id.and_then(|id| self.history.elapsed(id));
+        let card = Card::new(title, &self.game, self.stats.streak()).timed(time);
+        let palette = if self.high_contrast {
+            Palette::HIGH_CONTRAST
+        } else {
+            Palette::CLASSIC
+        };
+        let image = card.render(&palette);
+        self.screen = Screen::Card(Box::new(Shared {
+            saved: card::save(&image, &name).ok(),
+            sixel: self.sixel.then(|| image.to_sixel()),
+            card,
+            image,
+            copied: false,
+        }));
+    }
+
+    // Sends the text of the result card to the clipboard, as `[share]` has
+    // it
+    fn copy_card(&mut self) {
+        if let Screen::Card(shared) = &mut self.screen {
+            let text = shared.card.text(&self.share, self.high_contrast);
+            self.clipboard = Some(text.join("\n"));
+            shared.copied = true;
+        }
+    }
+
+    fn open_analysis(&mut self) {
+        let report = analysis::analyze(&self.game.history(), &self.candidate_history);
+        self.screen = Screen::Analysis(report);
+    }
+
+    // Recompute suggestions when the panel is open and a guess was submitted
+    // (or a new game started) since the last computation
+    fn refresh_assistant(&mut self) {
+        let submitted = self.game.submitted().count();
+        let Some(assistant) = &mut self.assistant else {
+            return;
+        };
+        if assistant.computed_for == submitted {
+            return;
+        }
+
+        assistant.remaining = self.candidates.len();
+        assistant.suggestions = match &self.patterns {
+            // The matrix is for the word list, not the warmup's alphabet
+            Some(patterns) if self.source != Source::Warmup => {
+                solver::rank_guesses_cached(patterns, &self.candidates, ASSISTANT_ROWS)
+            }
+            _ => {
+                let candidates: Vec<&str> = self.candidates.iter().collect();
+                solver::rank_guesses(self.candidates.words(), &candidates, ASSISTANT_ROWS)
+            }
+        };
+        assistant.computed_for = submitted;
+    }
+
+    // Pick up the pattern matrix once the background load is done, and
+    // redo the suggestions with it
+    fn poll_patterns(&mut self) {
+        let Some(loading) = &self.patterns_loading else {
+            return;
+        };
+        match loading.try_recv() {
+            Ok(patterns) => {
+                self.patterns = patterns.map(Arc::new);
+                self.patterns_loading = None;
+                if let Some(assistant) = &mut self.assistant {
+                    assistant.computed_for = usize::MAX;
+                }
+            }
+            Err(mpsc::TryRecvError::Empty) => {}
+            Err(mpsc::TryRecvError::Disconnected) => self.patterns_loading = None,
+        }
+    }
+
+    // Finds the definition of the word just played: in the dictionary of
+    // the word list, among those fetched before, or at the API
+    fn look_up_definition(&mut self) {
+        let word = self.game.display_word.clone();
+        let language = self.language.code();
+        let text = self
+            .definitions
+            .get(&word)
+            .or_else(|| self.definitions_cache.get(language, &word))
+            .map(str::to_string);
+        let url = self
+            .definitions_api
+            .as_deref()
+            .filter(|_| text.is_none())
+            .map(|template| definitions::api_url(template, language, &word));
+        self.definition_loading = url.map(definitions::fetch_in_background);
+        self.definition = Some(Lookup {
+            word,
+            text,
+            loading: self.definition_loading.is_some(),
+            scroll: 0,
+        });
+    }
+
+    /// Scrolls the definition of the finished game by `lines`, as far as
+    /// it goes.
+    pub fn scroll_definition(&mut self, lines: i32) {
+        let Some(lookup) = &mut self.definition else {
+            return;
+        };
+        let total = lookup.text.as_deref().map_or(0, definition::wrapped_lines);
+        let last = total.saturating_sub(DEFINITION_ROWS);
+        lookup.scroll = (lookup.scroll as i32 + lines).clamp(0, last as i32) as u16;
+    }
+
+    // Pick up the definition once the API answers, keeping it for next
+    // time
+    fn poll_definition(&mut self) {
+        let Some(loading) = &self.definition_loading else {
+            return;
+        };
+        let text = match loading.try_recv() {
+            Ok(text) => text,
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => None,
+        };
+        self.definition_loading = None;
+        let texts = self.texts();
+        let language = self.language.code();
+        let Some(lookup) = &mut self.definition else {
+            return;
+        };
+        lookup.loading = false;
+        if let Some(text) = text {
+            if self
+                .definitions_cache
+                .insert(language, &lookup.word, text.clone())
+                .is_err()
+            {
+                self.game.show_message(texts.definition_unsaved);
+            }
+            lookup.text = Some(text);
+        }
+    }
+
+    // Pick up the community puzzle once it's downloaded
+    fn poll_community(&mut self) {
+        let Some(loading) = &self.community_loading else {
+            return;
+        };
+        match loading.try_recv() {
+            Ok(puzzle) => {
+                self.community = puzzle;
+                self.community_loading = None;
+            }
+            Err(mpsc::TryRecvError::Empty) => {}
+            Err(mpsc::TryRecvError::Disconnected) => self.community_loading = None,
+        }
+    }
+
+    // Pick up the online leaderboard once the daily result is shared
+    fn poll_online(&mut self) {
+        let Some(loading) = &self.online_loading else {
+            return;
+        };
+        match loading.try_recv() {
+            Ok(top) => {
+                if top.is_none() {
+                    self.game.show_message(self.texts().online_unavailable);
+                }
+                self.online_top = top;
+                self.online_loading = None;
+            }
+            Err(mpsc::TryRecvError::Empty) => {}
+            Err(mpsc::TryRecvError::Disconnected) => self.online_loading = None,
+        }
+    }
+
+    fn request_hint(&mut self) {
+        let texts = self.language.texts();
+        let message = match self.game.hint(self.candidates.words()) {
+            Some(word) => format!("{}: {}", texts.hint_label, word),
+            None if self.game.status.accepts_input() => texts.no_hint.to_string(),
+            None => return,
+        };
+        self.game.show_message(message);
+    }
+
+    // A click on a key of the virtual keyboard types it
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return;
+        }
+        let clicked = self
+            .keyboard_keys
+            .borrow()
+            .iter()
+            .find(|(_, area)| area.contains((mouse.column, mouse.row).into()))
+            .map(|(key, _)| *key);
+        if let Some(key) = clicked {
+            self.handle_key(KeyEvent::new(KeyCode::Char(key), KeyModifiers::NONE));
+        }
+    }
+
+    fn handle_paste(&mut self, text: &str) {
+        if self.view == View::Game && (self.mode.is_over() || matches!(self.screen, Screen::Dvr(_)))
+        {
+            return;
+        }
+        if self.view == View::Challenge {
+            if let Ok(code) = ChallengeCode::decode(text) {
+                self.play_code(code);
+                return;
+            }
+        }
+        match normalize_input(text) {
+            Ok(letters) if self.view == View::Challenge => {
+                self.secret.extend(letters);
+                self.secret = self.secret.chars().take(WORD_LENGTH).collect();
+            }
+            Ok(letters) => {
+                self.timing.paste();
+                if let Mode::Speedrun(run) = &mut self.mode {
+                    run.start();
+                }
+                for letter in letters {
+                    self.game.input_letter(letter);
+                }
+            }
+            Err(c) => {
+                let texts = self.language.texts();
+                self.game
+                    .show_message(format!("{} '{}'", texts.paste_unsupported, c));
+            }
+        }
+    }
+
+    pub fn on_tick(&mut self) {
+        self.game.on_tick();
+        if !self.toasts.is_empty() && self.toast_since.elapsed() >= TOAST_DURATION {
+            self.toasts.pop_front();
+            self.toast_since = Instant::now();
+        }
+        // The replay moves on by a letter every tick
+        if let (View::Replay(_), Some(replay)) = (self.view, &mut self.replay) {
+            if !replay.paused {
+                if let Some(column) = replay.step() {
+                    self.animations.typed(column);
+                }
+            }
+        }
+        self.run_countdown();
+        self.run_race();
+        self.run_pair();
+        self.run_watch();
+        self.run_idle_lock();
+        self.poll_community();
+        self.poll_definition();
+        self.poll_online();
+        self.poll_patterns();
+        self.refresh_assistant();
+        self.dvr.record(&self.game);
+        self.write_overlay();
+    }
+}
+
+// Letters typed so far in the current row
+fn typed_letters(game: &Game) -> usize {
+    game.current_row().map_or(0, <[char]>::len)
+}
+
+// Days since the Unix epoch, in UTC, for the daily word
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() / 86_400)
+}
+
+// Folds the accents of every word, dropping the duplicates this creates
+// (a list may have both AVIÃO and AVIAO)
+fn fold_words(spellings: &[String]) -> Arc<[String]> {
+    let mut seen = HashSet::new();
+    spellings
+        .iter()
+        .map(|word| fold_word(word))
+        .filter(|word| seen.insert(word.clone()))
+        .collect()
+}
+
+// The game `seed` draws from `dictionary`. With the filter on and its word
+// filtered, or a tier its word isn't of, the first of the seeds after it to
+// draw a word that will do: the game keeps that seed, which gives its word
+// to anyone with the list.
+fn draw(
+    dictionary: &Dictionary,
+    mode: WordFilterMode,
+    filter: &WordFilter,
+    tier: Option<Tier>,
+    seed: u64,
+) -> Game {
+    dictionary.draw_where(seed, |game| {
+        let word = &game.target_word;
+        (mode == WordFilterMode::Off || !filter.blocks(word))
+            && tier.is_none_or(|tier| dictionary.tier(word) == Some(tier))
+    })
+}
+
+// What goes with the word list of a language
+struct Assets {
+    dictionary: Dictionary,
+    words: Arc<[String]>,
+    ratings: Ratings,
+    frequencies: Frequencies,
+    definitions: Definitions,
+    filter: WordFilter,
+    guesses: GuessChain,
+    patterns_loading: Option<Receiver<Option<PatternMatrix>>>,
+}
+
+impl Assets {
+    // The word list of `language` with its tiers, its ratings, frequencies,
+    // definitions, filter and guess dictionaries from `guess_sources`, and
+    // its pattern matrix on the way if `use_patterns`. A `theme` fitting
+    // the language replaces the word list; its words aren't rated, and too
+    // few to need the matrix.
+    fn load(
+        language: Language,
+        use_patterns: bool,
+        theme: Option<&WordTheme>,
+        guess_sources: &[GuessSource],
+    ) -> Assets {
+        let theme = theme.filter(|theme| theme.fits(language));
+        let spellings: Arc<[String]> = match theme {
+            Some(theme) => theme.words.clone().into(),
+            None => language.load_words().into(),
+        };
+        let words = fold_words(&spellings);
+        let words_path = language.words_path();
+        let ratings = match theme {
+            Some(_) => Ratings::default(),
+            None => words_path
+                .as_deref()
+                .and_then(|path| Ratings::load(&Ratings::path_for(path)).ok())
+                .unwrap_or_else(|| Ratings::parse(language.embedded_difficulty())),
+        };
+        // The frequency list and pattern cache go next to the word list, or
+        // in the data directory for the built-in one
+        let words_path =
+            words_path.or_else(|| Some(paths::data_dir()?.join(language.words_file())));
+        let frequencies = words_path
+            .as_deref()
+            .filter(|_| theme.is_none())
+            .and_then(|path| Frequencies::load(&Frequencies::path_for(path)).ok())
+            .unwrap_or_else(|| Frequencies::from_letters(&words));
+        let definitions = words_path
+            .as_deref()
+            .and_then(|path| Definitions::load(&Definitions::path_for(path)).ok())
+            .unwrap_or_default();
+        let patterns_cache = words_path.map(|path| PatternMatrix::cache_path(&path));
+        let patterns_loading = patterns_cache
+            .filter(|_| use_patterns && theme.is_none())
+            .map(|cache| load_patterns(cache, words.clone()));
+        // The base list of the guesses is the language's, theme or not
+        let base = match theme {
+            Some(_) if guess_sources.contains(&GuessSource::Base) => language.load_words(),
+            Some(_) => Vec::new(),
+            None => spellings.to_vec(),
+        };
+        Assets {
+            guesses: GuessChain::load(guess_sources, language, &base),
+            dictionary: Dictionary::new(spellings, &frequencies),
+            words,
+            ratings,
+            frequencies,
+            definitions,
+            filter: WordFilter::load(language),
+            patterns_loading,
+        }
+    }
+}
+
+// The guess dictionaries of `language` among `sources`
+fn guess_sources(
+    sources: &HashMap<String, Vec<GuessSource>>,
+    language: Language,
+) -> &[GuessSource] {
+    sources.get(language.code()).map_or(&[], Vec::as_slice)
+}
+
+// Loads the pattern matrix for `words` from `cache` (or builds and saves
+// it there) on another thread, so startup doesn't wait for it
+fn load_patterns(cache: PathBuf, words: Arc<[String]>) -> Receiver<Option<PatternMatrix>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(PatternMatrix::load_or_build(&cache, &words));
+    });
+    receiver
+}
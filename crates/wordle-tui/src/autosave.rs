@@ -0,0 +1,45 @@
+//! The game in progress, saved to `autosave.json` in the data directory
+//! when the screen locks after a while without input (see
+//! [`crate::config::LockConfig`]). The next start picks it up where it
+//! was left, and the file goes once the game is back on screen.
+//!
+//! Only games that can be played again from their word and guesses are
+//! saved: timed modes, versus games and puzzles tracked on their own
+//! aren't.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::config::Config;
+
+pub use wordle_proto::save::Saved;
+
+/// Reads the saved game, if there is one.
+pub fn load() -> Option<Saved> {
+    let text = fs::read_to_string(path()?).ok()?;
+    serde_json::from_str(&text).ok()
+}
+
+/// Saves `saved`, over any game saved before.
+pub fn save(saved: &Saved) -> io::Result<()> {
+    let Some(path) = path() else {
+        return Ok(());
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let text = serde_json::to_string_pretty(saved).map_err(io::Error::other)?;
+    fs::write(path, text + "\n")
+}
+
+/// Forgets the saved game.
+pub fn clear() {
+    if let Some(path) = path() {
+        let _ = fs::remove_file(path);
+    }
+}
+
+fn path() -> Option<PathBuf> {
+    Some(Config::data_dir()?.join("autosave.json"))
+}
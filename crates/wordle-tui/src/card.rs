@@ -0,0 +1,243 @@
+//! The result card of a finished game, to share: its title (with the
+//! puzzle number or the seed), the colors of every guess without the
+//! letters, the result and the winning streak. It comes as text, with
+//! colored squares or the symbols of `[share]` in the configuration, and
+//! as an image (see [`crate::raster`]) saved as a PNG
+//! in the data directory and shown in the terminal when it can show
+//! Sixel graphics.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::config::{ShareConfig, ShareGlyphs};
+use crate::game::{Game, GameStatus, LetterStatus};
+use crate::paths;
+use crate::raster::{self, Image, Rgb, GLYPH_HEIGHT};
+
+/// Size of a pixel of the font on the card.
+const TEXT_SCALE: usize = 3;
+/// Side of a square of the grid.
+const TILE: usize = 40;
+/// Space between squares.
+const GAP: usize = 6;
+/// Space around everything, and between the title, grid and footer.
+const MARGIN: usize = 24;
+
+/// Colors of the card image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    pub background: Rgb,
+    pub text: Rgb,
+    pub correct: Rgb,
+    pub present: Rgb,
+    pub absent: Rgb,
+}
+
+impl Palette {
+    pub const CLASSIC: Palette = Palette {
+        background: [0x12, 0x12, 0x13],
+        text: [0xff, 0xff, 0xff],
+        correct: [0x6a, 0xaa, 0x64],
+        present: [0xc9, 0xb4, 0x58],
+        absent: [0x3a, 0x3a, 0x3c],
+    };
+    /// Orange and blue instead of green and yellow, as in the game's high
+    /// contrast mode.
+    pub const HIGH_CONTRAST: Palette = Palette {
+        correct: [0xf5, 0x79, 0x3a],
+        present: [0x85, 0xc0, 0xf9],
+        ..Palette::CLASSIC
+    };
+
+    fn status(&self, status: LetterStatus) -> Rgb {
+        match status {
+            LetterStatus::Correct => self.correct,
+            LetterStatus::Present => self.present,
+            LetterStatus::Absent | LetterStatus::Unused => self.absent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    /// `WORDLE`, then the puzzle number or the seed when there is one.
+    pub title: String,
+    /// Colors of each guess.
+    pub rows: Vec<Vec<LetterStatus>>,
+    /// Guesses taken out of those allowed, `X` for a loss: `4/6`, `X/6`.
+    pub result: String,
+    /// Games won in a row, this one included.
+    pub streak: usize,
+    /// How long the game took, when it was timed.
+    pub time: Option<Duration>,
+    /// Hints taken during the game.
+    pub hints: usize,
+}
+
+impl Card {
+    /// The card of `game`, which should be over.
+    pub fn new(title: String, game: &Game, streak: usize) -> Card {
+        let rows: Vec<Vec<LetterStatus>> = game
+            .submitted()
+            .map(|(_, statuses)| statuses.to_vec())
+            .collect();
+        let taken = if game.status == GameStatus::Won {
+            rows.len().to_string()
+        } else {
+            "X".to_string()
+        };
+        Card {
+            title,
+            result: format!("{}/{}", taken, game.config.max_attempts),
+            rows,
+            streak,
+            time: None,
+            hints: game.hints_used,
+        }
+    }
+
+    /// The card with how long the game took.
+    pub fn timed(mut self, time: Option<Duration>) -> Card {
+        self.time = time;
+        self
+    }
+
+    /// The card as lines of text, a colored square per letter.
+    pub fn lines(&self, high_contrast: bool) -> Vec<String> {
+        self.text(&ShareConfig::default(), high_contrast)
+    }
+
+    /// The card as lines of text, as `share` has it: a symbol per letter,
+    /// and after the result a `*` if hints were taken and the time the
+    /// game took, when asked for.
+    pub fn text(&self, share: &ShareConfig, high_contrast: bool) -> Vec<String> {
+        let mut heading = format!("{} {}", self.title, self.result);
+        if share.markers && self.hints > 0 {
+            heading.push('*');
+        }
+        if let (true, Some(time)) = (share.time, self.time) {
+            let seconds = time.as_secs();
+            heading += &format!(" {}:{:02}", seconds / 60, seconds % 60);
+        }
+        let mut lines = vec![heading, String::new()];
+        lines.extend(self.rows.iter().map(|row| {
+            row.iter()
+                .map(|status| glyph(share, *status, high_contrast))
+                .collect()
+        }));
+        lines.push(String::new());
+        lines.push(self.footer());
+        lines
+    }
+
+    /// The card as an image: the title and result on top, the grid, and
+    /// the streak under it.
+    pub fn render(&self, palette: &Palette) -> Image {
+        let heading = format!("{}  {}", self.title, self.result);
+        let footer = self.footer();
+        let columns = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let grid_width = (columns * (TILE + GAP)).saturating_sub(GAP);
+        let grid_height = (self.rows.len() * (TILE + GAP)).saturating_sub(GAP);
+        let text_height = GLYPH_HEIGHT * TEXT_SCALE;
+        let content_width = grid_width
+            .max(raster::text_width(&heading, TEXT_SCALE))
+            .max(raster::text_width(&footer, TEXT_SCALE));
+        let width = content_width + 2 * MARGIN;
+        let height = text_height * 2 + grid_height + 4 * MARGIN;
+        let mut image = Image::new(width, height, palette.background);
+
+        let centered = |inner: usize| (width - inner) / 2;
+        image.draw_text(
+            centered(raster::text_width(&heading, TEXT_SCALE)),
+            MARGIN,
+            &heading,
+            TEXT_SCALE,
+            palette.text,
+        );
+        let grid_top = MARGIN * 2 + text_height;
+        for (row, statuses) in self.rows.iter().enumerate() {
+            let row_width = (statuses.len() * (TILE + GAP)).saturating_sub(GAP);
+            for (column, status) in statuses.iter().enumerate() {
+                image.fill_rect(
+                    centered(row_width) + column * (TILE + GAP),
+                    grid_top + row * (TILE + GAP),
+                    TILE,
+                    TILE,
+                    palette.status(*status),
+                );
+            }
+        }
+        image.draw_text(
+            centered(raster::text_width(&footer, TEXT_SCALE)),
+            grid_top + grid_height + MARGIN,
+            &footer,
+            TEXT_SCALE,
+            palette.text,
+        );
+        image
+    }
+
+    fn footer(&self) -> String {
+        format!("STREAK {}", self.streak)
+    }
+}
+
+/// The colored squares of a guess, as on the text card.
+pub fn squares(statuses: &[LetterStatus], high_contrast: bool) -> String {
+    let share = ShareConfig::default();
+    statuses
+        .iter()
+        .map(|status| glyph(&share, *status, high_contrast))
+        .collect()
+}
+
+// The symbol of `status` in the text card
+fn glyph(share: &ShareConfig, status: LetterStatus, high_contrast: bool) -> &str {
+    let custom = match status {
+        LetterStatus::Correct => &share.correct,
+        LetterStatus::Present => &share.present,
+        LetterStatus::Absent | LetterStatus::Unused => &share.absent,
+    };
+    match (share.glyphs, custom) {
+        (ShareGlyphs::Custom, Some(custom)) => custom,
+        (ShareGlyphs::Ascii, _) => match status {
+            LetterStatus::Correct => "#",
+            LetterStatus::Present => "+",
+            LetterStatus::Absent | LetterStatus::Unused => ".",
+        },
+        _ => match (status, high_contrast) {
+            (LetterStatus::Correct, false) => "🟩",
+            (LetterStatus::Present, false) => "🟨",
+            (LetterStatus::Correct, true) => "🟧",
+            (LetterStatus::Present, true) => "🟦",
+            (LetterStatus::Absent | LetterStatus::Unused, _) => "⬛",
+        },
+    }
+}
+
+/// A card as shown on the screen it opens.
+pub struct Shared {
+    pub card: Card,
+    /// Its image, for the size of the space to leave for it.
+    pub image: Image,
+    /// The image as Sixel graphics, when the terminal shows them.
+    pub sixel: Option<String>,
+    /// Where the PNG went, once saved.
+    pub saved: Option<PathBuf>,
+    /// Whether its text was copied to the clipboard.
+    pub copied: bool,
+}
+
+/// Saves `image` as `<name>.png` in the `cards` folder of the data
+/// directory, returning its path.
+pub fn save(image: &Image, name: &str) -> io::Result<PathBuf> {
+    let dir = paths::data_dir()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?
+        .join("cards");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.png", name));
+    fs::write(&path, image.to_png())?;
+    Ok(path)
+}
@@ -0,0 +1,473 @@
+//! Quick matches on one machine: the owner types a secret word without
+//! showing it, then hands the keyboard to a guest who tries to find it.
+//!
+//! Results go to their own ledger, `challenges.jsonl` in the data
+//! directory, one JSON object per line, so they don't mix with the owner's
+//! own games.
+//!
+//! Friends elsewhere get a [`ChallengeFile`] instead, sent by any means
+//! (`wordle challenge create`). It holds the word, encrypted so it can't be
+//! read at a glance, and the rules to play it by. Whoever plays it
+//! (`wordle challenge play <file>`) gets a [`Reply`] file next to it, to
+//! send back; importing the replies (`wordle challenge import <file>`)
+//! compares everyone who played the challenge. The key travels with the
+//! file: the encryption keeps the word from the eyes, not from someone
+//! set on finding it.
+//!
+//! Shorter still, a [`ChallengeCode`] of eight letters and digits stands
+//! for a word and its language (`wordle challenge code <word>`), to be
+//! played with `wordle --challenge <code>` or pasted in the guest
+//! challenge, without the word showing in a chat or the shell history.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context};
+use chacha20::cipher::{KeyIvInit, StreamCipher};
+use chacha20::ChaCha20;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::config::Config;
+use crate::game::normalize::fold_word;
+use crate::game::{FeedbackStyle, Game, GameConfig, GameStatus, WORD_LENGTH};
+use crate::i18n::Language;
+use crate::net::encode_row;
+
+pub use wordle_proto::share::ShareCode;
+
+/// Version of the challenge and reply files; a game refuses those of
+/// another.
+pub const FILE_VERSION: u32 = 1;
+/// Most guesses a challenge file can allow.
+pub const MAX_FILE_ATTEMPTS: usize = 10;
+
+/// How a guest did on the owner's word.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Challenge {
+    /// Seconds since the Unix epoch, when the game ended.
+    pub at: u64,
+    /// The secret word, accents folded.
+    pub word: String,
+    pub won: bool,
+    /// Guesses the guest submitted.
+    pub guesses: usize,
+}
+
+impl Challenge {
+    /// A challenge that ended just now.
+    pub fn now(word: String, won: bool, guesses: usize) -> Challenge {
+        Challenge {
+            at: now(),
+            word,
+            won,
+            guesses,
+        }
+    }
+}
+
+// Seconds since the Unix epoch
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Every challenge played on this machine.
+#[derive(Debug, Default)]
+pub struct Ledger {
+    /// Oldest first.
+    pub challenges: Vec<Challenge>,
+}
+
+impl Ledger {
+    /// Reads the ledger, starting afresh if there is none.
+    pub fn load() -> Ledger {
+        let challenges = Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|text| {
+                text.lines()
+                    .filter_map(|line| serde_json::from_str(line).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ledger { challenges }
+    }
+
+    /// Adds `challenge` to the ledger and saves it.
+    pub fn record(&mut self, challenge: Challenge) -> io::Result<()> {
+        let line = serde_json::to_string(&challenge).map_err(io::Error::other)?;
+        self.challenges.push(challenge);
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", line)
+    }
+
+    /// Challenges the guest won.
+    pub fn guest_wins(&self) -> usize {
+        self.challenges
+            .iter()
+            .filter(|challenge| challenge.won)
+            .count()
+    }
+
+    fn path() -> Option<PathBuf> {
+        Some(Config::data_dir()?.join("challenges.jsonl"))
+    }
+}
+
+/// A challenge to send to a friend: a word, hidden, and the rules to play
+/// it by.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChallengeFile {
+    pub version: u32,
+    /// Random, to tell the replies to this challenge from any other.
+    pub id: String,
+    /// Who sent it.
+    pub from: String,
+    /// Code of the language of the word, and of the guesses.
+    pub language: String,
+    /// Guesses allowed.
+    pub attempts: usize,
+    /// Whether guesses are only answered with counts, as in the mastermind
+    /// mode.
+    #[serde(default)]
+    pub mastermind: bool,
+    // The word as spelled, encrypted, in hexadecimal
+    word: String,
+}
+
+impl ChallengeFile {
+    /// A new challenge on `word`, from `from`.
+    pub fn new(
+        word: &str,
+        from: &str,
+        language: Language,
+        attempts: usize,
+        mastermind: bool,
+    ) -> ChallengeFile {
+        let id = format!("{:016x}", rand::random::<u64>());
+        let mut word = word.as_bytes().to_vec();
+        cipher(&id).apply_keystream(&mut word);
+        ChallengeFile {
+            version: FILE_VERSION,
+            id,
+            from: from.to_string(),
+            language: language.code().to_string(),
+            attempts,
+            mastermind,
+            word: word.iter().map(|byte| format!("{:02x}", byte)).collect(),
+        }
+    }
+
+    /// Reads the challenge at `path`, refusing one the game can't play.
+    pub fn load(path: &Path) -> anyhow::Result<ChallengeFile> {
+        let text =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let challenge: ChallengeFile = serde_json::from_str(&text)
+            .with_context(|| format!("{} is not a challenge file", path.display()))?;
+        if challenge.version != FILE_VERSION {
+            bail!(
+                "{} is a challenge of version {}, this game reads version {}",
+                path.display(),
+                challenge.version,
+                FILE_VERSION
+            );
+        }
+        // The id names the replies' files, so nothing but what `new` writes
+        if challenge.id.len() != 16 || !challenge.id.bytes().all(|b| b.is_ascii_hexdigit()) {
+            bail!("the challenge id '{}' is damaged", challenge.id);
+        }
+        if Language::from_code(&challenge.language).is_none() {
+            bail!(
+                "the challenge is in unknown language '{}'",
+                challenge.language
+            );
+        }
+        if !(1..=MAX_FILE_ATTEMPTS).contains(&challenge.attempts) {
+            bail!(
+                "the challenge allows {} guesses, this game 1 to {}",
+                challenge.attempts,
+                MAX_FILE_ATTEMPTS
+            );
+        }
+        Ok(challenge)
+    }
+
+    /// Writes the challenge to `path`.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n").with_context(|| format!("writing {}", path.display()))
+    }
+
+    /// The hidden word, as spelled.
+    pub fn word(&self) -> anyhow::Result<String> {
+        let bytes: Option<Vec<u8>> = (0..self.word.len())
+            .step_by(2)
+            .map(|at| {
+                let digits = self.word.get(at..at + 2)?;
+                u8::from_str_radix(digits, 16).ok()
+            })
+            .collect();
+        let mut bytes = bytes.context("the challenge word is damaged")?;
+        cipher(&self.id).apply_keystream(&mut bytes);
+        let word = String::from_utf8(bytes)
+            .ok()
+            .context("the challenge word is damaged")?;
+        let folded = fold_word(&word);
+        if folded.chars().count() != WORD_LENGTH || !folded.chars().all(|c| c.is_ascii_uppercase())
+        {
+            bail!("the challenge word can't be played");
+        }
+        Ok(word)
+    }
+
+    /// The rules of the challenge.
+    pub fn config(&self) -> GameConfig {
+        let feedback = if self.mastermind {
+            FeedbackStyle::Counts
+        } else {
+            FeedbackStyle::Letters
+        };
+        GameConfig::new(WORD_LENGTH, self.attempts).with_feedback(feedback)
+    }
+
+    /// Where the reply of `player` to the challenge at `path` goes: beside
+    /// it.
+    pub fn reply_path(&self, path: &Path, player: &str) -> PathBuf {
+        let name: String = player
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect();
+        path.with_file_name(format!("reply-{}-{}.json", self.short_id(), name))
+    }
+
+    /// The start of the id, enough to tell challenges apart in file names.
+    pub fn short_id(&self) -> &str {
+        self.id.get(..8).unwrap_or(&self.id)
+    }
+}
+
+// The cipher hiding the word of challenge `id`
+fn cipher(id: &str) -> ChaCha20 {
+    let key = Sha256::digest(format!("wordle challenge {}", id));
+    ChaCha20::new(&key, &[0; 12].into())
+}
+
+/// A word shared as a short code, such as `7QX2-KM4D`: a
+/// [`ShareCode`] in one of the game's languages.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChallengeCode {
+    code: ShareCode,
+    pub language: Language,
+}
+
+impl ChallengeCode {
+    /// The code of `word` in `language`, which has to be of
+    /// [`WORD_LENGTH`] letters once its accents are folded.
+    pub fn new(word: &str, language: Language) -> anyhow::Result<ChallengeCode> {
+        let code = ShareCode::new(word, language.code())?;
+        Ok(ChallengeCode { code, language })
+    }
+
+    /// Reads a code, in any case, with or without its dash; `I`, `L` and
+    /// `O` are taken for the digits they look like.
+    pub fn decode(code: &str) -> anyhow::Result<ChallengeCode> {
+        let code = ShareCode::decode(code)?;
+        let language = Language::from_code(code.language())
+            .with_context(|| format!("the code is in unknown language '{}'", code.language()))?;
+        Ok(ChallengeCode { code, language })
+    }
+
+    /// A new code for the word, with a fresh salt.
+    pub fn encode(&self) -> String {
+        self.code.encode()
+    }
+
+    /// The code for the word with `salt`, of which the low 6 bits count.
+    pub fn encode_with(&self, salt: u8) -> String {
+        self.code.encode_with(salt)
+    }
+
+    /// The word, in uppercase with accents folded.
+    pub fn word(&self) -> String {
+        self.code.word()
+    }
+}
+
+/// A challenge file being played, and who plays it.
+#[derive(Debug, Clone)]
+pub struct Playing {
+    pub challenge: ChallengeFile,
+    /// Its word, as spelled.
+    pub word: String,
+    /// Where the challenge was read from; the reply goes beside it.
+    pub path: PathBuf,
+    /// The name the reply is signed with.
+    pub player: String,
+}
+
+impl Playing {
+    /// Opens the challenge at `path` for `player` to play.
+    pub fn open(path: PathBuf, player: String) -> anyhow::Result<Playing> {
+        let challenge = ChallengeFile::load(&path)?;
+        let word = challenge.word()?;
+        Ok(Playing {
+            challenge,
+            word,
+            path,
+            player,
+        })
+    }
+
+    /// Where the reply goes.
+    pub fn reply_path(&self) -> PathBuf {
+        self.challenge.reply_path(&self.path, &self.player)
+    }
+}
+
+/// How someone did on a challenge file, to send back to its author.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reply {
+    pub version: u32,
+    /// The challenge played.
+    pub id: String,
+    pub player: String,
+    /// Seconds since the Unix epoch, when the game ended.
+    pub at: u64,
+    pub won: bool,
+    /// Guesses submitted, out of `attempts`.
+    pub guesses: usize,
+    pub attempts: usize,
+    /// Colors of each guess, as in the `row` messages of a race.
+    pub rows: Vec<String>,
+}
+
+impl Reply {
+    /// The reply of `player` to `challenge`, whose `game` is over.
+    pub fn new(challenge: &ChallengeFile, player: &str, game: &Game) -> Reply {
+        let rows: Vec<String> = game
+            .submitted()
+            .map(|(_, statuses)| encode_row(statuses))
+            .collect();
+        Reply {
+            version: FILE_VERSION,
+            id: challenge.id.clone(),
+            player: player.to_string(),
+            at: now(),
+            won: game.status == GameStatus::Won,
+            guesses: rows.len(),
+            attempts: game.config.max_attempts,
+            rows,
+        }
+    }
+
+    /// Reads the reply at `path`.
+    pub fn load(path: &Path) -> anyhow::Result<Reply> {
+        let text =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let reply: Reply = serde_json::from_str(&text)
+            .with_context(|| format!("{} is not a challenge reply", path.display()))?;
+        if reply.version != FILE_VERSION {
+            bail!(
+                "{} is a reply of version {}, this game reads version {}",
+                path.display(),
+                reply.version,
+                FILE_VERSION
+            );
+        }
+        Ok(reply)
+    }
+
+    /// Writes the reply to `path`.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n").with_context(|| format!("writing {}", path.display()))
+    }
+
+    /// The result as shared: guesses out of those allowed, `X` for a loss,
+    /// then a colored square per letter of each guess.
+    pub fn summary(&self) -> String {
+        let taken = if self.won {
+            self.guesses.to_string()
+        } else {
+            "X".to_string()
+        };
+        let squares: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .map(|c| match c {
+                        'C' => '🟩',
+                        'P' => '🟨',
+                        _ => '⬛',
+                    })
+                    .collect()
+            })
+            .collect();
+        format!("{}/{} {}", taken, self.attempts, squares.join(" "))
+    }
+}
+
+/// Replies to challenge files: those of the games played on this machine
+/// and those imported, kept in `challenge-replies.jsonl` in the data
+/// directory.
+#[derive(Debug, Default)]
+pub struct Replies {
+    /// Oldest first.
+    pub replies: Vec<Reply>,
+}
+
+impl Replies {
+    /// Reads the replies, starting afresh if there are none.
+    pub fn load() -> Replies {
+        let replies = Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|text| {
+                text.lines()
+                    .filter_map(|line| serde_json::from_str(line).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Replies { replies }
+    }
+
+    /// Adds `reply` and saves it, unless it is already there. Returns
+    /// whether it was new.
+    pub fn record(&mut self, reply: Reply) -> io::Result<bool> {
+        if self.replies.contains(&reply) {
+            return Ok(false);
+        }
+        let line = serde_json::to_string(&reply).map_err(io::Error::other)?;
+        self.replies.push(reply);
+        let Some(path) = Self::path() else {
+            return Ok(true);
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", line)?;
+        Ok(true)
+    }
+
+    /// The replies to challenge `id`, best first: wins in the fewest
+    /// guesses, the earliest on a tie, then losses.
+    pub fn ranking(&self, id: &str) -> Vec<&Reply> {
+        let mut ranking: Vec<&Reply> = self.replies.iter().filter(|r| r.id == id).collect();
+        ranking.sort_by_key(|reply| (!reply.won, reply.guesses, reply.at));
+        ranking
+    }
+
+    fn path() -> Option<PathBuf> {
+        Some(Config::data_dir()?.join("challenge-replies.jsonl"))
+    }
+}
@@ -0,0 +1,97 @@
+//! What changed in each release, embedded from `data/changelog.toml`, and
+//! the last version whose changes the player has seen, kept in
+//! `seen-version` in the data directory.
+//!
+//! The "What's new" screen opens by itself on the first start after an
+//! upgrade, with the releases since the version seen; a first install
+//! starts on the menu and only records its version.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::config::Config;
+
+/// Version of this build.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// What one release brought.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Release {
+    pub version: String,
+    /// New game modes.
+    #[serde(default)]
+    pub modes: Vec<String>,
+    /// New keys and key bindings.
+    #[serde(default)]
+    pub keys: Vec<String>,
+    /// Everything else.
+    #[serde(default)]
+    pub other: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct Changelog {
+    release: Vec<Release>,
+}
+
+/// Every release, newest first.
+pub fn releases() -> Vec<Release> {
+    toml::from_str::<Changelog>(include_str!("../../../data/changelog.toml"))
+        .expect("the embedded changelog is valid")
+        .release
+}
+
+/// Releases newer than `seen`, newest first.
+pub fn since(seen: &str) -> Vec<Release> {
+    let seen = version_key(seen);
+    releases()
+        .into_iter()
+        .filter(|release| version_key(&release.version) > seen)
+        .collect()
+}
+
+/// Releases to show at startup: those since the version last seen, or
+/// none on a first install, whose version is recorded instead.
+pub fn unseen() -> Vec<Release> {
+    match seen_version() {
+        Some(seen) => since(&seen),
+        None => {
+            // Nothing to tell about, and nowhere to keep it is no reason to
+            // bother the player
+            let _ = mark_seen();
+            Vec::new()
+        }
+    }
+}
+
+/// Last version whose changes were shown, if any was.
+pub fn seen_version() -> Option<String> {
+    let text = fs::read_to_string(path()?).ok()?;
+    Some(text.trim().to_string())
+}
+
+/// Records this build's changes as seen, so they are not shown again.
+pub fn mark_seen() -> io::Result<()> {
+    let Some(path) = path() else {
+        return Ok(());
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, format!("{}\n", VERSION))
+}
+
+fn path() -> Option<PathBuf> {
+    Some(Config::data_dir()?.join("seen-version"))
+}
+
+// `1.10.0` after `1.9.2`: each part compared as a number
+fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split('.')
+        .map(|part| part.trim().parse().unwrap_or(0))
+        .collect()
+}
@@ -0,0 +1,739 @@
+//! Command-line options, which take precedence over the configuration
+//! file, and subcommands that run instead of the game.
+
+use std::fs;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context};
+use rand::seq::SliceRandom;
+
+use crate::challenge::{ChallengeCode, ChallengeFile, Replies, Reply, MAX_FILE_ATTEMPTS};
+use crate::config::Config;
+use crate::doctor::Report;
+use crate::export::Export;
+use crate::game::normalize::{fold_word, normalize_input};
+use crate::game::{letterle_letters, seed_from_code, sheet, MAX_ATTEMPTS, WORD_LENGTH};
+use crate::history::{History, Source, Verification};
+use crate::i18n::Language;
+use crate::net::{self, DEFAULT_PORT};
+use crate::packs::{self, Verdict};
+use crate::server;
+use crate::ssh::{self, DEFAULT_SSH_PORT};
+use crate::ui::profile;
+
+#[derive(Debug, Default, Clone)]
+pub struct Args {
+    /// `--lang <code>`: game language, overriding `language` in the config.
+    pub lang: Option<String>,
+    /// `--keyboard-audit`: keyboard-only audit mode.
+    pub keyboard_audit: bool,
+    /// `--profile-render <file>`: where to write the render profile at
+    /// exit (see [`crate::ui::profile`]).
+    pub profile_render: Option<PathBuf>,
+    /// `--seed <code>`: starts straight on the word this seed draws, the
+    /// same for everyone with the same word list.
+    pub seed: Option<u64>,
+    /// `--word <word>`: starts a guest challenge on this word, set by one
+    /// player for another to find (see [`challenge_word`]).
+    pub word: Option<String>,
+    /// `--any-word`: lets `--word` be a word that isn't in the word list.
+    pub any_word: bool,
+    /// `--challenge <code>`: starts a guest challenge on the word of a
+    /// challenge code, in its language (see [`ChallengeCode`]).
+    pub code: Option<ChallengeCode>,
+    /// Subcommand to run instead of the game.
+    pub command: Option<Command>,
+    /// Subcommand starting the game on a race over the network.
+    pub lan: Option<Lan>,
+    /// `challenge play <file> [--name <name>]`: starts on the word of a
+    /// challenge file (see [`crate::challenge`]).
+    pub challenge: Option<PlayChallenge>,
+    /// `--overlay-file <path>`: keeps a status line of the game in the
+    /// file, for streaming overlays (see [`crate::overlay`]).
+    pub overlay_file: Option<PathBuf>,
+    /// `--no-tui`: plays a line of text at a time instead of on the full
+    /// screen, for screen readers (see [`crate::linear`]).
+    pub no_tui: bool,
+}
+
+/// A challenge file to play, and the name to sign the reply with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayChallenge {
+    pub file: PathBuf,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// `replay --verify <id>`: draws the word of a logged game again from
+    /// its seed and checks it is the one that was played.
+    VerifyReplay(u64),
+    /// `print [--count <n>] [--out <file>]`: writes a sheet of blank grids
+    /// to play on paper, to the file or to standard output.
+    Print { count: usize, out: Option<PathBuf> },
+    /// `serve [--port <n>]`: runs a race server without the game (see
+    /// [`crate::server`]).
+    Serve { port: u16 },
+    /// `ssh [--port <n>]`: serves the game over SSH, a game per connection
+    /// (see [`crate::ssh`]).
+    Ssh { port: u16 },
+    /// `challenge create [<word>] [--out <file>] [--name <name>]
+    /// [--attempts <n>] [--mastermind]`: writes a challenge file on the
+    /// word, or on one drawn from the word list (see [`crate::challenge`]).
+    CreateChallenge {
+        word: Option<String>,
+        out: Option<PathBuf>,
+        name: Option<String>,
+        attempts: usize,
+        mastermind: bool,
+    },
+    /// `challenge code <word> [--any-word]`: writes the challenge code of
+    /// the word, to send instead of the word.
+    ChallengeCode { word: String, any_word: bool },
+    /// `challenge import <file>...`: keeps the replies to challenge files,
+    /// then ranks everyone who played each challenge.
+    ImportReplies(Vec<PathBuf>),
+    /// `stats export [--json <file>] [--csv <file>] [--commentary]`:
+    /// writes every game of the history and figures about them as JSON, or
+    /// a row per game as CSV (see [`crate::export`]). Without either file,
+    /// the JSON goes to standard output. `--commentary` adds how each game
+    /// went to the JSON.
+    ExportStats {
+        json: Option<PathBuf>,
+        csv: Option<PathBuf>,
+        commentary: bool,
+    },
+    /// `doctor`: what the terminal can do, and the settings that suit it
+    /// (see [`crate::doctor`]).
+    Doctor,
+    /// `pack list`: the packs of the index in `[packs]` (see
+    /// [`crate::packs`]).
+    ListPacks,
+    /// `pack install <name>`: downloads a pack of the index, if the trust
+    /// policy lets it in.
+    InstallPack(String),
+    /// `pack sign <words-file> <key-file>`: signs a pack for an index, with
+    /// the key in the file (made if missing).
+    SignPack { words: PathBuf, key: PathBuf },
+}
+
+/// The ways into a race over the network (see [`crate::net`]).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Lan {
+    /// `host [--port <n>]`: waits for a player to join, then starts.
+    Host { port: u16 },
+    /// `join <address> [--room <name>] [--name <name>]`: joins the race
+    /// hosted there, or a room of the server there.
+    Join {
+        address: String,
+        room: Option<String>,
+        name: Option<String>,
+    },
+    /// `watch <address> [--room <name>]`: watches a room of the server
+    /// there without playing.
+    Watch {
+        address: String,
+        room: Option<String>,
+    },
+    /// `pair host [--port <n>]`: waits for a partner to join, then plays
+    /// a word with them on one board.
+    PairHost { port: u16 },
+    /// `pair join <address>`: plays the word of the pair hosted there.
+    PairJoin { address: String },
+}
+
+impl Args {
+    /// Parses the arguments the program was started with.
+    pub fn parse() -> anyhow::Result<Self> {
+        Self::parse_from(std::env::args().skip(1))
+    }
+
+    /// Parses `args`, not including the program name.
+    pub fn parse_from(args: impl IntoIterator<Item = String>) -> anyhow::Result<Self> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter().peekable();
+        if args.peek().map(String::as_str) == Some("replay") {
+            args.next();
+            let id = match (args.next().as_deref(), args.next()) {
+                (Some("--verify"), Some(id)) => id,
+                _ => bail!("usage: wordle replay --verify <game id>"),
+            };
+            let id = id
+                .parse()
+                .with_context(|| format!("'{}' is not a game id", id))?;
+            parsed.command = Some(Command::VerifyReplay(id));
+        } else if args.peek().map(String::as_str) == Some("print") {
+            args.next();
+            parsed.command = Some(Command::Print {
+                count: 10,
+                out: None,
+            });
+        } else if args.peek().map(String::as_str) == Some("serve") {
+            args.next();
+            parsed.command = Some(Command::Serve { port: DEFAULT_PORT });
+        } else if args.peek().map(String::as_str) == Some("ssh") {
+            args.next();
+            parsed.command = Some(Command::Ssh {
+                port: DEFAULT_SSH_PORT,
+            });
+        } else if args.peek().map(String::as_str) == Some("challenge") {
+            args.next();
+            let usage = "usage: wordle challenge create [<word>] | code <word> | play <file> | import <file>...";
+            let is_value = |arg: &String| !arg.starts_with("--");
+            match args.next().as_deref() {
+                Some("create") => {
+                    parsed.command = Some(Command::CreateChallenge {
+                        word: args.next_if(is_value),
+                        out: None,
+                        name: None,
+                        attempts: MAX_ATTEMPTS,
+                        mastermind: false,
+                    });
+                }
+                Some("code") => {
+                    let word = args.next_if(is_value).context(usage)?;
+                    parsed.command = Some(Command::ChallengeCode {
+                        word,
+                        any_word: false,
+                    });
+                }
+                Some("play") => {
+                    let file = args.next_if(is_value).context(usage)?;
+                    parsed.challenge = Some(PlayChallenge {
+                        file: PathBuf::from(file),
+                        name: None,
+                    });
+                }
+                Some("import") => {
+                    let mut files = Vec::new();
+                    while let Some(file) = args.next_if(is_value) {
+                        files.push(PathBuf::from(file));
+                    }
+                    if files.is_empty() {
+                        bail!(usage);
+                    }
+                    parsed.command = Some(Command::ImportReplies(files));
+                }
+                _ => bail!(usage),
+            }
+        } else if args.peek().map(String::as_str) == Some("stats") {
+            args.next();
+            if args.next().as_deref() != Some("export") {
+                bail!("usage: wordle stats export [--json <file>] [--csv <file>] [--commentary]");
+            }
+            parsed.command = Some(Command::ExportStats {
+                json: None,
+                csv: None,
+                commentary: false,
+            });
+        } else if args.peek().map(String::as_str) == Some("doctor") {
+            args.next();
+            parsed.command = Some(Command::Doctor);
+        } else if args.peek().map(String::as_str) == Some("pack") {
+            args.next();
+            let usage = "usage: wordle pack list | install <name> | sign <words-file> <key-file>";
+            let is_value = |arg: &String| !arg.starts_with("--");
+            parsed.command = Some(match args.next().as_deref() {
+                Some("list") => Command::ListPacks,
+                Some("install") => Command::InstallPack(args.next_if(is_value).context(usage)?),
+                Some("sign") => Command::SignPack {
+                    words: PathBuf::from(args.next_if(is_value).context(usage)?),
+                    key: PathBuf::from(args.next_if(is_value).context(usage)?),
+                },
+                _ => bail!(usage),
+            });
+        } else if args.peek().map(String::as_str) == Some("host") {
+            args.next();
+            parsed.lan = Some(Lan::Host { port: DEFAULT_PORT });
+        } else if args.peek().map(String::as_str) == Some("join") {
+            args.next();
+            let address = args
+                .next()
+                .filter(|address| !address.starts_with("--"))
+                .context("usage: wordle join <address>")?;
+            parsed.lan = Some(Lan::Join {
+                address,
+                room: None,
+                name: None,
+            });
+        } else if args.peek().map(String::as_str) == Some("pair") {
+            args.next();
+            let usage = "usage: wordle pair host | wordle pair join <address>";
+            parsed.lan = Some(match args.next().as_deref() {
+                Some("host") => Lan::PairHost { port: DEFAULT_PORT },
+                Some("join") => Lan::PairJoin {
+                    address: args
+                        .next()
+                        .filter(|address| !address.starts_with("--"))
+                        .context(usage)?,
+                },
+                _ => bail!(usage),
+            });
+        } else if args.peek().map(String::as_str) == Some("watch") {
+            args.next();
+            let address = args
+                .next()
+                .filter(|address| !address.starts_with("--"))
+                .context("usage: wordle watch <address>")?;
+            parsed.lan = Some(Lan::Watch {
+                address,
+                room: None,
+            });
+        }
+        while let Some(arg) = args.next() {
+            let (name, inline) = match arg.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            match name.as_str() {
+                "--lang" => {
+                    let code = match inline {
+                        Some(code) => code,
+                        None => args.next().context("--lang needs a language code")?,
+                    };
+                    if Language::from_code(&code).is_none() {
+                        bail!("unknown language '{}' (expected en, pt, es or fr)", code);
+                    }
+                    parsed.lang = Some(code);
+                }
+                "--keyboard-audit" if inline.is_none() => parsed.keyboard_audit = true,
+                "--no-tui" if inline.is_none() => parsed.no_tui = true,
+                "--profile-render" => {
+                    if !profile::AVAILABLE {
+                        bail!("--profile-render needs a build with the profiling feature");
+                    }
+                    let path = match inline {
+                        Some(path) => path,
+                        None => args.next().context("--profile-render needs a file")?,
+                    };
+                    parsed.profile_render = Some(PathBuf::from(path));
+                }
+                "--overlay-file" => {
+                    let path = match inline {
+                        Some(path) => path,
+                        None => args.next().context("--overlay-file needs a file")?,
+                    };
+                    parsed.overlay_file = Some(PathBuf::from(path));
+                }
+                "--seed" => {
+                    let code = match inline {
+                        Some(code) => code,
+                        None => args.next().context("--seed needs a code")?,
+                    };
+                    let seed = seed_from_code(&code).context("--seed needs a code")?;
+                    parsed.seed = Some(seed);
+                }
+                "--word" => {
+                    let word = match inline {
+                        Some(word) => word,
+                        None => args.next().context("--word needs a word")?,
+                    };
+                    let letters = normalize_input(&word)
+                        .map_err(|c| anyhow::anyhow!("'{}' can't be in a word", c))?;
+                    if letters.len() != WORD_LENGTH {
+                        bail!("--word needs a {} letter word", WORD_LENGTH);
+                    }
+                    parsed.word = Some(word);
+                }
+                "--any-word" if inline.is_none() => match &mut parsed.command {
+                    Some(Command::ChallengeCode { any_word, .. }) => *any_word = true,
+                    _ => parsed.any_word = true,
+                },
+                "--challenge" => {
+                    let code = match inline {
+                        Some(code) => code,
+                        None => args.next().context("--challenge needs a code")?,
+                    };
+                    parsed.code = Some(ChallengeCode::decode(&code)?);
+                }
+                "--count" | "--out" => {
+                    let (count, out) = match &mut parsed.command {
+                        Some(Command::Print { count, out }) => (Some(count), out),
+                        Some(Command::CreateChallenge { out, .. }) if name == "--out" => {
+                            (None, out)
+                        }
+                        _ if name == "--out" => {
+                            bail!("--out only goes with print or challenge create")
+                        }
+                        _ => bail!("--count only goes with print"),
+                    };
+                    let value = match inline {
+                        Some(value) => value,
+                        None => args
+                            .next()
+                            .with_context(|| format!("{} needs a value", name))?,
+                    };
+                    match count {
+                        Some(count) if name == "--count" => {
+                            *count = value.parse().with_context(|| {
+                                format!("'{}' is not a number of puzzles", value)
+                            })?;
+                        }
+                        _ => *out = Some(PathBuf::from(value)),
+                    }
+                }
+                "--json" | "--csv" => {
+                    let Some(Command::ExportStats { json, csv, .. }) = &mut parsed.command else {
+                        bail!("{} only goes with stats export", name);
+                    };
+                    let path = match inline {
+                        Some(path) => path,
+                        None => args
+                            .next()
+                            .with_context(|| format!("{} needs a file", name))?,
+                    };
+                    let target = if name == "--json" { json } else { csv };
+                    *target = Some(PathBuf::from(path));
+                }
+                "--commentary" if inline.is_none() => {
+                    let Some(Command::ExportStats { commentary, .. }) = &mut parsed.command else {
+                        bail!("--commentary only goes with stats export");
+                    };
+                    *commentary = true;
+                }
+                "--attempts" => {
+                    let Some(Command::CreateChallenge { attempts, .. }) = &mut parsed.command
+                    else {
+                        bail!("--attempts only goes with challenge create");
+                    };
+                    let value = match inline {
+                        Some(value) => value,
+                        None => args.next().context("--attempts needs a number")?,
+                    };
+                    *attempts = value
+                        .parse()
+                        .ok()
+                        .filter(|n| (1..=MAX_FILE_ATTEMPTS).contains(n))
+                        .with_context(|| {
+                            format!("--attempts takes 1 to {} guesses", MAX_FILE_ATTEMPTS)
+                        })?;
+                }
+                "--mastermind" if inline.is_none() => {
+                    let Some(Command::CreateChallenge { mastermind, .. }) = &mut parsed.command
+                    else {
+                        bail!("--mastermind only goes with challenge create");
+                    };
+                    *mastermind = true;
+                }
+                "--port" => {
+                    let port = match (&mut parsed.lan, &mut parsed.command) {
+                        (Some(Lan::Host { port } | Lan::PairHost { port }), _)
+                        | (_, Some(Command::Serve { port } | Command::Ssh { port })) => port,
+                        _ => bail!("--port only goes with host, pair host, serve or ssh"),
+                    };
+                    let value = match inline {
+                        Some(value) => value,
+                        None => args.next().context("--port needs a number")?,
+                    };
+                    *port = value
+                        .parse()
+                        .with_context(|| format!("'{}' is not a port", value))?;
+                }
+                "--room" | "--name" => {
+                    let (room, player) =
+                        match (&mut parsed.lan, &mut parsed.command, &mut parsed.challenge) {
+                            (
+                                Some(Lan::Join {
+                                    room, name: player, ..
+                                }),
+                                _,
+                                _,
+                            ) => (Some(room), Some(player)),
+                            (Some(Lan::Watch { room, .. }), _, _) => (Some(room), None),
+                            (_, Some(Command::CreateChallenge { name: player, .. }), _)
+                            | (_, _, Some(PlayChallenge { name: player, .. })) => {
+                                (None, Some(player))
+                            }
+                            _ => (None, None),
+                        };
+                    let field = if name == "--room" {
+                        room.context("--room only goes with join or watch")?
+                    } else {
+                        player.context("--name only goes with join or challenge")?
+                    };
+                    let value = match inline {
+                        Some(value) => value,
+                        None => args
+                            .next()
+                            .with_context(|| format!("{} needs a name", name))?,
+                    };
+                    *field = Some(value);
+                }
+                _ => bail!("unknown option '{}'", name),
+            }
+        }
+        if parsed.any_word && parsed.word.is_none() {
+            bail!("--any-word only goes with --word or challenge code");
+        }
+        if parsed.word.is_some() && parsed.code.is_some() {
+            bail!("--word and --challenge both set the word; give one");
+        }
+        if parsed.no_tui && parsed.lan.is_some() {
+            bail!("--no-tui only plays alone: not with host, join, watch or pair");
+        }
+        Ok(parsed)
+    }
+
+    /// Overrides the configuration with the options that were given.
+    pub fn apply(self, config: &mut Config) {
+        if let Some(lang) = self.lang {
+            config.language = Some(lang);
+        }
+        if self.keyboard_audit {
+            config.display.keyboard_audit = true;
+        }
+    }
+}
+
+impl Command {
+    /// Runs the subcommand, printing its outcome. Fails when the check
+    /// does.
+    pub fn run(self, config: &Config) -> anyhow::Result<()> {
+        match self {
+            Command::VerifyReplay(id) => verify_replay(id),
+            Command::Print { count, out } => print_sheet(config, count, out),
+            Command::Serve { port } => {
+                let listener = TcpListener::bind(("0.0.0.0", port))
+                    .with_context(|| format!("listening on port {}", port))?;
+                println!("Serving races on port {}", port);
+                server::serve(listener)
+            }
+            Command::Ssh { port } => {
+                let listener = TcpListener::bind(("0.0.0.0", port))
+                    .with_context(|| format!("listening on port {}", port))?;
+                println!("Serving the game over SSH on port {}", port);
+                println!("Play with: ssh -p {} play@<this host>", port);
+                ssh::serve(listener, config.clone())
+            }
+            Command::CreateChallenge {
+                word,
+                out,
+                name,
+                attempts,
+                mastermind,
+            } => create_challenge(config, word, out, name, attempts, mastermind),
+            Command::ChallengeCode { word, any_word } => {
+                let language = Language::detect(config.language.as_deref());
+                let word = challenge_word(&word, language, any_word)?;
+                let code = ChallengeCode::new(&word, language)?.encode();
+                println!("Challenge code: {}", code);
+                println!("A friend plays it with: wordle --challenge {}", code);
+                println!("or pastes it in Challenge a guest");
+                Ok(())
+            }
+            Command::ImportReplies(files) => import_replies(files),
+            Command::ExportStats {
+                json,
+                csv,
+                commentary,
+            } => export_stats(json, csv, commentary),
+            Command::Doctor => {
+                print!("{}", Report::probe().render(config));
+                Ok(())
+            }
+            Command::ListPacks => {
+                for pack in packs::fetch_index(&config.packs)? {
+                    let signed = if pack.signature.is_some() {
+                        "signed"
+                    } else {
+                        "unsigned"
+                    };
+                    println!("{} ({})", pack.name, signed);
+                }
+                Ok(())
+            }
+            Command::InstallPack(name) => {
+                let installed = packs::install(&config.packs, &name)?;
+                match installed.verdict {
+                    Verdict::Unsigned => eprintln!("warning: '{}' isn't signed", name),
+                    Verdict::Untrusted => {
+                        eprintln!("warning: '{}' isn't signed by a trusted key", name)
+                    }
+                    Verdict::Trusted(_) | Verdict::Unchecked => {}
+                }
+                println!("Installed '{}' in {}", name, installed.path.display());
+                println!("Play it with word_theme = \"{}\" or in the settings", name);
+                Ok(())
+            }
+            Command::SignPack { words, key } => {
+                let (signature, public_key) = packs::sign(&words, &key)?;
+                println!("Signature: {}", signature);
+                println!("Public key, for [packs] trusted_keys: {}", public_key);
+                Ok(())
+            }
+        }
+    }
+}
+
+fn create_challenge(
+    config: &Config,
+    word: Option<String>,
+    out: Option<PathBuf>,
+    name: Option<String>,
+    attempts: usize,
+    mastermind: bool,
+) -> anyhow::Result<()> {
+    let language = Language::detect(config.language.as_deref());
+    let words = language.load_words();
+    let word = match word {
+        Some(word) => find_word(&words, &word)
+            .with_context(|| format!("'{}' is not in the {} word list", word, language.code()))?,
+        None => words
+            .choose(&mut rand::thread_rng())
+            .cloned()
+            .context("the word list is empty")?,
+    };
+    let from = name.unwrap_or_else(net::default_name);
+    let challenge = ChallengeFile::new(&word, &from, language, attempts, mastermind);
+    let path =
+        out.unwrap_or_else(|| PathBuf::from(format!("challenge-{}.json", challenge.short_id())));
+    challenge.save(&path)?;
+    println!("Challenge written to {}", path.display());
+    println!(
+        "Send it to a friend, who plays it with: wordle challenge play {}",
+        path.display()
+    );
+    Ok(())
+}
+
+/// The word `--word` sets for the guest, as spelled in the `language` word
+/// list. A word that isn't in it is refused, unless `any_word` lets it be
+/// played as typed.
+pub fn challenge_word(word: &str, language: Language, any_word: bool) -> anyhow::Result<String> {
+    if let Some(spelling) = find_word(&language.load_words(), word) {
+        return Ok(spelling);
+    }
+    if !any_word {
+        bail!(
+            "'{}' is not in the {} word list (--any-word plays it anyway)",
+            word,
+            language.code()
+        );
+    }
+    Ok(fold_word(word))
+}
+
+// The spelling of `word` in `words`, accents or not
+fn find_word(words: &[String], word: &str) -> Option<String> {
+    words
+        .iter()
+        .find(|spelling| fold_word(spelling) == fold_word(word))
+        .cloned()
+}
+
+fn import_replies(files: Vec<PathBuf>) -> anyhow::Result<()> {
+    let mut replies = Replies::load();
+    let mut challenges: Vec<String> = Vec::new();
+    for path in files {
+        let reply = Reply::load(&path)?;
+        let id = reply.id.clone();
+        let new = replies.record(reply).context("saving the replies")?;
+        if new {
+            println!("Imported {}", path.display());
+        } else {
+            println!("Already imported {}", path.display());
+        }
+        if !challenges.contains(&id) {
+            challenges.push(id);
+        }
+    }
+    for id in challenges {
+        println!("\nChallenge {}", id);
+        for (place, reply) in replies.ranking(&id).into_iter().enumerate() {
+            println!("{}. {:<12} {}", place + 1, reply.player, reply.summary());
+        }
+    }
+    Ok(())
+}
+
+fn export_stats(
+    json: Option<PathBuf>,
+    csv: Option<PathBuf>,
+    commentary: bool,
+) -> anyhow::Result<()> {
+    let at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let mut export = Export::new(&History::open().entries(), at);
+    if commentary {
+        export.add_commentary();
+    }
+    if json.is_none() && csv.is_none() {
+        print!("{}", export.to_json());
+        return Ok(());
+    }
+    let files = [
+        (json, Export::to_json as fn(&Export) -> String),
+        (csv, Export::to_csv),
+    ];
+    for (path, write) in files {
+        let Some(path) = path else {
+            continue;
+        };
+        fs::write(&path, write(&export)).with_context(|| format!("writing {}", path.display()))?;
+        println!(
+            "{} games exported to {}",
+            export.games.len(),
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+fn print_sheet(config: &Config, count: usize, out: Option<PathBuf>) -> anyhow::Result<()> {
+    let language = Language::detect(config.language.as_deref());
+    let answers = sheet::draw_answers(&language.load_words(), count, &mut rand::thread_rng());
+    let text = sheet::render(&answers, MAX_ATTEMPTS);
+    match out {
+        Some(path) => {
+            fs::write(&path, text).with_context(|| format!("writing {}", path.display()))?;
+            println!("{} puzzles written to {}", answers.len(), path.display());
+        }
+        None => print!("{}", text),
+    }
+    Ok(())
+}
+
+fn verify_replay(id: u64) -> anyhow::Result<()> {
+    let start = History::open()
+        .find(id)
+        .with_context(|| format!("no game {} in the history", id))?;
+    let word = &start.word;
+    let language = Language::from_code(&start.language).with_context(|| {
+        format!(
+            "game {} was played in unknown language '{}'",
+            id, start.language
+        )
+    })?;
+
+    println!("Game {}: {:?} word {}", id, start.source, word);
+    if let Some(seed) = start.seed {
+        println!("Seed: {}", seed);
+    }
+    // The warmup letter comes from the alphabet, not the word list
+    let words = match start.source {
+        Source::Warmup => letterle_letters(),
+        _ => language.load_words(),
+    };
+    match start.verify(&words) {
+        Verification::Verified => {
+            println!("Verified: the seed draws {} from the word list", word);
+            Ok(())
+        }
+        Verification::NotDrawn => {
+            println!("Not drawn from the word list, nothing to verify");
+            Ok(())
+        }
+        Verification::Mismatch { drawn } => {
+            bail!("the seed draws {}, not {}", drawn, word)
+        }
+        Verification::ListChanged => {
+            bail!(
+                "the {} word list changed since the game was played",
+                language.code()
+            )
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! The local time of day, which the `auto` theme follows (see
+//! [`crate::config::ThemeSchedule`]).
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Minutes since local midnight, in the system's time zone; in UTC where
+/// the time zone can't be read.
+pub fn local_minutes() -> u16 {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    local_time_minutes(seconds)
+        .map(|minutes| minutes as u16)
+        .unwrap_or((seconds % 86_400 / 60) as u16)
+}
+
+#[cfg(unix)]
+fn local_time_minutes(seconds: u64) -> Option<i32> {
+    let time = libc::time_t::try_from(seconds).ok()?;
+    // SAFETY: `tm` is plain data, filled in by `localtime_r`, which only
+    // reads `time`; a null result means it failed and `tm` isn't used
+    let tm = unsafe {
+        let mut tm: libc::tm = std::mem::zeroed();
+        if libc::localtime_r(&time, &mut tm).is_null() {
+            return None;
+        }
+        tm
+    };
+    Some(tm.tm_hour * 60 + tm.tm_min)
+}
+
+#[cfg(not(unix))]
+fn local_time_minutes(_seconds: u64) -> Option<i32> {
+    None
+}
@@ -0,0 +1,101 @@
+//! A few sentences telling how a finished game went, from its analysis
+//! (see [`crate::game::analysis`]):
+//!
+//! ```text
+//! Opened with CRANE, which left 112 words. Guess 2, SLOTH, pivoted well:
+//! 112 words down to 4. Found it on guess 3, a lucky pick among 4 words.
+//! ```
+//!
+//! Shown under the table of the analysis screen, and in the stats export
+//! with `--commentary`.
+
+use std::sync::Arc;
+
+use crate::game::analysis::{self, GuessAnalysis};
+use crate::game::candidates::CandidateSet;
+use crate::game::Feedback;
+use crate::i18n::Texts;
+
+/// Share of the solver's expected information above which a guess counts
+/// as a good pivot.
+const PIVOT: f64 = 0.9;
+
+/// The commentary of a game whose guesses went as `report` says, on the
+/// word `answer`.
+pub fn narrate(report: &[GuessAnalysis], answer: &str, texts: &Texts) -> String {
+    let mut sentences = Vec::new();
+    for (index, guess) in report.iter().enumerate() {
+        let word = guess.feedback.word();
+        let number = index + 1;
+        let sentence = if guess.feedback.is_solved() {
+            if guess.before > 1 {
+                format!(
+                    "{} {}, {} {} {}.",
+                    texts.commentary_won,
+                    number,
+                    texts.commentary_lucky,
+                    guess.before,
+                    texts.commentary_words
+                )
+            } else {
+                format!("{} {}.", texts.commentary_won, number)
+            }
+        } else if index == 0 {
+            format!(
+                "{} {}, {} {} {}.",
+                texts.commentary_opened,
+                word,
+                texts.commentary_left,
+                guess.after,
+                texts.commentary_words
+            )
+        } else {
+            let narrowed = format!(
+                "{} {} {} {}",
+                guess.before, texts.commentary_words, texts.commentary_down_to, guess.after
+            );
+            let named = format!("{} {}, {}", texts.commentary_guess, number, word);
+            match &guess.best {
+                _ if guess.after == guess.before => {
+                    format!("{}, {}.", named, texts.commentary_nothing)
+                }
+                Some(best) if best.word == word => {
+                    format!("{}, {}: {}.", named, texts.commentary_solver_pick, narrowed)
+                }
+                Some(best) if guess.entropy >= best.entropy * PIVOT => {
+                    format!("{}, {}: {}.", named, texts.commentary_pivot, narrowed)
+                }
+                Some(best) => format!(
+                    "{}: {} ({} {}).",
+                    named, narrowed, texts.commentary_solver_had, best.word
+                ),
+                None => format!("{}: {}.", named, narrowed),
+            }
+        };
+        sentences.push(sentence);
+    }
+    if !report
+        .last()
+        .is_some_and(|guess| guess.feedback.is_solved())
+    {
+        sentences.push(format!("{} {}.", texts.commentary_lost, answer));
+    }
+    sentences.join(" ")
+}
+
+/// The analysis of a game of the history, played on `answer` with
+/// `guesses`, replayed over `words`, the word list of its language with
+/// accents folded.
+pub fn replay(words: &Arc<[String]>, answer: &str, guesses: &[String]) -> Vec<GuessAnalysis> {
+    let history: Vec<Feedback> = guesses
+        .iter()
+        .map(|guess| Feedback::new(&guess.chars().collect::<Vec<_>>(), answer))
+        .collect();
+    let mut candidates = CandidateSet::new(words.clone());
+    let mut before = Vec::new();
+    for feedback in &history {
+        before.push(candidates.clone());
+        candidates.apply(&feedback.letters, &feedback.statuses);
+    }
+    analysis::analyze(&history, &before)
+}
@@ -0,0 +1,115 @@
+//! Community puzzle of the week, fetched from a feed chosen in the
+//! configuration (`[community] feed_url`). The feed is a JSON document:
+//!
+//! ```json
+//! {
+//!   "id": "2026-w42",
+//!   "word": "AVIÃO",
+//!   "author": "Ana",
+//!   "description": "Something that flies"
+//! }
+//! ```
+//!
+//! The ids of the puzzles already solved are kept in the data directory,
+//! apart from anything else, so a curator's puzzle is only marked done once.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+
+use crate::config::Config;
+use crate::game::normalize::fold_word;
+use crate::game::WORD_LENGTH;
+use crate::http;
+
+/// Gives up on the feed after this long, so a dead server only costs the
+/// puzzle.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Puzzle {
+    /// Unique per puzzle, to remember which were solved.
+    pub id: String,
+    /// The answer, as spelled (accents included).
+    pub word: String,
+    pub author: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl Puzzle {
+    /// Reads a puzzle from the feed's JSON, refusing words the game can't
+    /// play.
+    pub fn parse(json: &str) -> anyhow::Result<Puzzle> {
+        let puzzle: Puzzle = serde_json::from_str(json).context("reading the puzzle feed")?;
+        let folded = fold_word(&puzzle.word);
+        if folded.chars().count() != WORD_LENGTH || !folded.chars().all(|c| c.is_ascii_uppercase())
+        {
+            bail!("the puzzle word '{}' can't be played", puzzle.word);
+        }
+        Ok(puzzle)
+    }
+
+    /// Downloads the puzzle at `url`.
+    pub fn fetch(url: &str) -> anyhow::Result<Puzzle> {
+        Self::parse(&http::get(url, FETCH_TIMEOUT)?)
+    }
+}
+
+/// Fetches the puzzle at `url` on another thread, so startup doesn't wait
+/// for the network. Sends `None` if it can't be had.
+pub fn fetch_in_background(url: String) -> Receiver<Option<Puzzle>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(Puzzle::fetch(&url).ok());
+    });
+    receiver
+}
+
+/// Ids of the community puzzles solved so far.
+#[derive(Debug, Default, Clone)]
+pub struct Completed {
+    ids: HashSet<String>,
+}
+
+impl Completed {
+    /// Reads the solved puzzles, starting afresh if there is no record.
+    pub fn load() -> Completed {
+        let ids = Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|text| text.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+        Completed { ids }
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.contains(id)
+    }
+
+    /// Marks `id` as solved and saves the record.
+    pub fn insert(&mut self, id: &str) -> io::Result<()> {
+        if !self.ids.insert(id.to_string()) {
+            return Ok(());
+        }
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut ids: Vec<&str> = self.ids.iter().map(String::as_str).collect();
+        ids.sort_unstable();
+        fs::write(path, ids.join("\n") + "\n")
+    }
+
+    fn path() -> Option<PathBuf> {
+        Some(Config::data_dir()?.join("community-completed.txt"))
+    }
+}
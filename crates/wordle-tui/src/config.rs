@@ -0,0 +1,757 @@
+//! User configuration, read from `config.toml` in the wordle config directory
+//! (see [`paths::config_dir`]). The `WORDLE_CONFIG` environment variable
+//! points to an alternative file.
+//!
+//! Every field has a default, so a missing file or a partial file is fine.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::{Color, Style};
+use serde::{de, Deserialize, Deserializer};
+use unicode_width::UnicodeWidthChar;
+
+use crate::game::dictionary::Tier;
+use crate::game::LetterStatus;
+use crate::guesses::GuessSource;
+use crate::paths;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Game language code (`en`, `pt`, `es`, `fr`), for both the interface
+    /// and the word list. Defaults to the system locale.
+    pub language: Option<String>,
+    /// Languages the polyglot mode takes turns with, in that order
+    /// (`["en", "pt", "es"]`, the default when empty).
+    pub polyglot: Vec<String>,
+    /// Keeps the words of the filter list out of the game (see
+    /// [`crate::filter`]). It can also be changed in the settings.
+    pub word_filter: WordFilterMode,
+    /// Tier of the words drawn for new games. It can also be changed on
+    /// the menu, from the new game entry.
+    pub difficulty: Difficulty,
+    /// When quitting asks first. It can also be changed in the settings.
+    pub quit_confirm: QuitConfirm,
+    /// Themed word list new games are drawn from instead of the whole
+    /// list (`animals`; see [`crate::word_themes`]). It can also be changed
+    /// in the settings.
+    pub word_theme: Option<String>,
+    /// Lists a guess is looked up in, in order, by language code (see
+    /// [`crate::guesses`]). A language without any takes every guess.
+    pub guesses: HashMap<String, Vec<GuessSource>>,
+    pub display: DisplayConfig,
+    pub solver: SolverConfig,
+    pub community: CommunityConfig,
+    pub packs: PacksConfig,
+    pub sound: SoundConfig,
+    pub reminder: ReminderConfig,
+    pub definitions: DefinitionsConfig,
+    pub online: OnlineConfig,
+    pub share: ShareConfig,
+    pub lock: LockConfig,
+    pub keymap: KeymapConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    /// Wrap every frame in synchronized-output sequences (BSU/ESU) so the
+    /// terminal paints it at once. `auto` asks the terminal if it supports it.
+    pub synchronized_output: Toggle,
+    /// Minimum time between two frames, in milliseconds. Changes arriving
+    /// faster than this are coalesced into a single draw.
+    pub frame_interval_ms: u64,
+    /// Keyboard below the grid (`vertical`) or beside it (`horizontal`).
+    /// `auto` puts it beside the grid when the terminal is too short for
+    /// the vertical layout but wide enough for the other.
+    pub board_layout: BoardLayout,
+    /// Keyboard-only audit mode: mouse input is not captured at all, and
+    /// the footer shows where keyboard input goes.
+    pub keyboard_audit: bool,
+    /// Color theme; F2 cycles through them while playing. `auto` follows
+    /// `theme_schedule`.
+    pub theme: ThemeName,
+    /// Which theme `auto` shows at what time of day.
+    pub theme_schedule: ThemeSchedule,
+    /// Colorblind-friendly colors (orange and blue) with more contrast,
+    /// whatever the theme. F3 toggles it while playing.
+    pub high_contrast: bool,
+    /// Skip the board animations (shaking rows, highlighted letters) and
+    /// the metronome of the timed modes. Unset, it follows the environment
+    /// (see [`DisplayConfig::reduced_motion`]). It can also be changed in
+    /// the settings.
+    pub reduced_motion: Option<bool>,
+    /// Arrangement of the virtual keyboard, to match the physical one.
+    pub keyboard_layout: KeyboardLayout,
+    /// Color and symbol of each letter status, over the theme.
+    pub status: StatusStyles,
+    /// Mark every tile and key with its status, whatever the colors: the
+    /// symbol of `status`, or `*`, `~` and `-` by default. It can also be
+    /// changed in the settings.
+    pub status_markers: bool,
+    /// Show the result card as an image, with Sixel graphics. `auto` asks
+    /// the terminal if it supports them; otherwise the card is text.
+    pub sixel: Toggle,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        DisplayConfig {
+            synchronized_output: Toggle::Auto,
+            frame_interval_ms: 16,
+            board_layout: BoardLayout::Auto,
+            keyboard_audit: false,
+            theme: ThemeName::Classic,
+            theme_schedule: ThemeSchedule::default(),
+            high_contrast: false,
+            reduced_motion: None,
+            keyboard_layout: KeyboardLayout::Qwerty,
+            status: StatusStyles::default(),
+            status_markers: false,
+            sixel: Toggle::Auto,
+        }
+    }
+}
+
+/// How each letter status is shown, on top of the theme (`[display.status]`).
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct StatusStyles {
+    pub correct: StatusStyle,
+    pub present: StatusStyle,
+    pub absent: StatusStyle,
+}
+
+impl StatusStyles {
+    /// The style of `status`; letters not tried yet have none.
+    pub fn get(&self, status: LetterStatus) -> Option<&StatusStyle> {
+        match status {
+            LetterStatus::Correct => Some(&self.correct),
+            LetterStatus::Present => Some(&self.present),
+            LetterStatus::Absent => Some(&self.absent),
+            LetterStatus::Unused => None,
+        }
+    }
+
+    /// These styles with a symbol for every status: the one set, or the
+    /// default marker of the status.
+    pub fn marked(&self) -> StatusStyles {
+        let mark = |style: &StatusStyle, marker: char| StatusStyle {
+            symbol: style.symbol.or(Some(marker)),
+            ..style.clone()
+        };
+        StatusStyles {
+            correct: mark(&self.correct, '*'),
+            present: mark(&self.present, '~'),
+            absent: mark(&self.absent, '-'),
+        }
+    }
+}
+
+/// How one letter status is shown, so it can be told apart whatever
+/// colors the player sees.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct StatusStyle {
+    /// Background of tiles and keys with this status, instead of the
+    /// theme's: a color name (`blue`, `lightred`), a palette index (`208`)
+    /// or `#rrggbb`.
+    #[serde(deserialize_with = "color")]
+    pub color: Option<Color>,
+    /// Mark drawn inside tiles and keys with this status, next to the
+    /// letter; it must take a single column.
+    #[serde(deserialize_with = "symbol")]
+    pub symbol: Option<char>,
+    /// Which side of the letter the symbol goes.
+    pub placement: Placement,
+}
+
+impl StatusStyle {
+    /// `style` with this status's color as background, if one is set.
+    pub fn patch(&self, style: Style) -> Style {
+        match self.color {
+            Some(color) => style.bg(color),
+            None => style,
+        }
+    }
+}
+
+/// Side of the letter a status symbol goes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Placement {
+    Before,
+    #[default]
+    After,
+}
+
+fn color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Color>, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    name.parse()
+        .map(Some)
+        .map_err(|_| de::Error::custom(format!("unknown color '{}'", name)))
+}
+
+fn symbol<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<char>, D::Error> {
+    let symbol = char::deserialize(deserializer)?;
+    if symbol.width() != Some(1) {
+        return Err(de::Error::custom(format!(
+            "symbol '{}' must take a single column",
+            symbol
+        )));
+    }
+    Ok(Some(symbol))
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardLayout {
+    #[default]
+    Auto,
+    Vertical,
+    Horizontal,
+}
+
+/// What the word filter applies to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WordFilterMode {
+    /// Any word can come up.
+    #[default]
+    Off,
+    /// Filtered words are never drawn as the word to find.
+    Answers,
+    /// Nor accepted as guesses.
+    Guesses,
+}
+
+impl WordFilterMode {
+    /// Name as written in the configuration file.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WordFilterMode::Off => "off",
+            WordFilterMode::Answers => "answers",
+            WordFilterMode::Guesses => "guesses",
+        }
+    }
+
+    /// The mode after this one, wrapping around.
+    pub fn next(self) -> Self {
+        match self {
+            WordFilterMode::Off => WordFilterMode::Answers,
+            WordFilterMode::Answers => WordFilterMode::Guesses,
+            WordFilterMode::Guesses => WordFilterMode::Off,
+        }
+    }
+}
+
+/// When quitting asks for a confirmation first.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuitConfirm {
+    /// Quit at once.
+    Never,
+    /// Only with a game in progress, which would be lost.
+    #[default]
+    Playing,
+    Always,
+}
+
+impl QuitConfirm {
+    /// Name as written in the configuration file.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            QuitConfirm::Never => "never",
+            QuitConfirm::Playing => "playing",
+            QuitConfirm::Always => "always",
+        }
+    }
+
+    /// The policy after this one, wrapping around.
+    pub fn next(self) -> Self {
+        match self {
+            QuitConfirm::Never => QuitConfirm::Playing,
+            QuitConfirm::Playing => QuitConfirm::Always,
+            QuitConfirm::Always => QuitConfirm::Never,
+        }
+    }
+
+    /// Whether quitting asks first, with a game in progress or not.
+    pub fn asks(self, in_progress: bool) -> bool {
+        match self {
+            QuitConfirm::Never => false,
+            QuitConfirm::Playing => in_progress,
+            QuitConfirm::Always => true,
+        }
+    }
+}
+
+/// Difficulty of the words drawn for new games (see
+/// [`crate::game::dictionary`]).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    /// Any word can come up.
+    #[default]
+    Any,
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    /// The tier words are drawn from, if only one.
+    pub fn tier(self) -> Option<Tier> {
+        match self {
+            Difficulty::Any => None,
+            Difficulty::Easy => Some(Tier::Easy),
+            Difficulty::Medium => Some(Tier::Medium),
+            Difficulty::Hard => Some(Tier::Hard),
+        }
+    }
+}
+
+/// Key arrangements of the virtual keyboard.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    /// French.
+    Azerty,
+    /// German and Central European.
+    Qwertz,
+    /// Brazilian, with Ç at the end of the middle row.
+    Abnt2,
+}
+
+impl KeyboardLayout {
+    /// Letter keys, row by row.
+    pub fn rows(self) -> &'static [&'static str] {
+        match self {
+            KeyboardLayout::Qwerty => &["QWERTYUIOP", "ASDFGHJKL", "ZXCVBNM"],
+            KeyboardLayout::Azerty => &["AZERTYUIOP", "QSDFGHJKLM", "WXCVBN"],
+            KeyboardLayout::Qwertz => &["QWERTZUIOP", "ASDFGHJKL", "YXCVBNM"],
+            KeyboardLayout::Abnt2 => &["QWERTYUIOP", "ASDFGHJKLÇ", "ZXCVBNM"],
+        }
+    }
+
+    /// Name as written in the configuration file.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            KeyboardLayout::Qwerty => "qwerty",
+            KeyboardLayout::Azerty => "azerty",
+            KeyboardLayout::Qwertz => "qwertz",
+            KeyboardLayout::Abnt2 => "abnt2",
+        }
+    }
+
+    /// The layout after this one, wrapping around.
+    pub fn next(self) -> Self {
+        match self {
+            KeyboardLayout::Qwerty => KeyboardLayout::Azerty,
+            KeyboardLayout::Azerty => KeyboardLayout::Qwertz,
+            KeyboardLayout::Qwertz => KeyboardLayout::Abnt2,
+            KeyboardLayout::Abnt2 => KeyboardLayout::Qwerty,
+        }
+    }
+}
+
+/// Built-in color themes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeName {
+    /// The terminal's own background with the basic palette.
+    #[default]
+    Classic,
+    Dark,
+    Light,
+    Solarized,
+    /// Warm paper tones, easy on the eyes in the evening.
+    Sepia,
+    /// The theme of the [`ThemeSchedule`] for the time of day.
+    Auto,
+}
+
+impl ThemeName {
+    pub const ALL: [ThemeName; 6] = [
+        ThemeName::Classic,
+        ThemeName::Dark,
+        ThemeName::Light,
+        ThemeName::Solarized,
+        ThemeName::Sepia,
+        ThemeName::Auto,
+    ];
+
+    /// Name as written in the configuration file.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ThemeName::Classic => "classic",
+            ThemeName::Dark => "dark",
+            ThemeName::Light => "light",
+            ThemeName::Solarized => "solarized",
+            ThemeName::Sepia => "sepia",
+            ThemeName::Auto => "auto",
+        }
+    }
+
+    /// The theme after this one, wrapping around.
+    pub fn next(self) -> Self {
+        Self::ALL[(self as usize + 1) % Self::ALL.len()]
+    }
+}
+
+/// When the `auto` theme changes, and to what (`[display.theme_schedule]`):
+/// each entry is a time of day, `HH:MM`, and the theme from then on.
+///
+/// ```toml
+/// [display.theme_schedule]
+/// "07:00" = "light"
+/// "18:00" = "sepia"
+/// "21:30" = "dark"
+/// ```
+///
+/// Before the first time of the day, the last theme of the day before
+/// goes on. The default is the schedule above.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeSchedule {
+    // Minutes since midnight and the theme from then, in order
+    changes: Vec<(u16, ThemeName)>,
+}
+
+impl ThemeSchedule {
+    /// The theme at `minutes` past midnight.
+    pub fn theme_at(&self, minutes: u16) -> ThemeName {
+        self.changes
+            .iter()
+            .rev()
+            .find(|(from, _)| *from <= minutes)
+            .or(self.changes.last())
+            .map_or(ThemeName::Classic, |&(_, theme)| theme)
+    }
+}
+
+impl Default for ThemeSchedule {
+    fn default() -> Self {
+        ThemeSchedule {
+            changes: vec![
+                (7 * 60, ThemeName::Light),
+                (18 * 60, ThemeName::Sepia),
+                (21 * 60 + 30, ThemeName::Dark),
+            ],
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ThemeSchedule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = HashMap::<String, ThemeName>::deserialize(deserializer)?;
+        let mut changes = Vec::new();
+        for (time, theme) in entries {
+            let minutes = time
+                .split_once(':')
+                .and_then(|(hours, minutes)| Some((hours.parse().ok()?, minutes.parse().ok()?)))
+                .filter(|&(hours, minutes): &(u16, u16)| hours < 24 && minutes < 60)
+                .map(|(hours, minutes)| hours * 60 + minutes)
+                .ok_or_else(|| de::Error::custom(format!("'{}' is not a time (HH:MM)", time)))?;
+            if theme == ThemeName::Auto {
+                return Err(de::Error::custom("the theme schedule can't choose auto"));
+            }
+            changes.push((minutes, theme));
+        }
+        changes.sort_by_key(|&(minutes, _)| minutes);
+        Ok(ThemeSchedule { changes })
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct SolverConfig {
+    /// Precompute every guess/answer feedback pattern of the word list (and
+    /// cache them on disk next to it) for faster suggestions. Building them
+    /// the first time is slow in debug builds, so `auto` only does it in
+    /// release builds.
+    pub pattern_cache: Toggle,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct CommunityConfig {
+    /// Address of the community puzzle of the week (see
+    /// [`crate::community`]). Nothing is downloaded without one.
+    pub feed_url: Option<String>,
+}
+
+/// Reminder of the word of the day (`[reminder]`), for builds with the
+/// `reminder` feature. See [`crate::reminder`].
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct ReminderConfig {
+    /// Notify the desktop at launch while the word of the day is unplayed.
+    /// Off unless set.
+    pub notify: bool,
+}
+
+/// Sound cues (`[sound]`). See [`crate::sound`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SoundConfig {
+    /// Off unless set: no sound at all without it.
+    pub enabled: bool,
+    /// A guess refused.
+    pub invalid: bool,
+    /// A guess taken that didn't end the game.
+    pub guess: bool,
+    pub win: bool,
+    pub loss: bool,
+    /// Commands played instead of the bell, by event.
+    pub commands: SoundCommands,
+}
+
+impl Default for SoundConfig {
+    fn default() -> Self {
+        SoundConfig {
+            enabled: false,
+            invalid: true,
+            guess: false,
+            win: true,
+            loss: true,
+            commands: SoundCommands::default(),
+        }
+    }
+}
+
+/// A command to run for each event (`[sound.commands]`), the bell where
+/// there is none.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SoundCommands {
+    pub invalid: Option<String>,
+    pub guess: Option<String>,
+    pub win: Option<String>,
+    pub loss: Option<String>,
+}
+
+/// Themed word lists to install (`[packs]`), and which to trust. See
+/// [`crate::packs`].
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct PacksConfig {
+    /// Address of the index of packs. Nothing is downloaded without one.
+    pub index_url: Option<String>,
+    /// Public Ed25519 keys, in hex, whose signatures are trusted.
+    pub trusted_keys: Vec<String>,
+    /// What becomes of packs none of the trusted keys signed.
+    pub trust: TrustPolicy,
+}
+
+/// Whether packs need a trusted signature to be installed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrustPolicy {
+    /// Signatures aren't checked.
+    Off,
+    /// Installed, with a warning.
+    #[default]
+    Warn,
+    /// Refused.
+    Require,
+}
+
+/// Where definitions of the words played are looked up (`[definitions]`),
+/// past the `.definitions` file of the word list. See
+/// [`crate::definitions`].
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct DefinitionsConfig {
+    /// Address of a dictionary API, with `{language}` and `{word}` where
+    /// they go. Nothing is looked up online without one.
+    pub api_url: Option<String>,
+}
+
+/// Online leaderboard of the word of the day (`[online]`), for builds with
+/// the `online-leaderboard` feature. See [`crate::online`].
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct OnlineConfig {
+    /// Address results are sent to and the leaderboard read from.
+    pub leaderboard_url: Option<String>,
+    /// Send the result of each daily game. Off unless set: nothing leaves
+    /// the computer without it.
+    pub submit_daily: bool,
+    /// Name on the leaderboard; defaults to the user's login.
+    pub name: Option<String>,
+}
+
+/// The text of result cards (`[share]`), as copied with `C` on the card.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ShareConfig {
+    /// Symbols of the squares: `emoji`, `ascii` (`#`, `+` and `.`), or
+    /// `custom` for the ones below.
+    pub glyphs: ShareGlyphs,
+    /// Symbols of `custom` glyphs, per letter status; emoji where missing.
+    pub correct: Option<String>,
+    pub present: Option<String>,
+    pub absent: Option<String>,
+    /// The time the game took, after the result.
+    pub time: bool,
+    /// A `*` after the result when hints were taken.
+    pub markers: bool,
+}
+
+impl DisplayConfig {
+    /// Whether the animations are off: as `reduced_motion` says, or else
+    /// if `REDUCED_MOTION` or `NO_COLOR` is set, by someone who asked for
+    /// a quieter terminal.
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion.unwrap_or_else(|| {
+            ["REDUCED_MOTION", "NO_COLOR"]
+                .iter()
+                .any(|name| env::var_os(name).is_some_and(|value| !value.is_empty()))
+        })
+    }
+}
+
+impl Default for ShareConfig {
+    fn default() -> Self {
+        ShareConfig {
+            glyphs: ShareGlyphs::Emoji,
+            correct: None,
+            present: None,
+            absent: None,
+            time: false,
+            markers: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShareGlyphs {
+    #[default]
+    Emoji,
+    Ascii,
+    Custom,
+}
+
+/// Locking the screen of an unattended game (`[lock]`), for shared or
+/// public terminals.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct LockConfig {
+    /// Minutes without input after which the game in progress is saved
+    /// and the screen locked; 0, the default, never locks it.
+    pub idle_minutes: u64,
+    /// What has to be typed to unlock the screen. Without it any key
+    /// does. It is kept as written, so the file should be readable only
+    /// by its owner.
+    pub passphrase: Option<String>,
+}
+
+/// Remapping of typed letters (`[keymap]`), for keyboards whose keys say
+/// something else than the layout the system is set to. See
+/// [`crate::keymap`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct KeymapConfig {
+    /// Layout the system is set to.
+    pub system: Option<KeyboardLayout>,
+    /// Layout printed on the keys: each key types the letter printed on
+    /// it, whatever `system` makes it send. Defaults to `qwerty`.
+    pub printed: KeyboardLayout,
+    /// Letters typed as others, over the layouts: `{ "é" = "e" }`.
+    pub remap: HashMap<char, char>,
+    /// Shortcut that saves the game in progress and quits without asking:
+    /// `ctrl+` or `alt+` and a letter, a function key like `f12`, or
+    /// `none`. Defaults to `ctrl+q`.
+    #[serde(deserialize_with = "shortcut")]
+    pub quit: Option<KeyEvent>,
+}
+
+impl Default for KeymapConfig {
+    fn default() -> Self {
+        KeymapConfig {
+            system: None,
+            printed: KeyboardLayout::default(),
+            remap: HashMap::new(),
+            quit: Some(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL)),
+        }
+    }
+}
+
+fn shortcut<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<KeyEvent>, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    let name = text.trim().to_lowercase();
+    if name == "none" || name.is_empty() {
+        return Ok(None);
+    }
+    let (modifiers, key) = match name.split_once('+') {
+        Some(("ctrl", key)) => (KeyModifiers::CONTROL, key),
+        Some(("alt", key)) => (KeyModifiers::ALT, key),
+        Some(_) => return Err(de::Error::custom(format!("unknown shortcut '{}'", text))),
+        None => (KeyModifiers::NONE, name.as_str()),
+    };
+    let mut chars = key.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_alphanumeric() && modifiers != KeyModifiers::NONE => {
+            KeyCode::Char(c)
+        }
+        (Some('f'), Some(_)) => match key[1..].parse() {
+            Ok(number @ 1..=12) => KeyCode::F(number),
+            _ => return Err(de::Error::custom(format!("unknown shortcut '{}'", text))),
+        },
+        _ => return Err(de::Error::custom(format!("unknown shortcut '{}'", text))),
+    };
+    Ok(Some(KeyEvent::new(code, modifiers)))
+}
+
+/// Setting that can be forced on or off, or detected at startup.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Toggle {
+    #[default]
+    Auto,
+    On,
+    Off,
+}
+
+impl Config {
+    /// Loads the configuration file, falling back to defaults when it does
+    /// not exist. A file that exists but can't be parsed is an error.
+    pub fn load() -> anyhow::Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Config::default());
+        };
+
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => {
+                return Err(e).with_context(|| format!("reading {}", path.display()));
+            }
+        };
+
+        toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    /// Location of the configuration file, if a home directory is known.
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os("WORDLE_CONFIG") {
+            return Some(PathBuf::from(path));
+        }
+
+        Some(paths::config_dir()?.join("config.toml"))
+    }
+
+    /// Where the game keeps what it records between sessions (see
+    /// [`paths::data_dir`]).
+    pub fn data_dir() -> Option<PathBuf> {
+        paths::data_dir()
+    }
+}
@@ -0,0 +1,167 @@
+//! Definitions of the words played, shown once a game ends.
+//!
+//! They come from a dictionary shipped next to the word list as a
+//! `.definitions` file, a word and its definition a line, separated by a
+//! tab (`AVIÃO`, tab, `Veículo que voa com asas fixas.`).
+//!
+//! Words missing from it are looked up with the dictionary API of
+//! `[definitions] api_url`, if there is one; its answers are kept in
+//! `definitions.json` in the data directory, so a word is only fetched once.
+//! The API is expected to answer like <https://dictionaryapi.dev>.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::config::Config;
+use crate::game::normalize::fold_word;
+use crate::http;
+
+/// Gives up on the API after this long.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Meanings of an API answer kept, each with its first definition.
+const MEANINGS: usize = 3;
+
+/// The offline dictionary of a word list.
+#[derive(Debug, Default, Clone)]
+pub struct Definitions {
+    // By word, accents folded
+    words: HashMap<String, String>,
+}
+
+impl Definitions {
+    /// Reads a dictionary, skipping lines without a tab.
+    pub fn parse(text: &str) -> Definitions {
+        let words = text
+            .lines()
+            .filter_map(|line| line.split_once('\t'))
+            .map(|(word, definition)| (fold_word(word.trim()), definition.trim().to_string()))
+            .filter(|(word, definition)| !word.is_empty() && !definition.is_empty())
+            .collect();
+        Definitions { words }
+    }
+
+    pub fn load(path: &Path) -> io::Result<Definitions> {
+        Ok(Self::parse(&fs::read_to_string(path)?))
+    }
+
+    /// Where the dictionary of the word list at `words_file` is kept.
+    pub fn path_for(words_file: &Path) -> PathBuf {
+        words_file.with_extension("definitions")
+    }
+
+    /// The definition of `word`, accents or not.
+    pub fn get(&self, word: &str) -> Option<&str> {
+        self.words.get(&fold_word(word)).map(String::as_str)
+    }
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    #[serde(default)]
+    meanings: Vec<Meaning>,
+}
+
+#[derive(Deserialize)]
+struct Meaning {
+    #[serde(rename = "partOfSpeech", default)]
+    part_of_speech: String,
+    #[serde(default)]
+    definitions: Vec<Sense>,
+}
+
+#[derive(Deserialize)]
+struct Sense {
+    definition: String,
+}
+
+/// The definition in an API answer: the first sense of the first few
+/// meanings, a line each, or `None` if it has none.
+pub fn parse_answer(json: &str) -> Option<String> {
+    let entries: Vec<Entry> = serde_json::from_str(json).ok()?;
+    let lines: Vec<String> = entries
+        .iter()
+        .flat_map(|entry| &entry.meanings)
+        .filter_map(|meaning| {
+            let sense = meaning.definitions.first()?;
+            Some(if meaning.part_of_speech.is_empty() {
+                sense.definition.clone()
+            } else {
+                format!("({}) {}", meaning.part_of_speech, sense.definition)
+            })
+        })
+        .take(MEANINGS)
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// The API address for `word`, filling in `{language}` and `{word}` in
+/// `template`.
+pub fn api_url(template: &str, language: &str, word: &str) -> String {
+    template
+        .replace("{language}", language)
+        .replace("{word}", &word.to_lowercase())
+}
+
+/// Asks the API at `url` on another thread, so the end of the game
+/// doesn't wait for the network. Sends `None` if it can't be had.
+pub fn fetch_in_background(url: String) -> Receiver<Option<String>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let answer = http::get(&url, FETCH_TIMEOUT);
+        let _ = sender.send(answer.ok().as_deref().and_then(parse_answer));
+    });
+    receiver
+}
+
+/// Definitions fetched from the API before.
+#[derive(Debug, Default, Clone)]
+pub struct Cache {
+    // By language and word, accents folded: `pt:AVIAO`
+    entries: BTreeMap<String, String>,
+}
+
+impl Cache {
+    /// Reads the cache, starting afresh if there is none.
+    pub fn load() -> Cache {
+        let entries = Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+        Cache { entries }
+    }
+
+    pub fn get(&self, language: &str, word: &str) -> Option<&str> {
+        self.entries
+            .get(&Self::key(language, word))
+            .map(String::as_str)
+    }
+
+    /// Keeps the definition of `word` and saves the cache.
+    pub fn insert(&mut self, language: &str, word: &str, definition: String) -> io::Result<()> {
+        self.entries.insert(Self::key(language, word), definition);
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string_pretty(&self.entries).map_err(io::Error::other)?;
+        fs::write(path, text + "\n")
+    }
+
+    fn key(language: &str, word: &str) -> String {
+        format!("{}:{}", language, fold_word(word))
+    }
+
+    fn path() -> Option<PathBuf> {
+        Some(Config::data_dir()?.join("definitions.json"))
+    }
+}
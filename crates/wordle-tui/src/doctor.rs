@@ -0,0 +1,268 @@
+//! `wordle doctor`: what the terminal can do, as far as the game cares, and
+//! the settings that suit it, to sort out how the game looks on unusual
+//! terminals.
+//!
+//! What can be asked is asked (see [`crate::term`]); the rest comes from the
+//! environment. [`Report::probe`] does the asking, while [`Report::render`]
+//! only writes what was found, so a report can be made up for tests.
+
+use std::env;
+use std::fmt::Write;
+use std::io::{self, IsTerminal};
+
+use crossterm::terminal;
+use ratatui::layout::Rect;
+
+use crate::config::{Config, ThemeName, Toggle};
+use crate::term::{self, SGR_MOUSE, SYNCHRONIZED_OUTPUT};
+use crate::ui::{
+    Fit, MIN_HEIGHT, MIN_MINIMAL_HEIGHT, MIN_MINIMAL_WIDTH, MIN_WIDE_HEIGHT, MIN_WIDE_WIDTH,
+    MIN_WIDTH,
+};
+
+/// Test patterns for the width of characters the game draws, with the
+/// columns each should take.
+pub const PATTERNS: [(&str, &str, u16); 5] = [
+    ("Box drawing", "╭─╮│╰╯", 6),
+    ("Arrows and blocks", "▸◂←→█", 5),
+    ("Marks", "✓✗★☆•", 5),
+    ("Accented letters", "ÁÇÑÜ", 4),
+    ("Emoji", "🟩🟨⬛", 6),
+];
+
+/// Colors the terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    Monochrome,
+    /// The 16 colors of the basic palette.
+    Basic,
+    /// 256 indexed colors.
+    Indexed,
+    /// Any RGB color.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// The depth `TERM` and `COLORTERM` tell of.
+    pub fn from_env(term: Option<&str>, colorterm: Option<&str>) -> ColorDepth {
+        let term = term.unwrap_or_default();
+        match colorterm {
+            Some("truecolor" | "24bit") => ColorDepth::TrueColor,
+            _ if term.contains("256color") => ColorDepth::Indexed,
+            _ if term == "dumb" || term.is_empty() => ColorDepth::Monochrome,
+            _ => ColorDepth::Basic,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ColorDepth::Monochrome => "none",
+            ColorDepth::Basic => "16 colors",
+            ColorDepth::Indexed => "256 colors",
+            ColorDepth::TrueColor => "true color (24-bit)",
+        }
+    }
+}
+
+/// What was found about the terminal. `None` stands for a question the
+/// terminal didn't answer.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    /// Columns and rows.
+    pub size: Option<(u16, u16)>,
+    /// `TERM`.
+    pub term: Option<String>,
+    pub colors: Option<ColorDepth>,
+    /// `NO_COLOR` is set.
+    pub no_color: bool,
+    /// Columns each of [`PATTERNS`] took.
+    pub widths: Vec<Option<u16>>,
+    /// Mouse reports in the SGR encoding.
+    pub mouse: Option<bool>,
+    /// The kitty keyboard protocol, which tells apart keys like Ctrl+Tab.
+    pub keyboard_protocol: Option<bool>,
+    pub synchronized_output: Option<bool>,
+    pub sixel: Option<bool>,
+}
+
+impl Report {
+    /// Asks the terminal, briefly in raw mode. Only the environment is
+    /// read when the output isn't a terminal.
+    pub fn probe() -> Report {
+        let term = env::var("TERM").ok();
+        let colorterm = env::var("COLORTERM").ok();
+        let mut report = Report {
+            colors: Some(ColorDepth::from_env(term.as_deref(), colorterm.as_deref())),
+            term,
+            no_color: env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()),
+            widths: vec![None; PATTERNS.len()],
+            ..Report::default()
+        };
+        if !io::stdout().is_terminal() || terminal::enable_raw_mode().is_err() {
+            return report;
+        }
+        report.size = terminal::size().ok();
+        // Crossterm reads the answer to this one itself, so it goes before
+        // the queries reading the terminal on their own
+        report.keyboard_protocol = terminal::supports_keyboard_enhancement().ok();
+        report.mouse = term::query_mode(SGR_MOUSE);
+        report.synchronized_output = term::query_mode(SYNCHRONIZED_OUTPUT);
+        report.sixel = Some(term::sixel_enabled(Toggle::Auto));
+        report.widths = PATTERNS
+            .iter()
+            .map(|(_, pattern, _)| term::measure_width(pattern))
+            .collect();
+        let _ = terminal::disable_raw_mode();
+        report
+    }
+
+    /// Settings that would suit the terminal better than those of
+    /// `config`, each with the reason.
+    pub fn suggestions(&self, config: &Config) -> Vec<String> {
+        let display = &config.display;
+        let mut suggestions = Vec::new();
+        if let Some((width, height)) = self.size {
+            // The layout is picked from the size, so only a bigger terminal
+            // helps
+            match Fit::for_size(Rect::new(0, 0, width, height), display.board_layout) {
+                None => suggestions.push(format!(
+                    "The terminal is too small to play: make it at least {}x{}",
+                    MIN_MINIMAL_WIDTH, MIN_MINIMAL_HEIGHT
+                )),
+                Some(Fit::Compact | Fit::Minimal) => suggestions.push(format!(
+                    "The keyboard is hidden: make the terminal at least {}x{}, or {}x{}",
+                    MIN_WIDTH, MIN_HEIGHT, MIN_WIDE_WIDTH, MIN_WIDE_HEIGHT
+                )),
+                Some(Fit::Full(_)) => {}
+            }
+        }
+        let colors = self.colors.unwrap_or(ColorDepth::TrueColor);
+        if colors < ColorDepth::TrueColor && display.theme != ThemeName::Classic {
+            suggestions.push(
+                "The theme uses RGB colors the terminal may not show: set theme = \"classic\" in [display]"
+                    .to_string(),
+            );
+        }
+        if (colors == ColorDepth::Monochrome || self.no_color) && !display.high_contrast {
+            suggestions.push(
+                "Without colors, set high_contrast = true and symbols in [display.status] to tell letters apart"
+                    .to_string(),
+            );
+        }
+        let wrong = |index: usize| {
+            self.widths
+                .get(index)
+                .copied()
+                .flatten()
+                .is_some_and(|width| width != PATTERNS[index].2)
+        };
+        if (0..3).any(wrong) {
+            suggestions.push(
+                "Some symbols are drawn two columns wide: set the terminal's ambiguous-width characters to narrow"
+                    .to_string(),
+            );
+        }
+        if wrong(3) {
+            suggestions.push(
+                "Accented letters take the wrong width: try another font in the terminal"
+                    .to_string(),
+            );
+        }
+        if wrong(4) {
+            suggestions.push(
+                "Emoji take the wrong width: set glyphs = \"ascii\" in [share] for the shared results"
+                    .to_string(),
+            );
+        }
+        if self.mouse == Some(false) && !display.keyboard_audit {
+            suggestions.push(
+                "No mouse reports: everything works from the keyboard, and keyboard_audit = true in [display] stops asking for them"
+                    .to_string(),
+            );
+        }
+        if self.keyboard_protocol == Some(false) {
+            suggestions.push(
+                "Ctrl+Tab may come through as Tab: use Ctrl+PageDown and Ctrl+PageUp to switch tabs".to_string(),
+            );
+        }
+        if self.synchronized_output == Some(false) && display.synchronized_output == Toggle::On {
+            suggestions.push(
+                "No synchronized output: set synchronized_output = \"auto\" in [display]"
+                    .to_string(),
+            );
+        }
+        if self.sixel == Some(false) && display.sixel == Toggle::On {
+            suggestions.push(
+                "No Sixel graphics: set sixel = \"auto\" in [display] to get the card as text"
+                    .to_string(),
+            );
+        }
+        suggestions
+    }
+
+    /// The report as printed, with the suggestions for `config` last.
+    pub fn render(&self, config: &Config) -> String {
+        let unknown = || "unknown".to_string();
+        let yes_no = |answer: Option<bool>| match answer {
+            Some(true) => "yes".to_string(),
+            Some(false) => "no".to_string(),
+            None => "no answer".to_string(),
+        };
+        let mut text = String::from("Terminal report\n\n");
+        let mut line = |name: &str, value: String| {
+            let _ = writeln!(text, "{:<22}{}", format!("{}:", name), value);
+        };
+        line(
+            "Size",
+            self.size
+                .map_or_else(unknown, |(width, height)| format!("{}x{}", width, height)),
+        );
+        line("TERM", self.term.clone().unwrap_or_else(unknown));
+        let colors = self
+            .colors
+            .map_or_else(unknown, |colors| colors.name().to_string());
+        if self.no_color {
+            line("Colors", format!("{} (NO_COLOR is set)", colors));
+        } else {
+            line("Colors", colors);
+        }
+        let motion = match (
+            config.display.reduced_motion(),
+            config.display.reduced_motion,
+        ) {
+            (false, _) => "on",
+            (true, Some(_)) => "reduced",
+            (true, None) => "reduced (asked by the environment)",
+        };
+        line("Animations", motion.to_string());
+        line("Mouse", yes_no(self.mouse));
+        line("Keyboard protocol", yes_no(self.keyboard_protocol));
+        line("Synchronized output", yes_no(self.synchronized_output));
+        line("Sixel graphics", yes_no(self.sixel));
+
+        text.push_str("\nWidth of the symbols, in columns:\n");
+        for (index, (name, pattern, expected)) in PATTERNS.iter().enumerate() {
+            let measured = self.widths.get(index).copied().flatten();
+            let verdict = match measured {
+                Some(width) if width == *expected => format!("{} (ok)", width),
+                Some(width) => format!("{} (expected {})", width, expected),
+                None => "no answer".to_string(),
+            };
+            // The bar lines up with the others when the pattern takes the
+            // columns it should, for terminals that didn't answer
+            let padding = " ".repeat(8 - *expected as usize);
+            let _ = writeln!(text, "  {:<20}{}{}|  {}", name, pattern, padding, verdict);
+        }
+
+        let suggestions = self.suggestions(config);
+        if suggestions.is_empty() {
+            text.push_str("\nNothing to change: the game should look right here.\n");
+        } else {
+            text.push_str("\nSuggestions:\n");
+            for suggestion in suggestions {
+                let _ = writeln!(text, "  - {}", suggestion);
+            }
+        }
+        text
+    }
+}
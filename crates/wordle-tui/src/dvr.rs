@@ -0,0 +1,55 @@
+//! The DVR of the game being played: the board after each of the last
+//! things done to it (letters typed or deleted, guesses, undos), kept in
+//! memory so `Ctrl+R` can scrub back through them on a read-only screen,
+//! for explaining a line of thought while streaming.
+
+use std::collections::VecDeque;
+
+use crate::game::{Game, Snapshot};
+
+/// How many boards are kept; the oldest goes when another comes.
+pub const DVR_FRAMES: usize = 64;
+
+/// The boards kept, oldest first.
+#[derive(Debug, Default)]
+pub struct Dvr {
+    // Answer of the game the boards are of
+    word: String,
+    frames: VecDeque<Snapshot>,
+}
+
+impl Dvr {
+    /// Keeps the board of `game` if it changed since the last one. The
+    /// boards of a game on another word are dropped first.
+    pub fn record(&mut self, game: &Game) {
+        if game.target_word != self.word {
+            self.word = game.target_word.clone();
+            self.frames.clear();
+        }
+        let snapshot = game.snapshot();
+        if self.frames.back() == Some(&snapshot) {
+            return;
+        }
+        if self.frames.len() == DVR_FRAMES {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(snapshot);
+    }
+
+    /// How many boards are kept.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The board `back` changes before the latest one.
+    pub fn frame(&self, back: usize) -> Option<&Snapshot> {
+        self.frames
+            .len()
+            .checked_sub(back + 1)
+            .and_then(|index| self.frames.get(index))
+    }
+}
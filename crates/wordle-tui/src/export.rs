@@ -0,0 +1,291 @@
+//! `wordle stats export`: every game of the history, and figures about
+//! them, as one JSON document for spreadsheets, notebooks and scripts.
+//!
+//! The schema is stable: fields are only ever added, and anything else
+//! bumps `version`.
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "exported_at": 1760000000,
+//!   "games": [
+//!     {
+//!       "id": 12, "started_at": 1759990000, "finished_at": 1759990081,
+//!       "source": "daily", "language": "en", "word": "CRANE",
+//!       "seed": 20370, "guesses": ["SLATE", "CRANE"], "won": true,
+//!       "seconds": 81
+//!     }
+//!   ],
+//!   "totals": {
+//!     "started": 1, "finished": 1, "wins": 1, "win_rate": 100.0,
+//!     "average_guesses": 2.0, "current_streak": 1, "best_streak": 1,
+//!     "guess_distribution": { "2": 1 }
+//!   },
+//!   "by_source": { "daily": { ... } },
+//!   "by_language": { "en": { ... } }
+//! }
+//! ```
+//!
+//! With `--commentary`, each finished game also has a `commentary`, a few
+//! sentences telling how it went in the language it was played in (see
+//! [`crate::commentary`]).
+//!
+//! Games that were never finished have `null` for `finished_at`, `won`
+//! and `seconds`, and no guesses. Streaks count finished games in the
+//! order they ended.
+//!
+//! For spreadsheets there is also CSV, a row per game and no totals:
+//!
+//! ```text
+//! date,source,language,word,guesses,result,seconds,words
+//! 2025-10-09,daily,en,CRANE,2,won,81,SLATE CRANE
+//! 2025-10-09,random,en,PIANO,,unfinished,,
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::commentary;
+use crate::game::normalize::fold_word;
+use crate::history::{self, Entry, Finish, Source};
+use crate::i18n::Language;
+
+/// Version of the schema, bumped on any change but a new field.
+pub const VERSION: u32 = 1;
+/// First line of the CSV export.
+pub const CSV_HEADER: &str = "date,source,language,word,guesses,result,seconds,words";
+
+/// The whole export.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Export {
+    pub version: u32,
+    /// Seconds since the Unix epoch.
+    pub exported_at: u64,
+    /// Every game, in the order they started.
+    pub games: Vec<ExportedGame>,
+    pub totals: Totals,
+    pub by_source: BTreeMap<String, Totals>,
+    pub by_language: BTreeMap<String, Totals>,
+}
+
+/// A game of the history, its start and its end together.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExportedGame {
+    pub id: u64,
+    pub started_at: u64,
+    pub finished_at: Option<u64>,
+    pub source: Source,
+    pub language: String,
+    /// The answer, accents folded.
+    pub word: String,
+    pub seed: Option<u64>,
+    pub guesses: Vec<String>,
+    pub won: Option<bool>,
+    pub seconds: Option<u64>,
+    /// How the game went, when asked for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commentary: Option<String>,
+}
+
+/// Figures about a set of games.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Totals {
+    pub started: usize,
+    pub finished: usize,
+    pub wins: usize,
+    /// Share of the finished games won, in percent.
+    pub win_rate: Option<f64>,
+    /// Mean number of guesses of the games won.
+    pub average_guesses: Option<f64>,
+    /// Games won in a row, up to the last one finished.
+    pub current_streak: usize,
+    pub best_streak: usize,
+    /// Games won, by the number of guesses they took.
+    pub guess_distribution: BTreeMap<usize, usize>,
+}
+
+impl Totals {
+    fn of<'a>(games: impl IntoIterator<Item = &'a ExportedGame>) -> Totals {
+        let games: Vec<&ExportedGame> = games.into_iter().collect();
+        let mut finished: Vec<&ExportedGame> = games
+            .iter()
+            .copied()
+            .filter(|game| game.won.is_some())
+            .collect();
+        finished.sort_by_key(|game| (game.finished_at, game.id));
+
+        let mut totals = Totals {
+            started: games.len(),
+            finished: finished.len(),
+            ..Totals::default()
+        };
+        let mut streak = 0;
+        for game in &finished {
+            if game.won == Some(true) {
+                totals.wins += 1;
+                *totals
+                    .guess_distribution
+                    .entry(game.guesses.len())
+                    .or_default() += 1;
+                streak += 1;
+                totals.best_streak = totals.best_streak.max(streak);
+            } else {
+                streak = 0;
+            }
+        }
+        totals.current_streak = streak;
+        if totals.finished > 0 {
+            totals.win_rate = Some(totals.wins as f64 * 100.0 / totals.finished as f64);
+        }
+        if totals.wins > 0 {
+            let guesses: usize = totals
+                .guess_distribution
+                .iter()
+                .map(|(guesses, games)| guesses * games)
+                .sum();
+            totals.average_guesses = Some(guesses as f64 / totals.wins as f64);
+        }
+        totals
+    }
+}
+
+impl Export {
+    /// The export of the history `entries`, made at `at`.
+    pub fn new(entries: &[Entry], at: u64) -> Export {
+        let finishes: BTreeMap<u64, &Finish> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Finish(finish) => Some((finish.id, finish)),
+                Entry::Start(_) => None,
+            })
+            .collect();
+        let games: Vec<ExportedGame> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Start(start) => Some(start),
+                Entry::Finish(_) => None,
+            })
+            .map(|start| {
+                let finish = finishes.get(&start.id);
+                ExportedGame {
+                    id: start.id,
+                    started_at: start.at,
+                    finished_at: finish.map(|finish| finish.at),
+                    source: start.source,
+                    language: start.language.clone(),
+                    word: start.word.clone(),
+                    seed: start.seed,
+                    guesses: finish.map_or_else(Vec::new, |finish| finish.guesses.clone()),
+                    won: finish.map(|finish| finish.won),
+                    seconds: finish.map(|finish| finish.seconds),
+                    commentary: None,
+                }
+            })
+            .collect();
+
+        let mut by_source: BTreeMap<String, Vec<&ExportedGame>> = BTreeMap::new();
+        let mut by_language: BTreeMap<String, Vec<&ExportedGame>> = BTreeMap::new();
+        for game in &games {
+            by_source
+                .entry(source_name(game.source))
+                .or_default()
+                .push(game);
+            by_language
+                .entry(game.language.clone())
+                .or_default()
+                .push(game);
+        }
+        let totals_of = |groups: BTreeMap<String, Vec<&ExportedGame>>| {
+            groups
+                .into_iter()
+                .map(|(key, games)| (key, Totals::of(games)))
+                .collect()
+        };
+
+        Export {
+            version: VERSION,
+            exported_at: at,
+            totals: Totals::of(&games),
+            by_source: totals_of(by_source),
+            by_language: totals_of(by_language),
+            games,
+        }
+    }
+
+    /// Adds the commentary of each finished game, replayed over the word
+    /// list of its language. Warmups, and games in a language no longer
+    /// known, go without.
+    pub fn add_commentary(&mut self) {
+        let mut lists: BTreeMap<&str, Arc<[String]>> = BTreeMap::new();
+        for game in &mut self.games {
+            let Some(language) = Language::from_code(&game.language) else {
+                continue;
+            };
+            if game.won.is_none() || game.source == Source::Warmup {
+                continue;
+            }
+            let words = lists.entry(language.code()).or_insert_with(|| {
+                language
+                    .load_words()
+                    .iter()
+                    .map(|word| fold_word(word))
+                    .collect()
+            });
+            let report = commentary::replay(words, &game.word, &game.guesses);
+            game.commentary = Some(commentary::narrate(&report, &game.word, language.texts()));
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        // Nothing in an export can fail to serialize
+        serde_json::to_string_pretty(self).unwrap_or_default() + "\n"
+    }
+
+    /// The games as CSV, with [`CSV_HEADER`]: a row per game, dated by its
+    /// start. The guesses and time of unfinished games are left empty.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER) + "\n";
+        for game in &self.games {
+            let result = match game.won {
+                Some(true) => "won",
+                Some(false) => "lost",
+                None => "unfinished",
+            };
+            let row = [
+                history::date(game.started_at),
+                source_name(game.source),
+                game.language.clone(),
+                game.word.clone(),
+                game.won
+                    .map_or_else(String::new, |_| game.guesses.len().to_string()),
+                result.to_string(),
+                game.seconds
+                    .map_or_else(String::new, |seconds| seconds.to_string()),
+                game.guesses.join(" "),
+            ];
+            let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+            csv += &fields.join(",");
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+// How `source` is written in the history
+fn source_name(source: Source) -> String {
+    serde_json::to_value(source)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+// `field` quoted if it has to be, its quotes doubled
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,53 @@
+//! Words kept out of the game, for classrooms: with the filter on they are
+//! never drawn as the word to find, and can be refused as guesses too.
+//! Each language has its list (see [`Language::filter_file`]), a word per
+//! line, `#` starting a comment. It is looked for where the word lists are
+//! (see [`crate::paths`]), so a copy in the data directory can be edited;
+//! the built-in copy is used otherwise.
+
+use std::collections::HashSet;
+use std::fs;
+
+use crate::game::normalize::fold_word;
+use crate::i18n::Language;
+
+#[derive(Debug, Clone, Default)]
+pub struct WordFilter {
+    // Accents folded, uppercase
+    words: HashSet<String>,
+}
+
+impl WordFilter {
+    /// The list of `language`: the file if one is found and can be read,
+    /// otherwise the built-in copy.
+    pub fn load(language: Language) -> WordFilter {
+        let text = language
+            .filter_path()
+            .and_then(|path| fs::read_to_string(path).ok());
+        WordFilter::parse(text.as_deref().unwrap_or(language.embedded_filter()))
+    }
+
+    /// Reads a list: a word per line, blank lines and comments skipped.
+    pub fn parse(text: &str) -> WordFilter {
+        let words = text
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or("").trim())
+            .filter(|word| !word.is_empty())
+            .map(fold_word)
+            .collect();
+        WordFilter { words }
+    }
+
+    /// Whether `word`, accents or not, is on the list.
+    pub fn blocks(&self, word: &str) -> bool {
+        self.words.contains(&fold_word(word))
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
@@ -0,0 +1,143 @@
+//! Guess dictionaries: the lists a guess is looked up in before it is
+//! played, in the order `[guesses]` of the config gives for each language.
+//!
+//! ```toml
+//! [guesses]
+//! en = ["pack", "base", "system"]
+//! pt = ["base", "/home/ana/palavras.txt"]
+//! ```
+//!
+//! `pack` is the list of extra guesses of the language, `guesses.txt` (or
+//! `guesses-pt.txt`...) where the word lists are (see [`crate::paths`]);
+//! `base` is the word list of the language, whatever the theme played;
+//! `system` is the dictionary of the system, in `/usr/share/dict`. Anything
+//! else is the path of a list of one's own. Lists are a word per line, `#`
+//! starting a comment, and those that can't be read are skipped.
+//!
+//! A guess is played if any list of the chain has it, and the first one to
+//! have it is kept in the history with the game. A language without a
+//! chain takes any guess.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::game::normalize::fold_word;
+use crate::game::WORD_LENGTH;
+use crate::i18n::Language;
+use crate::paths;
+
+/// A list of the chain, as written in the config.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "String")]
+pub enum GuessSource {
+    /// The extra guesses of the language, among the word packs.
+    Pack,
+    /// The word list of the language.
+    Base,
+    /// The dictionary of the system.
+    System,
+    /// A list of one's own.
+    File(PathBuf),
+}
+
+impl From<String> for GuessSource {
+    fn from(name: String) -> GuessSource {
+        match name.as_str() {
+            "pack" => GuessSource::Pack,
+            "base" => GuessSource::Base,
+            "system" => GuessSource::System,
+            _ => GuessSource::File(PathBuf::from(name)),
+        }
+    }
+}
+
+impl GuessSource {
+    /// Name of the list, as written in the config and in the history.
+    pub fn label(&self) -> String {
+        match self {
+            GuessSource::Pack => "pack".to_string(),
+            GuessSource::Base => "base".to_string(),
+            GuessSource::System => "system".to_string(),
+            GuessSource::File(path) => path.display().to_string(),
+        }
+    }
+}
+
+/// The lists of a chain, loaded.
+#[derive(Debug, Clone, Default)]
+pub struct GuessChain {
+    // Accents folded, uppercase
+    lists: Vec<(GuessSource, HashSet<String>)>,
+}
+
+impl GuessChain {
+    /// Loads `sources` for `language`, with `base` as its word list.
+    pub fn load(sources: &[GuessSource], language: Language, base: &[String]) -> GuessChain {
+        let lists = sources
+            .iter()
+            .map(|source| {
+                let words = match source {
+                    GuessSource::Base => base.iter().map(|word| fold_word(word)).collect(),
+                    GuessSource::Pack => read(paths::find_asset(&pack_file(language))),
+                    GuessSource::System => read(system_dictionary(language)),
+                    GuessSource::File(path) => read(Some(path.clone())),
+                };
+                (source.clone(), words)
+            })
+            .collect();
+        GuessChain { lists }
+    }
+
+    /// Whether there is no chain, so any guess is played.
+    pub fn is_empty(&self) -> bool {
+        self.lists.is_empty()
+    }
+
+    /// The first list of the chain with `word`, accents or not; `None` if
+    /// none has it.
+    pub fn find(&self, word: &str) -> Option<&GuessSource> {
+        let word = fold_word(word);
+        self.lists
+            .iter()
+            .find(|(_, words)| words.contains(&word))
+            .map(|(source, _)| source)
+    }
+}
+
+/// Name of the list of extra guesses of `language`.
+pub fn pack_file(language: Language) -> String {
+    match language {
+        Language::En => "guesses.txt".to_string(),
+        language => format!("guesses-{}.txt", language.code()),
+    }
+}
+
+// The first dictionary of the system in `language` there is
+fn system_dictionary(language: Language) -> Option<PathBuf> {
+    let names: &[&str] = match language {
+        Language::En => &["words", "american-english", "british-english"],
+        Language::Pt => &["portuguese", "brazilian"],
+        Language::Es => &["spanish"],
+        Language::Fr => &["french"],
+    };
+    names
+        .iter()
+        .map(|name| PathBuf::from("/usr/share/dict").join(name))
+        .find(|path| path.is_file())
+}
+
+// The words of the right length in the list at `path`, if it can be read
+fn read(path: Option<PathBuf>) -> HashSet<String> {
+    let Some(text) = path.and_then(|path| fs::read_to_string(path).ok()) else {
+        return HashSet::new();
+    };
+    text.lines()
+        .map(|line| fold_word(line.split('#').next().unwrap_or("").trim()))
+        .filter(|word| {
+            word.chars().count() == WORD_LENGTH && word.chars().all(|c| c.is_ascii_uppercase())
+        })
+        .collect()
+}
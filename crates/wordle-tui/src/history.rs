@@ -0,0 +1,242 @@
+//! Append-only record of the games played, one JSON object per line in
+//! `history.jsonl` in the data directory.
+//!
+//! Every game leaves a `start` entry saying how its word was chosen: the
+//! seed and the word list it was drawn from. A disputed word ("the game
+//! repeated yesterday's!") can then be checked with
+//! `wordle replay --verify <id>`, which draws the word again from the
+//! logged seed. Games played to the end also leave a `finish` entry with
+//! the guesses and the result, which the history screen lists.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+use crate::game::{word_list_checksum, Game, GameStatus};
+use crate::i18n::Language;
+use crate::mode::Stopwatch;
+
+pub use wordle_proto::history::{Entry, Finish, Source, Start, Verification};
+
+/// The history file and the id the next game gets.
+#[derive(Debug)]
+pub struct History {
+    path: Option<PathBuf>,
+    next_id: u64,
+    // Clocks of the games started this session, less their pauses
+    started: HashMap<u64, Stopwatch>,
+    // How long the games finished this session took
+    took: HashMap<u64, Duration>,
+}
+
+impl History {
+    /// Opens the history in the data directory. Without one, nothing is
+    /// recorded.
+    pub fn open() -> History {
+        let path = Config::data_dir().map(|dir| dir.join("history.jsonl"));
+        let mut history = History {
+            path,
+            next_id: 1,
+            started: HashMap::new(),
+            took: HashMap::new(),
+        };
+        history.next_id = history.entries().iter().map(Entry::id).max().unwrap_or(0) + 1;
+        history
+    }
+
+    /// Every readable entry, oldest first.
+    pub fn entries(&self) -> Vec<Entry> {
+        let Some(text) = self
+            .path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+        else {
+            return Vec::new();
+        };
+        text.lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()
+    }
+
+    /// The `start` entry of game `id`.
+    pub fn find(&self, id: u64) -> Option<Start> {
+        self.entries().into_iter().find_map(|entry| match entry {
+            Entry::Start(start) if start.id == id => Some(start),
+            _ => None,
+        })
+    }
+
+    /// The games played to the end, most recent first.
+    pub fn finished(&self) -> Vec<Finish> {
+        let mut finished: Vec<Finish> = self
+            .entries()
+            .into_iter()
+            .filter_map(|entry| match entry {
+                Entry::Finish(finish) => Some(finish),
+                Entry::Start(_) => None,
+            })
+            .collect();
+        finished.reverse();
+        finished
+    }
+
+    /// Days up to `today` (not included) whose word of the day wasn't
+    /// played in `language`, oldest first: the last `limit` of those since
+    /// the first word of the day played in it.
+    pub fn missed_dailies(&self, today: u64, language: Language, limit: usize) -> Vec<u64> {
+        let played: HashSet<u64> = self
+            .entries()
+            .into_iter()
+            .filter_map(|entry| match entry {
+                Entry::Start(start)
+                    if matches!(start.source, Source::Daily | Source::Archive)
+                        && start.language == language.code() =>
+                {
+                    start.seed
+                }
+                _ => None,
+            })
+            .collect();
+        let Some(&first) = played.iter().min() else {
+            return Vec::new();
+        };
+        let mut missed: Vec<u64> = (first..today).filter(|day| !played.contains(day)).collect();
+        missed.drain(..missed.len().saturating_sub(limit));
+        missed
+    }
+
+    /// Whether the word of day `day` was played to the end in `language`.
+    pub fn daily_finished(&self, day: u64, language: Language) -> bool {
+        let entries = self.entries();
+        let finished: HashSet<u64> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Finish(finish) => Some(finish.id),
+                Entry::Start(_) => None,
+            })
+            .collect();
+        entries.iter().any(|entry| match entry {
+            Entry::Start(start) => {
+                matches!(start.source, Source::Daily | Source::Archive)
+                    && start.seed == Some(day)
+                    && start.language == language.code()
+                    && finished.contains(&start.id)
+            }
+            Entry::Finish(_) => false,
+        })
+    }
+
+    /// Records that `game` started, with `words` as the list it was drawn
+    /// from. Returns the id it got.
+    pub fn start(
+        &mut self,
+        game: &Game,
+        source: Source,
+        language: Language,
+        words: &[String],
+    ) -> io::Result<u64> {
+        let id = self.next_id;
+        self.append(&Entry::Start(Start {
+            id,
+            at: now(),
+            source,
+            language: language.code().to_string(),
+            word: game.target_word.clone(),
+            seed: game.seed,
+            words: words.len(),
+            checksum: word_list_checksum(words),
+        }))?;
+        self.started.insert(id, Stopwatch::start());
+        self.next_id += 1;
+        Ok(id)
+    }
+
+    /// How long game `id` has been going, or took once finished, if it
+    /// started this session.
+    pub fn elapsed(&self, id: u64) -> Option<Duration> {
+        self.started
+            .get(&id)
+            .map(Stopwatch::elapsed)
+            .or_else(|| self.took.get(&id).copied())
+    }
+
+    /// Stops the clock of game `id`, so a pause doesn't count in its time.
+    pub fn pause(&mut self, id: u64) {
+        if let Some(clock) = self.started.get_mut(&id) {
+            clock.pause();
+        }
+    }
+
+    /// Starts the clock of game `id` again.
+    pub fn resume(&mut self, id: u64) {
+        if let Some(clock) = self.started.get_mut(&id) {
+            clock.resume();
+        }
+    }
+
+    /// Records how game `id` ended. Only won and lost games are recorded.
+    pub fn finish(&mut self, id: u64, game: &Game, accepted_by: Vec<String>) -> io::Result<()> {
+        if !game.status.is_over() {
+            return Ok(());
+        }
+        let took = self.started.remove(&id).map(|clock| clock.elapsed());
+        if let Some(took) = took {
+            self.took.insert(id, took);
+        }
+        let seconds = took.map_or(0, |took| took.as_secs());
+        self.append(&Entry::Finish(Finish {
+            id,
+            at: now(),
+            word: game.target_word.clone(),
+            guesses: game
+                .submitted()
+                .map(|(guess, _)| guess.iter().collect())
+                .collect(),
+            won: game.status == GameStatus::Won,
+            seconds,
+            accepted_by,
+        }))
+    }
+
+    fn append(&self, entry: &Entry) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let line = serde_json::to_string(entry).map_err(io::Error::other)?;
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", line)
+    }
+}
+
+/// The UTC day of `at`, seconds since the Unix epoch, as `YYYY-MM-DD`.
+pub fn date(at: u64) -> String {
+    // Days to a civil date, after Howard Hinnant's algorithm
+    let days = (at / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// Seconds since the Unix epoch
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
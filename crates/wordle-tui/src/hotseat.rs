@@ -0,0 +1,175 @@
+//! Two players on one terminal, taking turns on the keyboard.
+//!
+//! Each player has a board of their own, on the same word or on words of
+//! their own. After every guess the keyboard goes to the other player, with
+//! the boards hidden while it is passed, until both are done; the round
+//! goes to whoever found the word in fewer guesses, and the next one is
+//! started by the other player.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::game::candidates::CandidateSet;
+use crate::game::timing::TimingSignature;
+use crate::game::{Game, GameStatus};
+
+/// Number of players at the keyboard.
+pub const PLAYERS: usize = 2;
+
+/// A versus session: the scores, whose turn it is, and the board of the
+/// player waiting for it. The board being played is the app's own.
+pub struct Hotseat {
+    /// Board of the player whose turn it isn't.
+    pub waiting: Seat,
+    /// Player at the keyboard: 0 or 1.
+    pub turn: usize,
+    /// Player who started the round.
+    pub starter: usize,
+    /// Rounds won by each player.
+    pub scores: [u32; PLAYERS],
+    /// Rounds neither player won.
+    pub draws: u32,
+    pub phase: Phase,
+    /// Guesses on the board being played when the turn started: one more
+    /// and the keyboard is passed.
+    pub turn_start: usize,
+    /// Whether both players look for the same word.
+    pub same_word: bool,
+}
+
+/// Where a round is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    /// The keyboard goes to the player whose turn it is, who hasn't seen
+    /// the boards yet.
+    Handover,
+    Playing,
+    /// Both players are done, or one can't catch up any more.
+    Over(Outcome),
+}
+
+/// How a round ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    /// Won by this player.
+    Winner(usize),
+    Draw,
+}
+
+/// The board of one player, and what their solver panels know of it.
+pub struct Seat {
+    pub game: Game,
+    pub candidates: CandidateSet,
+    pub candidate_history: Vec<CandidateSet>,
+    pub timing: TimingSignature,
+}
+
+impl Seat {
+    /// A board for `game`, with nothing guessed yet.
+    pub fn new(game: Game, words: Arc<[String]>) -> Seat {
+        Seat {
+            game,
+            candidates: CandidateSet::new(words),
+            candidate_history: Vec::new(),
+            timing: TimingSignature::default(),
+        }
+    }
+}
+
+impl Hotseat {
+    /// A session whose first round starts with player 0 at the keyboard,
+    /// `waiting` being player 1's board.
+    pub fn new(waiting: Seat, same_word: bool) -> Hotseat {
+        Hotseat {
+            waiting,
+            turn: 0,
+            starter: 0,
+            scores: [0; PLAYERS],
+            draws: 0,
+            phase: Phase::Handover,
+            turn_start: 0,
+            same_word,
+        }
+    }
+
+    /// The boards by player, given the one being played.
+    pub fn boards<'a>(&'a self, playing: &'a Game) -> [&'a Game; PLAYERS] {
+        if self.turn == 0 {
+            [playing, &self.waiting.game]
+        } else {
+            [&self.waiting.game, playing]
+        }
+    }
+
+    /// Whether the keyboard goes to the other player after a guess on
+    /// `playing`: only if they still have guesses to make.
+    pub fn should_pass(&self) -> bool {
+        self.waiting.game.status == GameStatus::Playing
+    }
+
+    /// Counts the round if it is over, given the board being played.
+    /// Returns whether it is.
+    pub fn settle(&mut self, playing: &Game) -> bool {
+        if let Phase::Over(_) = self.phase {
+            return true;
+        }
+        let [first, second] = self.boards(playing);
+        let Some(outcome) = outcome(first, second) else {
+            return false;
+        };
+        match outcome {
+            Outcome::Winner(player) => self.scores[player] += 1,
+            Outcome::Draw => self.draws += 1,
+        }
+        self.phase = Phase::Over(outcome);
+        true
+    }
+
+    /// Who starts the next round: the player who didn't start this one.
+    pub fn next_starter(&self) -> usize {
+        1 - self.starter
+    }
+
+    /// Whether neither player has made a guess this round, so the word can
+    /// still be changed.
+    pub fn untouched(&self, playing: &Game) -> bool {
+        self.boards(playing)
+            .iter()
+            .all(|game| game.submitted().next().is_none())
+    }
+}
+
+impl fmt::Debug for Hotseat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Hotseat")
+            .field("turn", &self.turn)
+            .field("scores", &self.scores)
+            .field("phase", &self.phase)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How the round between `first` and `second` ended, if it did: the word
+/// found in fewer guesses wins, the same number is a draw. A player whose
+/// rival found the word and who used as many guesses without finding it
+/// has lost, without playing on.
+pub fn outcome(first: &Game, second: &Game) -> Option<Outcome> {
+    let guesses = |game: &Game| game.submitted().count();
+    let won = |game: &Game| game.status == GameStatus::Won;
+    let done = |game: &Game| game.status.is_over();
+    match (won(first), won(second)) {
+        (true, true) => Some(match guesses(first).cmp(&guesses(second)) {
+            std::cmp::Ordering::Less => Outcome::Winner(0),
+            std::cmp::Ordering::Greater => Outcome::Winner(1),
+            std::cmp::Ordering::Equal => Outcome::Draw,
+        }),
+        (true, false) if done(second) || guesses(second) >= guesses(first) => {
+            Some(Outcome::Winner(0))
+        }
+        (false, true) if done(first) || guesses(first) >= guesses(second) => {
+            Some(Outcome::Winner(1))
+        }
+        (false, false) if done(first) && done(second) => Some(Outcome::Draw),
+        _ => None,
+    }
+}
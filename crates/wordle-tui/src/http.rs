@@ -0,0 +1,23 @@
+//! Documents fetched over HTTP: the community puzzle feed, the pack index
+//! and its packs, and definitions from the dictionary API. Built with the
+//! `http` feature (on by default); without it the game links no HTTP
+//! client, and every fetch fails as it would offline.
+
+use std::time::Duration;
+
+/// The body of the answer to a GET of `url`, giving up after `timeout`.
+#[cfg(feature = "http")]
+pub fn get(url: &str, timeout: Duration) -> anyhow::Result<String> {
+    use anyhow::Context;
+
+    Ok(ureq::get(url)
+        .timeout(timeout)
+        .call()
+        .with_context(|| format!("fetching {}", url))?
+        .into_string()?)
+}
+
+#[cfg(not(feature = "http"))]
+pub fn get(url: &str, _timeout: Duration) -> anyhow::Result<String> {
+    anyhow::bail!("can't fetch {}: this build has no `http` feature", url)
+}
@@ -0,0 +1,1458 @@
+//! Game language: translated interface text and the word list to play with.
+
+use std::env;
+use std::path::PathBuf;
+
+use crate::game::{Game, WORD_LENGTH};
+use crate::paths;
+
+/// Language of the interface and of the words to guess.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    En,
+    Pt,
+    Es,
+    Fr,
+}
+
+impl Language {
+    /// Parses a language code such as `pt`, `pt-BR` or `pt_BR.UTF-8`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.to_ascii_lowercase();
+        let prefix = code.split(['-', '_', '.']).next().unwrap_or("");
+        match prefix {
+            "en" => Some(Language::En),
+            "pt" => Some(Language::Pt),
+            "es" => Some(Language::Es),
+            "fr" => Some(Language::Fr),
+            _ => None,
+        }
+    }
+
+    /// Picks the language from the configured code, then from the usual
+    /// locale environment variables, then English.
+    pub fn detect(configured: Option<&str>) -> Self {
+        configured
+            .and_then(Self::from_code)
+            .or_else(|| {
+                ["LC_ALL", "LC_MESSAGES", "LANG"]
+                    .iter()
+                    .filter_map(|var| env::var(var).ok())
+                    .find_map(|value| Self::from_code(&value))
+            })
+            .unwrap_or(Language::En)
+    }
+
+    /// Code of the language, as `from_code` reads it.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Pt => "pt",
+            Language::Es => "es",
+            Language::Fr => "fr",
+        }
+    }
+
+    /// The words to guess, as spelled in the word list: from the file if
+    /// one is found and can be read, otherwise the built-in copy.
+    pub fn load_words(self) -> Vec<String> {
+        let words = self
+            .words_path()
+            .map(|path| Game::load_words_from_file(&path.to_string_lossy()))
+            .unwrap_or_default();
+        if words.is_empty() {
+            Game::parse_words(self.embedded_words(), WORD_LENGTH)
+        } else {
+            words
+        }
+    }
+
+    /// Name of the word list the game is played with in this language.
+    pub fn words_file(self) -> &'static str {
+        match self {
+            Language::En => "words.txt",
+            Language::Pt => "words-pt.txt",
+            Language::Es => "words-es.txt",
+            Language::Fr => "words-fr.txt",
+        }
+    }
+
+    /// Where the word list is, among the places [`paths`] searches.
+    pub fn words_path(self) -> Option<PathBuf> {
+        paths::find_asset(self.words_file())
+    }
+
+    /// Copy of the word list built into the binary, used when the file
+    /// can't be read (for instance when running from another directory).
+    pub fn embedded_words(self) -> &'static str {
+        match self {
+            Language::En => include_str!("../../../data/words.txt"),
+            Language::Pt => include_str!("../../../data/words-pt.txt"),
+            Language::Es => include_str!("../../../data/words-es.txt"),
+            Language::Fr => include_str!("../../../data/words-fr.txt"),
+        }
+    }
+
+    /// Difficulty ratings of the built-in word list, for the same cases.
+    pub fn embedded_difficulty(self) -> &'static str {
+        match self {
+            Language::En => include_str!("../../../data/words.difficulty"),
+            Language::Pt => include_str!("../../../data/words-pt.difficulty"),
+            Language::Es => include_str!("../../../data/words-es.difficulty"),
+            Language::Fr => include_str!("../../../data/words-fr.difficulty"),
+        }
+    }
+
+    /// Name of the list of words the filter keeps out in this language
+    /// (see [`crate::filter`]).
+    pub fn filter_file(self) -> &'static str {
+        match self {
+            Language::En => "filter.txt",
+            Language::Pt => "filter-pt.txt",
+            Language::Es => "filter-es.txt",
+            Language::Fr => "filter-fr.txt",
+        }
+    }
+
+    /// Where the filter list is, among the places [`paths`] searches.
+    pub fn filter_path(self) -> Option<PathBuf> {
+        paths::find_asset(self.filter_file())
+    }
+
+    /// Copy of the filter list built into the binary.
+    pub fn embedded_filter(self) -> &'static str {
+        match self {
+            Language::En => include_str!("../../../data/filter.txt"),
+            Language::Pt => include_str!("../../../data/filter-pt.txt"),
+            Language::Es => include_str!("../../../data/filter-es.txt"),
+            Language::Fr => include_str!("../../../data/filter-fr.txt"),
+        }
+    }
+
+    pub fn texts(self) -> &'static Texts {
+        match self {
+            Language::En => &EN,
+            Language::Pt => &PT,
+            Language::Es => &ES,
+            Language::Fr => &FR,
+        }
+    }
+}
+
+/// Every piece of interface text that depends on the language.
+pub struct Texts {
+    pub title: &'static str,
+    pub controls: &'static str,
+    pub won: &'static str,
+    /// Followed by the answer.
+    pub lost: &'static str,
+    pub lost_controls: &'static str,
+    pub confirm_quit: &'static str,
+    pub paste_unsupported: &'static str,
+    pub too_small: &'static str,
+    pub minimum_size: &'static str,
+    pub legend_title: &'static str,
+    pub legend_hint: &'static str,
+    pub legend_correct: &'static str,
+    pub legend_present: &'static str,
+    pub legend_absent: &'static str,
+    pub legend_unused: &'static str,
+    pub hint_label: &'static str,
+    pub hints_used: &'static str,
+    pub no_hint: &'static str,
+    pub assistant_title: &'static str,
+    pub assistant_remaining: &'static str,
+    pub analysis_title: &'static str,
+    pub analysis_guess: &'static str,
+    pub analysis_before: &'static str,
+    pub analysis_after: &'static str,
+    pub analysis_bits: &'static str,
+    pub analysis_best: &'static str,
+    pub analysis_back: &'static str,
+    pub focus_label: &'static str,
+    pub focus_board: &'static str,
+    pub focus_game_over: &'static str,
+    pub focus_quit: &'static str,
+    pub incomplete_guess: &'static str,
+    pub theme_label: &'static str,
+    pub high_contrast_on: &'static str,
+    pub high_contrast_off: &'static str,
+    pub practice_label: &'static str,
+    pub word_difficulty: &'static str,
+    pub average_difficulty: &'static str,
+    pub games: &'static str,
+    pub community_title: &'static str,
+    pub community_by: &'static str,
+    pub community_play: &'static str,
+    pub community_solved: &'static str,
+    pub community_missing: &'static str,
+    pub community_unsaved: &'static str,
+    pub constraints_title: &'static str,
+    pub menu_title: &'static str,
+    pub menu_resume: &'static str,
+    pub menu_new_game: &'static str,
+    pub menu_daily: &'static str,
+    pub menu_stats: &'static str,
+    pub menu_settings: &'static str,
+    pub menu_quit: &'static str,
+    pub menu_controls: &'static str,
+    pub menu_back: &'static str,
+    pub stats_empty: &'static str,
+    pub stats_games: &'static str,
+    pub stats_wins: &'static str,
+    pub stats_win_rate: &'static str,
+    pub stats_average_guesses: &'static str,
+    pub settings_high_contrast: &'static str,
+    pub settings_reduced_motion: &'static str,
+    pub settings_keyboard: &'static str,
+    pub settings_on: &'static str,
+    pub settings_off: &'static str,
+    pub settings_controls: &'static str,
+    pub menu_challenge: &'static str,
+    pub challenge_prompt: &'static str,
+    pub challenge_controls: &'static str,
+    pub challenge_unknown: &'static str,
+    pub challenge_start: &'static str,
+    pub challenge_unsaved: &'static str,
+    pub stats_challenges: &'static str,
+    pub menu_history: &'static str,
+    pub history_controls: &'static str,
+    pub history_unsaved: &'static str,
+    pub replay_label: &'static str,
+    pub replay_controls: &'static str,
+    pub replay_paused: &'static str,
+    pub menu_blitz: &'static str,
+    pub blitz_label: &'static str,
+    pub blitz_solved: &'static str,
+    pub blitz_over: &'static str,
+    pub blitz_again: &'static str,
+    pub blitz_next: &'static str,
+    pub menu_countdown: &'static str,
+    pub countdown_forfeit: &'static str,
+    pub menu_warmup: &'static str,
+    pub warmup_over: &'static str,
+    pub menu_speedrun: &'static str,
+    pub speedrun_label: &'static str,
+    pub speedrun_best: &'static str,
+    pub speedrun_record: &'static str,
+    pub records_unsaved: &'static str,
+    pub menu_mastermind: &'static str,
+    pub menu_zen: &'static str,
+    pub menu_whats_new: &'static str,
+    pub whats_new_in: &'static str,
+    pub whats_new_modes: &'static str,
+    pub whats_new_keys: &'static str,
+    pub whats_new_other: &'static str,
+    pub whats_new_controls: &'static str,
+    pub menu_practice: &'static str,
+    pub practice_undone: &'static str,
+    pub practice_no_undo: &'static str,
+    /// Followed by the code of the seed the word was drawn with.
+    pub seed_label: &'static str,
+    pub menu_hotseat: &'static str,
+    pub hotseat_player: &'static str,
+    pub hotseat_draws: &'static str,
+    /// Followed by the player who gets the keyboard.
+    pub hotseat_pass: &'static str,
+    pub hotseat_same_word: &'static str,
+    pub hotseat_own_words: &'static str,
+    /// After the player who won the round.
+    pub hotseat_wins: &'static str,
+    pub hotseat_draw: &'static str,
+    pub hotseat_controls: &'static str,
+    pub hotseat_over_controls: &'static str,
+    pub rival_title: &'static str,
+    pub rival_playing: &'static str,
+    /// Followed by the number of guesses.
+    pub rival_won: &'static str,
+    pub rival_lost: &'static str,
+    pub rival_left: &'static str,
+    pub card_title: &'static str,
+    /// Followed by where the image was saved.
+    pub card_saved: &'static str,
+    pub card_unsaved: &'static str,
+    pub rival_waiting: &'static str,
+    pub rival_points: &'static str,
+    /// In a room of a server, once the next round started.
+    pub rival_next_round: &'static str,
+    pub menu_polyglot: &'static str,
+    /// Heading of the wins and games in each language, once games were played in more than one.
+    pub stats_by_language: &'static str,
+    pub filtered_guess: &'static str,
+    pub settings_word_filter: &'static str,
+    /// Title of the spectator screen, before the room.
+    pub spectate_title: &'static str,
+    pub spectate_round: &'static str,
+    pub spectate_controls: &'static str,
+    pub spectate_closed: &'static str,
+    /// A game on a challenge file: who sent it, and where the reply went.
+    pub sent_from: &'static str,
+    pub sent_reply_saved: &'static str,
+    pub sent_reply_unsaved: &'static str,
+    /// The leaderboard screen: its entry, columns, controls and the short names of the modes.
+    pub menu_leaderboard: &'static str,
+    pub leaderboard_mode: &'static str,
+    pub leaderboard_length: &'static str,
+    pub leaderboard_wins: &'static str,
+    pub leaderboard_guesses: &'static str,
+    pub leaderboard_time: &'static str,
+    pub leaderboard_controls: &'static str,
+    pub leaderboard_unsaved: &'static str,
+    pub mode_classic: &'static str,
+    pub mode_daily: &'static str,
+    pub mode_warmup: &'static str,
+    pub mode_blitz: &'static str,
+    pub mode_countdown: &'static str,
+    pub mode_speedrun: &'static str,
+    pub mode_mastermind: &'static str,
+    pub mode_zen: &'static str,
+    pub mode_polyglot: &'static str,
+    pub mode_race: &'static str,
+    /// Short names of the modes off the leaderboard, for the status bar
+    pub mode_practice: &'static str,
+    pub mode_versus: &'static str,
+    pub mode_challenge: &'static str,
+    /// Badge of how common the answer is, on the analysis screen, and the
+    /// points weighted by it on the stats screen.
+    pub word_rarity: &'static str,
+    pub rarity_common: &'static str,
+    pub rarity_uncommon: &'static str,
+    pub rarity_rare: &'static str,
+    pub stats_rarity_score: &'static str,
+    /// Online leaderboard of the word of the day.
+    pub online_title: &'static str,
+    pub online_empty: &'static str,
+    pub online_unavailable: &'static str,
+    /// Copying the text of the result card.
+    pub card_copied: &'static str,
+    pub card_controls: &'static str,
+    pub lock_title: &'static str,
+    pub lock_any_key: &'static str,
+    pub lock_passphrase: &'static str,
+    pub lock_wrong: &'static str,
+    pub lock_saved: &'static str,
+    pub lock_unsaved: &'static str,
+    /// Shown when the game saved by the lock is played again.
+    pub lock_restored: &'static str,
+    pub menu_catch_up: &'static str,
+    pub catch_up_label: &'static str,
+    /// Followed by the words found out of those played.
+    pub catch_up_done: &'static str,
+    pub catch_up_guesses: &'static str,
+    pub menu_achievements: &'static str,
+    pub achievement_unlocked: &'static str,
+    /// Followed by how many badges were earned out of all of them.
+    pub achievements_count: &'static str,
+    pub achievement_first_win: &'static str,
+    pub achievement_first_win_about: &'static str,
+    pub achievement_in_two: &'static str,
+    pub achievement_in_two_about: &'static str,
+    pub achievement_streak: &'static str,
+    pub achievement_streak_about: &'static str,
+    pub achievement_no_hints: &'static str,
+    pub achievement_no_hints_about: &'static str,
+    pub achievement_hard_mode: &'static str,
+    pub achievement_hard_mode_about: &'static str,
+    pub achievements_unsaved: &'static str,
+    /// Heatmap of the letters deleted after typing them.
+    pub typos_title: &'static str,
+    pub typos_most: &'static str,
+    pub typos_unsaved: &'static str,
+    /// Panel with the definition of the word, once the game is over.
+    pub definition_title: &'static str,
+    pub definition_loading: &'static str,
+    pub definition_missing: &'static str,
+    pub definition_scroll: &'static str,
+    pub definition_unsaved: &'static str,
+    /// Difficulty of new games, shown on the menu
+    pub difficulty_any: &'static str,
+    pub difficulty_easy: &'static str,
+    pub difficulty_medium: &'static str,
+    pub difficulty_hard: &'static str,
+    /// Entries of the menu of a finished game
+    pub end_play_again: &'static str,
+    pub end_change_mode: &'static str,
+    pub end_analysis: &'static str,
+    pub end_share: &'static str,
+    pub end_quit: &'static str,
+    pub settings_quit_confirm: &'static str,
+    pub settings_word_theme: &'static str,
+    pub challenge_paste_code: &'static str,
+    /// In-game tag for guesses in a row that each found a new green.
+    pub momentum: &'static str,
+    pub achievement_momentum: &'static str,
+    pub achievement_momentum_about: &'static str,
+    /// Pair mode, before whose turn it is to submit.
+    pub pair_label: &'static str,
+    pub pair_your_turn: &'static str,
+    pub pair_partner_turn: &'static str,
+    /// Before the letters the partner is typing.
+    pub pair_partner: &'static str,
+    /// Enter pressed while the partner holds the turn.
+    pub pair_partner_submits: &'static str,
+    pub pair_left: &'static str,
+    /// A guess none of the guess dictionaries has.
+    pub guess_unknown: &'static str,
+    pub settings_status_markers: &'static str,
+    /// Linear mode (`--no-tui`), at the start.
+    pub linear_intro: &'static str,
+    /// Before the number of the guess and of those allowed, in linear mode.
+    pub linear_guess: &'static str,
+    pub linear_correct: &'static str,
+    pub linear_present: &'static str,
+    pub linear_absent: &'static str,
+    pub linear_play_again: &'static str,
+    pub too_many_letters: &'static str,
+    /// Footer of the DVR screen, before how far back the board shown is.
+    pub dvr_label: &'static str,
+    pub dvr_controls: &'static str,
+    /// Commentary of the game analysis: "Opened with CRANE, which left 112 words."
+    pub commentary_opened: &'static str,
+    pub commentary_left: &'static str,
+    pub commentary_words: &'static str,
+    pub commentary_guess: &'static str,
+    pub commentary_solver_pick: &'static str,
+    pub commentary_pivot: &'static str,
+    pub commentary_nothing: &'static str,
+    pub commentary_down_to: &'static str,
+    pub commentary_solver_had: &'static str,
+    pub commentary_won: &'static str,
+    pub commentary_lucky: &'static str,
+    pub commentary_lost: &'static str,
+    /// Desktop notification of the unplayed word of the day
+    pub reminder_title: &'static str,
+    pub reminder_body: &'static str,
+    /// Status bar of the footer: the guess being played and the wins in a
+    /// row
+    pub status_guess: &'static str,
+    pub status_streak: &'static str,
+    /// Title of the box hiding the board of a paused game.
+    pub pause_title: &'static str,
+    pub pause_hidden: &'static str,
+    /// Footer hint of the key pausing a timed game.
+    pub pause_controls: &'static str,
+}
+
+static EN: Texts = Texts {
+    title: "WORDLE",
+    controls: "[Enter] Submit | [Tab] Hint | [ESC] Exit",
+    won: "You won!",
+    lost: "The word was",
+    lost_controls: "[Space] Keep trying",
+    confirm_quit: "Are you sure you want to exit? [Enter] Yes | [Esc] No",
+    paste_unsupported: "Pasted text contains unsupported character",
+    too_small: "Terminal too small",
+    minimum_size: "Minimum size",
+    legend_title: "Legend",
+    legend_hint: "[F10] Menu | [Ctrl+L] Legend",
+    legend_correct: "Right letter, right spot",
+    legend_present: "In the word, wrong spot",
+    legend_absent: "Not in the word",
+    legend_unused: "Not tried yet",
+    hint_label: "Hint",
+    hints_used: "Hints",
+    no_hint: "No word fits the clues",
+    assistant_title: "Assistant",
+    assistant_remaining: "Possible words",
+    analysis_title: "Game analysis",
+    analysis_guess: "Guess",
+    analysis_before: "Before",
+    analysis_after: "After",
+    analysis_bits: "Bits",
+    analysis_best: "Solver pick",
+    analysis_back: "[Esc] Back to the board",
+    focus_label: "Focus",
+    focus_board: "Board",
+    focus_game_over: "Game over",
+    focus_quit: "Quit?",
+    incomplete_guess: "Not enough letters",
+    theme_label: "Theme",
+    high_contrast_on: "High contrast on",
+    high_contrast_off: "High contrast off",
+    practice_label: "Practice continuation",
+    word_difficulty: "This word's difficulty",
+    average_difficulty: "Average difficulty faced",
+    games: "games",
+    community_title: "Puzzle of the week",
+    community_by: "by",
+    community_play: "[F4] Play",
+    community_solved: "Solved",
+    community_missing: "No puzzle of the week available",
+    community_unsaved: "Couldn't save the solved puzzle",
+    constraints_title: "Clues",
+    menu_title: "Menu",
+    menu_resume: "Continue",
+    menu_new_game: "New game",
+    menu_daily: "Word of the day",
+    menu_stats: "Statistics",
+    menu_settings: "Settings",
+    menu_quit: "Quit",
+    menu_controls: "[↑↓] Choose | [Enter] Select",
+    menu_back: "[Esc] Back to the menu",
+    stats_empty: "No game finished yet",
+    stats_games: "Games played",
+    stats_wins: "Won",
+    stats_win_rate: "Win rate",
+    stats_average_guesses: "Average guesses to win",
+    settings_high_contrast: "High contrast",
+    settings_reduced_motion: "Reduced motion",
+    settings_keyboard: "Keyboard layout",
+    settings_on: "on",
+    settings_off: "off",
+    settings_controls: "[↑↓] Choose | [Enter] Change | [Esc] Back",
+    menu_challenge: "Challenge a guest",
+    challenge_prompt: "Type the secret word, then hand over the keyboard",
+    challenge_controls: "[Enter] Hand over | [Esc] Back",
+    challenge_unknown: "Not in the word list",
+    challenge_start: "Guest's turn: find the word",
+    challenge_unsaved: "Couldn't save the challenge",
+    stats_challenges: "Challenges won by guests",
+    menu_history: "History",
+    history_controls: "[↑↓] Choose | [Enter] Replay | [Esc] Back",
+    history_unsaved: "Couldn't save the game to the history",
+    replay_label: "Replay",
+    replay_controls: "[Space] Pause | [→] Next guess | [Esc] Back",
+    replay_paused: "Paused",
+    menu_blitz: "Blitz (3 minutes)",
+    blitz_label: "Blitz",
+    blitz_solved: "Solved",
+    blitz_over: "Time's up! Words solved:",
+    blitz_again: "[Esc] Play again | [F10] Menu",
+    blitz_next: "Next word! The answer was",
+    menu_countdown: "Timed guesses (20s each)",
+    countdown_forfeit: "Too slow! Row lost",
+    menu_warmup: "Warm-up letter of the day",
+    warmup_over: "The letter was",
+    menu_speedrun: "Speedrun",
+    speedrun_label: "Speedrun",
+    speedrun_best: "Best",
+    speedrun_record: "New record!",
+    records_unsaved: "Couldn't save the record",
+    menu_mastermind: "Mastermind (counts only)",
+    menu_zen: "Zen (no limit on guesses)",
+    menu_whats_new: "What's new",
+    whats_new_in: "What's new in",
+    whats_new_modes: "Modes",
+    whats_new_keys: "Keys",
+    whats_new_other: "Also",
+    whats_new_controls: "[←→] Releases | [D] Don't show again | [Esc] Back",
+    menu_practice: "Practice (Ctrl+Z undoes a guess)",
+    practice_undone: "Guess taken back",
+    practice_no_undo: "No guess to take back",
+    seed_label: "Seed",
+    menu_hotseat: "Versus (two players)",
+    hotseat_player: "Player",
+    hotseat_draws: "Draws",
+    hotseat_pass: "Pass the keyboard to",
+    hotseat_same_word: "Both players look for the same word",
+    hotseat_own_words: "Each player has a word of their own",
+    hotseat_wins: "wins the round!",
+    hotseat_draw: "It's a draw!",
+    hotseat_controls: "[Enter] Start the turn | [S] Same word | [Esc] Menu",
+    hotseat_over_controls: "[Enter] Next round | [Esc] Menu",
+    rival_title: "Rival",
+    rival_playing: "Still guessing…",
+    rival_won: "Found it in",
+    rival_lost: "Out of guesses",
+    rival_left: "Left the race",
+    card_title: "Result card",
+    card_saved: "Saved to",
+    card_unsaved: "Couldn't save the card",
+    rival_waiting: "Waiting for players…",
+    rival_points: "Points",
+    rival_next_round: "[Esc] Next round",
+    menu_polyglot: "Polyglot",
+    stats_by_language: "By language",
+    filtered_guess: "That word isn't allowed here",
+    settings_word_filter: "Word filter",
+    spectate_title: "Watching",
+    spectate_round: "Round",
+    spectate_controls: "[Esc] Stop watching",
+    spectate_closed: "The connection to the server was lost",
+    sent_from: "Challenge from",
+    sent_reply_saved: "Reply for",
+    sent_reply_unsaved: "Couldn't write the reply",
+    menu_leaderboard: "Leaderboard",
+    leaderboard_mode: "Mode",
+    leaderboard_length: "Letters",
+    leaderboard_wins: "Wins",
+    leaderboard_guesses: "Guesses",
+    leaderboard_time: "Best time",
+    leaderboard_controls: "[←→] Sort by | [Space] Reverse | [Esc] Back",
+    leaderboard_unsaved: "Couldn't save the leaderboard",
+    mode_classic: "Classic",
+    mode_daily: "Daily",
+    mode_warmup: "Warm-up",
+    mode_blitz: "Blitz",
+    mode_countdown: "Timed",
+    mode_speedrun: "Speedrun",
+    mode_mastermind: "Mastermind",
+    mode_zen: "Zen",
+    mode_polyglot: "Polyglot",
+    mode_race: "Race",
+    mode_practice: "Practice",
+    mode_versus: "Versus",
+    mode_challenge: "Challenge",
+    word_rarity: "This word is",
+    rarity_common: "common",
+    rarity_uncommon: "uncommon",
+    rarity_rare: "rare",
+    stats_rarity_score: "Rarity score",
+    online_title: "Today online",
+    online_empty: "No results yet",
+    online_unavailable: "The online leaderboard can't be reached",
+    card_copied: "Copied to the clipboard",
+    card_controls: "[C] Copy | [Esc] Back to the board",
+    lock_title: "Locked",
+    lock_any_key: "Press any key to resume",
+    lock_passphrase: "Type the passphrase and press Enter",
+    lock_wrong: "Wrong passphrase",
+    lock_saved: "The game was saved",
+    lock_unsaved: "Couldn't save the game",
+    lock_restored: "Your saved game is back",
+    menu_catch_up: "Catch up on missed days",
+    catch_up_label: "Catch-up",
+    catch_up_done: "Caught up! Found",
+    catch_up_guesses: "guesses in all",
+    menu_achievements: "Achievements",
+    achievement_unlocked: "Achievement unlocked",
+    achievements_count: "Earned",
+    achievement_first_win: "First win",
+    achievement_first_win_about: "Win a game",
+    achievement_in_two: "Sharp shooter",
+    achievement_in_two_about: "Find a word in 2 guesses",
+    achievement_streak: "On a roll",
+    achievement_streak_about: "Win 10 games in a row",
+    achievement_no_hints: "On my own",
+    achievement_no_hints_about: "Win without a hint",
+    achievement_hard_mode: "Hard mode",
+    achievement_hard_mode_about: "Win a Mastermind game",
+    achievements_unsaved: "Couldn't save the achievements",
+    typos_title: "Letters corrected",
+    typos_most: "Most corrected",
+    typos_unsaved: "Couldn't save the typing corrections",
+    definition_title: "Definition",
+    definition_loading: "Looking it up...",
+    definition_missing: "No definition found",
+    definition_scroll: "↑↓ scroll",
+    definition_unsaved: "Couldn't keep the definition",
+    difficulty_any: "Any",
+    difficulty_easy: "Easy",
+    difficulty_medium: "Medium",
+    difficulty_hard: "Hard",
+    end_play_again: "Play again",
+    end_change_mode: "Change mode",
+    end_analysis: "Analysis",
+    end_share: "Share",
+    end_quit: "Quit",
+    settings_quit_confirm: "Confirm quitting",
+    settings_word_theme: "Word theme",
+    challenge_paste_code: "or paste a challenge code",
+    momentum: "Momentum",
+    achievement_momentum: "Momentum",
+    achievement_momentum_about: "Win with 3 guesses in a row each finding a new green",
+    pair_label: "Pair",
+    pair_your_turn: "You submit",
+    pair_partner_turn: "Partner submits",
+    pair_partner: "Partner",
+    pair_partner_submits: "Your partner submits this guess",
+    pair_left: "Partner left",
+    guess_unknown: "Not in the word list",
+    settings_status_markers: "Status markers",
+    linear_intro: "Guess the word: type a guess and press Enter. Type quit to leave.",
+    linear_guess: "Guess",
+    linear_correct: "correct",
+    linear_present: "present",
+    linear_absent: "absent",
+    linear_play_again: "Play again? (y/n)",
+    too_many_letters: "Too many letters",
+    dvr_label: "DVR",
+    dvr_controls: "[←→] Scrub | [Home/End] Oldest/Latest | [Esc] Back to the game",
+    commentary_opened: "Opened with",
+    commentary_left: "which left",
+    commentary_words: "words",
+    commentary_guess: "Guess",
+    commentary_solver_pick: "the solver's own pick",
+    commentary_pivot: "pivoted well",
+    commentary_nothing: "told nothing new",
+    commentary_down_to: "down to",
+    commentary_solver_had: "the solver had",
+    commentary_won: "Found it on guess",
+    commentary_lucky: "a lucky pick among",
+    commentary_lost: "Out of guesses: the word was",
+    reminder_title: "Wordle",
+    reminder_body: "Today's word is still waiting for you",
+    status_guess: "Guess",
+    status_streak: "Streak",
+    pause_title: "Paused",
+    pause_hidden: "The clock is stopped and the board hidden",
+    pause_controls: "[Ctrl+P] Pause",
+};
+
+static PT: Texts = Texts {
+    title: "WORDLE (PT-BR)",
+    controls: "[Enter] Enviar | [Tab] Dica | [ESC] Sair",
+    won: "Você venceu!",
+    lost: "A palavra era",
+    lost_controls: "[Espaço] Continuar",
+    confirm_quit: "Quer mesmo sair? [Enter] Sim | [Esc] Não",
+    paste_unsupported: "O texto colado contém um caractere não suportado",
+    too_small: "Terminal pequeno demais",
+    minimum_size: "Tamanho mínimo",
+    legend_title: "Legenda",
+    legend_hint: "[F10] Menu | [Ctrl+L] Legenda",
+    legend_correct: "Letra certa, lugar certo",
+    legend_present: "Na palavra, lugar errado",
+    legend_absent: "Fora da palavra",
+    legend_unused: "Ainda não usada",
+    hint_label: "Dica",
+    hints_used: "Dicas",
+    no_hint: "Nenhuma palavra combina com as pistas",
+    assistant_title: "Assistente",
+    assistant_remaining: "Palavras possíveis",
+    analysis_title: "Análise da partida",
+    analysis_guess: "Palpite",
+    analysis_before: "Antes",
+    analysis_after: "Depois",
+    analysis_bits: "Bits",
+    analysis_best: "Escolha do solver",
+    analysis_back: "[Esc] Voltar ao tabuleiro",
+    focus_label: "Foco",
+    focus_board: "Tabuleiro",
+    focus_game_over: "Fim de jogo",
+    focus_quit: "Sair?",
+    incomplete_guess: "Letras insuficientes",
+    theme_label: "Tema",
+    high_contrast_on: "Alto contraste ativado",
+    high_contrast_off: "Alto contraste desativado",
+    practice_label: "Continuação (treino)",
+    word_difficulty: "Dificuldade desta palavra",
+    average_difficulty: "Dificuldade média enfrentada",
+    games: "jogos",
+    community_title: "Desafio da semana",
+    community_by: "por",
+    community_play: "[F4] Jogar",
+    community_solved: "Resolvido",
+    community_missing: "Nenhum desafio da semana disponível",
+    community_unsaved: "Não foi possível salvar o desafio resolvido",
+    constraints_title: "Pistas",
+    menu_title: "Menu",
+    menu_resume: "Continuar",
+    menu_new_game: "Novo jogo",
+    menu_daily: "Palavra do dia",
+    menu_stats: "Estatísticas",
+    menu_settings: "Configurações",
+    menu_quit: "Sair",
+    menu_controls: "[↑↓] Escolher | [Enter] Selecionar",
+    menu_back: "[Esc] Voltar ao menu",
+    stats_empty: "Nenhum jogo terminado ainda",
+    stats_games: "Jogos",
+    stats_wins: "Vitórias",
+    stats_win_rate: "Taxa de vitórias",
+    stats_average_guesses: "Média de tentativas para vencer",
+    settings_high_contrast: "Alto contraste",
+    settings_reduced_motion: "Movimento reduzido",
+    settings_keyboard: "Layout do teclado",
+    settings_on: "ligado",
+    settings_off: "desligado",
+    settings_controls: "[↑↓] Escolher | [Enter] Mudar | [Esc] Voltar",
+    menu_challenge: "Desafiar um convidado",
+    challenge_prompt: "Digite a palavra secreta e passe o teclado",
+    challenge_controls: "[Enter] Passar | [Esc] Voltar",
+    challenge_unknown: "Não está na lista de palavras",
+    challenge_start: "Vez do convidado: descubra a palavra",
+    challenge_unsaved: "Não foi possível salvar o desafio",
+    stats_challenges: "Desafios vencidos por convidados",
+    menu_history: "Histórico",
+    history_controls: "[↑↓] Escolher | [Enter] Replay | [Esc] Voltar",
+    history_unsaved: "Não foi possível salvar a partida no histórico",
+    replay_label: "Replay",
+    replay_controls: "[Espaço] Pausar | [→] Próximo palpite | [Esc] Voltar",
+    replay_paused: "Pausado",
+    menu_blitz: "Blitz (3 minutos)",
+    blitz_label: "Blitz",
+    blitz_solved: "Acertos",
+    blitz_over: "Acabou o tempo! Palavras descobertas:",
+    blitz_again: "[Esc] Jogar de novo | [F10] Menu",
+    blitz_next: "Próxima palavra! A resposta era",
+    menu_countdown: "Palpites cronometrados (20s cada)",
+    countdown_forfeit: "Devagar demais! Linha perdida",
+    menu_warmup: "Letra de aquecimento do dia",
+    warmup_over: "A letra era",
+    menu_speedrun: "Speedrun (contra o relógio)",
+    speedrun_label: "Speedrun",
+    speedrun_best: "Recorde",
+    speedrun_record: "Novo recorde!",
+    records_unsaved: "Não foi possível salvar o recorde",
+    menu_mastermind: "Mastermind (só contagens)",
+    menu_zen: "Zen (tentativas ilimitadas)",
+    menu_whats_new: "Novidades",
+    whats_new_in: "Novidades da versão",
+    whats_new_modes: "Modos",
+    whats_new_keys: "Teclas",
+    whats_new_other: "E mais",
+    whats_new_controls: "[←→] Versões | [D] Não mostrar mais | [Esc] Voltar",
+    menu_practice: "Treino (Ctrl+Z desfaz um palpite)",
+    practice_undone: "Palpite desfeito",
+    practice_no_undo: "Nenhum palpite para desfazer",
+    seed_label: "Semente",
+    menu_hotseat: "Versus (dois jogadores)",
+    hotseat_player: "Jogador",
+    hotseat_draws: "Empates",
+    hotseat_pass: "Passe o teclado ao",
+    hotseat_same_word: "Os dois jogadores buscam a mesma palavra",
+    hotseat_own_words: "Cada jogador tem a sua palavra",
+    hotseat_wins: "vence a rodada!",
+    hotseat_draw: "Empate!",
+    hotseat_controls: "[Enter] Começar a vez | [S] Mesma palavra | [Esc] Menu",
+    hotseat_over_controls: "[Enter] Próxima rodada | [Esc] Menu",
+    rival_title: "Rival",
+    rival_playing: "Ainda tentando…",
+    rival_won: "Acertou em",
+    rival_lost: "Sem tentativas",
+    rival_left: "Saiu da corrida",
+    card_title: "Cartão do resultado",
+    card_saved: "Salvo em",
+    card_unsaved: "Não foi possível salvar o cartão",
+    rival_waiting: "Esperando jogadores…",
+    rival_points: "Pontos",
+    rival_next_round: "[Esc] Próxima rodada",
+    menu_polyglot: "Poliglota",
+    stats_by_language: "Por idioma",
+    filtered_guess: "Essa palavra não é permitida aqui",
+    settings_word_filter: "Filtro de palavras",
+    spectate_title: "Assistindo",
+    spectate_round: "Rodada",
+    spectate_controls: "[Esc] Parar de assistir",
+    spectate_closed: "A conexão com o servidor caiu",
+    sent_from: "Desafio de",
+    sent_reply_saved: "Resposta para",
+    sent_reply_unsaved: "Não foi possível gravar a resposta",
+    menu_leaderboard: "Recordes",
+    leaderboard_mode: "Modo",
+    leaderboard_length: "Letras",
+    leaderboard_wins: "Vitórias",
+    leaderboard_guesses: "Tentativas",
+    leaderboard_time: "Melhor tempo",
+    leaderboard_controls: "[←→] Ordenar por | [Espaço] Inverter | [Esc] Voltar",
+    leaderboard_unsaved: "Não foi possível salvar os recordes",
+    mode_classic: "Clássico",
+    mode_daily: "Do dia",
+    mode_warmup: "Aquecimento",
+    mode_blitz: "Blitz",
+    mode_countdown: "Cronometrado",
+    mode_speedrun: "Speedrun",
+    mode_mastermind: "Mastermind",
+    mode_zen: "Zen",
+    mode_polyglot: "Poliglota",
+    mode_race: "Corrida",
+    mode_practice: "Treino",
+    mode_versus: "Versus",
+    mode_challenge: "Desafio",
+    word_rarity: "Esta palavra é",
+    rarity_common: "comum",
+    rarity_uncommon: "incomum",
+    rarity_rare: "rara",
+    stats_rarity_score: "Pontos por raridade",
+    online_title: "Hoje online",
+    online_empty: "Nenhum resultado ainda",
+    online_unavailable: "O placar online não responde",
+    card_copied: "Copiado para a área de transferência",
+    card_controls: "[C] Copiar | [Esc] Voltar ao tabuleiro",
+    lock_title: "Bloqueado",
+    lock_any_key: "Pressione qualquer tecla para continuar",
+    lock_passphrase: "Digite a senha e pressione Enter",
+    lock_wrong: "Senha errada",
+    lock_saved: "A partida foi salva",
+    lock_unsaved: "Não foi possível salvar a partida",
+    lock_restored: "Sua partida salva voltou",
+    menu_catch_up: "Recuperar dias perdidos",
+    catch_up_label: "Recuperação",
+    catch_up_done: "Em dia! Encontradas",
+    catch_up_guesses: "tentativas no total",
+    menu_achievements: "Conquistas",
+    achievement_unlocked: "Conquista desbloqueada",
+    achievements_count: "Obtidas",
+    achievement_first_win: "Primeira vitória",
+    achievement_first_win_about: "Vença uma partida",
+    achievement_in_two: "Certeiro",
+    achievement_in_two_about: "Acerte uma palavra em 2 tentativas",
+    achievement_streak: "Embalado",
+    achievement_streak_about: "Vença 10 partidas seguidas",
+    achievement_no_hints: "Sem ajuda",
+    achievement_no_hints_about: "Vença sem pedir dica",
+    achievement_hard_mode: "Modo difícil",
+    achievement_hard_mode_about: "Vença uma partida Mastermind",
+    achievements_unsaved: "Não foi possível salvar as conquistas",
+    typos_title: "Letras corrigidas",
+    typos_most: "Mais corrigidas",
+    typos_unsaved: "Não foi possível salvar as correções de digitação",
+    definition_title: "Definição",
+    definition_loading: "Procurando...",
+    definition_missing: "Nenhuma definição encontrada",
+    definition_scroll: "↑↓ rolar",
+    definition_unsaved: "Não foi possível guardar a definição",
+    difficulty_any: "Qualquer",
+    difficulty_easy: "Fácil",
+    difficulty_medium: "Médio",
+    difficulty_hard: "Difícil",
+    end_play_again: "Jogar de novo",
+    end_change_mode: "Mudar de modo",
+    end_analysis: "Análise",
+    end_share: "Compartilhar",
+    end_quit: "Sair",
+    settings_quit_confirm: "Confirmar saída",
+    settings_word_theme: "Tema das palavras",
+    challenge_paste_code: "ou cole um código de desafio",
+    momentum: "Embalo",
+    achievement_momentum: "No embalo",
+    achievement_momentum_about: "Vença com 3 tentativas seguidas achando um verde novo",
+    pair_label: "Dupla",
+    pair_your_turn: "Você envia",
+    pair_partner_turn: "Parceiro envia",
+    pair_partner: "Parceiro",
+    pair_partner_submits: "Seu parceiro envia esta tentativa",
+    pair_left: "O parceiro saiu",
+    guess_unknown: "Não está na lista de palavras",
+    settings_status_markers: "Marcadores de estado",
+    linear_intro: "Adivinhe a palavra: digite um palpite e aperte Enter. Digite quit para sair.",
+    linear_guess: "Palpite",
+    linear_correct: "certa",
+    linear_present: "presente",
+    linear_absent: "ausente",
+    linear_play_again: "Jogar de novo? (s/n)",
+    too_many_letters: "Letras demais",
+    dvr_label: "DVR",
+    dvr_controls: "[←→] Voltar/Avançar | [Home/End] Mais antigo/Último | [Esc] Voltar ao jogo",
+    commentary_opened: "Abriu com",
+    commentary_left: "que deixou",
+    commentary_words: "palavras",
+    commentary_guess: "Palpite",
+    commentary_solver_pick: "a própria escolha do solver",
+    commentary_pivot: "mudou bem de rumo",
+    commentary_nothing: "não disse nada de novo",
+    commentary_down_to: "reduzidas a",
+    commentary_solver_had: "o solver tinha",
+    commentary_won: "Acertou no palpite",
+    commentary_lucky: "um palpite de sorte entre",
+    commentary_lost: "Sem mais palpites: a palavra era",
+    reminder_title: "Wordle",
+    reminder_body: "A palavra de hoje ainda está à sua espera",
+    status_guess: "Palpite",
+    status_streak: "Sequência",
+    pause_title: "Pausado",
+    pause_hidden: "O relógio está parado e o tabuleiro escondido",
+    pause_controls: "[Ctrl+P] Pausar",
+};
+
+static ES: Texts = Texts {
+    title: "WORDLE (ES)",
+    controls: "[Enter] Enviar | [Tab] Pista | [ESC] Salir",
+    won: "¡Ganaste!",
+    lost: "La palabra era",
+    lost_controls: "[Espacio] Seguir",
+    confirm_quit: "¿Seguro que quieres salir? [Enter] Sí | [Esc] No",
+    paste_unsupported: "El texto pegado contiene un carácter no admitido",
+    too_small: "Terminal demasiado pequeña",
+    minimum_size: "Tamaño mínimo",
+    legend_title: "Leyenda",
+    legend_hint: "[F10] Menú | [Ctrl+L] Leyenda",
+    legend_correct: "Letra y lugar correctos",
+    legend_present: "En la palabra, otro lugar",
+    legend_absent: "No está en la palabra",
+    legend_unused: "Aún sin usar",
+    hint_label: "Pista",
+    hints_used: "Pistas",
+    no_hint: "Ninguna palabra encaja con las pistas",
+    assistant_title: "Asistente",
+    assistant_remaining: "Palabras posibles",
+    analysis_title: "Análisis de la partida",
+    analysis_guess: "Intento",
+    analysis_before: "Antes",
+    analysis_after: "Después",
+    analysis_bits: "Bits",
+    analysis_best: "Elección del solver",
+    analysis_back: "[Esc] Volver al tablero",
+    focus_label: "Foco",
+    focus_board: "Tablero",
+    focus_game_over: "Fin de la partida",
+    focus_quit: "¿Salir?",
+    incomplete_guess: "Faltan letras",
+    theme_label: "Tema",
+    high_contrast_on: "Alto contraste activado",
+    high_contrast_off: "Alto contraste desactivado",
+    practice_label: "Continuación (práctica)",
+    word_difficulty: "Dificultad de esta palabra",
+    average_difficulty: "Dificultad media afrontada",
+    games: "partidas",
+    community_title: "Reto de la semana",
+    community_by: "por",
+    community_play: "[F4] Jugar",
+    community_solved: "Resuelto",
+    community_missing: "No hay reto de la semana disponible",
+    community_unsaved: "No se pudo guardar el reto resuelto",
+    constraints_title: "Pistas",
+    menu_title: "Menú",
+    menu_resume: "Continuar",
+    menu_new_game: "Nueva partida",
+    menu_daily: "Palabra del día",
+    menu_stats: "Estadísticas",
+    menu_settings: "Ajustes",
+    menu_quit: "Salir",
+    menu_controls: "[↑↓] Elegir | [Enter] Seleccionar",
+    menu_back: "[Esc] Volver al menú",
+    stats_empty: "Todavía no terminaste ninguna partida",
+    stats_games: "Partidas",
+    stats_wins: "Ganadas",
+    stats_win_rate: "Porcentaje de victorias",
+    stats_average_guesses: "Intentos medios para ganar",
+    settings_high_contrast: "Alto contraste",
+    settings_reduced_motion: "Movimiento reducido",
+    settings_keyboard: "Distribución del teclado",
+    settings_on: "sí",
+    settings_off: "no",
+    settings_controls: "[↑↓] Elegir | [Enter] Cambiar | [Esc] Volver",
+    menu_challenge: "Desafiar a un invitado",
+    challenge_prompt: "Escribe la palabra secreta y pasa el teclado",
+    challenge_controls: "[Enter] Pasar | [Esc] Volver",
+    challenge_unknown: "No está en la lista de palabras",
+    challenge_start: "Turno del invitado: encuentra la palabra",
+    challenge_unsaved: "No se pudo guardar el desafío",
+    stats_challenges: "Desafíos ganados por invitados",
+    menu_history: "Historial",
+    history_controls: "[↑↓] Elegir | [Enter] Repetir | [Esc] Volver",
+    history_unsaved: "No se pudo guardar la partida en el historial",
+    replay_label: "Repetición",
+    replay_controls: "[Espacio] Pausa | [→] Siguiente intento | [Esc] Volver",
+    replay_paused: "En pausa",
+    menu_blitz: "Blitz (3 minutos)",
+    blitz_label: "Blitz",
+    blitz_solved: "Aciertos",
+    blitz_over: "¡Se acabó el tiempo! Palabras resueltas:",
+    blitz_again: "[Esc] Jugar otra vez | [F10] Menú",
+    blitz_next: "¡Siguiente palabra! La respuesta era",
+    menu_countdown: "Intentos cronometrados (20s cada uno)",
+    countdown_forfeit: "¡Demasiado lento! Fila perdida",
+    menu_warmup: "Letra de calentamiento del día",
+    warmup_over: "La letra era",
+    menu_speedrun: "Speedrun (contrarreloj)",
+    speedrun_label: "Speedrun",
+    speedrun_best: "Récord",
+    speedrun_record: "¡Nuevo récord!",
+    records_unsaved: "No se pudo guardar el récord",
+    menu_mastermind: "Mastermind (solo recuentos)",
+    menu_zen: "Zen (intentos ilimitados)",
+    menu_whats_new: "Novedades",
+    whats_new_in: "Novedades de la versión",
+    whats_new_modes: "Modos",
+    whats_new_keys: "Teclas",
+    whats_new_other: "Además",
+    whats_new_controls: "[←→] Versiones | [D] No volver a mostrar | [Esc] Volver",
+    menu_practice: "Práctica (Ctrl+Z deshace un intento)",
+    practice_undone: "Intento deshecho",
+    practice_no_undo: "Ningún intento que deshacer",
+    seed_label: "Semilla",
+    menu_hotseat: "Versus (dos jugadores)",
+    hotseat_player: "Jugador",
+    hotseat_draws: "Empates",
+    hotseat_pass: "Pasa el teclado al",
+    hotseat_same_word: "Los dos jugadores buscan la misma palabra",
+    hotseat_own_words: "Cada jugador tiene su propia palabra",
+    hotseat_wins: "gana la ronda!",
+    hotseat_draw: "¡Empate!",
+    hotseat_controls: "[Enter] Empezar el turno | [S] Misma palabra | [Esc] Menú",
+    hotseat_over_controls: "[Enter] Siguiente ronda | [Esc] Menú",
+    rival_title: "Rival",
+    rival_playing: "Todavía adivinando…",
+    rival_won: "La encontró en",
+    rival_lost: "Sin intentos",
+    rival_left: "Dejó la carrera",
+    card_title: "Tarjeta del resultado",
+    card_saved: "Guardada en",
+    card_unsaved: "No se pudo guardar la tarjeta",
+    rival_waiting: "Esperando jugadores…",
+    rival_points: "Puntos",
+    rival_next_round: "[Esc] Siguiente ronda",
+    menu_polyglot: "Políglota",
+    stats_by_language: "Por idioma",
+    filtered_guess: "Esa palabra no está permitida aquí",
+    settings_word_filter: "Filtro de palabras",
+    spectate_title: "Mirando",
+    spectate_round: "Ronda",
+    spectate_controls: "[Esc] Dejar de mirar",
+    spectate_closed: "Se perdió la conexión con el servidor",
+    sent_from: "Desafío de",
+    sent_reply_saved: "Respuesta para",
+    sent_reply_unsaved: "No se pudo guardar la respuesta",
+    menu_leaderboard: "Récords",
+    leaderboard_mode: "Modo",
+    leaderboard_length: "Letras",
+    leaderboard_wins: "Victorias",
+    leaderboard_guesses: "Intentos",
+    leaderboard_time: "Mejor tiempo",
+    leaderboard_controls: "[←→] Ordenar por | [Espacio] Invertir | [Esc] Volver",
+    leaderboard_unsaved: "No se pudieron guardar los récords",
+    mode_classic: "Clásico",
+    mode_daily: "Del día",
+    mode_warmup: "Calentamiento",
+    mode_blitz: "Blitz",
+    mode_countdown: "Cronometrado",
+    mode_speedrun: "Speedrun",
+    mode_mastermind: "Mastermind",
+    mode_zen: "Zen",
+    mode_polyglot: "Políglota",
+    mode_race: "Carrera",
+    mode_practice: "Práctica",
+    mode_versus: "Versus",
+    mode_challenge: "Desafío",
+    word_rarity: "Esta palabra es",
+    rarity_common: "común",
+    rarity_uncommon: "poco común",
+    rarity_rare: "rara",
+    stats_rarity_score: "Puntos por rareza",
+    online_title: "Hoy en línea",
+    online_empty: "Aún no hay resultados",
+    online_unavailable: "No se puede acceder a la clasificación en línea",
+    card_copied: "Copiado al portapapeles",
+    card_controls: "[C] Copiar | [Esc] Volver al tablero",
+    lock_title: "Bloqueado",
+    lock_any_key: "Pulsa cualquier tecla para continuar",
+    lock_passphrase: "Escribe la contraseña y pulsa Enter",
+    lock_wrong: "Contraseña incorrecta",
+    lock_saved: "La partida se guardó",
+    lock_unsaved: "No se pudo guardar la partida",
+    lock_restored: "Tu partida guardada ha vuelto",
+    menu_catch_up: "Recuperar días perdidos",
+    catch_up_label: "Recuperación",
+    catch_up_done: "¡Al día! Encontradas",
+    catch_up_guesses: "intentos en total",
+    menu_achievements: "Logros",
+    achievement_unlocked: "Logro desbloqueado",
+    achievements_count: "Conseguidos",
+    achievement_first_win: "Primera victoria",
+    achievement_first_win_about: "Gana una partida",
+    achievement_in_two: "Certero",
+    achievement_in_two_about: "Acierta una palabra en 2 intentos",
+    achievement_streak: "En racha",
+    achievement_streak_about: "Gana 10 partidas seguidas",
+    achievement_no_hints: "Sin ayuda",
+    achievement_no_hints_about: "Gana sin pedir pistas",
+    achievement_hard_mode: "Modo difícil",
+    achievement_hard_mode_about: "Gana una partida Mastermind",
+    achievements_unsaved: "No se pudieron guardar los logros",
+    typos_title: "Letras corregidas",
+    typos_most: "Más corregidas",
+    typos_unsaved: "No se pudieron guardar las correcciones de escritura",
+    definition_title: "Definición",
+    definition_loading: "Buscando...",
+    definition_missing: "No se encontró ninguna definición",
+    definition_scroll: "↑↓ desplazar",
+    definition_unsaved: "No se pudo guardar la definición",
+    difficulty_any: "Cualquiera",
+    difficulty_easy: "Fácil",
+    difficulty_medium: "Media",
+    difficulty_hard: "Difícil",
+    end_play_again: "Jugar otra vez",
+    end_change_mode: "Cambiar de modo",
+    end_analysis: "Análisis",
+    end_share: "Compartir",
+    end_quit: "Salir",
+    settings_quit_confirm: "Confirmar salida",
+    settings_word_theme: "Tema de palabras",
+    challenge_paste_code: "o pega un código de desafío",
+    momentum: "Racha",
+    achievement_momentum: "En racha",
+    achievement_momentum_about: "Gana con 3 intentos seguidos que encuentran un verde nuevo",
+    pair_label: "Pareja",
+    pair_your_turn: "Tú envías",
+    pair_partner_turn: "Tu pareja envía",
+    pair_partner: "Pareja",
+    pair_partner_submits: "Tu pareja envía este intento",
+    pair_left: "Tu pareja se fue",
+    guess_unknown: "No está en la lista de palabras",
+    settings_status_markers: "Marcadores de estado",
+    linear_intro: "Adivina la palabra: escribe un intento y pulsa Enter. Escribe quit para salir.",
+    linear_guess: "Intento",
+    linear_correct: "correcta",
+    linear_present: "presente",
+    linear_absent: "ausente",
+    linear_play_again: "¿Jugar otra vez? (s/n)",
+    too_many_letters: "Demasiadas letras",
+    dvr_label: "DVR",
+    dvr_controls: "[←→] Retroceder/Avanzar | [Home/End] Más antiguo/Último | [Esc] Volver al juego",
+    commentary_opened: "Abrió con",
+    commentary_left: "que dejó",
+    commentary_words: "palabras",
+    commentary_guess: "Intento",
+    commentary_solver_pick: "la misma elección del solver",
+    commentary_pivot: "cambió bien de rumbo",
+    commentary_nothing: "no aportó nada nuevo",
+    commentary_down_to: "reducidas a",
+    commentary_solver_had: "el solver tenía",
+    commentary_won: "Acertó en el intento",
+    commentary_lucky: "un acierto con suerte entre",
+    commentary_lost: "Sin más intentos: la palabra era",
+    reminder_title: "Wordle",
+    reminder_body: "La palabra de hoy todavía te espera",
+    status_guess: "Intento",
+    status_streak: "Racha",
+    pause_title: "En pausa",
+    pause_hidden: "El reloj está parado y el tablero oculto",
+    pause_controls: "[Ctrl+P] Pausar",
+};
+
+static FR: Texts = Texts {
+    title: "WORDLE (FR)",
+    controls: "[Enter] Valider | [Tab] Indice | [Esc] Quitter",
+    won: "Gagné !",
+    lost: "Le mot était",
+    lost_controls: "[Espace] Continuer",
+    confirm_quit: "Voulez-vous vraiment quitter ? [Enter] Oui | [Esc] Non",
+    paste_unsupported: "Le texte collé contient un caractère non pris en charge",
+    too_small: "Terminal trop petit",
+    minimum_size: "Taille minimale",
+    legend_title: "Légende",
+    legend_hint: "[F10] Menu | [Ctrl+L] Légende",
+    legend_correct: "Bonne lettre, bonne place",
+    legend_present: "Dans le mot, mal placée",
+    legend_absent: "Absente du mot",
+    legend_unused: "Pas encore essayée",
+    hint_label: "Indice",
+    hints_used: "Indices",
+    no_hint: "Aucun mot ne correspond aux indices",
+    assistant_title: "Assistant",
+    assistant_remaining: "Mots possibles",
+    analysis_title: "Analyse de la partie",
+    analysis_guess: "Essai",
+    analysis_before: "Avant",
+    analysis_after: "Après",
+    analysis_bits: "Bits",
+    analysis_best: "Choix du solveur",
+    analysis_back: "[Esc] Retour au plateau",
+    focus_label: "Focus",
+    focus_board: "Plateau",
+    focus_game_over: "Partie terminée",
+    focus_quit: "Quitter ?",
+    incomplete_guess: "Pas assez de lettres",
+    theme_label: "Thème",
+    high_contrast_on: "Contraste élevé activé",
+    high_contrast_off: "Contraste élevé désactivé",
+    practice_label: "Prolongation (entraînement)",
+    word_difficulty: "Difficulté de ce mot",
+    average_difficulty: "Difficulté moyenne rencontrée",
+    games: "parties",
+    community_title: "Défi de la semaine",
+    community_by: "par",
+    community_play: "[F4] Jouer",
+    community_solved: "Résolu",
+    community_missing: "Aucun défi de la semaine disponible",
+    community_unsaved: "Impossible d'enregistrer le défi résolu",
+    constraints_title: "Indices",
+    menu_title: "Menu",
+    menu_resume: "Continuer",
+    menu_new_game: "Nouvelle partie",
+    menu_daily: "Mot du jour",
+    menu_stats: "Statistiques",
+    menu_settings: "Réglages",
+    menu_quit: "Quitter",
+    menu_controls: "[↑↓] Choisir | [Enter] Valider",
+    menu_back: "[Esc] Retour au menu",
+    stats_empty: "Aucune partie terminée pour l'instant",
+    stats_games: "Parties jouées",
+    stats_wins: "Gagnées",
+    stats_win_rate: "Taux de victoire",
+    stats_average_guesses: "Essais moyens pour gagner",
+    settings_high_contrast: "Contraste élevé",
+    settings_reduced_motion: "Animations réduites",
+    settings_keyboard: "Disposition du clavier",
+    settings_on: "oui",
+    settings_off: "non",
+    settings_controls: "[↑↓] Choisir | [Enter] Changer | [Esc] Retour",
+    menu_challenge: "Défier un invité",
+    challenge_prompt: "Tapez le mot secret puis passez le clavier",
+    challenge_controls: "[Enter] Passer | [Esc] Retour",
+    challenge_unknown: "Absent de la liste de mots",
+    challenge_start: "Au tour de l'invité : trouvez le mot",
+    challenge_unsaved: "Impossible d'enregistrer le défi",
+    stats_challenges: "Défis gagnés par les invités",
+    menu_history: "Historique",
+    history_controls: "[↑↓] Choisir | [Enter] Revoir | [Esc] Retour",
+    history_unsaved: "Impossible d'enregistrer la partie dans l'historique",
+    replay_label: "Rediffusion",
+    replay_controls: "[Espace] Pause | [→] Essai suivant | [Esc] Retour",
+    replay_paused: "En pause",
+    menu_blitz: "Blitz (3 minutes)",
+    blitz_label: "Blitz",
+    blitz_solved: "Trouvés",
+    blitz_over: "Temps écoulé ! Mots trouvés :",
+    blitz_again: "[Esc] Rejouer | [F10] Menu",
+    blitz_next: "Mot suivant ! La réponse était",
+    menu_countdown: "Essais chronométrés (20s chacun)",
+    countdown_forfeit: "Trop lent ! Ligne perdue",
+    menu_warmup: "Lettre d'échauffement du jour",
+    warmup_over: "La lettre était",
+    menu_speedrun: "Speedrun (contre la montre)",
+    speedrun_label: "Speedrun",
+    speedrun_best: "Record",
+    speedrun_record: "Nouveau record !",
+    records_unsaved: "Impossible d'enregistrer le record",
+    menu_mastermind: "Mastermind (décomptes seulement)",
+    menu_zen: "Zen (essais illimités)",
+    menu_whats_new: "Nouveautés",
+    whats_new_in: "Nouveautés de la version",
+    whats_new_modes: "Modes",
+    whats_new_keys: "Touches",
+    whats_new_other: "Et aussi",
+    whats_new_controls: "[←→] Versions | [D] Ne plus afficher | [Esc] Retour",
+    menu_practice: "Entraînement (Ctrl+Z annule un essai)",
+    practice_undone: "Essai annulé",
+    practice_no_undo: "Aucun essai à annuler",
+    seed_label: "Graine",
+    menu_hotseat: "Duel (deux joueurs)",
+    hotseat_player: "Joueur",
+    hotseat_draws: "Nuls",
+    hotseat_pass: "Passez le clavier au",
+    hotseat_same_word: "Les deux joueurs cherchent le même mot",
+    hotseat_own_words: "Chaque joueur a son propre mot",
+    hotseat_wins: "gagne la manche !",
+    hotseat_draw: "Match nul !",
+    hotseat_controls: "[Enter] Commencer le tour | [S] Même mot | [Esc] Menu",
+    hotseat_over_controls: "[Enter] Manche suivante | [Esc] Menu",
+    rival_title: "Adversaire",
+    rival_playing: "Cherche encore…",
+    rival_won: "Trouvé en",
+    rival_lost: "Plus d'essais",
+    rival_left: "A quitté la course",
+    card_title: "Carte du résultat",
+    card_saved: "Enregistrée dans",
+    card_unsaved: "Impossible d'enregistrer la carte",
+    rival_waiting: "En attente de joueurs…",
+    rival_points: "Points",
+    rival_next_round: "[Esc] Manche suivante",
+    menu_polyglot: "Polyglotte",
+    stats_by_language: "Par langue",
+    filtered_guess: "Ce mot n'est pas autorisé ici",
+    settings_word_filter: "Filtre de mots",
+    spectate_title: "Spectateur de",
+    spectate_round: "Manche",
+    spectate_controls: "[Esc] Arrêter de regarder",
+    spectate_closed: "La connexion au serveur a été perdue",
+    sent_from: "Défi de",
+    sent_reply_saved: "Réponse pour",
+    sent_reply_unsaved: "Impossible d'écrire la réponse",
+    menu_leaderboard: "Records",
+    leaderboard_mode: "Mode",
+    leaderboard_length: "Lettres",
+    leaderboard_wins: "Victoires",
+    leaderboard_guesses: "Essais",
+    leaderboard_time: "Meilleur temps",
+    leaderboard_controls: "[←→] Trier par | [Espace] Inverser | [Esc] Retour",
+    leaderboard_unsaved: "Impossible d'enregistrer les records",
+    mode_classic: "Classique",
+    mode_daily: "Du jour",
+    mode_warmup: "Échauffement",
+    mode_blitz: "Blitz",
+    mode_countdown: "Chronométré",
+    mode_speedrun: "Speedrun",
+    mode_mastermind: "Mastermind",
+    mode_zen: "Zen",
+    mode_polyglot: "Polyglotte",
+    mode_race: "Course",
+    mode_practice: "Entraînement",
+    mode_versus: "Duel",
+    mode_challenge: "Défi",
+    word_rarity: "Ce mot est",
+    rarity_common: "courant",
+    rarity_uncommon: "peu courant",
+    rarity_rare: "rare",
+    stats_rarity_score: "Points de rareté",
+    online_title: "Aujourd'hui en ligne",
+    online_empty: "Aucun résultat pour l'instant",
+    online_unavailable: "Le classement en ligne est injoignable",
+    card_copied: "Copié dans le presse-papiers",
+    card_controls: "[C] Copier | [Esc] Retour au plateau",
+    lock_title: "Verrouillé",
+    lock_any_key: "Appuyez sur une touche pour reprendre",
+    lock_passphrase: "Tapez la phrase secrète puis Entrée",
+    lock_wrong: "Phrase secrète incorrecte",
+    lock_saved: "La partie a été enregistrée",
+    lock_unsaved: "Impossible d'enregistrer la partie",
+    lock_restored: "Votre partie enregistrée est de retour",
+    menu_catch_up: "Rattraper les jours manqués",
+    catch_up_label: "Rattrapage",
+    catch_up_done: "À jour ! Trouvés",
+    catch_up_guesses: "essais en tout",
+    menu_achievements: "Succès",
+    achievement_unlocked: "Succès débloqué",
+    achievements_count: "Obtenus",
+    achievement_first_win: "Première victoire",
+    achievement_first_win_about: "Gagnez une partie",
+    achievement_in_two: "Tireur d'élite",
+    achievement_in_two_about: "Trouvez un mot en 2 essais",
+    achievement_streak: "Sur une lancée",
+    achievement_streak_about: "Gagnez 10 parties d'affilée",
+    achievement_no_hints: "Sans aide",
+    achievement_no_hints_about: "Gagnez sans indice",
+    achievement_hard_mode: "Mode difficile",
+    achievement_hard_mode_about: "Gagnez une partie Mastermind",
+    achievements_unsaved: "Impossible d'enregistrer les succès",
+    typos_title: "Lettres corrigées",
+    typos_most: "Les plus corrigées",
+    typos_unsaved: "Impossible d'enregistrer les corrections de frappe",
+    definition_title: "Définition",
+    definition_loading: "Recherche...",
+    definition_missing: "Aucune définition trouvée",
+    definition_scroll: "↑↓ défiler",
+    definition_unsaved: "Impossible de garder la définition",
+    difficulty_any: "Toutes",
+    difficulty_easy: "Facile",
+    difficulty_medium: "Moyenne",
+    difficulty_hard: "Difficile",
+    end_play_again: "Rejouer",
+    end_change_mode: "Changer de mode",
+    end_analysis: "Analyse",
+    end_share: "Partager",
+    end_quit: "Quitter",
+    settings_quit_confirm: "Confirmer pour quitter",
+    settings_word_theme: "Thème des mots",
+    challenge_paste_code: "ou collez un code de défi",
+    momentum: "Élan",
+    achievement_momentum: "Sur sa lancée",
+    achievement_momentum_about: "Gagnez avec 3 essais de suite trouvant chacun un nouveau vert",
+    pair_label: "Binôme",
+    pair_your_turn: "Vous validez",
+    pair_partner_turn: "Partenaire valide",
+    pair_partner: "Partenaire",
+    pair_partner_submits: "Votre partenaire valide cet essai",
+    pair_left: "Partenaire parti",
+    guess_unknown: "Absent de la liste de mots",
+    settings_status_markers: "Marqueurs d'état",
+    linear_intro: "Devinez le mot : tapez un essai et appuyez sur Entrée. Tapez quit pour partir.",
+    linear_guess: "Essai",
+    linear_correct: "bien placée",
+    linear_present: "mal placée",
+    linear_absent: "absente",
+    linear_play_again: "Rejouer ? (o/n)",
+    too_many_letters: "Trop de lettres",
+    dvr_label: "DVR",
+    dvr_controls: "[←→] Reculer/Avancer | [Début/Fin] Plus ancien/Dernier | [Échap] Retour au jeu",
+    commentary_opened: "Ouverture avec",
+    commentary_left: "qui a laissé",
+    commentary_words: "mots",
+    commentary_guess: "Essai",
+    commentary_solver_pick: "le choix même du solveur",
+    commentary_pivot: "a bien rebondi",
+    commentary_nothing: "n'a rien appris de neuf",
+    commentary_down_to: "réduits à",
+    commentary_solver_had: "le solveur avait",
+    commentary_won: "Trouvé à l'essai",
+    commentary_lucky: "un coup de chance parmi",
+    commentary_lost: "Plus d'essais : le mot était",
+    reminder_title: "Wordle",
+    reminder_body: "Le mot du jour vous attend encore",
+    status_guess: "Essai",
+    status_streak: "Série",
+    pause_title: "En pause",
+    pause_hidden: "Le chrono est arrêté et la grille cachée",
+    pause_controls: "[Ctrl+P] Pause",
+};
@@ -0,0 +1,96 @@
+//! Remapping of typed letters, set in `[keymap]` in the configuration.
+//!
+//! Terminals only send characters, not which physical key was pressed, so
+//! a keyboard whose keys don't match the layout the system is set to types
+//! the wrong letters. With `system` and `printed` layouts, every letter is
+//! read as the one printed in the same place: with a QWERTY system and
+//! AZERTY keys, the top-left key sends `Q` and types `A`. `remap` adds
+//! letters of its own on top.
+//!
+//! Only keys from the keyboard go through the keymap; letters clicked on
+//! the virtual keyboard and pasted text are taken as they are. The keymap
+//! also knows the shortcut that quits at once (`quit`).
+
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::config::KeymapConfig;
+
+#[derive(Debug, Default, Clone)]
+pub struct Keymap {
+    letters: HashMap<char, char>,
+    quit: Option<KeyEvent>,
+}
+
+impl Keymap {
+    pub fn new(config: &KeymapConfig) -> Keymap {
+        let mut letters = HashMap::new();
+        if let Some(system) = config.system {
+            let places = system.rows().iter().zip(config.printed.rows());
+            for (sent, printed) in places {
+                for (sent, printed) in sent.chars().zip(printed.chars()) {
+                    add_cases(&mut letters, sent, printed);
+                }
+            }
+        }
+        // The letters written in the config come last, over their other
+        // case taken from another one
+        for (&sent, &letter) in &config.remap {
+            add_cases(&mut letters, sent, letter);
+        }
+        letters.extend(&config.remap);
+        letters.retain(|sent, printed| sent != printed);
+        Keymap {
+            letters,
+            quit: config.quit,
+        }
+    }
+
+    /// Whether `key` is the shortcut that quits at once, in either case.
+    pub fn is_quit(&self, key: KeyEvent) -> bool {
+        let Some(quit) = self.quit else {
+            return false;
+        };
+        let code = match key.code {
+            KeyCode::Char(c) => KeyCode::Char(c.to_ascii_lowercase()),
+            code => code,
+        };
+        code == quit.code && key.modifiers - KeyModifiers::SHIFT == quit.modifiers
+    }
+
+    /// The letter `c` types.
+    pub fn letter(&self, c: char) -> char {
+        self.letters.get(&c).copied().unwrap_or(c)
+    }
+
+    /// `key` with its letter remapped. Keys held with Ctrl or Alt are
+    /// shortcuts and keep theirs.
+    pub fn apply(&self, key: KeyEvent) -> KeyEvent {
+        match key.code {
+            KeyCode::Char(c) if (key.modifiers - KeyModifiers::SHIFT).is_empty() => KeyEvent {
+                code: KeyCode::Char(self.letter(c)),
+                ..key
+            },
+            _ => key,
+        }
+    }
+}
+
+// Maps `sent` to `letter` in both cases, when each case is a single
+// letter (not `ß`, which is `SS` in uppercase)
+fn add_cases(letters: &mut HashMap<char, char>, sent: char, letter: char) {
+    if let (Some(sent), Some(letter)) = (single(sent.to_lowercase()), single(letter.to_lowercase()))
+    {
+        letters.insert(sent, letter);
+    }
+    if let (Some(sent), Some(letter)) = (single(sent.to_uppercase()), single(letter.to_uppercase()))
+    {
+        letters.insert(sent, letter);
+    }
+}
+
+fn single(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    let c = chars.next()?;
+    chars.next().is_none().then_some(c)
+}
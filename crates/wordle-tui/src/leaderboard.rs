@@ -0,0 +1,223 @@
+//! Personal bests by mode and word length: games won, the fewest guesses
+//! and the best time a word was found in, kept in `leaderboard.json` in the
+//! data directory.
+//!
+//! Only games played alone by the rules of their mode count: not practice
+//! games, whose guesses can be taken back, nor games on a word someone
+//! else chose for the player.
+
+use std::cmp::Ordering;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::game::FeedbackStyle;
+use crate::history::Source;
+use crate::mode::Mode;
+
+/// The modes with a place on the leaderboard, in the order of the menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    Classic,
+    Daily,
+    Warmup,
+    Blitz,
+    Countdown,
+    Speedrun,
+    Mastermind,
+    Zen,
+    Polyglot,
+    Race,
+}
+
+impl Category {
+    /// The category of a game of `mode` on a word from `source`; `None`
+    /// if it doesn't count.
+    pub fn of(mode: &Mode, source: Source, feedback: FeedbackStyle) -> Option<Category> {
+        let category = match (mode, source) {
+            (Mode::Hotseat(_) | Mode::Pair(_), _)
+            | (_, Source::Practice | Source::Hotseat | Source::Challenge | Source::Sent) => {
+                return None
+            }
+            (Mode::Blitz(_), _) => Category::Blitz,
+            (Mode::Countdown(_), _) => Category::Countdown,
+            (Mode::Speedrun(_), _) => Category::Speedrun,
+            (Mode::Polyglot(_), _) => Category::Polyglot,
+            (Mode::Race(_), _) => Category::Race,
+            // Late words of the day are plain games
+            (Mode::CatchUp(_), _) => Category::Classic,
+            (Mode::Classic, Source::Daily) => Category::Daily,
+            (Mode::Classic, Source::Warmup) => Category::Warmup,
+            (Mode::Classic, Source::Zen) => Category::Zen,
+            (Mode::Classic, _) if feedback == FeedbackStyle::Counts => Category::Mastermind,
+            (Mode::Classic, _) => Category::Classic,
+        };
+        Some(category)
+    }
+}
+
+/// Bests of one mode at one word length.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub category: Category,
+    pub word_length: usize,
+    pub wins: u32,
+    pub fewest_guesses: usize,
+    /// Best time from the start of a game to its win, in milliseconds,
+    /// when games were timed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub best_ms: Option<u64>,
+}
+
+impl Entry {
+    pub fn best_time(&self) -> Option<Duration> {
+        self.best_ms.map(Duration::from_millis)
+    }
+}
+
+/// Columns of the leaderboard, which it can be sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Mode,
+    Length,
+    Wins,
+    Guesses,
+    Time,
+}
+
+impl Column {
+    pub const ALL: [Column; 5] = [
+        Column::Mode,
+        Column::Length,
+        Column::Wins,
+        Column::Guesses,
+        Column::Time,
+    ];
+
+    pub fn next(self) -> Column {
+        let index = Column::ALL.iter().position(|&c| c == self).unwrap_or(0);
+        Column::ALL[(index + 1) % Column::ALL.len()]
+    }
+
+    pub fn previous(self) -> Column {
+        let index = Column::ALL.iter().position(|&c| c == self).unwrap_or(0);
+        Column::ALL[(index + Column::ALL.len() - 1) % Column::ALL.len()]
+    }
+}
+
+/// How the leaderboard is sorted: by a column, the best first unless
+/// reversed. The most wins are the best, and the fewest guesses and the
+/// shortest time; modes go in the order of the menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sort {
+    pub column: Column,
+    pub reversed: bool,
+}
+
+impl Default for Sort {
+    fn default() -> Self {
+        Sort {
+            column: Column::Mode,
+            reversed: false,
+        }
+    }
+}
+
+/// Every entry of the leaderboard.
+#[derive(Debug, Default)]
+pub struct Leaderboard {
+    pub entries: Vec<Entry>,
+}
+
+impl Leaderboard {
+    /// Reads the leaderboard, starting afresh if there is none.
+    pub fn load() -> Leaderboard {
+        let entries = Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+        Leaderboard { entries }
+    }
+
+    /// Counts a game of `category` won in `guesses` and, if it was timed,
+    /// `time`, then saves the leaderboard.
+    pub fn submit(
+        &mut self,
+        category: Category,
+        word_length: usize,
+        guesses: usize,
+        time: Option<Duration>,
+    ) -> io::Result<()> {
+        let ms = time.map(|time| time.as_millis() as u64);
+        let entry = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.category == category && entry.word_length == word_length);
+        match entry {
+            Some(entry) => {
+                entry.wins += 1;
+                entry.fewest_guesses = entry.fewest_guesses.min(guesses);
+                entry.best_ms = match (entry.best_ms, ms) {
+                    (Some(best), Some(ms)) => Some(best.min(ms)),
+                    (best, ms) => best.or(ms),
+                };
+            }
+            None => self.entries.push(Entry {
+                category,
+                word_length,
+                wins: 1,
+                fewest_guesses: guesses,
+                best_ms: ms,
+            }),
+        }
+        self.save()
+    }
+
+    /// The entries in the order of `sort`. Ties go by mode, then word
+    /// length; entries without a time come last when sorting by time.
+    pub fn sorted(&self, sort: Sort) -> Vec<&Entry> {
+        let mut entries: Vec<&Entry> = self.entries.iter().collect();
+        let flip = |order: Ordering| {
+            if sort.reversed {
+                order.reverse()
+            } else {
+                order
+            }
+        };
+        entries.sort_by(|a, b| {
+            let modes = (a.category, a.word_length).cmp(&(b.category, b.word_length));
+            let order = match sort.column {
+                Column::Mode => return flip(modes),
+                Column::Length => flip(a.word_length.cmp(&b.word_length)),
+                Column::Wins => flip(b.wins.cmp(&a.wins)),
+                Column::Guesses => flip(a.fewest_guesses.cmp(&b.fewest_guesses)),
+                Column::Time => match (a.best_ms, b.best_ms) {
+                    (Some(a), Some(b)) => flip(a.cmp(&b)),
+                    (a, b) => a.is_none().cmp(&b.is_none()),
+                },
+            };
+            order.then(modes)
+        });
+        entries
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string_pretty(&self.entries).map_err(io::Error::other)?;
+        fs::write(path, text + "\n")
+    }
+
+    fn path() -> Option<PathBuf> {
+        Some(Config::data_dir()?.join("leaderboard.json"))
+    }
+}
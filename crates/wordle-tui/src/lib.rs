@@ -0,0 +1,70 @@
+//! Terminal Wordle.
+//!
+//! The rules live in the `wordle-engine` crate, re-exported here as
+//! [`game`]; it has no terminal dependencies, so it can be driven headless
+//! by tests, bots or other frontends. What is saved and sent (network
+//! messages, history, save files and share codes) is defined in the
+//! `wordle-proto` crate, and the race server in `wordle-server`, so every
+//! program around the game speaks the same formats. This crate is the
+//! terminal frontend:
+//! [`app`] holds its state and input handling, [`ui`] draws it (with the
+//! effects in [`animation`]), with the [`achievements`], [`autosave`],
+//! [`card`], [`challenge`], [`changelog`], [`cli`], [`clock`],
+//! [`commentary`], [`community`], [`config`], [`definitions`], [`doctor`],
+//! [`dvr`], [`export`], [`filter`], [`guesses`], [`history`], [`hotseat`],
+//! [`i18n`], [`keymap`], [`leaderboard`], [`linear`], [`mode`], [`net`],
+//! [`online`], [`overlay`], [`packs`], [`paths`], [`raster`], [`records`],
+//! [`reminder`], [`replay`], [`server`], [`sound`], [`ssh`], [`stats`],
+//! [`term`], [`typos`] and [`word_themes`] support modules.
+
+pub mod achievements;
+pub mod animation;
+pub mod app;
+pub mod autosave;
+pub mod card;
+pub mod challenge;
+pub mod changelog;
+pub mod cli;
+pub mod clock;
+pub mod commentary;
+pub mod community;
+pub mod config;
+pub mod definitions;
+pub mod doctor;
+pub mod dvr;
+pub mod export;
+pub mod filter;
+pub mod guesses;
+pub mod history;
+pub mod hotseat;
+pub mod http;
+pub mod i18n;
+pub mod keymap;
+pub mod leaderboard;
+pub mod linear;
+pub mod mode;
+pub mod net;
+pub mod online;
+pub mod overlay;
+pub mod packs;
+pub mod paths;
+pub mod raster;
+pub mod records;
+pub mod reminder;
+pub mod replay;
+pub mod server;
+pub mod sound;
+pub mod ssh;
+pub mod stats;
+pub mod term;
+pub mod typos;
+pub mod ui;
+pub mod word_themes;
+
+/// The game engine, re-exported from the `wordle-engine` crate.
+pub use wordle_engine as game;
+
+pub use game::{
+    evaluate, Counts, Feedback, FeedbackStyle, Game, GameConfig, GameStatus, GuessError,
+    LetterStatus, TransitionError,
+};
@@ -0,0 +1,112 @@
+//! Linear mode (`--no-tui`), for screen readers: instead of a grid drawn
+//! over the whole screen, a line of text per guess typed and one per
+//! result, read out in the order they come.
+//!
+//! ```text
+//! Guess 1/6: crane
+//! C absent, R present, A correct, N absent, E absent
+//! ```
+//!
+//! Guesses go through the game as if typed on its keyboard, so they are
+//! checked and recorded the same way.
+
+use std::io::{self, BufRead, Write};
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::App;
+use crate::game::normalize::normalize_input;
+use crate::game::{GameStatus, LetterStatus};
+use crate::sound;
+
+/// What is typed instead of a guess to leave.
+const QUIT: &str = "quit";
+
+/// Plays on `app` with guesses read from `input`, a line each, telling
+/// how each went on `output`, until `quit` or the end of the input.
+pub fn play(app: &mut App, mut input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let texts = app.texts();
+    writeln!(output, "{}", texts.linear_intro)?;
+    let mut line = String::new();
+    loop {
+        write!(
+            output,
+            "{} {}/{}: ",
+            texts.linear_guess,
+            app.game.submitted().count() + 1,
+            app.game.config.max_attempts
+        )?;
+        output.flush()?;
+        line.clear();
+        if input.read_line(&mut line)? == 0 || line.trim() == QUIT {
+            writeln!(output)?;
+            return Ok(());
+        }
+        if let Some(said) = guess(app, line.trim()) {
+            writeln!(output, "{}", said)?;
+        }
+        for cue in app.sound.take() {
+            sound::play(cue, &app.sound.config, &mut output)?;
+        }
+        if !app.game.status.is_over() {
+            continue;
+        }
+        let result = match app.game.status {
+            GameStatus::Won => texts.won.to_string(),
+            _ => format!("{} {}", texts.lost, app.game.display_word),
+        };
+        writeln!(output, "{}", result)?;
+        write!(output, "{} ", texts.linear_play_again)?;
+        output.flush()?;
+        line.clear();
+        input.read_line(&mut line)?;
+        // Yes in the languages of the game
+        if !matches!(line.trim().to_lowercase().as_str(), "y" | "s" | "o") {
+            return Ok(());
+        }
+        app.play_random();
+    }
+}
+
+// Types `word` and submits it, giving what became of it: the result of
+// each letter, or why it wasn't taken
+fn guess(app: &mut App, word: &str) -> Option<String> {
+    let texts = app.texts();
+    let typed = normalize_input(word).map_or(0, |letters| letters.len());
+    if typed > app.game.config.word_length {
+        return Some(texts.too_many_letters.to_string());
+    }
+    let before = app.game.submitted().count();
+    app.game.message = None;
+    for c in word.chars() {
+        press(app, KeyCode::Char(c));
+    }
+    press(app, KeyCode::Enter);
+    if app.game.submitted().count() > before {
+        let (letters, statuses) = app.game.submitted().last()?;
+        return Some(
+            letters
+                .iter()
+                .zip(statuses)
+                .map(|(letter, status)| {
+                    let status = match status {
+                        LetterStatus::Correct => texts.linear_correct,
+                        LetterStatus::Present => texts.linear_present,
+                        LetterStatus::Absent | LetterStatus::Unused => texts.linear_absent,
+                    };
+                    format!("{} {}", letter, status)
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+    }
+    // Refused: the row is emptied for the next try
+    while app.game.current_row().is_some_and(|row| !row.is_empty()) {
+        press(app, KeyCode::Backspace);
+    }
+    app.game.message.clone()
+}
+
+fn press(app: &mut App, code: KeyCode) {
+    app.handle_event(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+}
@@ -0,0 +1,212 @@
+use std::io;
+use std::net::TcpListener;
+use std::time::Duration;
+
+use anyhow::Context;
+use crossterm::{
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+    },
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::prelude::*;
+
+use wordle::app::App;
+use wordle::challenge::Playing;
+use wordle::cli::{self, Args, Lan, PlayChallenge};
+use wordle::config::Config;
+use wordle::i18n::Language;
+use wordle::linear;
+use wordle::net;
+use wordle::overlay::Overlay;
+use wordle::reminder;
+use wordle::term;
+use wordle::ui::profile;
+
+fn main() -> anyhow::Result<()> {
+    let mut config = Config::load()?;
+    let mut args = Args::parse()?;
+    let command = args.command.take();
+    let profile_render = args.profile_render.take();
+    let seed = args.seed.take();
+    let word = args.word.take();
+    let any_word = args.any_word;
+    let code = args.code.take();
+    let lan = args.lan.take();
+    let challenge = args.challenge.take();
+    let overlay_file = args.overlay_file.take();
+    let no_tui = args.no_tui;
+    args.apply(&mut config);
+    if let Some(command) = command {
+        return command.run(&config);
+    }
+
+    // A challenge file is played in its own language
+    let sent = match challenge {
+        Some(PlayChallenge { file, name }) => {
+            let playing = Playing::open(file, name.unwrap_or_else(net::default_name))?;
+            config.language = Some(playing.challenge.language.clone());
+            Some(playing)
+        }
+        None => None,
+    };
+    // So is a challenge code
+    if let Some(code) = &code {
+        config.language = Some(code.language.code().to_string());
+    }
+
+    // A race is set up before the terminal is taken over, so the wait and
+    // any error show as plain text
+    let language = Language::detect(config.language.as_deref());
+    let word = match (word, code) {
+        (Some(word), _) => Some(cli::challenge_word(&word, language, any_word)?),
+        (None, Some(code)) => Some(cli::challenge_word(&code.word(), language, true)?),
+        (None, None) => None,
+    };
+    let mut watch = None;
+    let mut pair = None;
+    let race = match lan {
+        Some(Lan::Host { port }) => {
+            let listener = TcpListener::bind(("0.0.0.0", port))
+                .with_context(|| format!("listening on port {}", port))?;
+            println!("Waiting for a player on port {}...", port);
+            Some(net::host(&listener, language)?)
+        }
+        Some(Lan::Join {
+            address,
+            room,
+            name,
+        }) => Some(net::join(
+            &address,
+            language,
+            room.as_deref().unwrap_or(net::DEFAULT_ROOM),
+            &name.unwrap_or_else(net::default_name),
+        )?),
+        Some(Lan::Watch { address, room }) => {
+            watch = Some(net::watch(
+                &address,
+                room.as_deref().unwrap_or(net::DEFAULT_ROOM),
+            )?);
+            None
+        }
+        Some(Lan::PairHost { port }) => {
+            let listener = TcpListener::bind(("0.0.0.0", port))
+                .with_context(|| format!("listening on port {}", port))?;
+            println!("Waiting for a partner on port {}...", port);
+            pair = Some(net::pair_host(&listener, language)?);
+            None
+        }
+        Some(Lan::PairJoin { address }) => {
+            pair = Some(net::pair_join(&address, language)?);
+            None
+        }
+        None => None,
+    };
+
+    // Before the terminal is taken over too, so a file that can't be
+    // written is said plainly
+    let overlay = match overlay_file {
+        Some(path) => Some(
+            Overlay::create(path.clone())
+                .with_context(|| format!("writing the overlay to {}", path.display()))?,
+        ),
+        None => None,
+    };
+
+    // A screen reader gets lines of text, without the terminal taken over
+    if no_tui {
+        let mut app = App::new(&config);
+        app.overlay = overlay;
+        if let Some(playing) = sent {
+            app.play_sent(playing);
+        } else if let Some(word) = word {
+            app.play_challenge(&word);
+        } else if let Some(seed) = seed {
+            app.play_seed(seed);
+        } else {
+            app.play_random();
+        }
+        linear::play(&mut app, io::stdin().lock(), io::stdout())?;
+        return Ok(());
+    }
+
+    // Terminal configuration
+    enable_raw_mode()?;
+    let synchronized = term::synchronized_output_enabled(config.display.synchronized_output);
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableBracketedPaste)?;
+    // The keyboard audit runs without the mouse, so nothing can depend on it
+    if !config.display.keyboard_audit {
+        execute!(stdout, EnableMouseCapture)?;
+    }
+
+    // Create the terminal backend
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    if profile_render.is_some() {
+        profile::enable();
+    }
+
+    // Create game instance
+    let mut app = App::new(&config);
+    if config.reminder.notify && app.daily_pending {
+        reminder::notify(app.texts());
+    }
+    app.sixel = term::sixel_enabled(config.display.sixel);
+    app.overlay = overlay;
+    // A terminal too small waits for the player to make it bigger
+    let size = terminal.size()?;
+    app.resize(size.width, size.height);
+    if let Some(race) = race {
+        app.play_race(race);
+    } else if let Some(pair) = pair {
+        app.play_pair(pair);
+    } else if let Some(watch) = watch {
+        app.watch(watch);
+    } else if let Some(playing) = sent {
+        app.play_sent(playing);
+    } else if let Some(word) = word {
+        app.play_challenge(&word);
+    } else if let Some(seed) = seed {
+        app.play_seed(seed);
+    }
+    let frame_interval = Duration::from_millis(config.display.frame_interval_ms);
+    let result = term::run(
+        &mut terminal,
+        &mut app,
+        synchronized,
+        frame_interval,
+        |timeout| {
+            if event::poll(timeout)? {
+                event::read().map(Some)
+            } else {
+                Ok(None)
+            }
+        },
+    );
+
+    // Restore the terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )?;
+    terminal.show_cursor()?;
+
+    if let Err(e) = result {
+        println!("Error running the game: {}", e);
+        println!("The game was terminated to avoid unexpected behavior.");
+        return Ok(());
+    }
+
+    if let Some(path) = profile_render {
+        profile::write_folded(&path)?;
+        println!("Render profile written to {}", path.display());
+    }
+
+    Ok(())
+}
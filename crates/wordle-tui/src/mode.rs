@@ -0,0 +1,376 @@
+//! Ways to play built on top of a plain [`Game`](crate::game::Game): the
+//! rules of each round stay the engine's, the mode decides what happens
+//! around them (a clock, a score, what comes after a round).
+
+use std::time::{Duration, Instant};
+
+use crate::hotseat::Hotseat;
+use crate::i18n::Language;
+use crate::net::{Pair, Race};
+
+/// How long a blitz lasts.
+pub const BLITZ_DURATION: Duration = Duration::from_secs(180);
+/// Time to submit each guess in a countdown game.
+pub const GUESS_DURATION: Duration = Duration::from_secs(20);
+/// Most missed words of the day a catch-up goes back to.
+pub const CATCH_UP_DAYS: usize = 7;
+
+/// Mode of the game in a tab.
+#[derive(Debug, Default)]
+pub enum Mode {
+    /// One word, as many tries as the board has.
+    #[default]
+    Classic,
+    /// As many words as possible before the clock runs out.
+    Blitz(Blitz),
+    /// One word, with a clock on every guess.
+    Countdown(Countdown),
+    /// One word, as fast as possible, against the best time so far.
+    Speedrun(Speedrun),
+    /// Two players taking turns at the keyboard, each on a board of their
+    /// own.
+    Hotseat(Box<Hotseat>),
+    /// One word, raced against a player on the network.
+    Race(Box<Race>),
+    /// One word on one board, played with a partner on the network.
+    Pair(Box<Pair>),
+    /// One word after another, each in the next of a few languages.
+    Polyglot(Polyglot),
+    /// The words of the days missed, one after another.
+    CatchUp(CatchUp),
+}
+
+impl Mode {
+    /// Whether the mode ended the game on its own, whatever the board says.
+    pub fn is_over(&self) -> bool {
+        match self {
+            Mode::Classic => false,
+            Mode::Blitz(blitz) => blitz.is_over(),
+            Mode::Countdown(_)
+            | Mode::Speedrun(_)
+            | Mode::Hotseat(_)
+            | Mode::Race(_)
+            | Mode::Pair(_)
+            | Mode::Polyglot(_)
+            | Mode::CatchUp(_) => false,
+        }
+    }
+
+    /// Whether the mode plays against a clock, which a pause stops.
+    pub fn is_timed(&self) -> bool {
+        matches!(
+            self,
+            Mode::Blitz(_) | Mode::Countdown(_) | Mode::Speedrun(_)
+        )
+    }
+
+    /// Stops the clock of a timed mode, until [`Mode::resume`].
+    pub fn pause(&mut self) {
+        match self {
+            Mode::Blitz(blitz) => blitz.clock.pause(),
+            Mode::Countdown(countdown) => countdown.row_clock.pause(),
+            Mode::Speedrun(run) => run.clock.iter_mut().for_each(Stopwatch::pause),
+            _ => {}
+        }
+    }
+
+    /// Starts the clock of a timed mode again where it was paused.
+    pub fn resume(&mut self) {
+        match self {
+            Mode::Blitz(blitz) => blitz.clock.resume(),
+            Mode::Countdown(countdown) => countdown.row_clock.resume(),
+            Mode::Speedrun(run) => run.clock.iter_mut().for_each(Stopwatch::resume),
+            _ => {}
+        }
+    }
+
+    /// Whether the clock of a timed mode is in the first half of a second,
+    /// counted from when its digits change; `None` without a clock going.
+    pub fn beat(&self) -> Option<bool> {
+        // Counting down, the digits change as the time left goes past a
+        // whole second
+        let down = |remaining: Duration| {
+            (!remaining.is_zero()).then(|| {
+                let into = remaining.subsec_millis();
+                into == 0 || into > 500
+            })
+        };
+        match self {
+            Mode::Blitz(blitz) => down(blitz.remaining()),
+            Mode::Countdown(countdown) => down(countdown.remaining()),
+            Mode::Speedrun(run) if run.is_running() => Some(run.elapsed().subsec_millis() < 500),
+            _ => None,
+        }
+    }
+}
+
+/// Time since a clock started, less the time it spent paused.
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch {
+    started: Instant,
+    // Time paused before the current pause, if one is going
+    paused: Duration,
+    paused_at: Option<Instant>,
+}
+
+impl Stopwatch {
+    /// A stopwatch going from now.
+    pub fn start() -> Stopwatch {
+        Stopwatch {
+            started: Instant::now(),
+            paused: Duration::ZERO,
+            paused_at: None,
+        }
+    }
+
+    /// Time counted so far, frozen while paused.
+    pub fn elapsed(&self) -> Duration {
+        let now = self.paused_at.unwrap_or_else(Instant::now);
+        now.saturating_duration_since(self.started)
+            .saturating_sub(self.paused)
+    }
+
+    /// Stops counting, unless it already is stopped.
+    pub fn pause(&mut self) {
+        self.paused_at.get_or_insert_with(Instant::now);
+    }
+
+    /// Counts again, leaving out the time paused.
+    pub fn resume(&mut self) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.paused += paused_at.elapsed();
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+}
+
+/// A countdown shared by every word of the blitz: solving a word loads the
+/// next one without resetting it.
+#[derive(Debug, Clone)]
+pub struct Blitz {
+    duration: Duration,
+    clock: Stopwatch,
+    /// Words found so far.
+    pub solved: usize,
+    /// Words whose rows ran out.
+    pub missed: usize,
+}
+
+impl Blitz {
+    /// Starts the clock, `duration` from now.
+    pub fn start(duration: Duration) -> Blitz {
+        Blitz {
+            duration,
+            clock: Stopwatch::start(),
+            solved: 0,
+            missed: 0,
+        }
+    }
+
+    /// Time left on the clock.
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.clock.elapsed())
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.remaining().is_zero()
+    }
+}
+
+/// A clock on the row being typed: the guess must be in before it runs
+/// out, or the row is forfeited. Each new row starts it again.
+#[derive(Debug, Clone)]
+pub struct Countdown {
+    limit: Duration,
+    row: usize,
+    row_clock: Stopwatch,
+}
+
+impl Countdown {
+    /// Starts the clock of the first row, `limit` per guess.
+    pub fn start(limit: Duration) -> Countdown {
+        Countdown {
+            limit,
+            row: 0,
+            row_clock: Stopwatch::start(),
+        }
+    }
+
+    /// Keeps up with the board: the clock starts again once `row` is not
+    /// the one it was timing.
+    pub fn follow(&mut self, row: usize) {
+        if row != self.row {
+            self.row = row;
+            self.row_clock = Stopwatch::start();
+        }
+    }
+
+    /// Time left for the current row.
+    pub fn remaining(&self) -> Duration {
+        self.limit.saturating_sub(self.row_clock.elapsed())
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.remaining().is_zero()
+    }
+}
+
+/// A stopwatch from the first letter typed to the end of the game, read to
+/// the millisecond.
+#[derive(Debug, Clone, Default)]
+pub struct Speedrun {
+    clock: Option<Stopwatch>,
+    finished: Option<Duration>,
+    /// Best time so far for words of this length.
+    pub best: Option<Duration>,
+    /// Whether the run beat `best`.
+    pub new_record: bool,
+}
+
+impl Speedrun {
+    /// A run to beat `best`, its clock still waiting for the first letter.
+    pub fn new(best: Option<Duration>) -> Speedrun {
+        Speedrun {
+            best,
+            ..Speedrun::default()
+        }
+    }
+
+    /// Starts the clock, unless it already is.
+    pub fn start(&mut self) {
+        self.clock.get_or_insert_with(Stopwatch::start);
+    }
+
+    /// Stops the clock for good, returning the time of the run.
+    pub fn stop(&mut self) -> Duration {
+        let time = self.elapsed();
+        self.finished = Some(time);
+        time
+    }
+
+    /// Time on the clock: zero until the first letter, frozen once stopped.
+    pub fn elapsed(&self) -> Duration {
+        match (self.finished, self.clock) {
+            (Some(time), _) => time,
+            (None, Some(clock)) => clock.elapsed(),
+            (None, None) => Duration::ZERO,
+        }
+    }
+
+    /// Whether the clock is going, so it needs redrawing more often than
+    /// the main loop ticks.
+    pub fn is_running(&self) -> bool {
+        self.clock.is_some_and(|clock| !clock.is_paused()) && self.finished.is_none()
+    }
+}
+
+/// Languages taken in turn, a game each, for learners of more than one:
+/// each game draws from the word list of its language and is played with
+/// the interface in it.
+#[derive(Debug, Clone)]
+pub struct Polyglot {
+    /// The languages, in the order they come.
+    pub languages: Vec<Language>,
+    /// Index in `languages` of the one being played.
+    pub current: usize,
+}
+
+impl Polyglot {
+    /// Languages played when none are configured.
+    pub const DEFAULT: [Language; 3] = [Language::En, Language::Pt, Language::Es];
+
+    /// Turns through the languages of `codes`, skipping unknown codes and
+    /// repeats; through [`Polyglot::DEFAULT`] if that leaves none.
+    pub fn from_codes(codes: &[String]) -> Polyglot {
+        let mut languages: Vec<Language> = Vec::new();
+        for language in codes.iter().filter_map(|code| Language::from_code(code)) {
+            if !languages.contains(&language) {
+                languages.push(language);
+            }
+        }
+        if languages.is_empty() {
+            languages = Polyglot::DEFAULT.to_vec();
+        }
+        Polyglot {
+            languages,
+            current: 0,
+        }
+    }
+
+    /// Language of the game being played.
+    pub fn language(&self) -> Language {
+        self.languages[self.current]
+    }
+
+    /// Moves on to the next language, back to the first after the last,
+    /// and returns it.
+    pub fn advance(&mut self) -> Language {
+        self.current = (self.current + 1) % self.languages.len();
+        self.language()
+    }
+}
+
+/// Words of the day missed, played back to back as archive games, with
+/// how each went for the summary at the end.
+#[derive(Debug, Clone)]
+pub struct CatchUp {
+    /// The days, oldest first, counted from the Unix epoch.
+    pub days: Vec<u64>,
+    /// Index in `days` of the one being played.
+    pub current: usize,
+    /// Whether the word of each day played so far was found, and in how
+    /// many guesses.
+    pub results: Vec<(bool, usize)>,
+}
+
+impl CatchUp {
+    /// A catch-up of `days`, which shouldn't be empty, starting with the
+    /// first.
+    pub fn new(days: Vec<u64>) -> CatchUp {
+        CatchUp {
+            days,
+            current: 0,
+            results: Vec::new(),
+        }
+    }
+
+    /// Day of the word being played.
+    pub fn day(&self) -> u64 {
+        self.days[self.current]
+    }
+
+    /// Keeps how the word of the current day went.
+    pub fn record(&mut self, won: bool, guesses: usize) {
+        if self.results.len() == self.current {
+            self.results.push((won, guesses));
+        }
+    }
+
+    /// Moves on to the next day once the current one is played, and
+    /// returns it; `None` after the last.
+    pub fn advance(&mut self) -> Option<u64> {
+        if self.results.len() <= self.current || self.current + 1 >= self.days.len() {
+            return None;
+        }
+        self.current += 1;
+        Some(self.day())
+    }
+
+    /// Whether every day was played.
+    pub fn is_done(&self) -> bool {
+        self.results.len() == self.days.len()
+    }
+
+    /// Words found, and the guesses they took in all.
+    pub fn found(&self) -> (usize, usize) {
+        self.results
+            .iter()
+            .filter(|(won, _)| *won)
+            .fold((0, 0), |(found, guesses), (_, taken)| {
+                (found + 1, guesses + taken)
+            })
+    }
+}
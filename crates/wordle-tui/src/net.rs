@@ -0,0 +1,580 @@
+//! Racing other players over the network: one on the local network
+//! (`wordle host` and `wordle join <address>`), or any number in a room of
+//! a server (`wordle serve`, see [`crate::server`]). Everyone plays the
+//! word the same seed draws; each guess sends its colors, never its
+//! letters, so the rivals' rows can be shown without giving the word away.
+//!
+//! The messages sent, one JSON object per line over TCP, are those of
+//! [`wordle_proto::net`], re-exported here.
+
+use std::collections::VecDeque;
+use std::io;
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+
+use crate::game::{word_list_checksum, Game, GameStatus, LetterStatus};
+use crate::i18n::Language;
+
+pub use wordle_proto::net::{
+    decode_row, encode_row, read_messages, write_message, Message, Score, DEFAULT_PORT,
+    DEFAULT_ROOM, PROTOCOL_VERSION,
+};
+
+/// How long the joining player waits for the host to start the race.
+const START_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A connection to the other player. Their messages are read on another
+/// thread, so the game never waits for the network.
+#[derive(Debug)]
+pub struct Peer {
+    writer: TcpStream,
+    incoming: Receiver<Message>,
+}
+
+impl Peer {
+    pub fn new(stream: TcpStream) -> io::Result<Peer> {
+        let reader = stream.try_clone()?;
+        let (sender, incoming) = mpsc::channel();
+        // The thread ends, and the channel with it, when the connection does
+        thread::spawn(move || read_messages(reader, |message| sender.send(message).is_ok()));
+        Ok(Peer {
+            writer: stream,
+            incoming,
+        })
+    }
+
+    pub fn send(&mut self, message: &Message) -> io::Result<()> {
+        write_message(&mut self.writer, message)
+    }
+}
+
+impl Drop for Peer {
+    // The reader thread holds the socket too, so it must be shut down for
+    // the other side to see the connection end
+    fn drop(&mut self) {
+        let _ = self.writer.shutdown(Shutdown::Both);
+    }
+}
+
+/// How a rival's game ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RivalResult {
+    pub won: bool,
+    pub guesses: usize,
+}
+
+/// What is known of a rival's board.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Rival {
+    /// Their name in the room; the player of a host has none.
+    pub name: Option<String>,
+    /// Colors of their guesses so far.
+    pub rows: Vec<Vec<LetterStatus>>,
+    /// How their game ended, once it has.
+    pub result: Option<RivalResult>,
+    /// Whether they left the room.
+    pub left: bool,
+}
+
+/// A race in progress: the connection, and what is known of the rivals'
+/// boards.
+#[derive(Debug)]
+pub struct Race {
+    peer: Peer,
+    /// Seed of the word everyone looks for.
+    pub seed: u64,
+    /// The room of the server the race is in; `None` against a host.
+    pub room: Option<String>,
+    /// Our name in the room.
+    pub name: String,
+    /// The other players: the one of a host, or those of the room.
+    pub rivals: Vec<Rival>,
+    /// Points of everyone in the room, ourselves included.
+    pub scores: Vec<Score>,
+    /// The seed of the next round, once the server started it.
+    pub next_seed: Option<u64>,
+    /// Whether the connection dropped.
+    pub disconnected: bool,
+    // Rows and ends of the next round, heard before we moved on to it
+    early: Vec<Message>,
+    // Guesses of ours sent so far, and whether our end was
+    sent: usize,
+    sent_done: bool,
+}
+
+impl Race {
+    pub fn new(peer: Peer, seed: u64, room: Option<String>, name: String) -> Race {
+        // A host is a single rival, there from the start
+        let rivals = match room {
+            Some(_) => Vec::new(),
+            None => vec![Rival::default()],
+        };
+        Race {
+            peer,
+            seed,
+            room,
+            name,
+            rivals,
+            scores: Vec::new(),
+            next_seed: None,
+            disconnected: false,
+            early: Vec::new(),
+            sent: 0,
+            sent_done: false,
+        }
+    }
+
+    /// Sends the guesses of `game` the rivals haven't seen yet, and its end
+    /// once it's over.
+    pub fn send_progress(&mut self, game: &Game) {
+        if self.disconnected {
+            return;
+        }
+        let rows: Vec<String> = game
+            .submitted()
+            .skip(self.sent)
+            .map(|(_, statuses)| encode_row(statuses))
+            .collect();
+        for feedback in rows {
+            let row = Message::Row {
+                player: None,
+                feedback,
+            };
+            if self.peer.send(&row).is_err() {
+                self.disconnected = true;
+                return;
+            }
+            self.sent += 1;
+        }
+        if game.status.is_over() && !self.sent_done {
+            let done = Message::Done {
+                player: None,
+                won: game.status == GameStatus::Won,
+                guesses: self.sent,
+            };
+            self.disconnected = self.peer.send(&done).is_err();
+            self.sent_done = true;
+        }
+    }
+
+    /// Picks up what the rivals sent since the last call.
+    pub fn poll(&mut self) {
+        loop {
+            match self.peer.incoming.try_recv() {
+                Ok(message @ (Message::Row { .. } | Message::Done { .. }))
+                    if self.next_seed.is_some() =>
+                {
+                    self.early.push(message)
+                }
+                Ok(message) => self.hear(message),
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    self.disconnected = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Moves on to the round the server started, returning its seed; `None`
+    /// if it hasn't started one.
+    pub fn next_round(&mut self) -> Option<u64> {
+        self.seed = self.next_seed.take()?;
+        self.rivals.retain(|rival| !rival.left);
+        for rival in &mut self.rivals {
+            rival.rows.clear();
+            rival.result = None;
+        }
+        self.sent = 0;
+        self.sent_done = false;
+        for message in std::mem::take(&mut self.early) {
+            self.hear(message);
+        }
+        Some(self.seed)
+    }
+
+    fn hear(&mut self, message: Message) {
+        match message {
+            Message::Row { player, feedback } => {
+                if let Some(row) = decode_row(&feedback) {
+                    self.rival(player).rows.push(row);
+                }
+            }
+            Message::Done {
+                player,
+                won,
+                guesses,
+            } => self.rival(player).result = Some(RivalResult { won, guesses }),
+            Message::Left { player } => self.rival(Some(player)).left = true,
+            Message::Scores { scores } => {
+                // Everyone with a score is in the room
+                for score in &scores {
+                    if score.name != self.name {
+                        self.rival(Some(score.name.clone())).left = false;
+                    }
+                }
+                self.scores = scores;
+            }
+            // A new round, once the race started
+            Message::Start { seed, .. } => self.next_seed = Some(seed),
+            Message::Join { .. }
+            | Message::Watch { .. }
+            | Message::Refused { .. }
+            | Message::Typing { .. }
+            | Message::Guess { .. } => {}
+        }
+    }
+
+    fn rival(&mut self, name: Option<String>) -> &mut Rival {
+        rival(&mut self.rivals, name)
+    }
+}
+
+// The one of `rivals` called `name`, met now if they are new
+fn rival(rivals: &mut Vec<Rival>, name: Option<String>) -> &mut Rival {
+    let index = match rivals.iter().position(|rival| rival.name == name) {
+        Some(index) => index,
+        None => {
+            rivals.push(Rival {
+                name,
+                ..Rival::default()
+            });
+            rivals.len() - 1
+        }
+    };
+    &mut rivals[index]
+}
+
+/// A room of a server seen by a spectator: the boards of its players, in
+/// colors only, as they play.
+#[derive(Debug)]
+pub struct Watch {
+    peer: Peer,
+    /// The room watched.
+    pub room: String,
+    /// Everyone playing in the room, in the order they were met.
+    pub players: Vec<Rival>,
+    /// Points of the players.
+    pub scores: Vec<Score>,
+    /// Rounds seen so far, the current one included.
+    pub round: u32,
+    /// Whether the connection dropped.
+    pub disconnected: bool,
+}
+
+impl Watch {
+    /// Picks up what the players did since the last call.
+    pub fn poll(&mut self) {
+        loop {
+            match self.peer.incoming.try_recv() {
+                Ok(message) => self.hear(message),
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    self.disconnected = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    fn hear(&mut self, message: Message) {
+        match message {
+            Message::Row {
+                player: Some(player),
+                feedback,
+            } => {
+                if let Some(row) = decode_row(&feedback) {
+                    rival(&mut self.players, Some(player)).rows.push(row);
+                }
+            }
+            Message::Done {
+                player: Some(player),
+                won,
+                guesses,
+            } => rival(&mut self.players, Some(player)).result = Some(RivalResult { won, guesses }),
+            Message::Left { player } => rival(&mut self.players, Some(player)).left = true,
+            Message::Scores { scores } => {
+                for score in &scores {
+                    rival(&mut self.players, Some(score.name.clone())).left = false;
+                }
+                self.scores = scores;
+            }
+            // The boards start over with every round
+            Message::Start { .. } => {
+                self.round += 1;
+                self.players.retain(|player| !player.left);
+                for player in &mut self.players {
+                    player.rows.clear();
+                    player.result = None;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A word played as a pair: one board, the same on both sides, where each
+/// sees what the other types and the holder of the turn submits.
+#[derive(Debug)]
+pub struct Pair {
+    peer: Peer,
+    /// Seed of the word played.
+    pub seed: u64,
+    /// Letters the partner typed so far in the current row.
+    pub typing: Vec<char>,
+    /// Whether we hold the turn, and so submit the next guess.
+    pub our_turn: bool,
+    /// Whether the connection dropped.
+    pub disconnected: bool,
+    // Rows of the board, ours and the partner's guesses both
+    rows: usize,
+    // Guesses of the partner not played on our board yet
+    guesses: VecDeque<String>,
+    // Letters of ours the partner last heard of
+    sent_typing: Vec<char>,
+}
+
+impl Pair {
+    pub fn new(peer: Peer, seed: u64, our_turn: bool) -> Pair {
+        Pair {
+            peer,
+            seed,
+            typing: Vec::new(),
+            our_turn,
+            disconnected: false,
+            rows: 0,
+            guesses: VecDeque::new(),
+            sent_typing: Vec::new(),
+        }
+    }
+
+    /// Sends the guesses submitted on `game` the partner hasn't played yet,
+    /// which passes them the turn, then the letters typed since last time.
+    pub fn send_progress(&mut self, game: &Game) {
+        if self.disconnected {
+            return;
+        }
+        let words: Vec<String> = game
+            .submitted()
+            .skip(self.rows)
+            .map(|(letters, _)| letters.iter().collect())
+            .collect();
+        for word in words {
+            if self.peer.send(&Message::Guess { word }).is_err() {
+                self.disconnected = true;
+                return;
+            }
+            self.rows += 1;
+            self.our_turn = false;
+            self.typing.clear();
+            self.sent_typing.clear();
+        }
+        let typed = match game.current_row() {
+            Some(row) if game.status.accepts_input() => row.to_vec(),
+            _ => Vec::new(),
+        };
+        if typed != self.sent_typing {
+            let typing = Message::Typing {
+                row: self.rows,
+                letters: typed.iter().collect(),
+            };
+            self.disconnected = self.peer.send(&typing).is_err();
+            self.sent_typing = typed;
+        }
+    }
+
+    /// Picks up what the partner typed and submitted since the last call.
+    pub fn poll(&mut self) {
+        loop {
+            match self.peer.incoming.try_recv() {
+                Ok(message) => self.hear(message),
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    // Alone at the board, we submit from now on
+                    self.disconnected = true;
+                    self.our_turn = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// The next guess the partner submitted, to play on our board.
+    pub fn next_guess(&mut self) -> Option<String> {
+        let word = self.guesses.pop_front()?;
+        // The row we were typing is gone with it
+        self.sent_typing.clear();
+        Some(word)
+    }
+
+    fn hear(&mut self, message: Message) {
+        match message {
+            // Letters of a row already played arrive late, and are dropped
+            Message::Typing { row, letters } if row == self.rows => {
+                self.typing = letters.chars().collect();
+            }
+            Message::Guess { word } => {
+                self.guesses.push_back(word);
+                self.rows += 1;
+                self.our_turn = true;
+                self.typing.clear();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Waits on `listener` for a player to join, then starts a race on a word
+/// drawn at random from the word list of `language`.
+pub fn host(listener: &TcpListener, language: Language) -> anyhow::Result<Race> {
+    let (peer, seed) = accept(listener, language, false).context("starting the race")?;
+    Ok(Race::new(peer, seed, None, String::new()))
+}
+
+/// Waits on `listener` for a partner to join, then starts a pair on a word
+/// drawn at random from the word list of `language`. The host submits
+/// first.
+pub fn pair_host(listener: &TcpListener, language: Language) -> anyhow::Result<Pair> {
+    let (peer, seed) = accept(listener, language, true).context("starting the pair")?;
+    Ok(Pair::new(peer, seed, true))
+}
+
+// Waits on `listener` for someone to join, then sends them the start of a
+// race or a pair on a random word
+fn accept(listener: &TcpListener, language: Language, pair: bool) -> anyhow::Result<(Peer, u64)> {
+    let (stream, _) = listener.accept().context("waiting for a player")?;
+    let mut peer = Peer::new(stream)?;
+    let seed = rand::random();
+    peer.send(&Message::Start {
+        version: PROTOCOL_VERSION,
+        seed,
+        language: language.code().to_string(),
+        checksum: word_list_checksum(&language.load_words()),
+        room: None,
+        pair,
+    })?;
+    Ok((peer, seed))
+}
+
+/// Joins the race hosted at `address` (`host` or `host:port`), which must
+/// be played in `language` with the same word list. On a server, the race
+/// is the one of `room`, where the others see us as `name`.
+pub fn join(address: &str, language: Language, room: &str, name: &str) -> anyhow::Result<Race> {
+    let (peer, seed, room) = connect(address, language, room, name, false)?;
+    Ok(Race::new(peer, seed, room, name.to_string()))
+}
+
+/// Joins the pair hosted at `address` (`host` or `host:port`), which must
+/// be played in `language` with the same word list.
+pub fn pair_join(address: &str, language: Language) -> anyhow::Result<Pair> {
+    let (peer, seed, _) = connect(address, language, DEFAULT_ROOM, &default_name(), true)?;
+    Ok(Pair::new(peer, seed, false))
+}
+
+// Joins the race or the pair at `address`, returning the connection, the
+// seed of the word and the room of a server
+fn connect(
+    address: &str,
+    language: Language,
+    room: &str,
+    name: &str,
+    pair: bool,
+) -> anyhow::Result<(Peer, u64, Option<String>)> {
+    let address = with_port(address);
+    let stream = TcpStream::connect(&address).with_context(|| format!("joining {}", address))?;
+    let mut peer = Peer::new(stream)?;
+    peer.send(&Message::Join {
+        version: PROTOCOL_VERSION,
+        room: room.to_string(),
+        name: name.to_string(),
+        language: language.code().to_string(),
+    })
+    .with_context(|| format!("joining {}", address))?;
+    let start = peer
+        .incoming
+        .recv_timeout(START_TIMEOUT)
+        .with_context(|| format!("{} didn't start the race", address))?;
+    let (version, seed, code, checksum, room, pairing) = match start {
+        Message::Start {
+            version,
+            seed,
+            language,
+            checksum,
+            room,
+            pair,
+        } => (version, seed, language, checksum, room, pair),
+        Message::Refused { reason } => bail!("{} turned us away: {}", address, reason),
+        _ => bail!("{} didn't start the race", address),
+    };
+    if version != PROTOCOL_VERSION {
+        bail!(
+            "the host speaks version {} of the protocol, this game version {}",
+            version,
+            PROTOCOL_VERSION
+        );
+    }
+    if code != language.code() {
+        bail!("the host plays in '{}': join with --lang {}", code, code);
+    }
+    if checksum != word_list_checksum(&language.load_words()) {
+        bail!("the host has a different '{}' word list", code);
+    }
+    match (pair, pairing) {
+        (false, true) => bail!("{} plays as a pair: join with wordle pair join", address),
+        (true, false) => bail!("{} hosts a race: join with wordle join", address),
+        _ => Ok((peer, seed, room)),
+    }
+}
+
+/// Watches `room` on the server at `address` (`host` or `host:port`).
+/// Anyone can watch a room someone plays in, whatever their language.
+pub fn watch(address: &str, room: &str) -> anyhow::Result<Watch> {
+    let address = with_port(address);
+    let stream = TcpStream::connect(&address).with_context(|| format!("watching {}", address))?;
+    let mut peer = Peer::new(stream)?;
+    peer.send(&Message::Watch {
+        version: PROTOCOL_VERSION,
+        room: room.to_string(),
+    })
+    .with_context(|| format!("watching {}", address))?;
+    let start = peer
+        .incoming
+        .recv_timeout(START_TIMEOUT)
+        .with_context(|| format!("{} didn't answer", address))?;
+    match start {
+        Message::Start { .. } => {}
+        Message::Refused { reason } => bail!("{} turned us away: {}", address, reason),
+        _ => bail!("{} didn't answer", address),
+    }
+    Ok(Watch {
+        peer,
+        room: room.to_string(),
+        players: Vec::new(),
+        scores: Vec::new(),
+        round: 1,
+        disconnected: false,
+    })
+}
+
+// `address` with the default port if it has none
+fn with_port(address: &str) -> String {
+    if address.contains(':') {
+        address.to_string()
+    } else {
+        format!("{}:{}", address, DEFAULT_PORT)
+    }
+}
+
+/// The name to join a room under when none is given: the user's login,
+/// or `player`.
+pub fn default_name() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok()
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "player".to_string())
+}
@@ -0,0 +1,160 @@
+//! Results of the word of the day shared with an online leaderboard, for
+//! builds with the `online-leaderboard` feature. Nothing is sent unless the
+//! configuration opts in:
+//!
+//! ```toml
+//! [online]
+//! leaderboard_url = "https://example.org/wordle/daily"
+//! submit_daily = true
+//! name = "ana"
+//! ```
+//!
+//! Once a daily game is over its result is POSTed to the address as JSON,
+//! with how each guess was typed so the server can run the same check for
+//! scripts as [`crate::game::timing`]:
+//!
+//! ```json
+//! {
+//!   "puzzle": 20378,
+//!   "language": "en",
+//!   "name": "ana",
+//!   "won": true,
+//!   "guesses": 3,
+//!   "time_ms": 81234,
+//!   "typing": [{ "intervals_ms": [180, 210, 150, 190], "pasted": false }]
+//! }
+//! ```
+//!
+//! Then `GET <address>?puzzle=20378&language=en` answers with the results
+//! of that puzzle, a JSON array of `{"name", "won", "guesses", "time_ms"}`
+//! objects; the best ten are shown next to the board.
+
+use std::sync::mpsc::{self, Receiver};
+#[cfg(feature = "online-leaderboard")]
+use std::{thread, time::Duration};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::config::OnlineConfig;
+use crate::game::timing::TimingSignature;
+use crate::net::default_name;
+
+/// Whether this build can share results.
+pub const AVAILABLE: bool = cfg!(feature = "online-leaderboard");
+/// Results shown from the leaderboard.
+pub const TOP: usize = 10;
+/// Gives up on the server after this long.
+#[cfg(feature = "online-leaderboard")]
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A daily game as sent to the leaderboard.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Submission {
+    /// Days since 1970-01-01, which draws the word of the day.
+    pub puzzle: u64,
+    pub language: String,
+    pub name: String,
+    pub won: bool,
+    pub guesses: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_ms: Option<u64>,
+    pub typing: Vec<Typed>,
+}
+
+/// How one guess was typed: milliseconds between its letters, and whether
+/// any were pasted.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Typed {
+    pub intervals_ms: Vec<u32>,
+    pub pasted: bool,
+}
+
+impl Typed {
+    /// Every guess of `timing`.
+    pub fn all(timing: &TimingSignature) -> Vec<Typed> {
+        timing
+            .guesses
+            .iter()
+            .map(|guess| Typed {
+                intervals_ms: guess.intervals_ms.clone(),
+                pasted: guess.pasted,
+            })
+            .collect()
+    }
+}
+
+/// A result on the leaderboard.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Score {
+    pub name: String,
+    pub won: bool,
+    pub guesses: usize,
+    #[serde(default)]
+    pub time_ms: Option<u64>,
+}
+
+/// The best [`TOP`] results of the server's answer: wins first, then the
+/// fewest guesses, then the fastest.
+pub fn top(json: &str) -> anyhow::Result<Vec<Score>> {
+    let mut scores: Vec<Score> = serde_json::from_str(json).context("reading the leaderboard")?;
+    scores.sort_by_key(|score| (!score.won, score.guesses, score.time_ms.unwrap_or(u64::MAX)));
+    scores.truncate(TOP);
+    Ok(scores)
+}
+
+/// Where and as whom results are shared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Online {
+    pub url: String,
+    pub name: String,
+}
+
+impl Online {
+    /// The leaderboard of the configuration, if it opted in and this build
+    /// can share results.
+    pub fn from_config(config: &OnlineConfig) -> Option<Online> {
+        if !AVAILABLE || !config.submit_daily {
+            return None;
+        }
+        Some(Online {
+            url: config.leaderboard_url.clone()?,
+            name: config.name.clone().unwrap_or_else(default_name),
+        })
+    }
+
+    /// Sends `submission` and fetches the leaderboard of its puzzle on
+    /// another thread, so the game doesn't wait for the network. Sends
+    /// `None` if either fails.
+    pub fn share_in_background(&self, submission: Submission) -> Receiver<Option<Vec<Score>>> {
+        let (sender, receiver) = mpsc::channel();
+        #[cfg(feature = "online-leaderboard")]
+        {
+            let url = self.url.clone();
+            thread::spawn(move || {
+                let _ = sender.send(share(&url, &submission).ok());
+            });
+        }
+        #[cfg(not(feature = "online-leaderboard"))]
+        let _ = (sender, submission);
+        receiver
+    }
+}
+
+#[cfg(feature = "online-leaderboard")]
+fn share(url: &str, submission: &Submission) -> anyhow::Result<Vec<Score>> {
+    let body = serde_json::to_string(submission)?;
+    ureq::post(url)
+        .timeout(TIMEOUT)
+        .set("Content-Type", "application/json")
+        .send_string(&body)
+        .with_context(|| format!("sending the result to {}", url))?;
+    let json = ureq::get(url)
+        .timeout(TIMEOUT)
+        .query("puzzle", &submission.puzzle.to_string())
+        .query("language", &submission.language)
+        .call()
+        .with_context(|| format!("fetching the leaderboard of {}", url))?
+        .into_string()?;
+    top(&json)
+}
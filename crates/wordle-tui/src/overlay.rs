@@ -0,0 +1,78 @@
+//! Status line for streaming overlays (`--overlay-file <path>`): the game
+//! in progress as one line of text, kept in a file that OBS and the like
+//! can show over the stream.
+//!
+//! ```text
+//! Daily #812 — attempt 3/6 — 🟩🟨⬛⬛⬛
+//! ```
+//!
+//! The line never gives a letter away, only the colors of the last guess.
+//! It is written to a file next to the target and renamed over it, so a
+//! reader never finds it half written.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::card;
+use crate::game::{Game, GameStatus};
+
+/// The file the line is kept in, and the line last written to it.
+#[derive(Debug)]
+pub struct Overlay {
+    path: PathBuf,
+    written: Option<String>,
+}
+
+impl Overlay {
+    /// Starts the file at `path` empty, failing if it can't be written.
+    pub fn create(path: PathBuf) -> io::Result<Overlay> {
+        let mut overlay = Overlay {
+            path,
+            written: None,
+        };
+        overlay.write(String::new())?;
+        Ok(overlay)
+    }
+
+    /// Writes `line` unless it is already the one in the file.
+    pub fn update(&mut self, line: String) -> io::Result<()> {
+        if self.written.as_ref() == Some(&line) {
+            return Ok(());
+        }
+        self.write(line)
+    }
+
+    fn write(&mut self, line: String) -> io::Result<()> {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(".tmp");
+        let temporary = self.path.with_file_name(name);
+        fs::write(&temporary, format!("{}\n", line))?;
+        fs::rename(&temporary, &self.path)?;
+        self.written = Some(line);
+        Ok(())
+    }
+}
+
+/// The line of `game`, under `title`: the guess being played out of those
+/// allowed (once over, how many it took), and the colors of the last one.
+pub fn line(title: &str, game: &Game, high_contrast: bool) -> String {
+    let rows: Vec<_> = game.submitted().map(|(_, statuses)| statuses).collect();
+    let max = game.config.max_attempts;
+    let progress = match game.status {
+        GameStatus::Won => format!("solved {}/{}", rows.len(), max),
+        GameStatus::Lost => format!("X/{}", max),
+        GameStatus::Playing | GameStatus::Quitting => {
+            format!("attempt {}/{}", rows.len() + 1, max)
+        }
+    };
+    match rows.last() {
+        Some(last) => format!(
+            "{} — {} — {}",
+            title,
+            progress,
+            card::squares(last, high_contrast)
+        ),
+        None => format!("{} — {}", title, progress),
+    }
+}
@@ -0,0 +1,272 @@
+//! Themed word lists to install from an index (`[packs] index_url`), and
+//! whether to trust them. The index is a JSON document:
+//!
+//! ```json
+//! {
+//!   "packs": [
+//!     {
+//!       "name": "birds",
+//!       "words": "https://example.org/birds.txt",
+//!       "metadata": "https://example.org/birds.toml",
+//!       "signature": "9c0f…"
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `wordle pack install birds` downloads the pack into `themes/` of the
+//! data directory, where it is played like any theme (see
+//! [`crate::word_themes`]).
+//!
+//! A pack is signed with Ed25519: `signature` is the signature, in hex, of
+//! its name and the length and SHA-256 of its word list and of its
+//! metadata, so no byte can pass from one file to the other, nor the files
+//! to a pack of another name. `wordle pack sign` makes it. It
+//! is checked against the public keys of `trusted_keys`, and `trust` says
+//! what becomes of packs none of them signed: `warn` (the default) installs
+//! them with a warning, `require` refuses them, and `off` doesn't look at
+//! signatures at all.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::config::{PacksConfig, TrustPolicy};
+use crate::http;
+use crate::paths;
+use crate::word_themes::WordTheme;
+
+/// Gives up on the index or a pack after this long.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A pack the index offers.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PackEntry {
+    /// Name of the theme once installed, as written in `word_theme`.
+    pub name: String,
+    /// Address of its word list.
+    pub words: String,
+    /// Address of its metadata, if it has some.
+    #[serde(default)]
+    pub metadata: Option<String>,
+    /// Signature of the name, word list and metadata, in hex.
+    #[serde(default)]
+    pub signature: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Index {
+    packs: Vec<PackEntry>,
+}
+
+/// What the signature of a pack says of it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    /// Signed by the trusted key at this place of `trusted_keys`.
+    Trusted(usize),
+    /// Signed, but by none of the trusted keys, or not for these files.
+    Untrusted,
+    Unsigned,
+    /// Signatures aren't looked at (`trust = "off"`).
+    Unchecked,
+}
+
+/// A pack installed, and what its signature said.
+#[derive(Debug, Clone)]
+pub struct Installed {
+    /// Where its word list was written.
+    pub path: PathBuf,
+    pub verdict: Verdict,
+}
+
+/// Reads the packs of an index from its JSON.
+pub fn parse_index(json: &str) -> anyhow::Result<Vec<PackEntry>> {
+    let index: Index = serde_json::from_str(json).context("reading the pack index")?;
+    Ok(index.packs)
+}
+
+/// Downloads the index of `config`.
+pub fn fetch_index(config: &PacksConfig) -> anyhow::Result<Vec<PackEntry>> {
+    let url = config
+        .index_url
+        .as_deref()
+        .context("no pack index: set [packs] index_url in the config")?;
+    parse_index(&http::get(url, FETCH_TIMEOUT)?)
+}
+
+/// Checks `signature` of the pack `name` made of `words` and `metadata`
+/// against the trusted keys of `config`.
+pub fn verify(
+    config: &PacksConfig,
+    name: &str,
+    words: &[u8],
+    metadata: &[u8],
+    signature: Option<&str>,
+) -> anyhow::Result<Verdict> {
+    if config.trust == TrustPolicy::Off {
+        return Ok(Verdict::Unchecked);
+    }
+    let keys = trusted_keys(config)?;
+    let Some(signature) = signature else {
+        return Ok(Verdict::Unsigned);
+    };
+    let Some(signature) = decode_hex(signature)
+        .and_then(|bytes| <[u8; 64]>::try_from(bytes).ok())
+        .map(|bytes| Signature::from_bytes(&bytes))
+    else {
+        return Ok(Verdict::Untrusted);
+    };
+    let message = signed_message(name, words, metadata);
+    Ok(keys
+        .iter()
+        .position(|key| key.verify(&message, &signature).is_ok())
+        .map_or(Verdict::Untrusted, Verdict::Trusted))
+}
+
+/// Downloads the pack `name` of the index into the user data directory,
+/// unless the trust policy refuses it.
+pub fn install(config: &PacksConfig, name: &str) -> anyhow::Result<Installed> {
+    let dir = paths::data_dir().context("no data directory to install packs in")?;
+    let packs = fetch_index(config)?;
+    let pack = packs
+        .iter()
+        .find(|pack| pack.name == name)
+        .with_context(|| format!("no pack '{}' in the index", name))?;
+    if pack.name.is_empty()
+        || !pack
+            .name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        bail!("'{}' can't be the name of a pack", pack.name);
+    }
+    let words = http::get(&pack.words, FETCH_TIMEOUT)?;
+    let metadata = match &pack.metadata {
+        Some(url) => http::get(url, FETCH_TIMEOUT)?,
+        None => String::new(),
+    };
+    let verdict = verify(
+        config,
+        name,
+        words.as_bytes(),
+        metadata.as_bytes(),
+        pack.signature.as_deref(),
+    )?;
+    if config.trust == TrustPolicy::Require && !matches!(verdict, Verdict::Trusted(_)) {
+        bail!(
+            "'{}' isn't signed by a trusted key, and [packs] trust requires it",
+            name
+        );
+    }
+    WordTheme::parse(name, &words, &metadata).with_context(|| format!("in the pack '{}'", name))?;
+
+    let dir = dir.join("themes");
+    fs::create_dir_all(&dir).with_context(|| format!("creating {}", dir.display()))?;
+    let path = dir.join(format!("{}.txt", name));
+    fs::write(&path, &words).with_context(|| format!("writing {}", path.display()))?;
+    let metadata_path = path.with_extension("toml");
+    if metadata.is_empty() {
+        match fs::remove_file(&metadata_path) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => {
+                return Err(error).context(format!("removing {}", metadata_path.display()))
+            }
+            _ => {}
+        }
+    } else {
+        fs::write(&metadata_path, &metadata)
+            .with_context(|| format!("writing {}", metadata_path.display()))?;
+    }
+    Ok(Installed { path, verdict })
+}
+
+/// The signature, in hex, of the pack whose word list is at `words`, named
+/// after the file, with the metadata next to it if there is some, by the
+/// key in the file `key`. The key is made there if missing. Also gives the
+/// public key to add to `trusted_keys`.
+pub fn sign(words: &Path, key: &Path) -> anyhow::Result<(String, String)> {
+    let key = signing_key(key)?;
+    let name = words
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .with_context(|| format!("{} doesn't name a pack", words.display()))?;
+    let list = fs::read(words).with_context(|| format!("reading {}", words.display()))?;
+    let metadata = fs::read(words.with_extension("toml")).unwrap_or_default();
+    let signature = key.sign(&signed_message(name, &list, &metadata));
+    Ok((
+        encode_hex(&signature.to_bytes()),
+        encode_hex(key.verifying_key().as_bytes()),
+    ))
+}
+
+// What the signature of a pack covers: its name, then the length and
+// hash of each of its files, each part framed by its length
+fn signed_message(name: &str, words: &[u8], metadata: &[u8]) -> Vec<u8> {
+    let mut message = b"wordle pack\n".to_vec();
+    message.extend((name.len() as u64).to_be_bytes());
+    message.extend(name.as_bytes());
+    for file in [words, metadata] {
+        message.extend((file.len() as u64).to_be_bytes());
+        message.extend(Sha256::digest(file));
+    }
+    message
+}
+
+fn trusted_keys(config: &PacksConfig) -> anyhow::Result<Vec<VerifyingKey>> {
+    config
+        .trusted_keys
+        .iter()
+        .map(|key| {
+            decode_hex(key)
+                .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                .and_then(|bytes| VerifyingKey::from_bytes(&bytes).ok())
+                .with_context(|| format!("'{}' in [packs] trusted_keys isn't a public key", key))
+        })
+        .collect()
+}
+
+// The seed in the file at `path`, made there if missing and readable only
+// by its owner
+fn signing_key(path: &Path) -> anyhow::Result<SigningKey> {
+    match fs::read(path) {
+        Ok(seed) => {
+            let seed: [u8; 32] = seed
+                .try_into()
+                .map_err(|_| anyhow::anyhow!("{} isn't a signing key", path.display()))?;
+            Ok(SigningKey::from_bytes(&seed))
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            let seed: [u8; 32] = rand::random();
+            let mut options = fs::OpenOptions::new();
+            options.write(true).create_new(true);
+            #[cfg(unix)]
+            std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+            options
+                .open(path)
+                .and_then(|mut file| file.write_all(&seed))
+                .with_context(|| format!("writing the signing key to {}", path.display()))?;
+            Ok(SigningKey::from_bytes(&seed))
+        }
+        Err(error) => Err(error).with_context(|| format!("reading {}", path.display())),
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim();
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|at| u8::from_str_radix(&text[at..at + 2], 16).ok())
+        .collect()
+}
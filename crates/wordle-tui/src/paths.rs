@@ -0,0 +1,117 @@
+//! Where the game finds its files, wherever it is started from and
+//! whichever platform it was built for.
+//!
+//! Word packs (`words*.txt` and the `.difficulty` and `.patterns` files
+//! next to them) are looked up, in order, in:
+//!
+//! 1. the directory in `WORDLE_DATA_DIR`, if set;
+//! 2. the user data directory (see [`data_dir`]);
+//! 3. `data/` next to the executable, then `../share/wordle/` from it, as
+//!    laid out by a portable archive or a system package;
+//! 4. `data/` in the working directory, for a checkout of the repository;
+//! 5. on Unix, `wordle/` in each of `$XDG_DATA_DIRS`
+//!    (`/usr/local/share:/usr/share` by default).
+//!
+//! When none has the file, the game plays with the copy built into the
+//! binary.
+//!
+//! A thread can keep what it records elsewhere than in the user data
+//! directory (see [`set_thread_data_dir`]), as each game of `wordle ssh`
+//! does; it still finds the user's word packs.
+
+use std::cell::RefCell;
+use std::env;
+use std::path::PathBuf;
+
+/// Environment variable naming a directory searched first for word packs.
+pub const DATA_DIR_VAR: &str = "WORDLE_DATA_DIR";
+
+thread_local! {
+    // Where this thread records things instead of the user data directory
+    static THREAD_DATA_DIR: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+/// Directory of the user's configuration: `$XDG_CONFIG_HOME/wordle` if set,
+/// otherwise `~/.config/wordle` on Unix, `~/Library/Application
+/// Support/wordle` on macOS and `%APPDATA%\wordle` on Windows.
+pub fn config_dir() -> Option<PathBuf> {
+    user_dir("XDG_CONFIG_HOME", ".config")
+}
+
+/// Directory of what the game records between sessions and of the user's
+/// own word packs: `$XDG_DATA_HOME/wordle` if set, otherwise
+/// `~/.local/share/wordle` on Unix and the same as [`config_dir`] on macOS
+/// and Windows. On a thread given a directory of its own by
+/// [`set_thread_data_dir`], that one.
+pub fn data_dir() -> Option<PathBuf> {
+    THREAD_DATA_DIR
+        .with(|dir| dir.borrow().clone())
+        .or_else(user_data_dir)
+}
+
+/// Makes [`data_dir`] `dir` on this thread, or the user's again with
+/// `None`.
+pub fn set_thread_data_dir(dir: Option<PathBuf>) {
+    THREAD_DATA_DIR.with(|current| *current.borrow_mut() = dir);
+}
+
+/// The first of the places listed in the module docs that has a file
+/// called `name`.
+pub fn find_asset(name: &str) -> Option<PathBuf> {
+    asset_dirs()
+        .into_iter()
+        .map(|dir| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Directories searched for word packs, in order.
+pub fn asset_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(dir) = env::var_os(DATA_DIR_VAR).filter(|dir| !dir.is_empty()) {
+        dirs.push(PathBuf::from(dir));
+    }
+    // The user's packs, whichever directory this thread records in
+    dirs.extend(user_data_dir());
+    if let Some(exe_dir) = env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(PathBuf::from))
+    {
+        dirs.push(exe_dir.join("data"));
+        dirs.push(exe_dir.join("../share/wordle"));
+    }
+    dirs.push(PathBuf::from("data"));
+    if cfg!(unix) && !cfg!(target_os = "macos") {
+        let system = env::var("XDG_DATA_DIRS")
+            .ok()
+            .filter(|dirs| !dirs.is_empty())
+            .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+        dirs.extend(
+            system
+                .split(':')
+                .filter(|dir| !dir.is_empty())
+                .map(|dir| PathBuf::from(dir).join("wordle")),
+        );
+    }
+    dirs
+}
+
+fn user_data_dir() -> Option<PathBuf> {
+    user_dir("XDG_DATA_HOME", ".local/share")
+}
+
+// `$<xdg_var>/wordle` when the variable is set, whatever the platform, so
+// it can always be redirected (tests rely on it); otherwise the platform's
+// place for it
+fn user_dir(xdg_var: &str, unix_default: &str) -> Option<PathBuf> {
+    if let Some(dir) = env::var_os(xdg_var).filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(dir).join("wordle"));
+    }
+    let base = if cfg!(windows) {
+        PathBuf::from(env::var_os("APPDATA")?)
+    } else if cfg!(target_os = "macos") {
+        PathBuf::from(env::var_os("HOME")?).join("Library/Application Support")
+    } else {
+        PathBuf::from(env::var_os("HOME")?).join(unix_default)
+    };
+    Some(base.join("wordle"))
+}
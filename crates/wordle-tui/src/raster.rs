@@ -0,0 +1,259 @@
+//! A small RGB canvas for the result card: filled rectangles, text in a
+//! built-in 5x7 font, and the two ways out of it, a PNG file and Sixel
+//! graphics for terminals that can show images. Pure Rust, no image
+//! library; the PNG is stored without compression, which is fine for a
+//! card made of flat squares of a few hundred pixels.
+
+/// A color, red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// Width of a glyph of the font, before scaling.
+const GLYPH_WIDTH: usize = 5;
+/// Height of a glyph of the font, before scaling.
+pub const GLYPH_HEIGHT: usize = 7;
+
+/// The font: a row of bits per line, the leftmost pixel in the high bit.
+/// Characters not in it are drawn as spaces.
+#[rustfmt::skip]
+const GLYPHS: &[(char, [u8; GLYPH_HEIGHT])] = &[
+    ('0', [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110]),
+    ('1', [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    ('2', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111]),
+    ('3', [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110]),
+    ('4', [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010]),
+    ('5', [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110]),
+    ('6', [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110]),
+    ('7', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000]),
+    ('8', [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110]),
+    ('9', [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100]),
+    ('A', [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    ('B', [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110]),
+    ('C', [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110]),
+    ('D', [0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100]),
+    ('E', [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111]),
+    ('F', [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000]),
+    ('G', [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111]),
+    ('H', [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    ('I', [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    ('J', [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100]),
+    ('K', [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001]),
+    ('L', [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111]),
+    ('M', [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001]),
+    ('N', [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001]),
+    ('O', [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    ('P', [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000]),
+    ('Q', [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101]),
+    ('R', [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001]),
+    ('S', [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110]),
+    ('T', [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100]),
+    ('U', [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    ('V', [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100]),
+    ('W', [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010]),
+    ('X', [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001]),
+    ('Y', [0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100]),
+    ('Z', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111]),
+    ('#', [0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010]),
+    ('/', [0b00001, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b10000]),
+    ('-', [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000]),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    // Row by row, from the top left
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// A `width`x`height` image filled with `background`.
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Paints a rectangle, clipped to the image.
+    pub fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize, color: Rgb) {
+        for row in y..(y + height).min(self.height) {
+            for column in x..(x + width).min(self.width) {
+                self.pixels[row * self.width + column] = color;
+            }
+        }
+    }
+
+    /// Writes `text` with its top left corner at `x`, `y`, each pixel of
+    /// the font a `scale`x`scale` square. Lowercase letters are drawn as
+    /// uppercase.
+    pub fn draw_text(&mut self, x: usize, y: usize, text: &str, scale: usize, color: Rgb) {
+        for (index, c) in text.chars().enumerate() {
+            let c = c.to_ascii_uppercase();
+            let Some((_, rows)) = GLYPHS.iter().find(|(glyph, _)| *glyph == c) else {
+                continue;
+            };
+            let left = x + index * (GLYPH_WIDTH + 1) * scale;
+            for (row, bits) in rows.iter().enumerate() {
+                for column in 0..GLYPH_WIDTH {
+                    if bits & (1 << (GLYPH_WIDTH - 1 - column)) != 0 {
+                        self.fill_rect(left + column * scale, y + row * scale, scale, scale, color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The image as a PNG file: 8-bit RGB, not interlaced.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // Bit depth, color type (RGB), compression, filter, interlace
+        header.extend([8, 2, 0, 0, 0]);
+
+        // Each row starts with its filter type, none here
+        let mut raw = Vec::with_capacity(self.height * (1 + self.width * 3));
+        for row in self.pixels.chunks(self.width.max(1)) {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut png, b"IHDR", &header);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// The image as Sixel graphics, ready to write to a terminal that
+    /// supports them. Meant for images of a few colors: each distinct
+    /// color gets a register.
+    pub fn to_sixel(&self) -> String {
+        let mut palette: Vec<Rgb> = Vec::new();
+        let indexes: Vec<usize> = self
+            .pixels
+            .iter()
+            .map(
+                |color| match palette.iter().position(|known| known == color) {
+                    Some(index) => index,
+                    None => {
+                        palette.push(*color);
+                        palette.len() - 1
+                    }
+                },
+            )
+            .collect();
+
+        let mut sixel = format!("\x1bPq\"1;1;{};{}", self.width, self.height);
+        for (index, [r, g, b]) in palette.iter().enumerate() {
+            // Sixel colors are in percent
+            let percent = |value: &u8| u32::from(*value) * 100 / 255;
+            sixel += &format!("#{};2;{};{};{}", index, percent(r), percent(g), percent(b));
+        }
+        // Six rows at a time, one pass per color over the band
+        for top in (0..self.height).step_by(6) {
+            let band = top..(top + 6).min(self.height);
+            for color in 0..palette.len() {
+                let columns: Vec<u8> = (0..self.width)
+                    .map(|x| {
+                        band.clone()
+                            .filter(|y| indexes[y * self.width + x] == color)
+                            .fold(0, |bits, y| bits | 1 << (y - top))
+                    })
+                    .collect();
+                if columns.iter().all(|bits| *bits == 0) {
+                    continue;
+                }
+                sixel += &format!("#{}", color);
+                push_runs(&mut sixel, &columns);
+                sixel.push('$');
+            }
+            sixel.push('-');
+        }
+        sixel += "\x1b\\";
+        sixel
+    }
+}
+
+/// Width of `text` drawn at `scale`, without the spacing after the last
+/// character.
+pub fn text_width(text: &str, scale: usize) -> usize {
+    let count = text.chars().count();
+    (count * (GLYPH_WIDTH + 1)).saturating_sub(1) * scale
+}
+
+// Writes the sixels of `columns`, a run of the same one as a repeat
+fn push_runs(sixel: &mut String, columns: &[u8]) {
+    let mut start = 0;
+    while start < columns.len() {
+        let bits = columns[start];
+        let length = columns[start..]
+            .iter()
+            .take_while(|next| **next == bits)
+            .count();
+        let c = char::from(63 + bits);
+        if length > 3 {
+            sixel.push_str(&format!("!{}{}", length, c));
+        } else {
+            sixel.extend(std::iter::repeat_n(c, length));
+        }
+        start += length;
+    }
+}
+
+// Appends a chunk: length, type, data, and the CRC of type and data
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+// A zlib stream of `data` in stored (uncompressed) deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(u8::from(blocks.peek().is_none()));
+        let length = block.len() as u16;
+        out.extend(length.to_le_bytes());
+        out.extend((!length).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                0xedb8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + u32::from(*byte)) % 65_521;
+        b = (b + a) % 65_521;
+    }
+    (b << 16) | a
+}
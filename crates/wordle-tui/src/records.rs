@@ -0,0 +1,63 @@
+//! Personal bests of speedruns, one per word length, kept in
+//! `speedrun.json` in the data directory.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::config::Config;
+
+/// Best times, in milliseconds, by word length.
+#[derive(Debug, Default)]
+pub struct Records {
+    best: BTreeMap<usize, u64>,
+}
+
+impl Records {
+    /// Reads the records, starting afresh if there are none.
+    pub fn load() -> Records {
+        let best = Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+        Records { best }
+    }
+
+    /// Best time for words of `word_length` letters.
+    pub fn best(&self, word_length: usize) -> Option<Duration> {
+        self.best
+            .get(&word_length)
+            .map(|&ms| Duration::from_millis(ms))
+    }
+
+    /// Keeps `time` if it beats the best for `word_length`, saving the
+    /// records. Returns whether it did.
+    pub fn submit(&mut self, word_length: usize, time: Duration) -> io::Result<bool> {
+        let ms = time.as_millis() as u64;
+        if self.best.get(&word_length).is_some_and(|&best| best <= ms) {
+            return Ok(false);
+        }
+        self.best.insert(word_length, ms);
+        let Some(path) = Self::path() else {
+            return Ok(true);
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string_pretty(&self.best).map_err(io::Error::other)?;
+        fs::write(path, text + "\n")?;
+        Ok(true)
+    }
+
+    fn path() -> Option<PathBuf> {
+        Some(Config::data_dir()?.join("speedrun.json"))
+    }
+}
+
+/// `time` as minutes, seconds and milliseconds: `1:02.345`.
+pub fn format_time(time: Duration) -> String {
+    let ms = time.as_millis();
+    format!("{}:{:02}.{:03}", ms / 60_000, ms / 1000 % 60, ms % 1000)
+}
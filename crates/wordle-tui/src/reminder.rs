@@ -0,0 +1,31 @@
+//! A desktop notification, when the game starts, that the word of the day
+//! is still to be played, for builds with the `reminder` feature. Nothing
+//! pops up unless the configuration opts in:
+//!
+//! ```toml
+//! [reminder]
+//! notify = true
+//! ```
+//!
+//! Whatever the build, the daily entry of the menu has a dot next to it
+//! until the word of the day is played to the end.
+
+use crate::i18n::Texts;
+
+/// Whether this build can show desktop notifications.
+pub const AVAILABLE: bool = cfg!(feature = "reminder");
+
+/// Tells the desktop the word of the day is waiting. A desktop without
+/// notifications is no reason to stop the game, so failures are ignored.
+pub fn notify(texts: &Texts) {
+    #[cfg(feature = "reminder")]
+    {
+        let _ = notify_rust::Notification::new()
+            .appname("wordle")
+            .summary(texts.reminder_title)
+            .body(texts.reminder_body)
+            .show();
+    }
+    #[cfg(not(feature = "reminder"))]
+    let _ = texts;
+}
@@ -0,0 +1,61 @@
+//! Watching a past game again from the history: the guesses are typed
+//! back on a fresh board one letter per tick of the main loop, each
+//! submitted once it is complete.
+
+use crate::game::Game;
+use crate::history::Finish;
+
+pub struct Replay {
+    /// The game as replayed so far.
+    pub game: Game,
+    /// The game being replayed.
+    pub finish: Finish,
+    pub paused: bool,
+    // Guess being typed, and how many of its letters are in
+    guess: usize,
+    typed: usize,
+}
+
+impl Replay {
+    pub fn new(finish: Finish) -> Replay {
+        Replay {
+            game: Game::with_word(&finish.word),
+            finish,
+            paused: false,
+            guess: 0,
+            typed: 0,
+        }
+    }
+
+    /// Whether every guess is on the board.
+    pub fn is_done(&self) -> bool {
+        self.guess >= self.finish.guesses.len()
+    }
+
+    /// Types the next letter, or submits the guess once it is complete.
+    /// Returns the column of the letter typed, if one was.
+    pub fn step(&mut self) -> Option<usize> {
+        let guess = self.finish.guesses.get(self.guess)?;
+        match guess.chars().nth(self.typed) {
+            Some(letter) => {
+                self.game.input_letter(letter);
+                self.typed += 1;
+                Some(self.typed - 1)
+            }
+            None => {
+                self.game.submit_guess();
+                self.guess += 1;
+                self.typed = 0;
+                None
+            }
+        }
+    }
+
+    /// Finishes the guess being typed at once.
+    pub fn skip_guess(&mut self) {
+        let guess = self.guess;
+        while self.guess == guess && !self.is_done() {
+            self.step();
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! `wordle serve`: the race server of the `wordle-server` crate, playing
+//! in the game's languages with their word lists.
+
+use std::net::TcpListener;
+
+use crate::game::word_list_checksum;
+use crate::i18n::Language;
+
+pub use wordle_server::{ClientId, Languages, Server, WordList};
+
+/// The word list of the language of `code`, as the game plays it.
+pub fn word_list(code: &str) -> Option<WordList> {
+    let language = Language::from_code(code)?;
+    Some(WordList {
+        language: language.code(),
+        checksum: word_list_checksum(&language.load_words()),
+    })
+}
+
+/// Runs a server in the game's languages on `listener`. See
+/// [`wordle_server::serve`].
+pub fn serve(listener: TcpListener) -> anyhow::Result<()> {
+    wordle_server::serve(listener, word_list)
+}
@@ -0,0 +1,106 @@
+//! Sound cues (`[sound]`): a sound when a guess is refused or taken, and
+//! when the game is won or lost. Each is the terminal bell, or a command of
+//! one's own for sounds that tell the events apart:
+//!
+//! ```toml
+//! [sound]
+//! enabled = true
+//! guess = true
+//!
+//! [sound.commands]
+//! win = "paplay /usr/share/sounds/freedesktop/stereo/complete.oga"
+//! ```
+//!
+//! The app only queues the cues; the main loop plays them, so the bell
+//! goes to the terminal the game is drawn on. Commands run on the computer
+//! running the game, without waiting for them.
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+
+use crate::config::SoundConfig;
+
+/// What a sound is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cue {
+    /// A guess refused: too short, unknown or filtered.
+    Invalid,
+    /// A guess taken that didn't end the game.
+    Guess,
+    Win,
+    Loss,
+}
+
+impl SoundConfig {
+    /// Whether `cue` makes a sound.
+    pub fn plays(&self, cue: Cue) -> bool {
+        self.enabled
+            && match cue {
+                Cue::Invalid => self.invalid,
+                Cue::Guess => self.guess,
+                Cue::Win => self.win,
+                Cue::Loss => self.loss,
+            }
+    }
+
+    /// The command of `cue`, if it has one rather than the bell.
+    pub fn command(&self, cue: Cue) -> Option<&str> {
+        let commands = &self.commands;
+        match cue {
+            Cue::Invalid => commands.invalid.as_deref(),
+            Cue::Guess => commands.guess.as_deref(),
+            Cue::Win => commands.win.as_deref(),
+            Cue::Loss => commands.loss.as_deref(),
+        }
+    }
+}
+
+/// The sounds of a game: which events make one, and those waiting for the
+/// main loop to play.
+#[derive(Debug, Default)]
+pub struct Sound {
+    pub config: SoundConfig,
+    cues: Vec<Cue>,
+}
+
+impl Sound {
+    pub fn new(config: SoundConfig) -> Self {
+        Sound {
+            config,
+            cues: Vec::new(),
+        }
+    }
+
+    /// Queues the sound of `cue`, if it makes one.
+    pub fn cue(&mut self, cue: Cue) {
+        if self.config.plays(cue) {
+            self.cues.push(cue);
+        }
+    }
+
+    /// Takes the queued sounds, oldest first.
+    pub fn take(&mut self) -> Vec<Cue> {
+        std::mem::take(&mut self.cues)
+    }
+}
+
+/// Plays `cue`: starts its command, or rings the bell on `terminal`. A
+/// command that can't be started rings the bell instead.
+pub fn play(cue: Cue, config: &SoundConfig, terminal: &mut impl Write) -> io::Result<()> {
+    if let Some(command) = config.command(cue) {
+        let started = Command::new(if cfg!(windows) { "cmd" } else { "sh" })
+            .args([if cfg!(windows) { "/C" } else { "-c" }, command])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        if let Ok(mut child) = started {
+            // Reaped whenever it ends, so the game doesn't wait for it
+            thread::spawn(move || child.wait());
+            return Ok(());
+        }
+    }
+    terminal.write_all(b"\x07")?;
+    terminal.flush()
+}
@@ -0,0 +1,245 @@
+//! Turns what a remote terminal sends into the events crossterm would have
+//! read from a local one: characters, the escape sequences of the special
+//! keys (with their Shift, Alt and Ctrl variants), bracketed pastes and
+//! SGR mouse clicks.
+
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+
+const PASTE_START: &[u8] = b"\x1b[200~";
+const PASTE_END: &[u8] = b"\x1b[201~";
+
+/// Input of one terminal, read in chunks as they arrive.
+#[derive(Debug, Default)]
+pub struct Input {
+    // Bytes of a sequence or character split across chunks
+    pending: Vec<u8>,
+    // Text of a paste not ended yet
+    paste: Option<Vec<u8>>,
+}
+
+impl Input {
+    pub fn new() -> Input {
+        Input::default()
+    }
+
+    /// The events in `bytes`, following what came before. An Esc at the
+    /// end of a chunk is taken as the Esc key: terminals send sequences in
+    /// one piece.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<Event> {
+        self.pending.extend(bytes);
+        let mut events = Vec::new();
+        let mut at = 0;
+        while at < self.pending.len() {
+            let rest = &self.pending[at..];
+            if let Some(paste) = &mut self.paste {
+                match find(rest, PASTE_END) {
+                    Some(end) => {
+                        paste.extend(&rest[..end]);
+                        let text = String::from_utf8_lossy(paste).into_owned();
+                        events.push(Event::Paste(text));
+                        self.paste = None;
+                        at += end + PASTE_END.len();
+                    }
+                    None => {
+                        // Keep what could be the start of the end marker
+                        let keep = (1..PASTE_END.len())
+                            .rev()
+                            .find(|length| rest.ends_with(&PASTE_END[..*length]))
+                            .unwrap_or(0);
+                        paste.extend(&rest[..rest.len() - keep]);
+                        at = self.pending.len() - keep;
+                        break;
+                    }
+                }
+                continue;
+            }
+            if rest.starts_with(PASTE_START) {
+                self.paste = Some(Vec::new());
+                at += PASTE_START.len();
+                continue;
+            }
+            match parse(rest) {
+                Parsed::Event(event, length) => {
+                    events.extend(event);
+                    at += length;
+                }
+                Parsed::Incomplete => break,
+            }
+        }
+        self.pending.drain(..at);
+        events
+    }
+}
+
+enum Parsed {
+    // An event, if the bytes meant one, and how many bytes it took
+    Event(Option<Event>, usize),
+    Incomplete,
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Option<Event> {
+    Some(Event::Key(KeyEvent::new(code, modifiers)))
+}
+
+fn parse(bytes: &[u8]) -> Parsed {
+    let none = KeyModifiers::NONE;
+    match bytes {
+        [] => Parsed::Incomplete,
+        [b'\x1b'] => Parsed::Event(key(KeyCode::Esc, none), 1),
+        [b'\x1b', b'[', rest @ ..] => csi(rest),
+        [b'\x1b', b'O', rest @ ..] => match rest.first() {
+            None => Parsed::Incomplete,
+            Some(&last) => Parsed::Event(ss3(last, none), 3),
+        },
+        // Alt and a key, sent as Esc before it
+        [b'\x1b', rest @ ..] => match parse(rest) {
+            Parsed::Event(Some(Event::Key(mut event)), length) => {
+                event.modifiers |= KeyModifiers::ALT;
+                Parsed::Event(Some(Event::Key(event)), length + 1)
+            }
+            Parsed::Event(_, _) => Parsed::Event(key(KeyCode::Esc, none), 1),
+            Parsed::Incomplete => Parsed::Incomplete,
+        },
+        [b'\r' | b'\n', ..] => Parsed::Event(key(KeyCode::Enter, none), 1),
+        [b'\t', ..] => Parsed::Event(key(KeyCode::Tab, none), 1),
+        [0x7f | 0x08, ..] => Parsed::Event(key(KeyCode::Backspace, none), 1),
+        [0, ..] => Parsed::Event(key(KeyCode::Char(' '), KeyModifiers::CONTROL), 1),
+        [control @ 1..=26, ..] => Parsed::Event(
+            key(
+                KeyCode::Char(char::from(b'a' + control - 1)),
+                KeyModifiers::CONTROL,
+            ),
+            1,
+        ),
+        [first, ..] => {
+            let length = match first {
+                0xc0..=0xdf => 2,
+                0xe0..=0xef => 3,
+                0xf0..=0xf7 => 4,
+                _ => 1,
+            };
+            if bytes.len() < length {
+                return Parsed::Incomplete;
+            }
+            let event = std::str::from_utf8(&bytes[..length])
+                .ok()
+                .and_then(|text| text.chars().next())
+                .filter(|c| !c.is_control())
+                .and_then(|c| key(KeyCode::Char(c), none));
+            Parsed::Event(event, length)
+        }
+    }
+}
+
+// The key of Esc O and `last`, as sent in application mode
+fn ss3(last: u8, modifiers: KeyModifiers) -> Option<Event> {
+    let code = match last {
+        b'A' => KeyCode::Up,
+        b'B' => KeyCode::Down,
+        b'C' => KeyCode::Right,
+        b'D' => KeyCode::Left,
+        b'H' => KeyCode::Home,
+        b'F' => KeyCode::End,
+        b'P'..=b'S' => KeyCode::F(last - b'P' + 1),
+        _ => return None,
+    };
+    key(code, modifiers)
+}
+
+// A control sequence, after its Esc [
+fn csi(rest: &[u8]) -> Parsed {
+    // Parameters and intermediates, up to the final byte
+    let Some(end) = rest.iter().position(|byte| (0x40..=0x7e).contains(byte)) else {
+        return if rest.len() > 32 {
+            Parsed::Event(None, 2 + rest.len())
+        } else {
+            Parsed::Incomplete
+        };
+    };
+    let length = 2 + end + 1;
+    let last = rest[end];
+    let parameters = String::from_utf8_lossy(&rest[..end]).into_owned();
+
+    if let Some(mouse) = parameters.strip_prefix('<') {
+        return Parsed::Event(sgr_mouse(mouse, last), length);
+    }
+    let numbers: Vec<u16> = parameters
+        .split(';')
+        .map(|number| number.parse().unwrap_or(1))
+        .collect();
+    // The second number is the modifiers, plus one: 1 Shift, 2 Alt, 4 Ctrl
+    let modifiers = numbers.get(1).map_or(KeyModifiers::NONE, |number| {
+        let bits = number.saturating_sub(1);
+        let mut modifiers = KeyModifiers::NONE;
+        if bits & 1 != 0 {
+            modifiers |= KeyModifiers::SHIFT;
+        }
+        if bits & 2 != 0 {
+            modifiers |= KeyModifiers::ALT;
+        }
+        if bits & 4 != 0 {
+            modifiers |= KeyModifiers::CONTROL;
+        }
+        modifiers
+    });
+    let event = match last {
+        b'Z' => key(KeyCode::BackTab, KeyModifiers::SHIFT),
+        b'~' => {
+            let code = match numbers[0] {
+                1 | 7 => KeyCode::Home,
+                2 => KeyCode::Insert,
+                3 => KeyCode::Delete,
+                4 | 8 => KeyCode::End,
+                5 => KeyCode::PageUp,
+                6 => KeyCode::PageDown,
+                number @ 11..=15 => KeyCode::F((number - 10) as u8),
+                number @ 17..=21 => KeyCode::F((number - 11) as u8),
+                number @ 23..=24 => KeyCode::F((number - 12) as u8),
+                _ => return Parsed::Event(None, length),
+            };
+            key(code, modifiers)
+        }
+        _ => ss3(last, modifiers),
+    };
+    Parsed::Event(event, length)
+}
+
+// An SGR mouse report: button;column;row, then M for a press and m for a
+// release. Only presses and releases of the buttons are passed on
+fn sgr_mouse(parameters: &str, last: u8) -> Option<Event> {
+    let mut numbers = parameters.split(';').map(|number| number.parse::<u16>());
+    let (Some(Ok(button)), Some(Ok(column)), Some(Ok(row))) =
+        (numbers.next(), numbers.next(), numbers.next())
+    else {
+        return None;
+    };
+    // Motion and the wheel have the bits of 32 and 64
+    if button & (32 | 64) != 0 {
+        return None;
+    }
+    let button = match button & 3 {
+        0 => MouseButton::Left,
+        1 => MouseButton::Middle,
+        2 => MouseButton::Right,
+        _ => return None,
+    };
+    let kind = match last {
+        b'M' => MouseEventKind::Down(button),
+        b'm' => MouseEventKind::Up(button),
+        _ => return None,
+    };
+    Some(Event::Mouse(MouseEvent {
+        kind,
+        column: column.saturating_sub(1),
+        row: row.saturating_sub(1),
+        modifiers: KeyModifiers::NONE,
+    }))
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
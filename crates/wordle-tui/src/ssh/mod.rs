@@ -0,0 +1,715 @@
+//! `wordle ssh`: the game as an SSH server, so that anyone can play with
+//! `ssh -p 2222 play@host` and nothing to install. Any user name goes and
+//! no password is asked. Each connection gets its own game, drawn on a
+//! terminal of the size the client gives, and follows it as it is resized.
+//! A game records its history, records and saves in a directory of its
+//! own, gone once it ends: players are strangers, and nothing they do
+//! reaches the files of the user running the server, nor those of the
+//! other players. Nor does the lock passphrase of that user apply to them.
+//!
+//! [`transport`] encrypts the connection; this module lets the client in,
+//! opens its session channel (RFC 4254) and runs the game on it, with
+//! [`input`] reading the keys. The host key is made on the first run and
+//! kept in the user data directory, so clients can recognize the server.
+//!
+//! Anyone is let in, whatever the user name and the way they log in: the
+//! game is open to all, like a web page, and a player can reach nothing
+//! but their own game. At most [`MAX_SESSIONS`] games go at once; a
+//! client has [`HANDSHAKE_TIMEOUT`] to get from connecting to its game,
+//! and one that sends nothing for [`IDLE_TIMEOUT`] is let go.
+//!
+//! The protocol is written here rather than taken from `russh`, which
+//! would bring an async runtime into a game that has none: the server only
+//! needs one key exchange, one cipher and one channel, over the threads
+//! and blocking sockets the rest of the game uses.
+
+pub mod input;
+pub mod transport;
+
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, Write};
+use std::net::{Shutdown, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context};
+use crossterm::{
+    event::{DisableBracketedPaste, EnableBracketedPaste, Event},
+    execute, style,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ed25519_dalek::SigningKey;
+use ratatui::backend::{ClearType, WindowSize};
+use ratatui::buffer::Cell;
+use ratatui::layout::Size;
+use ratatui::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::app::App;
+use crate::config::{Config, Toggle};
+use crate::paths;
+use crate::term;
+use input::Input;
+use transport::*;
+
+/// Port `wordle ssh` listens on unless told otherwise.
+pub const DEFAULT_SSH_PORT: u16 = 2222;
+/// Games played at once; connections past them are turned away.
+pub const MAX_SESSIONS: usize = 32;
+/// Time a client has to exchange keys and open its session.
+pub const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Time a client may go without sending anything, keys or keepalives,
+/// before its game ends.
+pub const IDLE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// File of the host key in the user data directory: the 32 bytes of its
+/// Ed25519 seed.
+const HOST_KEY_FILE: &str = "ssh-host-key";
+/// Our number for the session channel, the only one a client opens.
+const CHANNEL: u32 = 0;
+/// How much the client may send before it waits for the server.
+const WINDOW: u32 = 1 << 20;
+/// The largest data packet taken from the client.
+const MAX_DATA: u32 = 32 * 1024;
+/// Size of the terminal of a client that doesn't give it.
+const DEFAULT_SIZE: (u16, u16) = (80, 24);
+
+// SGR mouse reports of button presses and releases; the game only needs
+// clicks, and reports of motion would be a lot of traffic
+const ENABLE_MOUSE: &str = "\x1b[?1000h\x1b[?1006h";
+const DISABLE_MOUSE: &str = "\x1b[?1006l\x1b[?1000l";
+
+/// Runs the server on `listener`, a game per connection, logging who
+/// comes and goes. Only returns once it stops taking connections.
+pub fn serve(listener: TcpListener, mut config: Config) -> anyhow::Result<()> {
+    let host_key = Arc::new(load_host_key()?);
+    println!("Host key {}", fingerprint(&host_key));
+    config.lock.passphrase = None;
+    let config = Arc::new(config);
+    let sessions = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+        let address = stream
+            .peer_addr()
+            .map_or_else(|_| "?".to_string(), |address| address.to_string());
+        let Some(seat) = Seat::take(&sessions) else {
+            println!("{} turned away: {} games going", address, MAX_SESSIONS);
+            continue;
+        };
+        let config = config.clone();
+        let host_key = host_key.clone();
+        thread::spawn(move || {
+            let _seat = seat;
+            match session(stream, &config, &host_key) {
+                Ok(name) => println!("{} ({}) is done playing", address, name),
+                Err(error) => println!("{} left: {:#}", address, error),
+            }
+        });
+    }
+    Ok(())
+}
+
+// One of the games going, given back when its thread ends however it ends
+struct Seat(Arc<AtomicUsize>);
+
+impl Seat {
+    fn take(sessions: &Arc<AtomicUsize>) -> Option<Seat> {
+        sessions
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |taken| {
+                (taken < MAX_SESSIONS).then_some(taken + 1)
+            })
+            .ok()?;
+        Some(Seat(sessions.clone()))
+    }
+}
+
+impl Drop for Seat {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// The data directory of a game, for its thread only, removed with it
+struct Scratch(PathBuf);
+
+impl Scratch {
+    fn make() -> io::Result<Scratch> {
+        static MADE: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "wordle-ssh-game-{}-{}",
+            process::id(),
+            MADE.fetch_add(1, Ordering::SeqCst)
+        ));
+        // Whatever was left there by a server of the same pid
+        let _ = fs::remove_dir_all(&dir);
+        let mut builder = fs::DirBuilder::new();
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        builder.create(&dir)?;
+        paths::set_thread_data_dir(Some(dir.clone()));
+        Ok(Scratch(dir))
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        paths::set_thread_data_dir(None);
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// The SHA256 fingerprint of `host_key`, as ssh shows it when asking
+/// whether to trust a server.
+pub fn fingerprint(host_key: &SigningKey) -> String {
+    let digest = Sha256::digest(host_key_blob(host_key));
+    format!("SHA256:{}", base64(&digest))
+}
+
+// The key in the user data directory, made there if missing. Without a
+// data directory the key only lasts as long as the server
+fn load_host_key() -> anyhow::Result<SigningKey> {
+    let Some(dir) = paths::data_dir() else {
+        return Ok(SigningKey::from_bytes(&rand::random()));
+    };
+    let path = dir.join(HOST_KEY_FILE);
+    match fs::read(&path) {
+        Ok(seed) => {
+            let seed: [u8; 32] = seed
+                .try_into()
+                .map_err(|_| anyhow::anyhow!("{} isn't a host key", path.display()))?;
+            Ok(SigningKey::from_bytes(&seed))
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            let seed: [u8; 32] = rand::random();
+            fs::create_dir_all(&dir)?;
+            let mut options = fs::OpenOptions::new();
+            options.write(true).create_new(true);
+            // Only the user running the server may read it
+            #[cfg(unix)]
+            std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+            options
+                .open(&path)
+                .and_then(|mut file| file.write_all(&seed))
+                .with_context(|| format!("writing the host key to {}", path.display()))?;
+            println!("New host key written to {}", path.display());
+            Ok(SigningKey::from_bytes(&seed))
+        }
+        Err(error) => {
+            Err(error).with_context(|| format!("reading the host key from {}", path.display()))
+        }
+    }
+}
+
+// Base64 without padding, the way fingerprints are written
+pub(crate) fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut text = String::new();
+    for chunk in bytes.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (index, byte)| {
+            bits | u32::from(*byte) << (16 - 8 * index)
+        });
+        for index in 0..=chunk.len() {
+            text.push(char::from(
+                ALPHABET[(bits >> (18 - 6 * index) & 63) as usize],
+            ));
+        }
+    }
+    text
+}
+
+// The session channel as the client opened it
+struct Opened {
+    // The client's number for the channel
+    remote: u32,
+    window: u32,
+    max_packet: u32,
+    // Columns and rows, if the client asked for a terminal
+    size: Option<(u16, u16)>,
+    user: String,
+}
+
+// Plays a game with the client on `stream`, returning the name it gave
+fn session(stream: TcpStream, config: &Config, host_key: &SigningKey) -> anyhow::Result<String> {
+    stream.set_nodelay(true)?;
+    // A client that stops reading doesn't hold the game up either
+    stream.set_read_timeout(Some(IDLE_TIMEOUT))?;
+    stream.set_write_timeout(Some(IDLE_TIMEOUT))?;
+    // Cuts the connection off if the client isn't in its game in time,
+    // however slowly it sends
+    let (handshaken, waiting) = mpsc::channel::<()>();
+    {
+        let stream = stream.try_clone()?;
+        thread::spawn(move || {
+            if let Err(RecvTimeoutError::Timeout) = waiting.recv_timeout(HANDSHAKE_TIMEOUT) {
+                let _ = stream.shutdown(Shutdown::Both);
+            }
+        });
+    }
+    let (mut sender, mut receiver) = transport::accept(stream, host_key)?;
+    let opened = match open(&mut sender, &mut receiver) {
+        Ok(opened) => opened,
+        Err(error) => {
+            sender.disconnect(&error.to_string());
+            return Err(error);
+        }
+    };
+    let _ = handshaken.send(());
+    let (columns, rows) = opened.size.unwrap_or(DEFAULT_SIZE);
+    let channel = Arc::new(Channel {
+        remote: opened.remote,
+        max_packet: opened.max_packet.clamp(1, MAX_DATA),
+        sender: Mutex::new(sender),
+        window: Mutex::new(Some(opened.window)),
+        window_grown: Condvar::new(),
+        size: Mutex::new(Rect::new(0, 0, columns, rows)),
+    });
+
+    let (events, incoming) = mpsc::channel();
+    {
+        let channel = channel.clone();
+        thread::spawn(move || channel.read(receiver, events));
+    }
+
+    if opened.size.is_none() {
+        channel.write_data(b"wordle needs a terminal: connect with ssh -t\r\n")?;
+        channel.end(1)?;
+        return Ok(opened.user);
+    }
+
+    let _scratch = Scratch::make().context("making the game's data directory")?;
+    let mut app = App::new(config);
+    app.resize(columns, rows);
+    let mut terminal = Terminal::new(Remote::new(channel.clone()))?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableBracketedPaste,
+        style::Print(ENABLE_MOUSE)
+    )?;
+    let mut input = Input::new();
+    let mut pending = VecDeque::new();
+    let result = term::run(
+        &mut terminal,
+        &mut app,
+        config.display.synchronized_output == Toggle::On,
+        Duration::from_millis(config.display.frame_interval_ms),
+        |timeout| {
+            let deadline = Instant::now() + timeout;
+            loop {
+                if let Some(event) = pending.pop_front() {
+                    return Ok(Some(event));
+                }
+                match incoming.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(Incoming::Data(bytes)) => pending.extend(input.feed(&bytes)),
+                    Ok(Incoming::Resize(columns, rows)) => {
+                        return Ok(Some(Event::Resize(columns, rows)))
+                    }
+                    Err(RecvTimeoutError::Timeout) => return Ok(None),
+                    Err(RecvTimeoutError::Disconnected) => {
+                        return Err(io::ErrorKind::ConnectionAborted.into())
+                    }
+                }
+            }
+        },
+    );
+    if result.is_err() {
+        bail!("the connection was lost");
+    }
+
+    execute!(
+        terminal.backend_mut(),
+        style::Print(DISABLE_MOUSE),
+        DisableBracketedPaste,
+        LeaveAlternateScreen
+    )?;
+    terminal.show_cursor()?;
+    channel.end(0)?;
+    // The client closes its side in turn, which ends the reading thread
+    let deadline = Instant::now() + Duration::from_secs(1);
+    while incoming
+        .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+        .is_ok()
+    {}
+    Ok(opened.user)
+}
+
+// Lets the client in and waits for it to open a session channel and start
+// a shell on it. Anyone is let in, whatever the user name and the method
+// (see the module docs)
+fn open(sender: &mut Sender, receiver: &mut Receiver) -> anyhow::Result<Opened> {
+    let mut user = None;
+    let mut opened: Option<Opened> = None;
+    loop {
+        let message = receiver.receive()?;
+        let mut reader = Reader::new(&message);
+        match message[0] {
+            MSG_SERVICE_REQUEST => {
+                let service = reader.string()?;
+                if service != b"ssh-userauth" {
+                    bail!("unknown service {}", String::from_utf8_lossy(service));
+                }
+                sender.send(&Writer::new(MSG_SERVICE_ACCEPT).string(service).finish())?;
+            }
+            MSG_USERAUTH_REQUEST => {
+                user = Some(reader.text()?);
+                sender.send(&[MSG_USERAUTH_SUCCESS])?;
+            }
+            _ if user.is_none() => bail!("message {} before logging in", message[0]),
+            MSG_GLOBAL_REQUEST => {
+                reader.string()?;
+                if reader.bool()? {
+                    sender.send(&[MSG_REQUEST_FAILURE])?;
+                }
+            }
+            MSG_CHANNEL_OPEN => {
+                let kind = reader.string()?;
+                let remote = reader.u32()?;
+                let window = reader.u32()?;
+                let max_packet = reader.u32()?;
+                if kind != b"session" || opened.is_some() {
+                    // Reason 1: administratively prohibited
+                    sender.send(
+                        &Writer::new(MSG_CHANNEL_OPEN_FAILURE)
+                            .u32(remote)
+                            .u32(1)
+                            .string(b"one game per connection")
+                            .string(b"")
+                            .finish(),
+                    )?;
+                    continue;
+                }
+                sender.send(
+                    &Writer::new(MSG_CHANNEL_OPEN_CONFIRMATION)
+                        .u32(remote)
+                        .u32(CHANNEL)
+                        .u32(WINDOW)
+                        .u32(MAX_DATA)
+                        .finish(),
+                )?;
+                opened = Some(Opened {
+                    remote,
+                    window,
+                    max_packet,
+                    size: None,
+                    user: user.clone().unwrap_or_default(),
+                });
+            }
+            MSG_CHANNEL_REQUEST => {
+                let Some(opened) = &mut opened else {
+                    bail!("a request without a channel");
+                };
+                ensure_channel(reader.u32()?)?;
+                let request = reader.string()?;
+                let want_reply = reader.bool()?;
+                let accepted = match request {
+                    b"pty-req" => {
+                        reader.string()?;
+                        let columns = reader.u32()?;
+                        let rows = reader.u32()?;
+                        opened.size = Some(clamp_size(columns, rows));
+                        true
+                    }
+                    b"window-change" => {
+                        let columns = reader.u32()?;
+                        let rows = reader.u32()?;
+                        opened.size = Some(clamp_size(columns, rows));
+                        true
+                    }
+                    // The game, whatever command was asked for
+                    b"shell" | b"exec" => true,
+                    _ => false,
+                };
+                if want_reply {
+                    let reply = if accepted {
+                        MSG_CHANNEL_SUCCESS
+                    } else {
+                        MSG_CHANNEL_FAILURE
+                    };
+                    sender.send(&Writer::new(reply).u32(opened.remote).finish())?;
+                }
+                if matches!(request, b"shell" | b"exec") {
+                    break;
+                }
+            }
+            MSG_CHANNEL_EOF | MSG_CHANNEL_CLOSE => bail!("the channel closed before the game"),
+            _ => {}
+        }
+    }
+    Ok(opened.expect("a shell on the channel"))
+}
+
+// Fails on a message for a channel other than the session's
+fn ensure_channel(recipient: u32) -> anyhow::Result<()> {
+    if recipient != CHANNEL {
+        bail!("no channel {}", recipient);
+    }
+    Ok(())
+}
+
+// Columns and rows as a terminal backend takes them; zero means the client
+// doesn't know
+fn clamp_size(columns: u32, rows: u32) -> (u16, u16) {
+    let clamp = |value: u32, default: u16| match value {
+        0 => default,
+        value => value.min(u32::from(u16::MAX)) as u16,
+    };
+    (clamp(columns, DEFAULT_SIZE.0), clamp(rows, DEFAULT_SIZE.1))
+}
+
+// What the thread reading the client tells the game
+enum Incoming {
+    Data(Vec<u8>),
+    Resize(u16, u16),
+}
+
+// The session channel, shared by the game writing to it and the thread
+// reading from the client
+struct Channel {
+    remote: u32,
+    max_packet: u32,
+    sender: Mutex<Sender>,
+    // How much more data the client takes; None once it is gone
+    window: Mutex<Option<u32>>,
+    window_grown: Condvar,
+    size: Mutex<Rect>,
+}
+
+impl Channel {
+    fn send(&self, message: &[u8]) -> io::Result<()> {
+        self.sender.lock().expect("sender lock").send(message)
+    }
+
+    // Sends `data` as it fits in the client's window, waiting for room
+    fn write_data(&self, mut data: &[u8]) -> io::Result<()> {
+        while !data.is_empty() {
+            let count = {
+                let mut window = self.window.lock().expect("window lock");
+                while *window == Some(0) {
+                    window = self.window_grown.wait(window).expect("window lock");
+                }
+                let Some(room) = window.as_mut() else {
+                    return Err(io::ErrorKind::BrokenPipe.into());
+                };
+                let count = (*room).min(self.max_packet).min(data.len() as u32);
+                *room -= count;
+                count as usize
+            };
+            let (packet, rest) = data.split_at(count);
+            self.send(
+                &Writer::new(MSG_CHANNEL_DATA)
+                    .u32(self.remote)
+                    .string(packet)
+                    .finish(),
+            )?;
+            data = rest;
+        }
+        Ok(())
+    }
+
+    // Closes the channel with `status` as the exit status of the game
+    fn end(&self, status: u32) -> io::Result<()> {
+        self.send(
+            &Writer::new(MSG_CHANNEL_REQUEST)
+                .u32(self.remote)
+                .string(b"exit-status")
+                .bool(false)
+                .u32(status)
+                .finish(),
+        )?;
+        self.send(&Writer::new(MSG_CHANNEL_EOF).u32(self.remote).finish())?;
+        self.send(&Writer::new(MSG_CHANNEL_CLOSE).u32(self.remote).finish())
+    }
+
+    // Reads from the client until it goes, passing keys and resizes on to
+    // the game. A client that sends past the window it was given, or to
+    // another channel, is let go
+    fn read(&self, mut receiver: Receiver, events: mpsc::Sender<Incoming>) {
+        // How much more the client may send
+        let mut window = WINDOW;
+        while let Ok(message) = receiver.receive() {
+            let mut reader = Reader::new(&message);
+            let handled = match message[0] {
+                MSG_CHANNEL_DATA => self.data(&mut reader, &mut window, &events),
+                MSG_CHANNEL_WINDOW_ADJUST => self.adjust(&mut reader),
+                MSG_CHANNEL_REQUEST => self.request(&mut reader, &events),
+                MSG_GLOBAL_REQUEST => reader.string().and_then(|_| reader.bool()).map(|reply| {
+                    if reply {
+                        let _ = self.send(&[MSG_REQUEST_FAILURE]);
+                    }
+                }),
+                MSG_CHANNEL_EOF | MSG_CHANNEL_CLOSE => break,
+                _ => Ok(()),
+            };
+            if handled.is_err() {
+                break;
+            }
+        }
+        // Writers waiting for room give up
+        *self.window.lock().expect("window lock") = None;
+        self.window_grown.notify_all();
+    }
+
+    fn data(
+        &self,
+        reader: &mut Reader,
+        window: &mut u32,
+        events: &mpsc::Sender<Incoming>,
+    ) -> anyhow::Result<()> {
+        ensure_channel(reader.u32()?)?;
+        let data = reader.string()?;
+        if data.len() > MAX_DATA as usize || data.len() as u32 > *window {
+            bail!("the client sent past its window");
+        }
+        *window -= data.len() as u32;
+        // Room for more once half the window is used
+        if *window <= WINDOW / 2 {
+            let adjust = Writer::new(MSG_CHANNEL_WINDOW_ADJUST)
+                .u32(self.remote)
+                .u32(WINDOW - *window)
+                .finish();
+            self.send(&adjust)?;
+            *window = WINDOW;
+        }
+        let _ = events.send(Incoming::Data(data.to_vec()));
+        Ok(())
+    }
+
+    fn adjust(&self, reader: &mut Reader) -> anyhow::Result<()> {
+        ensure_channel(reader.u32()?)?;
+        let more = reader.u32()?;
+        let mut window = self.window.lock().expect("window lock");
+        if let Some(room) = window.as_mut() {
+            *room = room.saturating_add(more);
+        }
+        self.window_grown.notify_all();
+        Ok(())
+    }
+
+    fn request(&self, reader: &mut Reader, events: &mpsc::Sender<Incoming>) -> anyhow::Result<()> {
+        ensure_channel(reader.u32()?)?;
+        let request = reader.string()?;
+        let want_reply = reader.bool()?;
+        if request == b"window-change" {
+            let (columns, rows) = clamp_size(reader.u32()?, reader.u32()?);
+            *self.size.lock().expect("size lock") = Rect::new(0, 0, columns, rows);
+            let _ = events.send(Incoming::Resize(columns, rows));
+        } else if want_reply {
+            self.send(&Writer::new(MSG_CHANNEL_FAILURE).u32(self.remote).finish())?;
+        }
+        Ok(())
+    }
+}
+
+// Output to the client, sent as channel data when flushed
+struct Output {
+    channel: Arc<Channel>,
+    buffer: Vec<u8>,
+}
+
+impl Write for Output {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.buffer.extend(bytes);
+        Ok(bytes.len())
+    }
+
+    // Output to a client that is gone is dropped: the game finds out from
+    // its input, and the terminal still restores the cursor when dropped
+    fn flush(&mut self) -> io::Result<()> {
+        let buffer = std::mem::take(&mut self.buffer);
+        match self.channel.write_data(&buffer) {
+            Err(error) if error.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+            result => result,
+        }
+    }
+}
+
+// The client's terminal: crossterm's sequences, and the size the client
+// last gave
+struct Remote {
+    inner: CrosstermBackend<Output>,
+    channel: Arc<Channel>,
+}
+
+impl Remote {
+    fn new(channel: Arc<Channel>) -> Remote {
+        Remote {
+            inner: CrosstermBackend::new(Output {
+                channel: channel.clone(),
+                buffer: Vec::new(),
+            }),
+            channel,
+        }
+    }
+}
+
+impl Write for Remote {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.inner.write(bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Write::flush(&mut self.inner)
+    }
+}
+
+impl Backend for Remote {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        self.inner.draw(content)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.inner.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.inner.show_cursor()
+    }
+
+    // Asking the remote terminal would mean reading its reply among the
+    // keys; full-screen drawing never needs it
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        Ok((0, 0))
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.inner.set_cursor(x, y)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.inner.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.inner.clear_region(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        Ok(*self.channel.size.lock().expect("size lock"))
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        let size = self.size()?;
+        Ok(WindowSize {
+            columns_rows: Size {
+                width: size.width,
+                height: size.height,
+            },
+            pixels: Size::default(),
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Backend::flush(&mut self.inner)
+    }
+}
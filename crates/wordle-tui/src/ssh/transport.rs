@@ -0,0 +1,510 @@
+//! The SSH transport layer (RFC 4253), as much of it as the game needs:
+//! the version exchange, one curve25519-sha256 key exchange signed with an
+//! Ed25519 host key, and the chacha20-poly1305@openssh.com cipher.
+//! Connections are never re-keyed: a game session sends far less than the
+//! amounts that would call for it.
+//!
+//! The key exchange is OpenSSH's strict one (`kex-strict-s-v00`), which
+//! clients have to offer too, as OpenSSH does since 9.6: nothing but its
+//! own messages may come before NEWKEYS, and sequence numbers start over
+//! after it. Without it, a machine in the middle could drop the first
+//! encrypted packets unseen (the Terrapin attack, CVE-2023-48795).
+//!
+//! [`accept`] runs the exchange and splits the connection into a
+//! [`Sender`] and a [`Receiver`], so that one thread can wait on the client
+//! while another writes to it.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+use anyhow::{bail, ensure, Context};
+use chacha20::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+use chacha20::ChaCha20Legacy;
+use ed25519_dalek::{Signer, SigningKey};
+use poly1305::universal_hash::KeyInit;
+use poly1305::Poly1305;
+use sha2::{Digest, Sha256};
+
+pub const MSG_DISCONNECT: u8 = 1;
+pub const MSG_IGNORE: u8 = 2;
+pub const MSG_UNIMPLEMENTED: u8 = 3;
+pub const MSG_DEBUG: u8 = 4;
+pub const MSG_SERVICE_REQUEST: u8 = 5;
+pub const MSG_SERVICE_ACCEPT: u8 = 6;
+pub const MSG_KEXINIT: u8 = 20;
+pub const MSG_NEWKEYS: u8 = 21;
+pub const MSG_KEX_ECDH_INIT: u8 = 30;
+pub const MSG_KEX_ECDH_REPLY: u8 = 31;
+pub const MSG_USERAUTH_REQUEST: u8 = 50;
+pub const MSG_USERAUTH_SUCCESS: u8 = 52;
+pub const MSG_GLOBAL_REQUEST: u8 = 80;
+pub const MSG_REQUEST_FAILURE: u8 = 82;
+pub const MSG_CHANNEL_OPEN: u8 = 90;
+pub const MSG_CHANNEL_OPEN_CONFIRMATION: u8 = 91;
+pub const MSG_CHANNEL_OPEN_FAILURE: u8 = 92;
+pub const MSG_CHANNEL_WINDOW_ADJUST: u8 = 93;
+pub const MSG_CHANNEL_DATA: u8 = 94;
+pub const MSG_CHANNEL_EOF: u8 = 96;
+pub const MSG_CHANNEL_CLOSE: u8 = 97;
+pub const MSG_CHANNEL_REQUEST: u8 = 98;
+pub const MSG_CHANNEL_SUCCESS: u8 = 99;
+pub const MSG_CHANNEL_FAILURE: u8 = 100;
+
+/// What the server says it is, before anything else.
+const VERSION: &str = concat!("SSH-2.0-wordle_", env!("CARGO_PKG_VERSION"));
+
+const KEX: &str = "curve25519-sha256";
+const KEX_LIBSSH: &str = "curve25519-sha256@libssh.org";
+/// Pseudo-algorithms by which the server and the client say they do strict
+/// key exchange.
+const STRICT_SERVER: &str = "kex-strict-s-v00@openssh.com";
+const STRICT_CLIENT: &str = "kex-strict-c-v00@openssh.com";
+const HOST_KEY: &str = "ssh-ed25519";
+const CIPHER: &str = "chacha20-poly1305@openssh.com";
+/// Offered for the negotiation only: the cipher authenticates packets
+/// itself, so no MAC is used.
+const MAC: &str = "hmac-sha2-256";
+const COMPRESSION: &str = "none";
+
+/// The largest packet taken from a client, as in OpenSSH.
+const MAX_PACKET: usize = 256 * 1024;
+/// Length of the Poly1305 tag after each encrypted packet.
+const TAG_LENGTH: usize = 16;
+
+/// Builds the payload of a message, field by field.
+pub struct Writer(Vec<u8>);
+
+impl Writer {
+    /// A message of type `kind`.
+    pub fn new(kind: u8) -> Writer {
+        Writer(vec![kind])
+    }
+
+    /// Fields that aren't a message, like a key or a signature.
+    pub fn blob() -> Writer {
+        Writer(Vec::new())
+    }
+
+    pub fn u8(mut self, value: u8) -> Writer {
+        self.0.push(value);
+        self
+    }
+
+    pub fn bool(self, value: bool) -> Writer {
+        self.u8(u8::from(value))
+    }
+
+    pub fn u32(mut self, value: u32) -> Writer {
+        self.0.extend(value.to_be_bytes());
+        self
+    }
+
+    /// A length-prefixed string of bytes.
+    pub fn string(mut self, value: &[u8]) -> Writer {
+        self.0.extend((value.len() as u32).to_be_bytes());
+        self.0.extend(value);
+        self
+    }
+
+    /// `value`, a big-endian unsigned number, as an mpint: no leading
+    /// zeros, and a zero byte before a high bit that would read as a sign.
+    pub fn mpint(self, value: &[u8]) -> Writer {
+        let start = value.iter().position(|byte| *byte != 0);
+        let digits = start.map_or(&[][..], |start| &value[start..]);
+        if digits.first().is_some_and(|byte| byte & 0x80 != 0) {
+            let mut signed = vec![0];
+            signed.extend(digits);
+            self.string(&signed)
+        } else {
+            self.string(digits)
+        }
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+/// Reads the fields of a message in order, failing on a message cut
+/// short.
+pub struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    /// Reads `payload` after its message type.
+    pub fn new(payload: &'a [u8]) -> Reader<'a> {
+        Reader(payload.get(1..).unwrap_or_default())
+    }
+
+    fn take(&mut self, count: usize) -> anyhow::Result<&'a [u8]> {
+        ensure!(self.0.len() >= count, "message cut short");
+        let (taken, rest) = self.0.split_at(count);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    pub fn u8(&mut self) -> anyhow::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn bool(&mut self) -> anyhow::Result<bool> {
+        Ok(self.u8()? != 0)
+    }
+
+    pub fn u32(&mut self) -> anyhow::Result<u32> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into()?))
+    }
+
+    pub fn string(&mut self) -> anyhow::Result<&'a [u8]> {
+        let length = self.u32()? as usize;
+        self.take(length)
+    }
+
+    /// A string read as text, replacing what isn't UTF-8.
+    pub fn text(&mut self) -> anyhow::Result<String> {
+        Ok(String::from_utf8_lossy(self.string()?).into_owned())
+    }
+}
+
+// The keys of one direction of chacha20-poly1305@openssh.com: the second
+// half of the derived key encrypts packet lengths, the first half the rest
+// and, from the start of its stream, the key of each packet's tag
+struct Keys {
+    main: [u8; 32],
+    length: [u8; 32],
+}
+
+impl Keys {
+    fn new(derived: &[u8; 64]) -> Keys {
+        let (main, length) = derived.split_at(32);
+        Keys {
+            main: main.try_into().expect("half of 64 bytes"),
+            length: length.try_into().expect("half of 64 bytes"),
+        }
+    }
+
+    // The cipher of packet `sequence`, keyed with `key`; the sequence
+    // number is the nonce
+    fn cipher(key: &[u8; 32], sequence: u32) -> ChaCha20Legacy {
+        let nonce = u64::from(sequence).to_be_bytes();
+        ChaCha20Legacy::new(key.into(), &nonce.into())
+    }
+
+    fn crypt_length(&self, sequence: u32, length: &mut [u8; 4]) {
+        Self::cipher(&self.length, sequence).apply_keystream(length);
+    }
+
+    // The payload is encrypted from the second block of the stream on
+    fn crypt_body(&self, sequence: u32, body: &mut [u8]) {
+        let mut cipher = Self::cipher(&self.main, sequence);
+        cipher.seek(64);
+        cipher.apply_keystream(body);
+    }
+
+    fn tag(&self, sequence: u32, packet: &[u8]) -> [u8; TAG_LENGTH] {
+        let mut key = [0; 32];
+        Self::cipher(&self.main, sequence).apply_keystream(&mut key);
+        Poly1305::new(&key.into()).compute_unpadded(packet).into()
+    }
+}
+
+/// The half of a connection that sends packets to the client.
+pub struct Sender {
+    stream: TcpStream,
+    sequence: u32,
+    keys: Option<Keys>,
+}
+
+impl Sender {
+    /// Sends a message, encrypted once the key exchange is over.
+    pub fn send(&mut self, payload: &[u8]) -> std::io::Result<()> {
+        // Padding brings the packet to a multiple of eight bytes, without
+        // its length when that is encrypted on its own
+        let counted = if self.keys.is_some() { 1 } else { 5 } + payload.len();
+        let mut padding = 8 - counted % 8;
+        if padding < 4 {
+            padding += 8;
+        }
+        let length = (1 + payload.len() + padding) as u32;
+
+        let mut packet = Vec::with_capacity(4 + length as usize + TAG_LENGTH);
+        packet.extend(length.to_be_bytes());
+        packet.push(padding as u8);
+        packet.extend(payload);
+        packet.extend((0..padding).map(|_| rand::random::<u8>()));
+        if let Some(keys) = &self.keys {
+            let (length, body) = packet.split_at_mut(4);
+            keys.crypt_length(self.sequence, length.try_into().expect("four bytes"));
+            keys.crypt_body(self.sequence, body);
+            let tag = keys.tag(self.sequence, &packet);
+            packet.extend(tag);
+        }
+        self.sequence = self.sequence.wrapping_add(1);
+        self.stream.write_all(&packet)
+    }
+
+    /// Tells the client why the connection ends, as far as it listens.
+    pub fn disconnect(&mut self, reason: &str) {
+        // Reason 11: by application
+        let message = Writer::new(MSG_DISCONNECT)
+            .u32(11)
+            .string(reason.as_bytes())
+            .string(b"")
+            .finish();
+        let _ = self.send(&message);
+        let _ = self.stream.shutdown(std::net::Shutdown::Both);
+    }
+}
+
+/// The half of a connection that receives packets from the client.
+pub struct Receiver {
+    stream: TcpStream,
+    sequence: u32,
+    keys: Option<Keys>,
+}
+
+impl Receiver {
+    /// Waits for the next message, checking it came from the client
+    /// unaltered. Messages meant to be ignored are skipped.
+    pub fn receive(&mut self) -> anyhow::Result<Vec<u8>> {
+        loop {
+            let payload = self.receive_any()?;
+            match payload.first() {
+                None => bail!("empty message"),
+                Some(&(MSG_IGNORE | MSG_DEBUG | MSG_UNIMPLEMENTED)) => continue,
+                Some(&MSG_DISCONNECT) => bail!("the client disconnected"),
+                Some(&MSG_KEXINIT) => bail!("the client asked for a new key exchange"),
+                Some(_) => return Ok(payload),
+            }
+        }
+    }
+
+    // The next message of the key exchange, which has to be of `kind`: not
+    // even one to ignore may come first
+    fn expect(&mut self, kind: u8, what: &str) -> anyhow::Result<Vec<u8>> {
+        let message = self.receive_any()?;
+        let got = message.first().copied().unwrap_or_default();
+        ensure!(got == kind, "expected {}, got message {}", what, got);
+        Ok(message)
+    }
+
+    fn receive_any(&mut self) -> anyhow::Result<Vec<u8>> {
+        let mut length = [0; 4];
+        self.stream.read_exact(&mut length)?;
+        let encrypted_length = length;
+        if let Some(keys) = &self.keys {
+            keys.crypt_length(self.sequence, &mut length);
+        }
+        let length = u32::from_be_bytes(length) as usize;
+        ensure!(
+            (5..=MAX_PACKET).contains(&length),
+            "packet of {} bytes",
+            length
+        );
+
+        let tag = if self.keys.is_some() { TAG_LENGTH } else { 0 };
+        let mut body = vec![0; length + tag];
+        self.stream.read_exact(&mut body)?;
+        if let Some(keys) = &self.keys {
+            let (body, tag) = body.split_at_mut(length);
+            let mut packet = encrypted_length.to_vec();
+            packet.extend(&*body);
+            let expected = keys.tag(self.sequence, &packet);
+            // Compared in full, so the time taken doesn't tell where they
+            // differ
+            let difference = expected
+                .iter()
+                .zip(tag.iter())
+                .fold(0, |difference, (a, b)| difference | (a ^ b));
+            ensure!(difference == 0, "a packet failed its authentication");
+            keys.crypt_body(self.sequence, body);
+        }
+        body.truncate(length);
+        self.sequence = self.sequence.wrapping_add(1);
+
+        let padding = body[0] as usize;
+        ensure!(padding < length, "padding longer than its packet");
+        Ok(body[1..length - padding].to_vec())
+    }
+}
+
+/// The key blob of `host_key`, as clients record it in `known_hosts`.
+pub fn host_key_blob(host_key: &SigningKey) -> Vec<u8> {
+    let blob = Writer::blob()
+        .string(HOST_KEY.as_bytes())
+        .string(host_key.verifying_key().as_bytes());
+    blob.finish()
+}
+
+/// Runs the version and key exchanges with a client that just connected,
+/// returning the two halves of the now encrypted connection.
+pub fn accept(stream: TcpStream, host_key: &SigningKey) -> anyhow::Result<(Sender, Receiver)> {
+    let mut sender = Sender {
+        stream: stream.try_clone()?,
+        sequence: 0,
+        keys: None,
+    };
+    let mut receiver = Receiver {
+        stream,
+        sequence: 0,
+        keys: None,
+    };
+
+    sender
+        .stream
+        .write_all(format!("{}\r\n", VERSION).as_bytes())?;
+    let client_version = read_version(&mut receiver.stream)?;
+
+    let server_init = kex_init();
+    sender.send(&server_init)?;
+    let client_init = receiver.expect(MSG_KEXINIT, "the key exchange")?;
+    negotiate(&client_init)?;
+
+    let ecdh_init = receiver.expect(MSG_KEX_ECDH_INIT, "the client's key")?;
+    let client_public: [u8; 32] = Reader::new(&ecdh_init)
+        .string()?
+        .try_into()
+        .context("the client's key isn't a Curve25519 key")?;
+    let secret: [u8; 32] = rand::random();
+    let server_public = x25519_dalek::x25519(secret, x25519_dalek::X25519_BASEPOINT_BYTES);
+    let shared = x25519_dalek::x25519(secret, client_public);
+    ensure!(shared != [0; 32], "the client's key is a weak point");
+
+    let key_blob = host_key_blob(host_key);
+    let exchange_hash: [u8; 32] = Sha256::new()
+        .chain_update(
+            Writer::blob()
+                .string(client_version.as_bytes())
+                .string(VERSION.as_bytes())
+                .string(&client_init)
+                .string(&server_init)
+                .string(&key_blob)
+                .string(&client_public)
+                .string(&server_public)
+                .mpint(&shared)
+                .finish(),
+        )
+        .finalize()
+        .into();
+    let signature = Writer::blob()
+        .string(HOST_KEY.as_bytes())
+        .string(&host_key.sign(&exchange_hash).to_bytes())
+        .finish();
+    sender.send(
+        &Writer::new(MSG_KEX_ECDH_REPLY)
+            .string(&key_blob)
+            .string(&server_public)
+            .string(&signature)
+            .finish(),
+    )?;
+    sender.send(&[MSG_NEWKEYS])?;
+    sender.sequence = 0;
+    receiver.expect(MSG_NEWKEYS, "the end of the key exchange")?;
+    receiver.sequence = 0;
+
+    // The first exchange's hash is the session identifier
+    let derive = |letter: u8| derive_key(&shared, &exchange_hash, letter, &exchange_hash);
+    receiver.keys = Some(Keys::new(&derive(b'C')));
+    sender.keys = Some(Keys::new(&derive(b'D')));
+    Ok((sender, receiver))
+}
+
+// The client's version line, without its line ending. Read a byte at a
+// time so that nothing after it is taken from the stream
+fn read_version(stream: &mut TcpStream) -> anyhow::Result<String> {
+    let mut line = Vec::new();
+    let mut byte = [0];
+    while line.len() < 255 {
+        stream.read_exact(&mut byte)?;
+        if byte[0] == b'\n' {
+            let version = String::from_utf8_lossy(&line)
+                .trim_end_matches('\r')
+                .to_string();
+            ensure!(
+                version.starts_with("SSH-2.0-") || version.starts_with("SSH-1.99-"),
+                "not an SSH 2 client: {:?}",
+                version
+            );
+            return Ok(version);
+        }
+        line.push(byte[0]);
+    }
+    bail!("not an SSH client")
+}
+
+fn kex_init() -> Vec<u8> {
+    let cookie: [u8; 16] = rand::random();
+    let mut init = Writer::new(MSG_KEXINIT);
+    for byte in cookie {
+        init = init.u8(byte);
+    }
+    let kex = format!("{},{},{}", KEX, KEX_LIBSSH, STRICT_SERVER);
+    init.string(kex.as_bytes())
+        .string(HOST_KEY.as_bytes())
+        .string(CIPHER.as_bytes())
+        .string(CIPHER.as_bytes())
+        .string(MAC.as_bytes())
+        .string(MAC.as_bytes())
+        .string(COMPRESSION.as_bytes())
+        .string(COMPRESSION.as_bytes())
+        .string(b"")
+        .string(b"")
+        .bool(false)
+        .u32(0)
+        .finish()
+}
+
+// Checks the client can use the algorithms of the server, which it has
+// only one of for each
+fn negotiate(client_init: &[u8]) -> anyhow::Result<()> {
+    let mut reader = Reader::new(client_init);
+    reader.take(16)?;
+    let mut lists = Vec::new();
+    for _ in 0..8 {
+        lists.push(reader.text()?);
+    }
+    let offers = |list: &str, name: &str| list.split(',').any(|offered| offered == name);
+    ensure!(
+        offers(&lists[0], KEX) || offers(&lists[0], KEX_LIBSSH),
+        "the client doesn't offer {} key exchange",
+        KEX
+    );
+    ensure!(
+        offers(&lists[0], STRICT_CLIENT),
+        "the client doesn't do strict key exchange ({})",
+        STRICT_CLIENT
+    );
+    ensure!(
+        offers(&lists[1], HOST_KEY),
+        "the client doesn't accept {} host keys",
+        HOST_KEY
+    );
+    for list in &lists[2..4] {
+        ensure!(offers(list, CIPHER), "the client doesn't offer {}", CIPHER);
+    }
+    for list in &lists[6..8] {
+        ensure!(offers(list, COMPRESSION), "the client wants compression");
+    }
+    Ok(())
+}
+
+// The 64 bytes of the key for `letter` (RFC 4253, section 7.2): the hash
+// of the shared secret, exchange hash, letter and session identifier,
+// then of the secret, hash and key so far
+fn derive_key(shared: &[u8; 32], hash: &[u8; 32], letter: u8, session: &[u8; 32]) -> [u8; 64] {
+    let secret = Writer::blob().mpint(shared).finish();
+    let first = Sha256::new()
+        .chain_update(&secret)
+        .chain_update(hash)
+        .chain_update([letter])
+        .chain_update(session)
+        .finalize();
+    let second = Sha256::new()
+        .chain_update(&secret)
+        .chain_update(hash)
+        .chain_update(first)
+        .finalize();
+    let mut key = [0; 64];
+    key[..32].copy_from_slice(&first);
+    key[32..].copy_from_slice(&second);
+    key
+}
@@ -0,0 +1,127 @@
+//! Results of the games finished this session, and the figures the stats
+//! screen shows about them.
+
+use crate::game::rarity::Rarity;
+use crate::game::MAX_ATTEMPTS;
+use crate::i18n::Language;
+
+/// How one finished game went.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Played {
+    /// The answer, accents folded.
+    pub word: String,
+    pub won: bool,
+    /// Guesses submitted before the game ended.
+    pub guesses: usize,
+    /// Rating of the answer, when the word list has one.
+    pub difficulty: Option<f32>,
+    /// How common the answer is, when the word list ranks it.
+    pub rarity: Option<Rarity>,
+    /// Language of the word list it was played with.
+    pub language: Language,
+    /// Longest run of guesses that each found a new green (see
+    /// [`Game::best_momentum`](crate::game::Game::best_momentum)).
+    pub momentum: usize,
+}
+
+impl Played {
+    /// Points for the game: a point per guess to spare when it was won,
+    /// counted twice on an uncommon word and three times on a rare one,
+    /// and a point for each guess of momentum past the first. Nothing for
+    /// a loss.
+    pub fn score(&self) -> u32 {
+        if !self.won {
+            return 0;
+        }
+        let spare = (MAX_ATTEMPTS + 1).saturating_sub(self.guesses).max(1) as u32;
+        let bonus = self.momentum.saturating_sub(1) as u32;
+        spare * self.rarity.map_or(1, Rarity::weight) + bonus
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    /// Finished games, oldest first.
+    pub played: Vec<Played>,
+}
+
+impl Stats {
+    pub fn record(&mut self, played: Played) {
+        self.played.push(played);
+    }
+
+    /// Languages played in, in the order they first were.
+    pub fn languages(&self) -> Vec<Language> {
+        let mut languages = Vec::new();
+        for game in &self.played {
+            if !languages.contains(&game.language) {
+                languages.push(game.language);
+            }
+        }
+        languages
+    }
+
+    /// The games played in `language` only.
+    pub fn in_language(&self, language: Language) -> Stats {
+        Stats {
+            played: self
+                .played
+                .iter()
+                .filter(|game| game.language == language)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn games(&self) -> usize {
+        self.played.len()
+    }
+
+    pub fn wins(&self) -> usize {
+        self.played.iter().filter(|game| game.won).count()
+    }
+
+    /// Games won in a row, up to the last one.
+    pub fn streak(&self) -> usize {
+        self.played.iter().rev().take_while(|game| game.won).count()
+    }
+
+    /// Share of the games won, in percent.
+    pub fn win_rate(&self) -> Option<f32> {
+        (self.games() > 0).then(|| self.wins() as f32 * 100.0 / self.games() as f32)
+    }
+
+    /// Mean number of guesses of the games won.
+    pub fn average_guesses(&self) -> Option<f32> {
+        let wins = self.wins();
+        (wins > 0).then(|| {
+            let guesses: usize = self
+                .played
+                .iter()
+                .filter(|game| game.won)
+                .map(|game| game.guesses)
+                .sum();
+            guesses as f32 / wins as f32
+        })
+    }
+
+    /// Points of every game, weighted by how rare their words were.
+    pub fn rarity_score(&self) -> u32 {
+        self.played.iter().map(Played::score).sum()
+    }
+
+    /// Mean rating of the words faced, and how many games had one.
+    pub fn average_difficulty(&self) -> Option<(f32, usize)> {
+        let ratings: Vec<f32> = self
+            .played
+            .iter()
+            .filter_map(|game| game.difficulty)
+            .collect();
+        (!ratings.is_empty()).then(|| {
+            (
+                ratings.iter().sum::<f32>() / ratings.len() as f32,
+                ratings.len(),
+            )
+        })
+    }
+}
@@ -0,0 +1,306 @@
+//! Terminal helpers that go beyond what ratatui does for us.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crossterm::{
+    cursor,
+    event::Event,
+    execute, style,
+    terminal::{BeginSynchronizedUpdate, EndSynchronizedUpdate},
+    QueueableCommand,
+};
+use ratatui::prelude::*;
+
+use crate::app::{App, Screen};
+use crate::config::Toggle;
+use crate::sound;
+use crate::ssh;
+use crate::ui::{self, profile};
+
+/// DEC private mode of synchronized output.
+pub const SYNCHRONIZED_OUTPUT: u16 = 2026;
+/// DEC private mode of mouse reports in the SGR encoding, the one
+/// crossterm asks for.
+pub const SGR_MOUSE: u16 = 1006;
+
+/// How often the game is ticked.
+const TICK_RATE: Duration = Duration::from_millis(250);
+
+/// How long to wait for the terminal to answer a capability query.
+const QUERY_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Decides whether frames should be wrapped in synchronized-output sequences.
+///
+/// `Auto` queries the terminal, so it must be called after raw mode has been
+/// enabled (otherwise the reply would be echoed) and before the event loop
+/// starts reading input.
+pub fn synchronized_output_enabled(setting: Toggle) -> bool {
+    match setting {
+        Toggle::On => true,
+        Toggle::Off => false,
+        Toggle::Auto => query_mode(SYNCHRONIZED_OUTPUT).unwrap_or(false),
+    }
+}
+
+/// Decides whether images can be shown with Sixel graphics. Like
+/// [`synchronized_output_enabled`], `Auto` queries the terminal, so it must
+/// be called in raw mode before the event loop starts.
+pub fn sixel_enabled(setting: Toggle) -> bool {
+    match setting {
+        Toggle::On => true,
+        Toggle::Off => false,
+        Toggle::Auto => query_sixel().unwrap_or(false),
+    }
+}
+
+/// Draws one frame, wrapped in BSU/ESU when `synchronized` is set so that
+/// slow links (SSH) show the whole frame at once instead of tearing.
+pub fn draw<B, F>(terminal: &mut Terminal<B>, synchronized: bool, render: F) -> io::Result<()>
+where
+    B: Backend + Write,
+    F: FnOnce(&mut Frame),
+{
+    if synchronized {
+        terminal.backend_mut().queue(BeginSynchronizedUpdate)?;
+    }
+    terminal.draw(render)?;
+    if synchronized {
+        terminal.backend_mut().queue(EndSynchronizedUpdate)?;
+        Write::flush(terminal.backend_mut())?;
+    }
+    Ok(())
+}
+
+/// Runs `app` on `terminal` until it quits. `next_event` waits up to the
+/// given time for input, `None` if there was none; frames are drawn when
+/// something changed, at most once per `frame_interval`.
+pub fn run<B, E>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    synchronized: bool,
+    frame_interval: Duration,
+    mut next_event: E,
+) -> io::Result<()>
+where
+    B: Backend + Write,
+    E: FnMut(Duration) -> io::Result<Option<Event>>,
+{
+    let mut last_tick = Instant::now();
+
+    // Frames are only drawn when something changed, and at most once per
+    // frame interval, so bursts of events (pastes, key repeat) share a frame
+    let mut last_frame: Option<Instant> = None;
+    let mut needs_redraw = true;
+    // Whether a card image is on screen, which the next frames don't know
+    // about and wouldn't erase
+    let mut image_shown = false;
+
+    loop {
+        let frame_due = last_frame.is_none_or(|t| t.elapsed() >= frame_interval);
+        if needs_redraw && frame_due {
+            profile::span("frame", || {
+                draw(terminal, synchronized, |f| {
+                    profile::span("draw", || ui::draw(f, app))
+                })
+            })?;
+            needs_redraw = false;
+            last_frame = Some(Instant::now());
+
+            // The card image goes where the frame left room for it
+            let area = *app.card_area.borrow();
+            match (&app.screen, area) {
+                (Screen::Card(shared), Some(area)) if shared.sixel.is_some() => {
+                    let sixel = shared.sixel.as_deref().unwrap_or_default();
+                    execute!(
+                        terminal.backend_mut(),
+                        cursor::MoveTo(area.x, area.y),
+                        style::Print(sixel)
+                    )?;
+                    image_shown = true;
+                }
+                _ if image_shown => {
+                    terminal.clear()?;
+                    image_shown = false;
+                    needs_redraw = true;
+                }
+                _ => {}
+            }
+        }
+
+        let mut timeout = TICK_RATE
+            .checked_sub(last_tick.elapsed())
+            .unwrap_or_else(|| Duration::from_secs(0));
+        if needs_redraw {
+            // Wake up in time to draw the pending frame
+            let until_frame = last_frame.map_or(Duration::ZERO, |t| {
+                frame_interval.saturating_sub(t.elapsed())
+            });
+            timeout = timeout.min(until_frame);
+        }
+
+        if let Some(event) = next_event(timeout)? {
+            if let Event::Resize(columns, rows) = event {
+                // Starts the next frame afresh at the new size, without
+                // what was on screen, card image included
+                terminal.resize(Rect::new(0, 0, columns, rows))?;
+                image_shown = false;
+            }
+            needs_redraw = true;
+            app.handle_event(event);
+        }
+
+        if let Some(text) = app.clipboard.take() {
+            execute!(terminal.backend_mut(), style::Print(copy_sequence(&text)))?;
+        }
+        for cue in app.sound.take() {
+            sound::play(cue, &app.sound.config, terminal.backend_mut())?;
+        }
+
+        if last_tick.elapsed() >= TICK_RATE {
+            app.on_tick();
+            last_tick = Instant::now();
+            needs_redraw = true;
+        }
+
+        // Keep drawing while an effect or a live clock runs; the next tick
+        // draws the board at rest
+        if app.animations.running() || app.clock_running() {
+            needs_redraw = true;
+        }
+
+        if app.should_quit {
+            return Ok(());
+        }
+    }
+}
+
+/// The OSC 52 sequence asking the terminal to put `text` on the system
+/// clipboard. Terminals that don't support it ignore it.
+pub fn copy_sequence(text: &str) -> String {
+    let mut encoded = ssh::base64(text.as_bytes());
+    while !encoded.len().is_multiple_of(4) {
+        encoded.push('=');
+    }
+    format!("\x1b]52;c;{}\x07", encoded)
+}
+
+/// Asks the terminal whether it knows the DEC private `mode`, with
+/// DECRQM; `None` when it doesn't answer. Must be called in raw mode.
+///
+/// The query is followed by a primary device attributes request, which
+/// every terminal answers, so we always know when to stop reading: if the
+/// DA reply arrives without a DECRQM reply, the mode is unsupported.
+pub fn query_mode(mode: u16) -> Option<bool> {
+    let reply = query(format!("\x1b[?{}$p\x1b[c", mode).as_bytes())?;
+    // Reply is CSI ? mode ; Ps $ y where Ps 1 or 2 means recognized (set/reset)
+    Some((1..=2).any(|ps| reply.contains(&format!("\x1b[?{};{}$y", mode, ps))))
+}
+
+/// How many columns the terminal advances the cursor for `text`, found by
+/// printing it at the start of the line and asking where the cursor went;
+/// `None` when it doesn't answer. The line is cleared afterwards. Must be
+/// called in raw mode.
+pub fn measure_width(text: &str) -> Option<u16> {
+    let reply = query(format!("\r{}\x1b[6n\x1b[c", text).as_bytes());
+    print!("\r\x1b[2K");
+    let _ = io::stdout().flush();
+    // The cursor position report is CSI row ; column R
+    let reply = reply?;
+    let start = reply.find("\x1b[")? + 2;
+    let end = start + reply[start..].find('R')?;
+    let (_, column) = reply[start..end].split_once(';')?;
+    Some(column.parse::<u16>().ok()?.saturating_sub(1))
+}
+
+// Sixel support is attribute 4 of the primary device attributes reply,
+// CSI ? 62 ; 4 ; ... c
+fn query_sixel() -> Option<bool> {
+    let reply = query(b"\x1b[c")?;
+    let start = reply.find("\x1b[?")? + 3;
+    let attributes = reply[start..].trim_end_matches('c');
+    Some(
+        attributes
+            .split(';')
+            .skip(1)
+            .any(|attribute| attribute == "4"),
+    )
+}
+
+// Writes `request` to the terminal and reads the reply, up to the primary
+// device attributes reply that must end it. Each byte is only read once
+// `select` says it is there, so nothing is left reading the terminal past
+// the timeout to swallow the keys typed next.
+#[cfg(unix)]
+fn query(request: &[u8]) -> Option<String> {
+    use std::fs::OpenOptions;
+    use std::io::Read;
+    use std::os::unix::io::AsRawFd;
+    use std::time::Instant;
+
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    let fd = tty.as_raw_fd();
+    if fd >= libc::FD_SETSIZE as i32 {
+        return None;
+    }
+
+    tty.write_all(request).ok()?;
+    tty.flush().ok()?;
+
+    let deadline = Instant::now() + QUERY_TIMEOUT;
+    let mut reply = Vec::new();
+    let mut byte = [0u8; 1];
+    loop {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return None;
+        }
+        let mut timeout = libc::timeval {
+            tv_sec: left.as_secs() as libc::time_t,
+            tv_usec: left.subsec_micros() as libc::suseconds_t,
+        };
+        // SAFETY: the set is plain data, emptied before `fd` (open, and
+        // below FD_SETSIZE) goes in; `select` only writes to it and to
+        // `timeout`
+        let ready = unsafe {
+            let mut set: libc::fd_set = std::mem::zeroed();
+            libc::FD_ZERO(&mut set);
+            libc::FD_SET(fd, &mut set);
+            libc::select(
+                fd + 1,
+                &mut set,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                &mut timeout,
+            )
+        };
+        match ready {
+            0 => return None,
+            _ if ready < 0 => {
+                if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return None;
+            }
+            _ => {}
+        }
+        if tty.read(&mut byte).ok()? != 1 {
+            return None;
+        }
+        reply.push(byte[0]);
+        // The DA reply ends with 'c' and always comes last
+        if byte[0] == b'c' && reply.windows(3).any(|w| w == b"\x1b[?") {
+            break;
+        }
+    }
+    Some(String::from_utf8_lossy(&reply).into_owned())
+}
+
+#[cfg(not(unix))]
+fn query(_request: &[u8]) -> Option<String> {
+    None
+}
@@ -0,0 +1,90 @@
+//! Letters typed and then deleted, across every game, for the heatmap of
+//! the stats screen. They are kept in `typos.json` in the data directory,
+//! saved as each game ends.
+//!
+//! A deleted letter counts as a correction whatever the reason, so a guess
+//! taken back and typed again counts too: its letters come back to the row
+//! and are deleted from there.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+/// How often each letter was typed, and deleted.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Typos {
+    typed: BTreeMap<char, u64>,
+    deleted: BTreeMap<char, u64>,
+}
+
+impl Typos {
+    /// Reads the counts, starting afresh if there are none.
+    pub fn load() -> Typos {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    /// Counts `letter` typed.
+    pub fn typed(&mut self, letter: char) {
+        *self.typed.entry(letter).or_default() += 1;
+    }
+
+    /// Counts `letter` deleted.
+    pub fn deleted(&mut self, letter: char) {
+        *self.deleted.entry(letter).or_default() += 1;
+    }
+
+    /// Whether any letter was deleted yet.
+    pub fn is_empty(&self) -> bool {
+        self.deleted.is_empty()
+    }
+
+    /// Share of the times `letter` was typed that it was deleted, from 0
+    /// to 1.
+    pub fn rate(&self, letter: char) -> f64 {
+        let typed = self.typed.get(&letter).copied().unwrap_or(0);
+        let deleted = self.deleted.get(&letter).copied().unwrap_or(0);
+        if typed == 0 {
+            0.0
+        } else {
+            (deleted as f64 / typed as f64).min(1.0)
+        }
+    }
+
+    /// The `count` letters deleted most often for how often they were
+    /// typed, with their rate, most first.
+    pub fn worst(&self, count: usize) -> Vec<(char, f64)> {
+        let mut rates: Vec<(char, f64)> = self
+            .deleted
+            .keys()
+            .map(|&letter| (letter, self.rate(letter)))
+            .collect();
+        rates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        rates.truncate(count);
+        rates
+    }
+
+    /// Saves the counts.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, text + "\n")
+    }
+
+    fn path() -> Option<PathBuf> {
+        Some(Config::data_dir()?.join("typos.json"))
+    }
+}
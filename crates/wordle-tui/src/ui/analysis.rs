@@ -0,0 +1,183 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Cell, Row, Table},
+};
+
+use super::theme::Theme;
+use crate::game::analysis::GuessAnalysis;
+use crate::game::difficulty::MAX_RATING;
+use crate::game::rarity::Rarity;
+use crate::i18n::Texts;
+
+/// Table reviewing each guess of a finished game.
+pub struct AnalysisWidget<'a> {
+    report: &'a [GuessAnalysis],
+    texts: &'a Texts,
+    theme: &'a Theme,
+    difficulty: Option<f32>,
+    average: Option<(f32, usize)>,
+    rarity: Option<Rarity>,
+    commentary: Option<String>,
+}
+
+impl<'a> AnalysisWidget<'a> {
+    pub fn new(report: &'a [GuessAnalysis], texts: &'a Texts, theme: &'a Theme) -> Self {
+        AnalysisWidget {
+            report,
+            texts,
+            theme,
+            difficulty: None,
+            average: None,
+            rarity: None,
+            commentary: None,
+        }
+    }
+
+    /// Adds the rating of the word below the table, with the average over
+    /// the games played so far and their count.
+    pub fn difficulty(mut self, rating: Option<f32>, average: Option<(f32, usize)>) -> Self {
+        self.difficulty = rating;
+        self.average = average;
+        self
+    }
+
+    /// Adds a badge telling how common the word is.
+    pub fn rarity(mut self, rarity: Option<Rarity>) -> Self {
+        self.rarity = rarity;
+        self
+    }
+
+    /// Adds the commentary of the game below the table, wrapped.
+    pub fn commentary(mut self, text: String) -> Self {
+        self.commentary = Some(text);
+        self
+    }
+}
+
+impl<'a> Widget for AnalysisWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let header = Row::new([
+            "#",
+            self.texts.analysis_guess,
+            self.texts.analysis_before,
+            self.texts.analysis_after,
+            self.texts.analysis_bits,
+            self.texts.analysis_best,
+        ])
+        .style(self.theme.title);
+
+        let rows = self.report.iter().enumerate().map(|(idx, guess)| {
+            // The guess keeps its colors from the board
+            let letters: Vec<Span> = guess
+                .feedback
+                .letters
+                .iter()
+                .zip(&guess.feedback.statuses)
+                .map(|(letter, status)| Span::styled(letter.to_string(), self.theme.tile(*status)))
+                .collect();
+
+            let best = match &guess.best {
+                Some(best) if best.word == guess.feedback.word() => "=".to_string(),
+                Some(best) => format!("{} ({:.2})", best.word, best.entropy),
+                None => "-".to_string(),
+            };
+
+            Row::new([
+                Cell::from((idx + 1).to_string()),
+                Cell::from(Line::from(letters)),
+                Cell::from(guess.before.to_string()),
+                Cell::from(guess.after.to_string()),
+                Cell::from(format!("{:.2}", guess.entropy)),
+                Cell::from(best),
+            ])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(2),
+                Constraint::Length(8),
+                Constraint::Length(7),
+                Constraint::Length(7),
+                Constraint::Length(5),
+                Constraint::Min(10),
+            ],
+        )
+        .header(header)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(self.theme.border)
+                .title(self.texts.analysis_title),
+        );
+
+        // Commentary, rarity and difficulty lines under the table
+        let mut footer = Vec::new();
+        if let Some(text) = &self.commentary {
+            let width = area.width.saturating_sub(2) as usize;
+            footer.extend(wrap(text, width).into_iter().map(Line::raw));
+        }
+        if let Some(rarity) = self.rarity {
+            let (badge, style) = match rarity {
+                Rarity::Common => (self.texts.rarity_common, self.theme.info),
+                Rarity::Uncommon => (self.texts.rarity_uncommon, self.theme.message),
+                Rarity::Rare => (self.texts.rarity_rare, self.theme.success),
+            };
+            footer.push(Line::from(vec![
+                Span::styled(format!("{} ", self.texts.word_rarity), self.theme.info),
+                Span::styled(
+                    format!(" {} ", badge),
+                    style.add_modifier(Modifier::REVERSED),
+                ),
+            ]));
+        }
+        if let Some(rating) = self.difficulty {
+            footer.push(Line::styled(
+                format!(
+                    "{}: {:.1}/{}",
+                    self.texts.word_difficulty, rating, MAX_RATING
+                ),
+                self.theme.info,
+            ));
+        }
+        if let Some((average, games)) = self.average {
+            footer.push(Line::styled(
+                format!(
+                    "{}: {:.1}/{} ({} {})",
+                    self.texts.average_difficulty, average, MAX_RATING, games, self.texts.games
+                ),
+                self.theme.info,
+            ));
+        }
+        let table_area = Rect {
+            height: area.height.saturating_sub(footer.len() as u16),
+            ..area
+        };
+        Widget::render(table, table_area, buf);
+
+        for (row, line) in footer.iter().enumerate() {
+            let y = table_area.bottom() + row as u16;
+            buf.set_line(area.x + 1, y, line, area.width.saturating_sub(2));
+        }
+    }
+}
+
+// `text` in lines of at most `width` characters, broken between words
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
@@ -0,0 +1,78 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear},
+};
+
+use crate::app::Assistant;
+use crate::i18n::Texts;
+use crate::ui::theme::Theme;
+
+/// Suggestions shown by the assistant panel.
+pub const ASSISTANT_ROWS: usize = 8;
+
+/// Ranked guess suggestions with how many words can still be the answer.
+pub struct AssistantWidget<'a> {
+    assistant: &'a Assistant,
+    texts: &'a Texts,
+    theme: &'a Theme,
+}
+
+impl<'a> AssistantWidget<'a> {
+    pub fn new(assistant: &'a Assistant, texts: &'a Texts, theme: &'a Theme) -> Self {
+        AssistantWidget {
+            assistant,
+            texts,
+            theme,
+        }
+    }
+
+    /// Height of the panel, borders included.
+    pub fn height(&self) -> u16 {
+        3 + self.assistant.suggestions.len().max(1) as u16
+    }
+}
+
+impl<'a> Widget for AssistantWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(self.theme.border)
+            .style(self.theme.background)
+            .title(self.texts.assistant_title);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let mut lines = vec![Line::from(format!(
+            "{}: {}",
+            self.texts.assistant_remaining, self.assistant.remaining
+        ))
+        .style(self.theme.info)];
+
+        if self.assistant.suggestions.is_empty() {
+            lines.push(Line::from(self.texts.no_hint).style(self.theme.error));
+        }
+        for suggestion in &self.assistant.suggestions {
+            // Words that can still win are highlighted
+            let style = if suggestion.candidate {
+                self.theme.success
+            } else {
+                Style::default()
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:<8}", suggestion.word), style),
+                Span::raw(format!("{:>5.2} bits", suggestion.entropy)),
+            ]));
+        }
+
+        for (row, line) in lines.iter().enumerate() {
+            let y = inner.y + row as u16;
+            if y >= inner.bottom() {
+                break;
+            }
+            buf.set_line(inner.x + 1, y, line, inner.width.saturating_sub(1));
+        }
+    }
+}
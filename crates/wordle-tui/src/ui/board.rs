@@ -0,0 +1,530 @@
+use std::time::Duration;
+
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders},
+};
+use unicode_width::UnicodeWidthChar;
+
+use super::profile;
+use super::theme::Theme;
+use crate::config::{KeyboardLayout, Placement, StatusStyles, ThemeName};
+use crate::game::normalize::normalize_letter;
+use crate::game::{Counts, FeedbackStyle, Game, GameStatus, LetterStatus};
+
+/// Time the shaking row stays on each side.
+const SHAKE_STEP_MS: u128 = 50;
+/// Time left on a row before its clock turns red.
+const LOW_COUNTDOWN_SECONDS: u64 = 5;
+
+/// Width the board needs: the keyboard's widest row plus some margin.
+pub const MIN_BOARD_WIDTH: u16 = 41;
+/// Width the board needs with the keyboard beside the grid.
+pub const MIN_WIDE_BOARD_WIDTH: u16 = 76;
+/// Height of the attempts grid: six rows of three-line cells.
+pub const GRID_HEIGHT: u16 = 18;
+/// Most the cells of the grid and the keys of the keyboard grow by on big
+/// terminals.
+pub const MAX_SCALE: usize = 3;
+
+/// Where the virtual keyboard goes relative to the attempts grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Orientation {
+    /// Keyboard below the grid.
+    Vertical,
+    /// Keyboard beside the grid, for short but wide terminals.
+    Horizontal,
+}
+
+impl Orientation {
+    /// Width the board needs in this orientation.
+    pub fn min_board_width(self) -> u16 {
+        match self {
+            Orientation::Vertical => MIN_BOARD_WIDTH,
+            Orientation::Horizontal => MIN_WIDE_BOARD_WIDTH,
+        }
+    }
+}
+
+/// Draws the attempts grid and the virtual keyboard of a game.
+pub struct GameWidget<'a> {
+    game: &'a Game,
+    theme: &'a Theme,
+    keyboard_layout: KeyboardLayout,
+    status_styles: Option<&'a StatusStyles>,
+    orientation: Orientation,
+    keyboard: bool,
+    flat: bool,
+    shake: Option<Duration>,
+    pop: Option<usize>,
+    countdown: Option<Duration>,
+    partner: &'a [char],
+}
+
+impl<'a> GameWidget<'a> {
+    pub fn new(game: &'a Game) -> Self {
+        GameWidget {
+            game,
+            theme: Theme::named(ThemeName::Classic),
+            keyboard_layout: KeyboardLayout::Qwerty,
+            status_styles: None,
+            orientation: Orientation::Vertical,
+            keyboard: true,
+            flat: false,
+            shake: None,
+            pop: None,
+            countdown: None,
+            partner: &[],
+        }
+    }
+
+    pub fn theme(mut self, theme: &'a Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn keyboard_layout(mut self, layout: KeyboardLayout) -> Self {
+        self.keyboard_layout = layout;
+        self
+    }
+
+    /// Colors and symbols of the letter statuses, over the theme's.
+    pub fn status_styles(mut self, styles: &'a StatusStyles) -> Self {
+        self.status_styles = Some(styles);
+        self
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Whether to draw the virtual keyboard; without it the grid gets the
+    /// whole area.
+    pub fn keyboard(mut self, keyboard: bool) -> Self {
+        self.keyboard = keyboard;
+        self
+    }
+
+    /// Draws the grid with one-line cells and no borders, for tiny
+    /// terminals.
+    pub fn flat(mut self, flat: bool) -> Self {
+        self.flat = flat;
+        self
+    }
+
+    /// Shakes the row being typed, in red, to show a guess was refused
+    /// `elapsed` ago.
+    pub fn shake(mut self, elapsed: Option<Duration>) -> Self {
+        self.shake = elapsed;
+        self
+    }
+
+    /// Highlights the letter just typed in `column` of the current row.
+    pub fn pop(mut self, column: Option<usize>) -> Self {
+        self.pop = column;
+        self
+    }
+
+    /// Shows the time left to submit the current row next to it.
+    pub fn countdown(mut self, remaining: Option<Duration>) -> Self {
+        self.countdown = remaining;
+        self
+    }
+
+    /// Shows the letters a partner is typing in the current row, dimmed,
+    /// in the cells not typed in here.
+    pub fn partner(mut self, letters: &'a [char]) -> Self {
+        self.partner = letters;
+        self
+    }
+}
+
+impl<'a> Widget for GameWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (grid_area, keyboard_area) = self.split(area);
+        profile::span("grid", || self.render_grid(grid_area, buf));
+        if let Some(keyboard_area) = keyboard_area {
+            profile::span("keyboard", || self.render_keyboard(keyboard_area, buf));
+        }
+    }
+}
+
+impl<'a> GameWidget<'a> {
+    /// Where each key of the virtual keyboard lands when the board is drawn
+    /// in `area`, to tell which one a mouse click hit.
+    pub fn key_areas(&self, area: Rect) -> Vec<(char, Rect)> {
+        match self.split(area) {
+            (_, Some(keyboard_area)) => keyboard_keys(self.keyboard_layout, keyboard_area),
+            (_, None) => Vec::new(),
+        }
+    }
+
+    // Areas of the attempts grid and of the virtual keyboard, if shown
+    fn split(&self, area: Rect) -> (Rect, Option<Rect>) {
+        if !self.keyboard {
+            return (area, None);
+        }
+
+        let game_layout = match self.orientation {
+            Orientation::Vertical => Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Percentage(70), // Attempts grid
+                    Constraint::Percentage(30), // Virtual keyboard
+                ])
+                .split(area),
+            Orientation::Horizontal => Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(45), // Attempts grid
+                    Constraint::Percentage(55), // Virtual keyboard
+                ])
+                .split(area),
+        };
+        (game_layout[0], Some(game_layout[1]))
+    }
+}
+
+impl<'a> GameWidget<'a> {
+    fn render_grid(&self, area: Rect, buf: &mut Buffer) {
+        let word_length = self.game.config.word_length;
+        // Bordered cells, and the gaps between them, grow with the room
+        // there is for every row of the board
+        let (cell_width, cell_height, horizontal_gap) = if self.flat {
+            (3, 1, 1)
+        } else {
+            let rows = self.game.attempts.len();
+            cell_size(scale_for(|scale| {
+                let (width, height, gap) = cell_size(scale);
+                word_length * width + word_length.saturating_sub(1) * gap <= area.width as usize
+                    && rows * height <= area.height as usize
+            }))
+        };
+
+        let max_attempts = self.game.config.max_attempts;
+        // Boards with many rows (Letterle, or rows added after a loss) can
+        // outgrow the area: the rows up to the one being typed stay in view
+        let fitting = (area.height as usize / cell_height).max(1);
+        let rows = self
+            .game
+            .attempts
+            .len()
+            .min((self.game.current_attempt + 1).max(fitting));
+        let first_row = rows.saturating_sub(fitting);
+
+        let grid_width = word_length * cell_width + word_length.saturating_sub(1) * horizontal_gap;
+        let grid_height = (rows - first_row) * cell_height;
+
+        let submitted = self.game.submitted().count();
+        // With counts only, submitted letters keep a neutral color and the
+        // counts go in badges beside their row
+        let counts_only = self.game.config.feedback == FeedbackStyle::Counts;
+        // The winning row reveals the word as spelled, accents included
+        let reveal: Vec<char> = self.game.display_word.chars().collect();
+        let winning_row =
+            (self.game.status == GameStatus::Won).then_some(self.game.current_attempt);
+        // The shaking row moves a column left and right in turns
+        let shake_offset =
+            self.shake
+                .map(|elapsed| match elapsed.as_millis() / SHAKE_STEP_MS % 2 {
+                    0 => -1,
+                    _ => 1,
+                });
+
+        // Calculate the starting point to center the grid
+        let start_x = area.x + (area.width as usize).saturating_sub(grid_width) as u16 / 2;
+        let start_y = area.y + (area.height as usize).saturating_sub(grid_height) as u16 / 2;
+
+        for attempt_idx in first_row..rows {
+            for letter_idx in 0..word_length {
+                let mut x = start_x + (letter_idx * (cell_width + horizontal_gap)) as u16;
+                let y = start_y + ((attempt_idx - first_row) * cell_height) as u16;
+                let shaking = shake_offset.filter(|_| {
+                    attempt_idx == self.game.current_attempt
+                        && self.game.status == GameStatus::Playing
+                });
+                if let Some(offset) = shaking {
+                    x = x.saturating_add_signed(offset);
+                }
+
+                let cell_area =
+                    Rect::new(x, y, cell_width as u16, cell_height as u16).intersection(area);
+
+                // Determine cell style based on letter status
+                let status = (attempt_idx < submitted && !counts_only)
+                    .then(|| self.game.status_at(attempt_idx, letter_idx))
+                    .flatten();
+                let style = if attempt_idx < submitted && counts_only {
+                    self.theme.tile(LetterStatus::Unused)
+                } else if let Some(status) = status {
+                    self.patch(self.theme.tile(status), status)
+                } else if shaking.is_some() {
+                    self.theme.rejected_row
+                } else if attempt_idx == self.game.current_attempt && self.pop == Some(letter_idx) {
+                    // The letter just typed pops out for a moment
+                    self.theme
+                        .current_row
+                        .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+                } else if attempt_idx == self.game.current_attempt {
+                    self.theme.current_row
+                } else {
+                    self.theme.empty_row
+                };
+                // Practice rows, past the real attempts, are dimmed; an
+                // endless game has no real attempts to go past
+                let style = if attempt_idx >= max_attempts && !self.game.config.endless {
+                    style.add_modifier(Modifier::DIM)
+                } else {
+                    style
+                };
+
+                // Draw cell with border, or just its background when flat
+                let block = if self.flat {
+                    Block::default().style(style)
+                } else {
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Plain)
+                        .style(style)
+                };
+
+                block.render(cell_area, buf);
+
+                let letter_y = y + cell_height as u16 / 2;
+
+                // Draw letter if it exists, in the rows typed so far
+                let typed = self
+                    .game
+                    .letter(attempt_idx, letter_idx)
+                    .filter(|_| attempt_idx <= self.game.current_attempt);
+                let partner = self.partner.get(letter_idx).filter(|_| {
+                    attempt_idx == self.game.current_attempt && self.game.status.accepts_input()
+                });
+                if let Some(typed) = typed {
+                    let letter = match reveal.get(letter_idx) {
+                        Some(&letter) if winning_row == Some(attempt_idx) => letter,
+                        _ => typed,
+                    };
+                    draw_letter(buf, x, letter_y, cell_width as u16, letter, style);
+                    if let Some(status) = status {
+                        self.draw_symbol(buf, x, letter_y, cell_width as u16, status, style);
+                    }
+                } else if let Some(&letter) = partner {
+                    let style = style.add_modifier(Modifier::DIM);
+                    draw_letter(buf, x, letter_y, cell_width as u16, letter, style);
+                } else if self.flat {
+                    // Without borders, empty cells need a mark to be seen
+                    draw_letter(buf, x, letter_y, cell_width as u16, '·', style);
+                }
+            }
+        }
+
+        // How many rows scrolled out above, left of the first one shown
+        if first_row > 0 {
+            let marker = format!("↑{}", first_row);
+            let x = start_x.saturating_sub(marker.chars().count() as u16 + 1);
+            let y = start_y + cell_height as u16 / 2;
+            if x >= area.left() && y < area.bottom() {
+                buf.set_string(x, y, marker, self.theme.info);
+            }
+        }
+
+        if counts_only {
+            let badges_x = start_x + grid_width as u16 + 2;
+            for row in first_row..submitted.min(rows) {
+                let y = start_y + ((row - first_row) * cell_height + cell_height / 2) as u16;
+                if badges_x >= area.right() || y >= area.bottom() {
+                    continue;
+                }
+                let Some(statuses) = self.game.row_statuses(row) else {
+                    continue;
+                };
+                let counts = Counts::of(statuses);
+                let badges = Line::from(vec![
+                    Span::styled(
+                        format!(" {} ", counts.correct),
+                        self.theme.tile(LetterStatus::Correct),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!(" {} ", counts.present),
+                        self.theme.tile(LetterStatus::Present),
+                    ),
+                ]);
+                buf.set_line(badges_x, y, &badges, area.right() - badges_x);
+            }
+        }
+
+        // The clock of the row being typed, right of it
+        if let Some(remaining) = self.countdown {
+            let row = self.game.current_attempt;
+            if self.game.status == GameStatus::Playing && row >= first_row && row < rows {
+                // Rounded up, so it reads 0 only once time is up
+                let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+                let style = if seconds <= LOW_COUNTDOWN_SECONDS {
+                    self.theme.error
+                } else {
+                    self.theme.title
+                };
+                let x = start_x + grid_width as u16 + 2;
+                let y = start_y + ((row - first_row) * cell_height + cell_height / 2) as u16;
+                if x < area.right() && y < area.bottom() {
+                    buf.set_stringn(
+                        x,
+                        y,
+                        format!("⏱ {}", seconds),
+                        (area.right() - x) as usize,
+                        style,
+                    );
+                }
+            }
+        }
+    }
+
+    fn render_keyboard(&self, area: Rect, buf: &mut Buffer) {
+        let keyboard_status = self.game.get_keyboard_status();
+        let (key_width, key_height, _) = key_size(key_scale(self.keyboard_layout, area));
+
+        for (key, key_area) in keyboard_keys(self.keyboard_layout, area) {
+            // Get key status; accented keys (Ç) share it with their letter
+            let status = match normalize_letter(key) {
+                Some(letter) => keyboard_status[(letter as u8 - b'A') as usize],
+                None => LetterStatus::Unused,
+            };
+
+            let style = self.patch(self.theme.key(status), status);
+
+            // Draw key
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .style(style);
+
+            block.render(key_area, buf);
+
+            // Draw letter, unless the key is cut off
+            if key_area.width == key_width && key_area.height == key_height {
+                let y = key_area.y + key_height / 2;
+                draw_letter(buf, key_area.x, y, key_width, key, style);
+                self.draw_symbol(buf, key_area.x, y, key_width, status, style);
+            }
+        }
+    }
+
+    // The style of a tile or key with `status`, with the configured color
+    fn patch(&self, style: Style, status: LetterStatus) -> Style {
+        match self.status_styles.and_then(|styles| styles.get(status)) {
+            Some(status_style) => status_style.patch(style),
+            None => style,
+        }
+    }
+
+    // Draws the configured symbol of `status` beside the letter centered in
+    // a cell, on the border of keys and bordered tiles
+    fn draw_symbol(
+        &self,
+        buf: &mut Buffer,
+        x: u16,
+        y: u16,
+        cell_width: u16,
+        status: LetterStatus,
+        style: Style,
+    ) {
+        let Some(status_style) = self.status_styles.and_then(|styles| styles.get(status)) else {
+            return;
+        };
+        let Some(symbol) = status_style.symbol else {
+            return;
+        };
+        let letter_x = x + cell_width.saturating_sub(1) / 2;
+        let symbol_x = match status_style.placement {
+            Placement::Before => letter_x.saturating_sub(1),
+            Placement::After => letter_x + 1,
+        };
+        draw_letter(buf, symbol_x, y, 1, symbol, style);
+    }
+}
+
+// The largest scale up to `MAX_SCALE` at which `fits` holds, or 1
+fn scale_for(fits: impl Fn(usize) -> bool) -> usize {
+    (1..=MAX_SCALE)
+        .rev()
+        .find(|&scale| fits(scale))
+        .unwrap_or(1)
+}
+
+// Width and height of a bordered cell of the grid at `scale`, and the gap
+// between two of them
+fn cell_size(scale: usize) -> (usize, usize, usize) {
+    (4 * scale + 1, 2 * scale + 1, scale)
+}
+
+// Width and height of a key at `scale`, and the gap between two of them
+fn key_size(scale: usize) -> (u16, u16, usize) {
+    (4 * scale as u16 - 1, 2 * scale as u16 + 1, scale)
+}
+
+// Width and height of the whole keyboard of `layout` at `scale`
+fn keyboard_size(layout: KeyboardLayout, scale: usize) -> (usize, usize) {
+    let rows = layout.rows();
+    let (key_width, key_height, gap) = key_size(scale);
+    let widest = rows.iter().map(|row| row.chars().count()).max().unwrap();
+    (
+        widest * key_width as usize + (widest - 1) * gap,
+        rows.len() * key_height as usize + (rows.len() - 1),
+    )
+}
+
+// The scale at which the keyboard of `layout` fills `area` best
+fn key_scale(layout: KeyboardLayout, area: Rect) -> usize {
+    scale_for(|scale| {
+        let (width, height) = keyboard_size(layout, scale);
+        width <= area.width as usize && height <= area.height as usize
+    })
+}
+
+// Position of every key of the virtual keyboard, as big as `area` allows,
+// centered in it and clipped to it
+fn keyboard_keys(layout: KeyboardLayout, area: Rect) -> Vec<(char, Rect)> {
+    let keyboard_layout = layout.rows();
+
+    let scale = key_scale(layout, area);
+    let (key_width, key_height, horizontal_gap) = key_size(scale);
+    let vertical_gap = 1;
+    let (keyboard_width, keyboard_height) = keyboard_size(layout, scale);
+
+    // Starting position to center keyboard
+    let start_x = area.x + (area.width as usize).saturating_sub(keyboard_width) as u16 / 2;
+    let start_y = area.y + (area.height as usize).saturating_sub(keyboard_height) as u16 / 2;
+
+    let mut keys = Vec::new();
+    for (row_idx, row) in keyboard_layout.iter().enumerate() {
+        // Center each row horizontally
+        let row_len = row.chars().count();
+        let row_width = row_len * key_width as usize + (row_len - 1) * horizontal_gap;
+        let row_start_x = start_x + (keyboard_width - row_width) as u16 / 2;
+
+        for (key_idx, key) in row.chars().enumerate() {
+            let x = row_start_x + (key_idx * (key_width as usize + horizontal_gap)) as u16;
+            let y = start_y + (row_idx * (key_height as usize + vertical_gap)) as u16;
+
+            let key_area = Rect::new(x, y, key_width, key_height).intersection(area);
+            if !key_area.is_empty() {
+                keys.push((key, key_area));
+            }
+        }
+    }
+    keys
+}
+
+// Write a single letter centered in a cell of the given width. Writing the
+// buffer cell directly avoids allocating a String per letter on every frame.
+fn draw_letter(buf: &mut Buffer, x: u16, y: u16, cell_width: u16, letter: char, style: Style) {
+    let width = letter.width().unwrap_or(1) as u16;
+    let letter_x = x + cell_width.saturating_sub(width) / 2;
+    let area = buf.area();
+    if letter_x >= area.left() && letter_x < area.right() && y >= area.top() && y < area.bottom() {
+        buf.get_mut(letter_x, y).set_char(letter).set_style(style);
+    }
+}
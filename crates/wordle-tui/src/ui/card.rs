@@ -0,0 +1,97 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear},
+};
+
+use super::centered;
+use crate::app::App;
+use crate::card::Shared;
+
+/// Terminal cells are assumed this many pixels wide and tall, to leave
+/// room for the image.
+const CELL_WIDTH: usize = 10;
+const CELL_HEIGHT: usize = 20;
+
+/// The result card: room for its image when the terminal shows Sixel
+/// graphics (drawn over it by the main loop), the card as text as it is
+/// copied, then where it was saved and whether it was copied.
+pub struct CardWidget<'a> {
+    app: &'a App,
+    shared: &'a Shared,
+}
+
+impl<'a> CardWidget<'a> {
+    pub fn new(app: &'a App, shared: &'a Shared) -> Self {
+        CardWidget { app, shared }
+    }
+}
+
+impl<'a> Widget for CardWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let texts = app.texts();
+        let theme = app.theme();
+        let mut status = vec![match &self.shared.saved {
+            Some(path) => Line::from(format!("{} {}", texts.card_saved, path.display())),
+            None => Line::styled(texts.card_unsaved, theme.error),
+        }];
+        if self.shared.copied {
+            status.push(Line::styled(texts.card_copied, theme.success));
+        }
+        let text: Vec<Line> = self
+            .shared
+            .card
+            .text(&app.share, app.high_contrast)
+            .into_iter()
+            .map(Line::from)
+            .collect();
+        let text_width = text.iter().map(Line::width).max().unwrap_or(0) as u16;
+        // The image, if any, and a blank line under it
+        let (image_width, image_height) = match &self.shared.sixel {
+            Some(_) => (
+                self.shared.image.width.div_ceil(CELL_WIDTH) as u16,
+                self.shared.image.height.div_ceil(CELL_HEIGHT) as u16 + 1,
+            ),
+            None => (0, 0),
+        };
+        let content_width = image_width.max(text_width);
+        let content_height = image_height + text.len() as u16;
+        // The card, a blank line and where it went
+        let status_width = status.iter().map(Line::width).max().unwrap_or(0) as u16;
+        let width = content_width.max(status_width) + 4;
+        let column = centered(area, width, content_height + 3 + status.len() as u16);
+
+        Clear.render(column, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border)
+            .style(theme.background)
+            .title(texts.card_title);
+        let inner = block.inner(column);
+        block.render(column, buf);
+
+        if self.shared.sixel.is_some() {
+            *app.card_area.borrow_mut() = Some(Rect::new(
+                inner.x + inner.width.saturating_sub(image_width) / 2,
+                inner.y,
+                image_width.min(inner.width),
+                image_height.saturating_sub(1).min(inner.height),
+            ));
+        }
+        let x = inner.x + inner.width.saturating_sub(text_width) / 2;
+        for (row, line) in text.iter().enumerate() {
+            let y = inner.y + image_height + row as u16;
+            if y < inner.bottom() {
+                buf.set_line(x, y, line, text_width.min(inner.width));
+            }
+        }
+        for (row, line) in status.iter().enumerate() {
+            let y = inner.y + content_height + 1 + row as u16;
+            if y < inner.bottom() {
+                let x = inner.x + inner.width.saturating_sub(line.width() as u16) / 2;
+                buf.set_line(x, y, line, inner.width);
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph, Wrap},
+};
+
+use super::theme::Theme;
+use super::PANEL_WIDTH;
+use crate::community::Puzzle;
+use crate::i18n::Texts;
+
+/// The community puzzle of the week: who made it, what it's about, and
+/// whether it was solved.
+pub struct CommunityWidget<'a> {
+    puzzle: &'a Puzzle,
+    solved: bool,
+    texts: &'a Texts,
+    theme: &'a Theme,
+}
+
+impl<'a> CommunityWidget<'a> {
+    pub fn new(puzzle: &'a Puzzle, solved: bool, texts: &'a Texts, theme: &'a Theme) -> Self {
+        CommunityWidget {
+            puzzle,
+            solved,
+            texts,
+            theme,
+        }
+    }
+
+    /// Height of the panel, borders included, with the description wrapped
+    /// to the panel's width.
+    pub fn height(&self) -> u16 {
+        // Borders and padding on both sides
+        let width = PANEL_WIDTH.saturating_sub(4) as usize;
+        let description = self.puzzle.description.chars().count().div_ceil(width);
+        4 + description as u16
+    }
+}
+
+impl<'a> Widget for CommunityWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(self.theme.border)
+            .style(self.theme.background)
+            .title(self.texts.community_title);
+
+        let status = if self.solved {
+            Line::from(format!("✓ {}", self.texts.community_solved)).style(self.theme.success)
+        } else {
+            Line::from(self.texts.community_play).style(self.theme.info)
+        };
+        let mut lines = vec![Line::from(format!(
+            "{} {}",
+            self.texts.community_by, self.puzzle.author
+        ))
+        .style(self.theme.title)];
+        if !self.puzzle.description.is_empty() {
+            lines.push(Line::from(self.puzzle.description.as_str()));
+        }
+        lines.push(status);
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .block(block.padding(Padding::horizontal(1)))
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,107 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear},
+};
+
+use super::theme::Theme;
+use crate::game::constraints::Constraints;
+use crate::game::LetterStatus;
+use crate::i18n::Texts;
+
+/// What is known of the answer: a template with the letters found (`_ R A
+/// _ _`), the letters ruled out of each slot in a column under it, then
+/// the letters still to place and the ones not in the word.
+pub struct ConstraintsWidget<'a> {
+    constraints: Constraints,
+    texts: &'a Texts,
+    theme: &'a Theme,
+}
+
+impl<'a> ConstraintsWidget<'a> {
+    pub fn new(constraints: Constraints, texts: &'a Texts, theme: &'a Theme) -> Self {
+        ConstraintsWidget {
+            constraints,
+            texts,
+            theme,
+        }
+    }
+
+    /// Height of the panel, borders included.
+    pub fn height(&self) -> u16 {
+        let columns = self.constraints.excluded.iter().map(Vec::len).max();
+        // Template, excluded letters, then two lines for what's in the word
+        // and two for what isn't
+        2 + 1 + columns.unwrap_or(0) as u16 + 4
+    }
+}
+
+impl<'a> Widget for ConstraintsWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(self.theme.border)
+            .style(self.theme.background)
+            .title(self.texts.constraints_title);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let constraints = &self.constraints;
+        let mut lines = Vec::new();
+
+        let template = constraints.known.iter().map(|slot| match slot {
+            Some(letter) => Span::styled(
+                format!(" {} ", letter),
+                self.theme.tile(LetterStatus::Correct),
+            ),
+            None => Span::raw(" _ "),
+        });
+        lines.push(slots(template));
+
+        let columns = constraints.excluded.iter().map(Vec::len).max();
+        for row in 0..columns.unwrap_or(0) {
+            let letters = constraints
+                .excluded
+                .iter()
+                .map(|letters| match letters.get(row) {
+                    Some(letter) => Span::styled(format!(" {} ", letter), self.theme.error),
+                    None => Span::raw("   "),
+                });
+            lines.push(slots(letters));
+        }
+
+        let letters = |letters: &[char]| letters.iter().collect::<String>();
+        lines.push(Line::from(self.texts.legend_present));
+        lines.push(Line::styled(
+            letters(&constraints.elsewhere),
+            self.theme.tile(LetterStatus::Present),
+        ));
+        lines.push(Line::from(self.texts.legend_absent));
+        lines.push(Line::styled(
+            letters(&constraints.absent),
+            self.theme.tile(LetterStatus::Absent),
+        ));
+
+        for (row, line) in lines.iter().enumerate() {
+            let y = inner.y + row as u16;
+            if y >= inner.bottom() {
+                break;
+            }
+            buf.set_line(inner.x + 1, y, line, inner.width.saturating_sub(1));
+        }
+    }
+}
+
+// One three-column cell per slot, with a gap between them
+fn slots<'a>(cells: impl Iterator<Item = Span<'a>>) -> Line<'a> {
+    let mut spans = Vec::new();
+    for (slot, cell) in cells.enumerate() {
+        if slot > 0 {
+            spans.push(Span::raw(" "));
+        }
+        spans.push(cell);
+    }
+    Line::from(spans)
+}
@@ -0,0 +1,100 @@
+use ratatui::{
+    prelude::*,
+    widgets::{
+        block::{Position, Title},
+        Block, BorderType, Borders, Clear, Padding, Paragraph, Wrap,
+    },
+};
+
+use super::theme::Theme;
+use super::PANEL_WIDTH;
+use crate::app::Lookup;
+use crate::i18n::Texts;
+
+/// Lines of the definition in view at once.
+pub const DEFINITION_ROWS: u16 = 8;
+
+/// The definition of the word just played, scrolled with the arrows when
+/// it is longer than the panel.
+pub struct DefinitionWidget<'a> {
+    lookup: &'a Lookup,
+    texts: &'a Texts,
+    theme: &'a Theme,
+}
+
+impl<'a> DefinitionWidget<'a> {
+    pub fn new(lookup: &'a Lookup, texts: &'a Texts, theme: &'a Theme) -> Self {
+        DefinitionWidget {
+            lookup,
+            texts,
+            theme,
+        }
+    }
+
+    /// Height of the panel, borders included.
+    pub fn height(&self) -> u16 {
+        let lines = self.lookup.text.as_deref().map_or(1, wrapped_lines);
+        lines.clamp(1, DEFINITION_ROWS) + 2
+    }
+}
+
+/// Lines `text` takes once wrapped to the width of the panel.
+pub fn wrapped_lines(text: &str) -> u16 {
+    // Borders and padding on both sides
+    let width = PANEL_WIDTH.saturating_sub(4) as usize;
+    let mut lines = 0;
+    for paragraph in text.lines() {
+        let mut used = 0;
+        lines += 1;
+        for word in paragraph.split_whitespace() {
+            let length = word.chars().count();
+            if used > 0 && used + 1 + length > width {
+                lines += 1;
+                used = 0;
+            }
+            used += if used > 0 { 1 } else { 0 } + length;
+            // Words longer than a line take as many as they need
+            while used > width {
+                lines += 1;
+                used -= width;
+            }
+        }
+    }
+    lines
+}
+
+impl<'a> Widget for DefinitionWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(self.theme.border)
+            .style(self.theme.background)
+            .title(format!(
+                "{} · {}",
+                self.texts.definition_title, self.lookup.word
+            ))
+            .padding(Padding::horizontal(1));
+
+        let paragraph = match (&self.lookup.text, self.lookup.loading) {
+            (Some(text), _) => {
+                if wrapped_lines(text) > DEFINITION_ROWS {
+                    block = block.title(
+                        Title::from(self.texts.definition_scroll)
+                            .position(Position::Bottom)
+                            .alignment(Alignment::Right),
+                    );
+                }
+                Paragraph::new(text.as_str()).scroll((self.lookup.scroll, 0))
+            }
+            (None, true) => Paragraph::new(self.texts.definition_loading).style(self.theme.info),
+            (None, false) => Paragraph::new(self.texts.definition_missing).style(self.theme.info),
+        };
+        paragraph
+            .wrap(Wrap { trim: true })
+            .block(block)
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,95 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear},
+};
+
+use super::centered;
+use crate::app::App;
+use crate::game::{Game, GameStatus};
+use crate::hotseat::{Hotseat, Outcome, Phase};
+
+/// Width of the box, borders included.
+const WIDTH: u16 = 48;
+
+/// What a versus game shows instead of the boards while the keyboard is
+/// passed, and once a round is over: whose turn it is, or who won it.
+pub struct HotseatWidget<'a> {
+    app: &'a App,
+    hotseat: &'a Hotseat,
+}
+
+impl<'a> HotseatWidget<'a> {
+    pub fn new(app: &'a App, hotseat: &'a Hotseat) -> Self {
+        HotseatWidget { app, hotseat }
+    }
+
+    fn player(&self, player: usize) -> String {
+        format!("{} {}", self.app.texts().hotseat_player, player + 1)
+    }
+
+    // The word of a board, and how it went
+    fn result(&self, player: usize, game: &Game) -> Line<'a> {
+        let theme = self.app.theme();
+        let (mark, style) = match game.status {
+            GameStatus::Won => ("✓", theme.success),
+            _ => ("✗", theme.error),
+        };
+        Line::from(vec![
+            Span::raw(format!("{}: {} ", self.player(player), game.display_word)),
+            Span::styled(format!("{} {}", mark, game.submitted().count()), style),
+        ])
+    }
+}
+
+impl<'a> Widget for HotseatWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let texts = app.texts();
+        let theme = app.theme();
+        let lines = match self.hotseat.phase {
+            Phase::Handover | Phase::Playing => vec![
+                Line::styled(
+                    format!("{} {}", texts.hotseat_pass, self.player(self.hotseat.turn)),
+                    theme.title,
+                ),
+                Line::default(),
+                Line::from(if self.hotseat.same_word {
+                    texts.hotseat_same_word
+                } else {
+                    texts.hotseat_own_words
+                }),
+            ],
+            Phase::Over(outcome) => {
+                let headline = match outcome {
+                    Outcome::Winner(player) => {
+                        format!("{} {}", self.player(player), texts.hotseat_wins)
+                    }
+                    Outcome::Draw => texts.hotseat_draw.to_string(),
+                };
+                let mut lines = vec![Line::styled(headline, theme.success), Line::default()];
+                for (player, game) in self.hotseat.boards(&app.game).into_iter().enumerate() {
+                    lines.push(self.result(player, game));
+                }
+                lines
+            }
+        };
+
+        let column = centered(area, WIDTH, lines.len() as u16 + 2);
+        Clear.render(column, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border)
+            .style(theme.background)
+            .title(texts.menu_hotseat);
+        let inner = block.inner(column);
+        block.render(column, buf);
+        for (row, line) in lines.into_iter().enumerate() {
+            let line = line.alignment(Alignment::Center);
+            let rect = Rect::new(inner.x, inner.y + row as u16, inner.width, 1);
+            if rect.y < inner.bottom() {
+                line.render(rect, buf);
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear},
+};
+
+use super::theme::Theme;
+use crate::config::{Placement, StatusStyles};
+use crate::game::LetterStatus;
+use crate::i18n::Texts;
+
+/// Height of the legend box, borders included.
+pub const LEGEND_HEIGHT: u16 = 6;
+
+/// Shows one example cell for each letter status with what it means.
+pub struct LegendWidget<'a> {
+    texts: &'a Texts,
+    theme: &'a Theme,
+    status_styles: Option<&'a StatusStyles>,
+}
+
+impl<'a> LegendWidget<'a> {
+    pub fn new(texts: &'a Texts, theme: &'a Theme) -> Self {
+        LegendWidget {
+            texts,
+            theme,
+            status_styles: None,
+        }
+    }
+
+    /// Shows the samples with the colors and symbols set in the config.
+    pub fn status_styles(mut self, styles: &'a StatusStyles) -> Self {
+        self.status_styles = Some(styles);
+        self
+    }
+}
+
+impl<'a> Widget for LegendWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // The legend may be drawn over the board, so wipe what's underneath
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(self.theme.border)
+            .style(self.theme.background)
+            .title(self.texts.legend_title);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let entries = [
+            (LetterStatus::Correct, self.texts.legend_correct),
+            (LetterStatus::Present, self.texts.legend_present),
+            (LetterStatus::Absent, self.texts.legend_absent),
+            (LetterStatus::Unused, self.texts.legend_unused),
+        ];
+
+        for (row, (status, meaning)) in entries.into_iter().enumerate() {
+            let y = inner.y + row as u16;
+            if y >= inner.bottom() {
+                break;
+            }
+            let mut sample = [' ', 'A', ' '];
+            let mut style = self.theme.tile(status);
+            if let Some(status_style) = self.status_styles.and_then(|styles| styles.get(status)) {
+                style = status_style.patch(style);
+                if let Some(symbol) = status_style.symbol {
+                    match status_style.placement {
+                        Placement::Before => sample[0] = symbol,
+                        Placement::After => sample[2] = symbol,
+                    }
+                }
+            }
+            let line = Line::from(vec![
+                Span::styled(sample.iter().collect::<String>(), style),
+                Span::raw(" "),
+                Span::raw(meaning),
+            ]);
+            buf.set_line(inner.x + 1, y, &line, inner.width.saturating_sub(1));
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear},
+};
+
+use super::centered;
+use crate::app::{App, Lock};
+
+/// Width of the box, borders included.
+const WIDTH: u16 = 48;
+
+/// What the screen shows once it locked after a while without input,
+/// instead of the game: whether the game was saved, and how to unlock it,
+/// with the passphrase masked as it is typed.
+pub struct LockWidget<'a> {
+    app: &'a App,
+    lock: &'a Lock,
+}
+
+impl<'a> LockWidget<'a> {
+    pub fn new(app: &'a App, lock: &'a Lock) -> Self {
+        LockWidget { app, lock }
+    }
+}
+
+impl<'a> Widget for LockWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let texts = self.app.texts();
+        let theme = self.app.theme();
+        let mut lines = Vec::new();
+        match self.lock.saved {
+            Some(true) => lines.push(Line::styled(texts.lock_saved, theme.success)),
+            Some(false) => lines.push(Line::styled(texts.lock_unsaved, theme.error)),
+            None => {}
+        }
+        if self.app.has_passphrase() {
+            lines.push(Line::from(texts.lock_passphrase));
+            lines.push(Line::styled(
+                "•".repeat(self.lock.typed.chars().count()),
+                theme.title,
+            ));
+            if self.lock.wrong {
+                lines.push(Line::styled(texts.lock_wrong, theme.error));
+            }
+        } else {
+            lines.push(Line::from(texts.lock_any_key));
+        }
+
+        // The whole area is cleared, so nothing of the board shows around
+        // the box
+        Clear.render(area, buf);
+        Block::default().style(theme.background).render(area, buf);
+        let column = centered(area, WIDTH, lines.len() as u16 + 2);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border)
+            .style(theme.background)
+            .title(texts.lock_title);
+        let inner = block.inner(column);
+        block.render(column, buf);
+        for (row, line) in lines.into_iter().enumerate() {
+            let line = line.alignment(Alignment::Center);
+            let rect = Rect::new(inner.x, inner.y + row as u16, inner.width, 1);
+            if rect.y < inner.bottom() {
+                line.render(rect, buf);
+            }
+        }
+    }
+}
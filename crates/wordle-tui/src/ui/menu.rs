@@ -0,0 +1,689 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Cell, Clear, Padding, Paragraph, Row, Table, Wrap},
+};
+
+use super::community::CommunityWidget;
+use super::{centered, PANEL_WIDTH};
+use crate::achievements::Achievement;
+use crate::app::{App, MenuItem, Setting, View};
+use crate::game::dictionary::Tier;
+use crate::game::WORD_LENGTH;
+use crate::history;
+use crate::i18n::Texts;
+use crate::leaderboard::{Category, Column, Sort};
+use crate::records::format_time;
+
+/// The main menu, with the community puzzle of the week under it when
+/// there is one.
+pub struct MenuWidget<'a> {
+    app: &'a App,
+}
+
+impl<'a> MenuWidget<'a> {
+    pub fn new(app: &'a App) -> Self {
+        MenuWidget { app }
+    }
+}
+
+impl<'a> Widget for MenuWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let texts = app.texts();
+        let selected = match app.view {
+            View::Menu(selected) => selected,
+            _ => 0,
+        };
+        let items = app.menu_items();
+        let lines: Vec<Line> = items
+            .iter()
+            .enumerate()
+            .map(|(index, &item)| {
+                let label = match item {
+                    MenuItem::Resume => texts.menu_resume,
+                    MenuItem::NewGame => texts.menu_new_game,
+                    MenuItem::Daily => texts.menu_daily,
+                    MenuItem::CatchUp => texts.menu_catch_up,
+                    MenuItem::Warmup => texts.menu_warmup,
+                    MenuItem::Blitz => texts.menu_blitz,
+                    MenuItem::Countdown => texts.menu_countdown,
+                    MenuItem::Speedrun => texts.menu_speedrun,
+                    MenuItem::Mastermind => texts.menu_mastermind,
+                    MenuItem::Zen => texts.menu_zen,
+                    MenuItem::Practice => texts.menu_practice,
+                    MenuItem::Hotseat => texts.menu_hotseat,
+                    MenuItem::Polyglot => texts.menu_polyglot,
+                    MenuItem::Community => texts.community_title,
+                    MenuItem::Challenge => texts.menu_challenge,
+                    MenuItem::Stats => texts.menu_stats,
+                    MenuItem::Achievements => texts.menu_achievements,
+                    MenuItem::Leaderboard => texts.menu_leaderboard,
+                    MenuItem::History => texts.menu_history,
+                    MenuItem::Settings => texts.menu_settings,
+                    MenuItem::WhatsNew => texts.menu_whats_new,
+                    MenuItem::Quit => texts.menu_quit,
+                };
+                // The difficulty is chosen on the new game entry
+                let selected = index == selected;
+                match (item, app.difficulty) {
+                    (MenuItem::NewGame, tier) if selected => {
+                        let tier = difficulty_name(tier, texts);
+                        choice(&format!("{} ◂ {} ▸", label, tier), selected, app)
+                    }
+                    (MenuItem::NewGame, Some(tier)) => {
+                        let tier = difficulty_name(Some(tier), texts);
+                        choice(&format!("{} ({})", label, tier), selected, app)
+                    }
+                    // Today's word is still to be played
+                    (MenuItem::Daily, _) if app.daily_pending => {
+                        choice(&format!("{} •", label), selected, app)
+                    }
+                    _ => choice(label, selected, app),
+                }
+            })
+            .collect();
+
+        let community = app.community.as_ref().map(|puzzle| {
+            CommunityWidget::new(
+                puzzle,
+                app.community_completed.contains(&puzzle.id),
+                texts,
+                app.theme(),
+            )
+        });
+        let menu_height = lines.len() as u16 + 2;
+        let height = menu_height + community.as_ref().map_or(0, CommunityWidget::height);
+        let column = centered(area, PANEL_WIDTH, height);
+
+        let menu_area = Rect {
+            height: menu_height.min(column.height),
+            ..column
+        };
+        render_box(texts.menu_title, &lines, menu_area, buf, app);
+        if let Some(community) = community {
+            let below = Rect {
+                y: menu_area.bottom(),
+                height: column.bottom().saturating_sub(menu_area.bottom()),
+                ..column
+            };
+            community.render(below, buf);
+        }
+    }
+}
+
+/// Figures about the games finished this session, and the heatmap of the
+/// letters corrected over every game.
+pub struct StatsWidget<'a> {
+    app: &'a App,
+}
+
+impl<'a> StatsWidget<'a> {
+    pub fn new(app: &'a App) -> Self {
+        StatsWidget { app }
+    }
+}
+
+impl<'a> Widget for StatsWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let texts = app.texts();
+        let stats = &app.stats;
+
+        let mut lines = Vec::new();
+        if stats.games() == 0 {
+            lines.push(Line::from(texts.stats_empty));
+        } else {
+            lines.push(figure(texts.stats_games, stats.games().to_string()));
+            lines.push(figure(texts.stats_wins, stats.wins().to_string()));
+            if let Some(rate) = stats.win_rate() {
+                lines.push(figure(texts.stats_win_rate, format!("{:.0}%", rate)));
+            }
+            if let Some(guesses) = stats.average_guesses() {
+                lines.push(figure(
+                    texts.stats_average_guesses,
+                    format!("{:.1}", guesses),
+                ));
+            }
+            if let Some((difficulty, _)) = stats.average_difficulty() {
+                lines.push(figure(
+                    texts.average_difficulty,
+                    format!("{:.1}", difficulty),
+                ));
+            }
+            lines.push(figure(
+                texts.stats_rarity_score,
+                stats.rarity_score().to_string(),
+            ));
+            let languages = stats.languages();
+            if languages.len() > 1 {
+                lines.push(Line::from(texts.stats_by_language));
+                for language in languages {
+                    let played = stats.in_language(language);
+                    lines.push(figure(
+                        &format!("  {}", language.code().to_uppercase()),
+                        format!("{}/{}", played.wins(), played.games()),
+                    ));
+                }
+            }
+        }
+        let challenges = &app.challenges;
+        if !challenges.challenges.is_empty() {
+            lines.push(figure(
+                texts.stats_challenges,
+                format!(
+                    "{}/{}",
+                    challenges.guest_wins(),
+                    challenges.challenges.len()
+                ),
+            ));
+        }
+
+        if !app.typos.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(texts.typos_title));
+            lines.extend(heatmap(app));
+            let worst: Vec<String> = app
+                .typos
+                .worst(3)
+                .into_iter()
+                .map(|(letter, rate)| format!("{} {:.0}%", letter, rate * 100.0))
+                .collect();
+            lines.push(figure(texts.typos_most, worst.join(", ")));
+        }
+
+        let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 4;
+        let column = centered(area, width.max(PANEL_WIDTH), lines.len() as u16 + 2);
+        render_box(texts.menu_stats, &lines, column, buf, app);
+    }
+}
+
+// The keyboard, each key colored by how often its letter was deleted
+// after typing it, against the letter deleted most often
+fn heatmap<'a>(app: &App) -> Vec<Line<'a>> {
+    let rows = app.keyboard_layout.rows();
+    let highest = rows
+        .iter()
+        .flat_map(|row| row.chars())
+        .map(|letter| app.typos.rate(letter))
+        .fold(0.0, f64::max);
+    rows.iter()
+        .enumerate()
+        .map(|(indent, row)| {
+            let mut spans = vec![Span::raw(" ".repeat(indent))];
+            spans.extend(row.chars().map(|letter| {
+                let heat = if highest > 0.0 {
+                    app.typos.rate(letter) / highest
+                } else {
+                    0.0
+                };
+                let style = match heat {
+                    heat if heat > 0.66 => Style::default().fg(Color::Black).bg(Color::Red),
+                    heat if heat > 0.33 => Style::default().fg(Color::Black).bg(Color::LightRed),
+                    heat if heat > 0.0 => Style::default().fg(Color::Black).bg(Color::Yellow),
+                    _ => Style::default().add_modifier(Modifier::DIM),
+                };
+                Span::styled(format!(" {} ", letter), style)
+            }));
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Every badge: when each earned one was earned, and what each locked one
+/// takes, dimmed.
+pub struct AchievementsWidget<'a> {
+    app: &'a App,
+}
+
+impl<'a> AchievementsWidget<'a> {
+    pub fn new(app: &'a App) -> Self {
+        AchievementsWidget { app }
+    }
+}
+
+impl<'a> Widget for AchievementsWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let texts = app.texts();
+        let achievements = &app.achievements;
+
+        let mut lines = Vec::new();
+        let mut earned = 0;
+        for achievement in Achievement::ALL {
+            let (name, about) = achievement_name(achievement, texts);
+            match achievements.unlocked(achievement) {
+                Some(at) => {
+                    earned += 1;
+                    lines.push(Line::styled(
+                        format!("★ {} ({})", name, history::date(at)),
+                        app.theme().success,
+                    ));
+                }
+                None => lines.push(Line::styled(
+                    format!("☆ {}: {}", name, about),
+                    Style::default().add_modifier(Modifier::DIM),
+                )),
+            }
+        }
+        lines.push(Line::from(""));
+        lines.push(figure(
+            texts.achievements_count,
+            format!("{}/{}", earned, Achievement::ALL.len()),
+        ));
+
+        let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 4;
+        let column = centered(area, width.max(PANEL_WIDTH), lines.len() as u16 + 2);
+        render_box(texts.menu_achievements, &lines, column, buf, app);
+    }
+}
+
+/// Name of a difficulty, or of any word for `None`.
+pub fn difficulty_name(tier: Option<Tier>, texts: &Texts) -> &'static str {
+    match tier {
+        None => texts.difficulty_any,
+        Some(Tier::Easy) => texts.difficulty_easy,
+        Some(Tier::Medium) => texts.difficulty_medium,
+        Some(Tier::Hard) => texts.difficulty_hard,
+    }
+}
+
+/// Name of a badge, and what it takes to earn it.
+pub fn achievement_name(achievement: Achievement, texts: &Texts) -> (&'static str, &'static str) {
+    match achievement {
+        Achievement::FirstWin => (
+            texts.achievement_first_win,
+            texts.achievement_first_win_about,
+        ),
+        Achievement::InTwo => (texts.achievement_in_two, texts.achievement_in_two_about),
+        Achievement::Streak => (texts.achievement_streak, texts.achievement_streak_about),
+        Achievement::NoHints => (texts.achievement_no_hints, texts.achievement_no_hints_about),
+        Achievement::HardMode => (
+            texts.achievement_hard_mode,
+            texts.achievement_hard_mode_about,
+        ),
+        Achievement::Momentum => (texts.achievement_momentum, texts.achievement_momentum_about),
+    }
+}
+
+/// Width of the leaderboard table, borders included.
+const LEADERBOARD_WIDTH: u16 = 66;
+
+/// Personal bests, a row per mode and word length, sorted by the column
+/// chosen: its heading is marked with the direction.
+pub struct LeaderboardWidget<'a> {
+    app: &'a App,
+}
+
+impl<'a> LeaderboardWidget<'a> {
+    pub fn new(app: &'a App) -> Self {
+        LeaderboardWidget { app }
+    }
+}
+
+impl<'a> Widget for LeaderboardWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let texts = app.texts();
+        let theme = app.theme();
+        let sort = match app.view {
+            View::Leaderboard(sort) => sort,
+            _ => Sort::default(),
+        };
+        let entries = app.leaderboard.sorted(sort);
+        if entries.is_empty() {
+            let column = centered(area, PANEL_WIDTH, 3);
+            render_box(
+                texts.menu_leaderboard,
+                &[Line::from(texts.stats_empty)],
+                column,
+                buf,
+                app,
+            );
+            return;
+        }
+
+        let header = Row::new(Column::ALL.map(|column| {
+            let name = match column {
+                Column::Mode => texts.leaderboard_mode,
+                Column::Length => texts.leaderboard_length,
+                Column::Wins => texts.leaderboard_wins,
+                Column::Guesses => texts.leaderboard_guesses,
+                Column::Time => texts.leaderboard_time,
+            };
+            if column == sort.column {
+                let arrow = if sort.reversed { "▲" } else { "▼" };
+                Cell::from(format!("{} {}", name, arrow))
+                    .style(theme.title.add_modifier(Modifier::REVERSED))
+            } else {
+                Cell::from(name).style(theme.title)
+            }
+        }));
+        let rows = entries.iter().map(|entry| {
+            Row::new([
+                category_name(entry.category, texts).to_string(),
+                entry.word_length.to_string(),
+                entry.wins.to_string(),
+                entry.fewest_guesses.to_string(),
+                entry.best_time().map_or("—".to_string(), format_time),
+            ])
+        });
+        let widths = [
+            Constraint::Length(14),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Length(11),
+            Constraint::Length(15),
+        ];
+        let height = (entries.len() as u16 + 3).min(area.height);
+        let column = centered(area, LEADERBOARD_WIDTH, height);
+        Clear.render(column, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border)
+            .style(theme.background)
+            .padding(Padding::horizontal(1))
+            .title(texts.menu_leaderboard);
+        Widget::render(
+            Table::new(rows, widths).header(header).block(block),
+            column,
+            buf,
+        );
+    }
+}
+
+/// The short name of a mode, on the leaderboard and the status bar.
+pub fn category_name(category: Category, texts: &Texts) -> &'static str {
+    match category {
+        Category::Classic => texts.mode_classic,
+        Category::Daily => texts.mode_daily,
+        Category::Warmup => texts.mode_warmup,
+        Category::Blitz => texts.mode_blitz,
+        Category::Countdown => texts.mode_countdown,
+        Category::Speedrun => texts.mode_speedrun,
+        Category::Mastermind => texts.mode_mastermind,
+        Category::Zen => texts.mode_zen,
+        Category::Polyglot => texts.mode_polyglot,
+        Category::Race => texts.mode_race,
+    }
+}
+
+/// Past games, most recent first: date, word, guesses taken (✗ when lost)
+/// and duration, with the guesses of the highlighted one under the list.
+pub struct HistoryWidget<'a> {
+    app: &'a App,
+}
+
+impl<'a> HistoryWidget<'a> {
+    pub fn new(app: &'a App) -> Self {
+        HistoryWidget { app }
+    }
+}
+
+impl<'a> Widget for HistoryWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let texts = app.texts();
+        let games = &app.past_games;
+        if games.is_empty() {
+            let column = centered(area, PANEL_WIDTH, 3);
+            render_box(
+                texts.menu_history,
+                &[Line::from(texts.stats_empty)],
+                column,
+                buf,
+                app,
+            );
+            return;
+        }
+        let selected = match app.view {
+            View::History(selected) => selected.min(games.len() - 1),
+            _ => 0,
+        };
+
+        // Borders, then a blank line and the guesses under the list
+        let height = (games.len() as u16 + 4).min(area.height);
+        let rows = usize::from(height.saturating_sub(4)).max(1);
+        let first = (selected + 1).saturating_sub(rows);
+        let mut lines: Vec<Line> = games
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(rows)
+            .map(|(index, game)| {
+                let result = if game.won {
+                    format!("{}/{}", game.guesses.len(), app.game.config.max_attempts)
+                } else {
+                    "✗".to_string()
+                };
+                let row = format!(
+                    "{}  {}  {:>3}  {:>2}:{:02}",
+                    history::date(game.at),
+                    game.word,
+                    result,
+                    game.seconds / 60,
+                    game.seconds % 60
+                );
+                choice(&row, index == selected, app)
+            })
+            .collect();
+        lines.push(Line::default());
+        lines.push(Line::styled(
+            games[selected].guesses.join(" "),
+            app.theme().info,
+        ));
+
+        let column = centered(area, PANEL_WIDTH, height);
+        render_box(texts.menu_history, &lines, column, buf, app);
+    }
+}
+
+/// Width of the "What's new" box, which holds sentences.
+const WHATS_NEW_WIDTH: u16 = 64;
+
+/// The changes of one release, by kind: modes, keys and the rest.
+pub struct WhatsNewWidget<'a> {
+    app: &'a App,
+}
+
+impl<'a> WhatsNewWidget<'a> {
+    pub fn new(app: &'a App) -> Self {
+        WhatsNewWidget { app }
+    }
+}
+
+impl<'a> Widget for WhatsNewWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let texts = app.texts();
+        let theme = app.theme();
+        let shown = match app.view {
+            View::WhatsNew(shown) => shown,
+            _ => 0,
+        };
+        let Some(release) = app
+            .releases
+            .get(shown.min(app.releases.len().saturating_sub(1)))
+        else {
+            return;
+        };
+
+        let mut lines = Vec::new();
+        for (heading, changes) in [
+            (texts.whats_new_modes, &release.modes),
+            (texts.whats_new_keys, &release.keys),
+            (texts.whats_new_other, &release.other),
+        ] {
+            if changes.is_empty() {
+                continue;
+            }
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+            lines.push(Line::styled(heading, theme.title));
+            lines.extend(
+                changes
+                    .iter()
+                    .map(|change| Line::from(format!("• {}", change))),
+            );
+        }
+        let mut title = format!("{} {}", texts.whats_new_in, release.version);
+        if app.releases.len() > 1 {
+            title = format!("{} ({}/{})", title, shown + 1, app.releases.len());
+        }
+
+        let column = centered(area, WHATS_NEW_WIDTH, area.height);
+        Clear.render(column, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border)
+            .style(theme.background)
+            .padding(Padding::horizontal(1))
+            .title(title);
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(block)
+            .render(column, buf);
+    }
+}
+
+/// Settings that can be changed for the session, with their values.
+pub struct SettingsWidget<'a> {
+    app: &'a App,
+}
+
+impl<'a> SettingsWidget<'a> {
+    pub fn new(app: &'a App) -> Self {
+        SettingsWidget { app }
+    }
+}
+
+impl<'a> Widget for SettingsWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let texts = app.texts();
+        let selected = match app.view {
+            View::Settings(selected) => selected,
+            _ => 0,
+        };
+        let switch = |on: bool| {
+            if on {
+                texts.settings_on
+            } else {
+                texts.settings_off
+            }
+        };
+
+        let lines: Vec<Line> = Setting::ALL
+            .iter()
+            .enumerate()
+            .map(|(index, setting)| {
+                let theme = app.theme_label();
+                let (name, value) = match setting {
+                    Setting::Theme => (texts.theme_label, theme.as_str()),
+                    Setting::HighContrast => {
+                        (texts.settings_high_contrast, switch(app.high_contrast))
+                    }
+                    Setting::StatusMarkers => {
+                        (texts.settings_status_markers, switch(app.status_markers))
+                    }
+                    Setting::ReducedMotion => (
+                        texts.settings_reduced_motion,
+                        switch(app.animations.reduced_motion()),
+                    ),
+                    Setting::KeyboardLayout => {
+                        (texts.settings_keyboard, app.keyboard_layout.as_str())
+                    }
+                    Setting::WordTheme => (
+                        texts.settings_word_theme,
+                        app.word_theme
+                            .as_ref()
+                            .map_or(texts.settings_off, |theme| theme.name(app.language)),
+                    ),
+                    Setting::WordFilter => (texts.settings_word_filter, app.word_filter.as_str()),
+                    Setting::QuitConfirm => {
+                        (texts.settings_quit_confirm, app.quit_confirm.as_str())
+                    }
+                };
+                choice(&format!("{}: {}", name, value), index == selected, app)
+            })
+            .collect();
+
+        let column = centered(area, PANEL_WIDTH, lines.len() as u16 + 2);
+        render_box(texts.menu_settings, &lines, column, buf, app);
+    }
+}
+
+/// The owner typing the secret word of a guest challenge: one dot per
+/// letter typed, so the guest can't read it over their shoulder. A
+/// challenge code can be pasted instead.
+pub struct ChallengeWidget<'a> {
+    app: &'a App,
+}
+
+impl<'a> ChallengeWidget<'a> {
+    pub fn new(app: &'a App) -> Self {
+        ChallengeWidget { app }
+    }
+}
+
+impl<'a> Widget for ChallengeWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let app = self.app;
+        let texts = app.texts();
+        let typed = app.secret.chars().count();
+        let slots: Vec<&str> = (0..WORD_LENGTH)
+            .map(|slot| if slot < typed { "●" } else { "_" })
+            .collect();
+        let lines = [
+            Line::from(texts.challenge_prompt),
+            Line::default(),
+            Line::styled(slots.join(" "), app.theme().title).alignment(Alignment::Center),
+            Line::default(),
+            Line::styled(texts.challenge_paste_code, app.theme().info).alignment(Alignment::Center),
+        ];
+
+        let width = texts.challenge_prompt.chars().count() as u16 + 4;
+        let column = centered(area, width.max(PANEL_WIDTH), lines.len() as u16 + 2);
+        render_box(texts.menu_challenge, &lines, column, buf, app);
+    }
+}
+
+// An entry of a list, marked and highlighted when selected
+fn choice<'a>(label: &str, selected: bool, app: &App) -> Line<'a> {
+    if selected {
+        Line::styled(
+            format!("▸ {}", label),
+            app.theme().title.add_modifier(Modifier::REVERSED),
+        )
+    } else {
+        Line::from(format!("  {}", label))
+    }
+}
+
+fn figure<'a>(name: &str, value: String) -> Line<'a> {
+    Line::from(format!("{}: {}", name, value))
+}
+
+// Lines in a titled box, one per row
+fn render_box(title: &str, lines: &[Line], area: Rect, buf: &mut Buffer, app: &App) {
+    Clear.render(area, buf);
+    let theme = app.theme();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(theme.border)
+        .style(theme.background)
+        .title(title.to_string());
+    let inner = block.inner(area);
+    block.render(area, buf);
+
+    for (row, line) in lines.iter().enumerate() {
+        let y = inner.y + row as u16;
+        if y >= inner.bottom() {
+            break;
+        }
+        buf.set_line(inner.x + 1, y, line, inner.width.saturating_sub(1));
+    }
+}
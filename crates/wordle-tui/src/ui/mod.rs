@@ -0,0 +1,733 @@
+//! Terminal rendering of a [`Game`], kept apart from the engine so the game
+//! can be driven without a terminal.
+
+pub mod analysis;
+pub mod assistant;
+pub mod board;
+pub mod card;
+pub mod community;
+pub mod constraints;
+pub mod definition;
+pub mod hotseat;
+pub mod legend;
+pub mod lock;
+pub mod menu;
+pub mod online;
+pub mod pause;
+pub mod profile;
+pub mod rival;
+pub mod spectator;
+pub mod status;
+pub mod tabs;
+pub mod theme;
+pub mod timer;
+pub mod toast;
+
+use ratatui::{
+    prelude::*,
+    widgets::{
+        block::{Position, Title},
+        *,
+    },
+};
+
+use crate::app::{App, EndAction, Screen, View};
+use crate::commentary;
+use crate::config::BoardLayout;
+use crate::game::{seed_code, Game, GameStatus};
+use crate::history::{self, Source};
+use crate::hotseat::Phase;
+use crate::mode::Mode;
+use analysis::AnalysisWidget;
+use assistant::AssistantWidget;
+use board::{GameWidget, Orientation, GRID_HEIGHT};
+use card::CardWidget;
+use community::CommunityWidget;
+use constraints::ConstraintsWidget;
+use definition::DefinitionWidget;
+use hotseat::HotseatWidget;
+use legend::{LegendWidget, LEGEND_HEIGHT};
+use lock::LockWidget;
+use menu::{
+    AchievementsWidget, ChallengeWidget, HistoryWidget, LeaderboardWidget, MenuWidget,
+    SettingsWidget, StatsWidget, WhatsNewWidget,
+};
+use online::OnlineWidget;
+use pause::PauseWidget;
+use rival::RivalWidget;
+use spectator::SpectatorWidget;
+use status::{game_status, StatusBarWidget};
+use tabs::TabBar;
+use theme::Theme;
+use timer::TimerWidget;
+use toast::ToastWidget;
+
+/// Width of the side panels (legend, assistant), borders included.
+pub const PANEL_WIDTH: u16 = 34;
+/// Momentum from which the title corner shows it.
+pub const MOMENTUM_SHOWN: usize = 2;
+
+/// Smallest terminal the full layout fits in.
+pub const MIN_WIDTH: u16 = 50;
+pub const MIN_HEIGHT: u16 = 25;
+/// Smallest terminal the horizontal layout fits in: the grid plus a line
+/// for the title and one for the instructions.
+pub const MIN_WIDE_WIDTH: u16 = 80;
+pub const MIN_WIDE_HEIGHT: u16 = GRID_HEIGHT + 2;
+/// Smallest terminal the compact layout (no keyboard) fits in.
+pub const MIN_COMPACT_WIDTH: u16 = 34;
+pub const MIN_COMPACT_HEIGHT: u16 = GRID_HEIGHT + 2;
+/// Smallest usable terminal: one line per attempt plus a status line.
+pub const MIN_MINIMAL_WIDTH: u16 = 20;
+pub const MIN_MINIMAL_HEIGHT: u16 = 7;
+
+/// How much of the interface fits in the terminal, from most to least.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fit {
+    /// Everything, with the keyboard below or beside the grid.
+    Full(Orientation),
+    /// Title, grid and instructions, without the virtual keyboard.
+    Compact,
+    /// Only the grid, with one-line cells, and a status line.
+    Minimal,
+}
+
+impl Fit {
+    /// Picks the richest layout for a terminal of `size`, honoring the
+    /// configured board layout when it fits. `None` if nothing usable fits.
+    pub fn for_size(size: Rect, layout: BoardLayout) -> Option<Self> {
+        let fits = |width, height| size.width >= width && size.height >= height;
+        let vertical = fits(MIN_WIDTH, MIN_HEIGHT);
+        let horizontal = fits(MIN_WIDE_WIDTH, MIN_WIDE_HEIGHT);
+        match layout {
+            BoardLayout::Horizontal if horizontal => Some(Fit::Full(Orientation::Horizontal)),
+            _ if vertical => Some(Fit::Full(Orientation::Vertical)),
+            _ if horizontal => Some(Fit::Full(Orientation::Horizontal)),
+            _ if fits(MIN_COMPACT_WIDTH, MIN_COMPACT_HEIGHT) => Some(Fit::Compact),
+            _ if fits(MIN_MINIMAL_WIDTH, MIN_MINIMAL_HEIGHT) => Some(Fit::Minimal),
+            _ => None,
+        }
+    }
+
+    // Height of the title and instructions bars
+    fn chrome_height(self) -> u16 {
+        match self {
+            Fit::Full(Orientation::Vertical) => 3,
+            _ => 1,
+        }
+    }
+
+    // Width the board needs, to decide whether side panels fit beside it
+    fn min_board_width(self) -> u16 {
+        match self {
+            Fit::Full(orientation) => orientation.min_board_width(),
+            Fit::Compact => MIN_COMPACT_WIDTH,
+            Fit::Minimal => MIN_MINIMAL_WIDTH,
+        }
+    }
+
+    fn board<'a>(self, app: &'a App, game: &'a Game) -> GameWidget<'a> {
+        let board = GameWidget::new(game)
+            .theme(app.theme())
+            .keyboard_layout(app.keyboard_layout)
+            .status_styles(app.shown_status_styles())
+            .shake(app.animations.shake())
+            .pop(app.animations.pop());
+        match self {
+            Fit::Full(orientation) => board.orientation(orientation),
+            Fit::Compact => board.keyboard(false),
+            Fit::Minimal => board.keyboard(false).flat(true),
+        }
+    }
+}
+
+/// Draws the whole screen.
+pub fn draw(f: &mut Frame, app: &App) {
+    let game = &app.game;
+    let texts = app.texts();
+    let theme = app.theme();
+    // Only a board with its keyboard on screen can be clicked
+    app.keyboard_keys.borrow_mut().clear();
+    *app.card_area.borrow_mut() = None;
+
+    // Paint the theme's background under everything
+    render(
+        f,
+        "background",
+        Block::default().style(theme.background),
+        f.size(),
+    );
+
+    // A locked screen shows nothing of what is behind it
+    if let Some(lock) = &app.lock {
+        render(f, "lock", LockWidget::new(app, lock), f.size());
+        return;
+    }
+
+    // Check if the terminal still has enough space
+    let size = f.size();
+    let Some(fit) = Fit::for_size(size, app.board_layout) else {
+        // Show warning message if terminal is too small
+        let warning = format!(
+            "{} ({}x{}). {}: {}x{}",
+            texts.too_small,
+            size.width,
+            size.height,
+            texts.minimum_size,
+            MIN_MINIMAL_WIDTH,
+            MIN_MINIMAL_HEIGHT
+        );
+
+        let warning_text = Paragraph::new(warning)
+            .style(theme.error)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::ALL));
+
+        render(f, "too_small", warning_text, size);
+        return;
+    };
+
+    // Main layout. Every layout but the full vertical one is for short
+    // terminals, so the title and instructions lose their borders there, and
+    // the minimal one drops the title altogether.
+    let title_height = match fit {
+        Fit::Minimal => 0,
+        _ => fit.chrome_height(),
+    };
+    // The tab bar only shows up once a second game is open
+    let tabs_height = u16::from(app.view == View::Game && app.tab_count() > 1);
+    // The clock, only in timed modes, the score of a versus game, the
+    // languages of the polyglot mode or the days of a catch-up. The
+    // countdown has its clock on the board, so the line is only there for
+    // the metronome, which reduced motion stops
+    let metronome = !app.animations.reduced_motion();
+    let clock = app.view == View::Game
+        && match app.mode {
+            Mode::Blitz(_)
+            | Mode::Speedrun(_)
+            | Mode::Hotseat(_)
+            | Mode::Pair(_)
+            | Mode::Polyglot(_)
+            | Mode::CatchUp(_) => true,
+            Mode::Countdown(_) => metronome,
+            Mode::Classic | Mode::Race(_) => false,
+        };
+    let main_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(title_height),        // Title
+            Constraint::Length(tabs_height),         // Open games
+            Constraint::Length(u16::from(clock)),    // Clock
+            Constraint::Min(0),                      // Game area
+            Constraint::Length(fit.chrome_height()), // Messages and instructions
+        ])
+        .split(f.size());
+
+    // Game title
+    let title_block =
+        chrome_block(fit, theme).title(Title::from(texts.legend_hint).alignment(Alignment::Right));
+    // The seed of a drawn word, to race friends on it, the hints taken and
+    // the momentum
+    let mut corner = Vec::new();
+    if app.view == View::Game
+        && !matches!(app.source, Source::Daily | Source::Warmup | Source::Archive)
+    {
+        if let Some(seed) = game.seed {
+            corner.push(format!("{} {}", texts.seed_label, seed_code(seed)));
+        }
+    }
+    if game.hints_used > 0 && app.view == View::Game {
+        corner.push(format!("{}: {}", texts.hints_used, game.hints_used));
+    }
+    // Guesses in a row finding new greens, from the second one on
+    let momentum = game.momentum();
+    if momentum >= MOMENTUM_SHOWN && app.view == View::Game {
+        corner.push(format!("{} ×{}", texts.momentum, momentum));
+    }
+    let title_block = if corner.is_empty() {
+        title_block
+    } else {
+        title_block.title(Title::from(corner.join(" | ")).alignment(Alignment::Left))
+    };
+
+    // The themed list played, by the name of the game
+    let title = match app
+        .word_theme
+        .as_ref()
+        .filter(|theme| theme.fits(app.language))
+    {
+        Some(theme) => format!("{} · {}", texts.title, theme.name(app.language)),
+        None => texts.title.to_string(),
+    };
+    let title = Paragraph::new(title)
+        .alignment(Alignment::Center)
+        .style(theme.title);
+
+    match fit {
+        Fit::Full(Orientation::Vertical) => {
+            render(f, "title", title.block(title_block), main_layout[0])
+        }
+        Fit::Minimal => {}
+        _ => {
+            // Without a border the block titles take the only line, so the
+            // game title goes on the same line instead of inside the block
+            render(f, "title", title_block, main_layout[0]);
+            render(f, "title", title, main_layout[0]);
+        }
+    }
+
+    render(f, "tabs", TabBar::new(app), main_layout[1]);
+    if clock {
+        render(
+            f,
+            "clock",
+            TimerWidget::new(&app.mode, texts, theme)
+                .metronome(metronome && game.status.accepts_input()),
+            main_layout[2],
+        );
+    }
+
+    // Game area, or the screen of the menu entry chosen
+    match (app.view, &app.screen) {
+        (View::Menu(_), _) => render(f, "menu", MenuWidget::new(app), main_layout[3]),
+        (View::Stats, _) => render(f, "stats", StatsWidget::new(app), main_layout[3]),
+        (View::Achievements, _) => render(
+            f,
+            "achievements",
+            AchievementsWidget::new(app),
+            main_layout[3],
+        ),
+        (View::Leaderboard(_), _) => render(
+            f,
+            "leaderboard",
+            LeaderboardWidget::new(app),
+            main_layout[3],
+        ),
+        (View::Settings(_), _) => render(f, "settings", SettingsWidget::new(app), main_layout[3]),
+        (View::Challenge, _) => render(f, "challenge", ChallengeWidget::new(app), main_layout[3]),
+        (View::History(_), _) => render(f, "history", HistoryWidget::new(app), main_layout[3]),
+        (View::WhatsNew(_), _) => render(f, "whats_new", WhatsNewWidget::new(app), main_layout[3]),
+        (View::Spectate, _) => {
+            if let Some(watch) = &app.watching {
+                render(
+                    f,
+                    "spectator",
+                    SpectatorWidget::new(watch, texts, theme),
+                    main_layout[3],
+                );
+            }
+        }
+        (View::Replay(_), _) => {
+            if let Some(replay) = &app.replay {
+                render(f, "replay", fit.board(app, &replay.game), main_layout[3]);
+            }
+        }
+        // Nor is the board of a paused game to be looked at
+        (View::Game, Screen::Board) if app.paused => {
+            render(f, "pause", PauseWidget::new(app), main_layout[3])
+        }
+        // The boards stay hidden while the keyboard is passed
+        (View::Game, Screen::Board) if hotseat_between_turns(app) => {
+            if let Mode::Hotseat(hotseat) = &app.mode {
+                render(
+                    f,
+                    "hotseat",
+                    HotseatWidget::new(app, hotseat),
+                    main_layout[3],
+                );
+            }
+        }
+        (View::Game, Screen::Board | Screen::Finished(_)) => {
+            profile::span("game", || draw_game_area(f, app, main_layout[3], fit))
+        }
+        (View::Game, Screen::Dvr(back)) => {
+            if let Some(snapshot) = app.dvr.frame(*back) {
+                let then = game.at(snapshot);
+                render(f, "dvr", fit.board(app, &then), main_layout[3]);
+            }
+        }
+        (View::Game, Screen::Card(shared)) => {
+            render(f, "card", CardWidget::new(app, shared), main_layout[3])
+        }
+        (View::Game, Screen::Analysis(report)) => render(
+            f,
+            "analysis",
+            AnalysisWidget::new(report, texts, theme)
+                .difficulty(
+                    app.ratings.get(&game.target_word),
+                    app.stats.average_difficulty(),
+                )
+                .rarity(app.frequencies.rarity(&game.target_word))
+                .commentary(commentary::narrate(report, &game.display_word, texts)),
+            main_layout[3],
+        ),
+    }
+
+    // Instructions, or the message of the moment
+    let (instructions, style) = if app.confirming_quit {
+        (texts.confirm_quit.to_string(), Style::default())
+    } else if app.paused {
+        (texts.lock_any_key.to_string(), theme.info)
+    } else if let View::Menu(_) = app.view {
+        (texts.menu_controls.to_string(), Style::default())
+    } else if let View::Stats | View::Achievements = app.view {
+        (texts.menu_back.to_string(), Style::default())
+    } else if let View::Leaderboard(_) = app.view {
+        (texts.leaderboard_controls.to_string(), Style::default())
+    } else if let View::Settings(_) = app.view {
+        (texts.settings_controls.to_string(), Style::default())
+    } else if let View::History(_) = app.view {
+        (texts.history_controls.to_string(), Style::default())
+    } else if let View::WhatsNew(_) = app.view {
+        (texts.whats_new_controls.to_string(), Style::default())
+    } else if app.view == View::Spectate {
+        (texts.spectate_controls.to_string(), Style::default())
+    } else if let (View::Replay(_), Some(replay)) = (app.view, &app.replay) {
+        let mut label = format!("{} {}", texts.replay_label, history::date(replay.finish.at));
+        if replay.paused {
+            label = format!("{} ({})", label, texts.replay_paused);
+        }
+        (format!("{} | {}", label, texts.replay_controls), theme.info)
+    } else if app.view == View::Challenge {
+        match &game.message {
+            Some(msg) => (msg.clone(), theme.message),
+            None => (texts.challenge_controls.to_string(), Style::default()),
+        }
+    } else if let Screen::Dvr(back) = app.screen {
+        (
+            format!(
+                "{} {}/{} | {}",
+                texts.dvr_label,
+                app.dvr.len() - back.min(app.dvr.len()),
+                app.dvr.len(),
+                texts.dvr_controls
+            ),
+            theme.info,
+        )
+    } else if let Screen::Card(_) = app.screen {
+        (texts.card_controls.to_string(), Style::default())
+    } else if let Screen::Analysis(_) = app.screen {
+        (texts.analysis_back.to_string(), Style::default())
+    } else if let (Mode::Blitz(blitz), true) = (&app.mode, app.mode.is_over()) {
+        (
+            format!(
+                "{} {}. {}",
+                texts.blitz_over, blitz.solved, texts.blitz_again
+            ),
+            theme.success,
+        )
+    } else if let (Mode::CatchUp(catch_up), true) = (&app.mode, caught_up(app)) {
+        // The summary of every day, once the last is played
+        let (found, guesses) = catch_up.found();
+        (
+            format!(
+                "{} {}/{}, {} {}.",
+                texts.catch_up_done,
+                found,
+                catch_up.days.len(),
+                guesses,
+                texts.catch_up_guesses
+            ),
+            theme.success,
+        )
+    } else if let (Mode::Hotseat(hotseat), true) = (&app.mode, hotseat_between_turns(app)) {
+        match hotseat.phase {
+            Phase::Over(_) => (texts.hotseat_over_controls.to_string(), theme.info),
+            _ => (texts.hotseat_controls.to_string(), Style::default()),
+        }
+    } else if let Some(msg) = &game.message {
+        (msg.clone(), theme.message)
+    } else if app.source == Source::Warmup && game.status.is_over() {
+        (
+            format!("{} {}.", texts.warmup_over, game.display_word),
+            theme.info,
+        )
+    } else {
+        match game.status {
+            GameStatus::Won => (texts.won.to_string(), theme.success),
+            GameStatus::Lost => (
+                format!(
+                    "{} {}. {}",
+                    texts.lost, game.display_word, texts.lost_controls
+                ),
+                theme.error,
+            ),
+            GameStatus::Playing if app.mode.is_timed() => (
+                format!("{} | {}", texts.controls, texts.pause_controls),
+                Style::default(),
+            ),
+            GameStatus::Playing => (texts.controls.to_string(), Style::default()),
+            GameStatus::Quitting => (texts.confirm_quit.to_string(), Style::default()),
+        }
+    };
+
+    // In keyboard audit mode the footer also tells where input goes: on
+    // the border when there is one, otherwise ahead of the instructions
+    let framed = fit == Fit::Full(Orientation::Vertical);
+    let mut footer_block = chrome_block(fit, theme);
+    let mut footer = Line::from(Span::styled(instructions, style));
+    if let (View::Game, Screen::Finished(selected)) = (app.view, &app.screen) {
+        footer.spans.extend(end_menu(app, *selected));
+    }
+    // Guesses after a loss are marked as not counting
+    if game.continued && app.view == View::Game {
+        footer.spans.insert(
+            0,
+            Span::styled(format!("{} | ", texts.practice_label), theme.info),
+        );
+    }
+    if app.keyboard_audit {
+        let focus = format!("{}: {}", texts.focus_label, app.focus_path().join(" › "));
+        if framed {
+            footer_block = footer_block.title(Title::from(focus).position(Position::Bottom));
+        } else {
+            footer.spans.insert(0, Span::raw(format!("{} | ", focus)));
+        }
+    }
+
+    // Where the game on screen stands, whatever the footer says
+    let mut status_bar = StatusBarWidget::new(footer).style(theme.info);
+    if app.view == View::Game {
+        status_bar = status_bar.items(game_status(app));
+    }
+    if framed {
+        status_bar = status_bar.block(footer_block);
+    }
+    render(f, "footer", status_bar, main_layout[4]);
+
+    // A badge just earned shows over the rest, in the top right corner
+    if let Some(&achievement) = app.toasts.front() {
+        render(f, "toast", ToastWidget::new(app, achievement), size);
+    }
+}
+
+// The menu of a finished game, its entries after the footer's text with
+// the one selected highlighted
+fn end_menu(app: &App, selected: usize) -> Vec<Span<'static>> {
+    let texts = app.texts();
+    let mut spans = Vec::new();
+    for (index, &action) in EndAction::ALL.iter().enumerate() {
+        let label = match action {
+            // The warmup leads to the word of the day
+            EndAction::PlayAgain if app.source == Source::Warmup => texts.menu_daily,
+            EndAction::PlayAgain => texts.end_play_again,
+            EndAction::ChangeMode => texts.end_change_mode,
+            EndAction::Analysis => texts.end_analysis,
+            EndAction::Share => texts.end_share,
+            EndAction::Quit => texts.end_quit,
+        };
+        spans.push(Span::raw(if index == 0 { " " } else { " | " }));
+        let entry = format!("[{}] {}", action.key().to_ascii_uppercase(), label);
+        spans.push(if index == selected {
+            Span::styled(entry, app.theme().title.add_modifier(Modifier::REVERSED))
+        } else {
+            Span::raw(entry)
+        });
+    }
+    spans
+}
+
+// Whether a versus game is between turns, or between rounds
+fn hotseat_between_turns(app: &App) -> bool {
+    match &app.mode {
+        Mode::Hotseat(hotseat) => hotseat.phase != Phase::Playing,
+        _ => false,
+    }
+}
+
+// Whether the last day of a catch-up was played
+fn caught_up(app: &App) -> bool {
+    match &app.mode {
+        Mode::CatchUp(catch_up) => catch_up.is_done(),
+        _ => false,
+    }
+}
+
+// Draws `widget` in `area`, timed under `name` when profiling
+fn render(f: &mut Frame, name: &'static str, widget: impl Widget, area: Rect) {
+    profile::span(name, || f.render_widget(widget, area));
+}
+
+// Frame of the title and instructions bars
+fn chrome_block<'a>(fit: Fit, theme: &Theme) -> Block<'a> {
+    match fit {
+        Fit::Full(Orientation::Vertical) => Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border),
+        _ => Block::default(),
+    }
+}
+
+// Board, plus the open side panels: beside the board if there is room for
+// both, otherwise on top of it. Records where the keyboard keys ended up
+// for mouse clicks.
+fn draw_game_area(f: &mut Frame, app: &App, area: Rect, fit: Fit) {
+    let mut panels = Vec::new();
+    // The rival of a race is always in view
+    if let Mode::Race(race) = &app.mode {
+        panels.push(Panel::Rival(RivalWidget::new(
+            race,
+            app.texts(),
+            app.theme(),
+        )));
+    }
+    // So is the online leaderboard once the word of the day is played
+    if let (Some(top), Some(online), Source::Daily, true) = (
+        &app.online_top,
+        &app.online,
+        app.source,
+        app.game.status.is_over(),
+    ) {
+        panels.push(Panel::Online(OnlineWidget::new(
+            top,
+            &online.name,
+            app.texts(),
+            app.theme(),
+        )));
+    }
+    // The definition of the word is there once the game is over
+    if let Some(lookup) = app
+        .definition
+        .as_ref()
+        .filter(|lookup| app.game.status.is_over() && lookup.word == app.game.display_word)
+    {
+        panels.push(Panel::Definition(DefinitionWidget::new(
+            lookup,
+            app.texts(),
+            app.theme(),
+        )));
+    }
+    if app.show_legend {
+        panels.push(Panel::Legend(
+            LegendWidget::new(app.texts(), app.theme()).status_styles(app.shown_status_styles()),
+        ));
+        // The puzzle of the week is announced with the legend
+        if let Some(puzzle) = &app.community {
+            panels.push(Panel::Community(CommunityWidget::new(
+                puzzle,
+                app.community_completed.contains(&puzzle.id),
+                app.texts(),
+                app.theme(),
+            )));
+        }
+    }
+    if let Some(assistant) = &app.assistant {
+        panels.push(Panel::Assistant(AssistantWidget::new(
+            assistant,
+            app.texts(),
+            app.theme(),
+        )));
+    }
+
+    if app.show_constraints {
+        panels.push(Panel::Constraints(ConstraintsWidget::new(
+            app.game.constraints(),
+            app.texts(),
+            app.theme(),
+        )));
+    }
+
+    let (board_area, panel_area) =
+        if !panels.is_empty() && area.width >= fit.min_board_width() + PANEL_WIDTH {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(0), Constraint::Length(PANEL_WIDTH)])
+                .split(area);
+            (columns[0], columns[1])
+        } else {
+            (area, area)
+        };
+    let countdown = match &app.mode {
+        Mode::Countdown(countdown) => Some(countdown.remaining()),
+        _ => None,
+    };
+    let partner = match &app.mode {
+        Mode::Pair(pair) => &pair.typing[..],
+        _ => &[],
+    };
+    let board = fit
+        .board(app, &app.game)
+        .countdown(countdown)
+        .partner(partner);
+    let mut keys = board.key_areas(board_area);
+    render(f, "board", board, board_area);
+
+    // Stack the panels, centered vertically
+    let total_height = panels.iter().map(Panel::height).sum();
+    let column = centered(panel_area, PANEL_WIDTH, total_height);
+    let mut y = column.y;
+    for panel in panels {
+        let height = panel.height().min(column.bottom().saturating_sub(y));
+        let panel_rect = Rect::new(column.x, y, column.width, height);
+        // Keys under a panel can't be clicked
+        keys.retain(|(_, key_area)| !key_area.intersects(panel_rect));
+        render(f, panel.name(), panel, panel_rect);
+        y += height;
+    }
+
+    *app.keyboard_keys.borrow_mut() = keys;
+}
+
+enum Panel<'a> {
+    Legend(LegendWidget<'a>),
+    Assistant(AssistantWidget<'a>),
+    Community(CommunityWidget<'a>),
+    Constraints(ConstraintsWidget<'a>),
+    Rival(RivalWidget<'a>),
+    Online(OnlineWidget<'a>),
+    Definition(DefinitionWidget<'a>),
+}
+
+impl Panel<'_> {
+    fn name(&self) -> &'static str {
+        match self {
+            Panel::Legend(_) => "legend",
+            Panel::Assistant(_) => "assistant",
+            Panel::Community(_) => "community",
+            Panel::Constraints(_) => "constraints",
+            Panel::Rival(_) => "rival",
+            Panel::Online(_) => "online",
+            Panel::Definition(_) => "definition",
+        }
+    }
+
+    fn height(&self) -> u16 {
+        match self {
+            Panel::Legend(_) => LEGEND_HEIGHT,
+            Panel::Assistant(assistant) => assistant.height(),
+            Panel::Community(community) => community.height(),
+            Panel::Constraints(constraints) => constraints.height(),
+            Panel::Rival(rival) => rival.height(),
+            Panel::Online(online) => online.height(),
+            Panel::Definition(definition) => definition.height(),
+        }
+    }
+}
+
+impl Widget for Panel<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        match self {
+            Panel::Legend(legend) => legend.render(area, buf),
+            Panel::Assistant(assistant) => assistant.render(area, buf),
+            Panel::Community(community) => community.render(area, buf),
+            Panel::Constraints(constraints) => constraints.render(area, buf),
+            Panel::Rival(rival) => rival.render(area, buf),
+            Panel::Online(online) => online.render(area, buf),
+            Panel::Definition(definition) => definition.render(area, buf),
+        }
+    }
+}
+
+/// A `width`x`height` rectangle centered in `area`, clipped to it.
+pub fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
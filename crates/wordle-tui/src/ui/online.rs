@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+};
+
+use super::theme::Theme;
+use crate::i18n::Texts;
+use crate::online::Score;
+use crate::records::format_time;
+
+/// Width of a name on the leaderboard.
+const NAME_WIDTH: usize = 8;
+
+/// The best results of the word of the day on the online leaderboard: a
+/// line per player with their guesses and time, the player's own name
+/// highlighted.
+pub struct OnlineWidget<'a> {
+    top: &'a [Score],
+    name: &'a str,
+    texts: &'a Texts,
+    theme: &'a Theme,
+}
+
+impl<'a> OnlineWidget<'a> {
+    pub fn new(top: &'a [Score], name: &'a str, texts: &'a Texts, theme: &'a Theme) -> Self {
+        OnlineWidget {
+            top,
+            name,
+            texts,
+            theme,
+        }
+    }
+
+    /// Height of the panel, borders included.
+    pub fn height(&self) -> u16 {
+        2 + self.top.len().max(1) as u16
+    }
+
+    fn line(&self, rank: usize, score: &Score) -> Line<'a> {
+        let name: String = score.name.chars().take(NAME_WIDTH).collect();
+        let result = if score.won {
+            score.guesses.to_string()
+        } else {
+            "✗".to_string()
+        };
+        let time = score
+            .time_ms
+            .map(|ms| format_time(Duration::from_millis(ms)))
+            .unwrap_or_default();
+        let text = format!(
+            "{:>2}. {:<width$} {:>2} {}",
+            rank,
+            name,
+            result,
+            time,
+            width = NAME_WIDTH
+        );
+        if score.name == self.name {
+            Line::styled(text, self.theme.success)
+        } else {
+            Line::from(text)
+        }
+    }
+}
+
+impl<'a> Widget for OnlineWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(self.theme.border)
+            .style(self.theme.background)
+            .title(self.texts.online_title);
+
+        let mut lines: Vec<Line> = self
+            .top
+            .iter()
+            .enumerate()
+            .map(|(index, score)| self.line(index + 1, score))
+            .collect();
+        if lines.is_empty() {
+            lines.push(Line::styled(self.texts.online_empty, self.theme.info));
+        }
+
+        Paragraph::new(lines)
+            .block(block.padding(Padding::horizontal(1)))
+            .render(area, buf);
+    }
+}
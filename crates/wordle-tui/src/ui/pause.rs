@@ -0,0 +1,52 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear},
+};
+
+use super::centered;
+use crate::app::App;
+
+/// Width of the box, borders included.
+const WIDTH: u16 = 48;
+
+/// What the game area shows while a timed game is paused, instead of the
+/// board, so the pause can't be used to think over it.
+pub struct PauseWidget<'a> {
+    app: &'a App,
+}
+
+impl<'a> PauseWidget<'a> {
+    pub fn new(app: &'a App) -> Self {
+        PauseWidget { app }
+    }
+}
+
+impl<'a> Widget for PauseWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let texts = self.app.texts();
+        let theme = self.app.theme();
+        let lines = [
+            Line::from(texts.pause_hidden),
+            Line::styled(texts.lock_any_key, theme.title),
+        ];
+
+        Clear.render(area, buf);
+        Block::default().style(theme.background).render(area, buf);
+        let column = centered(area, WIDTH, lines.len() as u16 + 2);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border)
+            .style(theme.background)
+            .title(texts.pause_title);
+        let inner = block.inner(column);
+        block.render(column, buf);
+        for (row, line) in lines.into_iter().enumerate() {
+            let line = line.alignment(Alignment::Center);
+            let rect = Rect::new(inner.x, inner.y + row as u16, inner.width, 1);
+            if rect.y < inner.bottom() {
+                line.render(rect, buf);
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Render profiling, for finding what makes frames slow on slow terminals.
+//!
+//! Built with the `profiling` feature and started with `--profile-render
+//! <file>`, every widget drawn is timed under the stack of widgets it was
+//! drawn in (`frame;draw;game;board;keyboard`); what `frame` takes beyond
+//! `draw` is sending the frame to the terminal. At exit the time spent in
+//! each stack, not counting the widgets drawn inside it, is written in the
+//! folded format that `inferno-flamegraph` and `flamegraph.pl` read: one
+//! `stack microseconds` line per stack, summed over every frame.
+//!
+//! Without the feature, [`span`] just calls its closure.
+
+use std::io;
+use std::path::Path;
+
+#[cfg(feature = "profiling")]
+use std::{cell::RefCell, collections::BTreeMap, fs, time::Duration, time::Instant};
+
+#[cfg(feature = "profiling")]
+thread_local! {
+    static PROFILE: RefCell<Profile> = RefCell::new(Profile::default());
+}
+
+#[cfg(feature = "profiling")]
+#[derive(Default)]
+struct Profile {
+    enabled: bool,
+    // Spans being timed, outermost first: name, start, and time spent in
+    // the spans inside it
+    open: Vec<(&'static str, Instant, Duration)>,
+    // Time spent in each stack itself, by folded stack
+    totals: BTreeMap<String, Duration>,
+}
+
+/// Whether this build can profile.
+pub const AVAILABLE: bool = cfg!(feature = "profiling");
+
+/// Starts recording spans on this thread.
+pub fn enable() {
+    #[cfg(feature = "profiling")]
+    PROFILE.with(|profile| profile.borrow_mut().enabled = true);
+}
+
+/// Runs `draw`, timing it under `name` when profiling.
+#[cfg(feature = "profiling")]
+pub fn span<R>(name: &'static str, draw: impl FnOnce() -> R) -> R {
+    let enabled = PROFILE.with(|profile| {
+        let mut profile = profile.borrow_mut();
+        if profile.enabled {
+            profile.open.push((name, Instant::now(), Duration::ZERO));
+        }
+        profile.enabled
+    });
+    let result = draw();
+    if enabled {
+        PROFILE.with(|profile| profile.borrow_mut().close());
+    }
+    result
+}
+
+/// Runs `draw`, timing it under `name` when profiling.
+#[cfg(not(feature = "profiling"))]
+#[inline(always)]
+pub fn span<R>(_name: &'static str, draw: impl FnOnce() -> R) -> R {
+    draw()
+}
+
+#[cfg(feature = "profiling")]
+impl Profile {
+    fn close(&mut self) {
+        let stack = self
+            .open
+            .iter()
+            .map(|(name, _, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let Some((_, started, inside)) = self.open.pop() else {
+            return;
+        };
+        let elapsed = started.elapsed();
+        *self.totals.entry(stack).or_default() += elapsed.saturating_sub(inside);
+        if let Some((_, _, parent_inside)) = self.open.last_mut() {
+            *parent_inside += elapsed;
+        }
+    }
+}
+
+/// What was recorded so far, in folded format.
+pub fn folded() -> String {
+    #[cfg(feature = "profiling")]
+    {
+        PROFILE.with(|profile| {
+            profile
+                .borrow()
+                .totals
+                .iter()
+                .map(|(stack, time)| format!("{} {}\n", stack, time.as_micros()))
+                .collect()
+        })
+    }
+    #[cfg(not(feature = "profiling"))]
+    String::new()
+}
+
+/// Writes what was recorded to `path`, in folded format.
+pub fn write_folded(path: &Path) -> io::Result<()> {
+    #[cfg(feature = "profiling")]
+    {
+        fs::write(path, folded())
+    }
+    #[cfg(not(feature = "profiling"))]
+    {
+        let _ = path;
+        Err(io::Error::other("built without the profiling feature"))
+    }
+}
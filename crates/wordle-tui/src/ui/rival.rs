@@ -0,0 +1,189 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear},
+};
+
+use super::theme::Theme;
+use crate::game::{LetterStatus, MAX_ATTEMPTS, WORD_LENGTH};
+use crate::i18n::Texts;
+use crate::net::{Race, Rival};
+
+/// Width of a name in the list of a room.
+const NAME_WIDTH: usize = 8;
+
+/// The rivals in a race. Against a host, the rival's board: a cell per
+/// letter in the colors of their guesses, without the letters, and how
+/// their game is going. In a room of a server, a line per rival with the
+/// colors of their last guess, then the points of everyone.
+pub struct RivalWidget<'a> {
+    race: &'a Race,
+    texts: &'a Texts,
+    theme: &'a Theme,
+}
+
+impl<'a> RivalWidget<'a> {
+    pub fn new(race: &'a Race, texts: &'a Texts, theme: &'a Theme) -> Self {
+        RivalWidget { race, texts, theme }
+    }
+
+    /// Height of the panel, borders included.
+    pub fn height(&self) -> u16 {
+        match &self.race.room {
+            // A row per guess, at least a board's worth, then a blank line
+            // and the status
+            None => {
+                let rows = self.rows().len().max(MAX_ATTEMPTS);
+                2 + rows as u16 + 2
+            }
+            Some(_) => 2 + self.room_lines().len() as u16,
+        }
+    }
+
+    // Rows of the rival against a host
+    fn rows(&self) -> &'a [Vec<LetterStatus>] {
+        self.race.rivals.first().map_or(&[], |rival| &rival.rows)
+    }
+
+    fn status(&self) -> Line<'a> {
+        let result = self.race.rivals.first().and_then(|rival| rival.result);
+        match result {
+            Some(result) if result.won => Line::styled(
+                format!("{} {}", self.texts.rival_won, result.guesses),
+                self.theme.success,
+            ),
+            Some(_) => Line::styled(self.texts.rival_lost, self.theme.error),
+            None if self.race.disconnected => Line::styled(self.texts.rival_left, self.theme.info),
+            None => Line::from(self.texts.rival_playing),
+        }
+    }
+
+    // The rivals of a room, their points and whether the next round is on
+    fn room_lines(&self) -> Vec<Line<'a>> {
+        let mut lines: Vec<Line> = self
+            .race
+            .rivals
+            .iter()
+            .map(|r| self.rival_line(r))
+            .collect();
+        if lines.is_empty() {
+            lines.push(Line::from(self.texts.rival_waiting));
+        }
+        if !self.race.scores.is_empty() {
+            lines.push(Line::default());
+            lines.push(Line::styled(self.texts.rival_points, self.theme.info));
+            for score in &self.race.scores {
+                lines.push(Line::from(format!(
+                    "{:<width$} {}",
+                    short_name(&score.name),
+                    score.points,
+                    width = NAME_WIDTH
+                )));
+            }
+        }
+        if self.race.next_seed.is_some() {
+            lines.push(Line::default());
+            lines.push(Line::styled(
+                self.texts.rival_next_round,
+                self.theme.success,
+            ));
+        }
+        if self.race.disconnected {
+            lines.push(Line::default());
+            lines.push(Line::styled(self.texts.rival_left, self.theme.info));
+        }
+        lines
+    }
+
+    // The name of `rival`, the colors of their last guess and how they are
+    // doing
+    fn rival_line(&self, rival: &Rival) -> Line<'a> {
+        let name = rival.name.as_deref().unwrap_or(self.texts.rival_title);
+        let mut spans = vec![Span::raw(format!(
+            "{:<width$} ",
+            short_name(name),
+            width = NAME_WIDTH
+        ))];
+        match rival.rows.last() {
+            Some(cells) => {
+                for status in cells {
+                    spans.push(Span::styled("  ", self.theme.tile(*status)));
+                    spans.push(Span::raw(" "));
+                }
+            }
+            None => spans.push(Span::styled("·· ".repeat(WORD_LENGTH), self.theme.border)),
+        }
+        spans.push(match rival.result {
+            Some(result) if result.won => {
+                Span::styled(format!("✓ {}", result.guesses), self.theme.success)
+            }
+            Some(_) => Span::styled("✗", self.theme.error),
+            None if rival.left => Span::styled("—", self.theme.info),
+            None => Span::raw(format!("{}/{}", rival.rows.len(), MAX_ATTEMPTS)),
+        });
+        Line::from(spans)
+    }
+}
+
+// `name` cut to fit its column
+fn short_name(name: &str) -> String {
+    name.chars().take(NAME_WIDTH).collect()
+}
+
+impl<'a> Widget for RivalWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let title = match &self.race.room {
+            Some(room) => room.as_str(),
+            None => self
+                .race
+                .rivals
+                .first()
+                .and_then(|rival| rival.name.as_deref())
+                .unwrap_or(self.texts.rival_title),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(self.theme.border)
+            .style(self.theme.background)
+            .title(title.to_string());
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if self.race.room.is_some() {
+            for (line, y) in self.room_lines().iter().zip(inner.y..inner.bottom()) {
+                buf.set_line(inner.x + 1, y, line, inner.width.saturating_sub(1));
+            }
+            return;
+        }
+
+        let rival_rows = self.rows();
+        let rows = rival_rows.len().max(MAX_ATTEMPTS);
+        for row in 0..rows {
+            let y = inner.y + row as u16;
+            if y >= inner.bottom() {
+                return;
+            }
+            let cells = rival_rows.get(row);
+            let width = cells.map_or(WORD_LENGTH, Vec::len) as u16 * 3;
+            let mut x = inner.x + inner.width.saturating_sub(width) / 2;
+            for column in 0..width / 3 {
+                let style = match cells {
+                    Some(cells) => self.theme.tile(cells[column as usize]),
+                    None => self.theme.border,
+                };
+                let cell = if cells.is_some() { "  " } else { "··" };
+                buf.set_string(x, y, cell, style);
+                x += 3;
+            }
+        }
+
+        let y = inner.y + rows as u16 + 1;
+        if y < inner.bottom() {
+            let status = self.status();
+            let x = inner.x + inner.width.saturating_sub(status.width() as u16) / 2;
+            buf.set_line(x, y, &status, inner.width);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear},
+};
+
+use super::theme::Theme;
+use crate::game::{MAX_ATTEMPTS, WORD_LENGTH};
+use crate::i18n::Texts;
+use crate::net::{Rival, Watch};
+
+/// Width of a board, the gap after it included.
+const BOARD_WIDTH: u16 = WORD_LENGTH as u16 * 3 + 3;
+/// Height of a board: the name, the rows, a blank line, the status and
+/// the points.
+const BOARD_HEIGHT: u16 = MAX_ATTEMPTS as u16 + 4;
+
+/// The room a spectator watches: the board of every player side by side,
+/// in the colors of their guesses without the letters, with how their game
+/// is going and their points. Boards that don't fit on a line go on the
+/// next.
+pub struct SpectatorWidget<'a> {
+    watch: &'a Watch,
+    texts: &'a Texts,
+    theme: &'a Theme,
+}
+
+impl<'a> SpectatorWidget<'a> {
+    pub fn new(watch: &'a Watch, texts: &'a Texts, theme: &'a Theme) -> Self {
+        SpectatorWidget {
+            watch,
+            texts,
+            theme,
+        }
+    }
+
+    fn status(&self, player: &Rival) -> Span<'a> {
+        match player.result {
+            Some(result) if result.won => {
+                Span::styled(format!("✓ {}", result.guesses), self.theme.success)
+            }
+            Some(_) => Span::styled("✗", self.theme.error),
+            None if player.left => Span::styled("—", self.theme.info),
+            None => Span::raw(format!("{}/{}", player.rows.len(), MAX_ATTEMPTS)),
+        }
+    }
+
+    fn points(&self, player: &Rival) -> u32 {
+        self.watch
+            .scores
+            .iter()
+            .find(|score| Some(&score.name) == player.name.as_ref())
+            .map_or(0, |score| score.points)
+    }
+
+    // The board of `player` with its top left corner at `x`, `y`
+    fn render_board(&self, player: &Rival, x: u16, y: u16, area: Rect, buf: &mut Buffer) {
+        let width = BOARD_WIDTH - 3;
+        let name: String = player
+            .name
+            .as_deref()
+            .unwrap_or_default()
+            .chars()
+            .take(width as usize)
+            .collect();
+        buf.set_string(x, y, name, self.theme.title);
+        for row in 0..MAX_ATTEMPTS {
+            let y = y + 1 + row as u16;
+            if y >= area.bottom() {
+                return;
+            }
+            let cells = player.rows.get(row);
+            for column in 0..WORD_LENGTH {
+                let (cell, style) = match cells.and_then(|cells| cells.get(column)) {
+                    Some(status) => ("  ", self.theme.tile(*status)),
+                    None => ("··", self.theme.border),
+                };
+                buf.set_string(x + column as u16 * 3, y, cell, style);
+            }
+        }
+        let lines = [
+            Line::from(self.status(player)),
+            Line::from(format!(
+                "{} {}",
+                self.texts.rival_points,
+                self.points(player)
+            )),
+        ];
+        for (line, y) in lines.iter().zip(y + MAX_ATTEMPTS as u16 + 2..area.bottom()) {
+            buf.set_line(x, y, line, width);
+        }
+    }
+}
+
+impl<'a> Widget for SpectatorWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let title = format!(
+            "{} {} | {} {}",
+            self.texts.spectate_title, self.watch.room, self.texts.spectate_round, self.watch.round
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(self.theme.border)
+            .style(self.theme.background)
+            .title(title);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let mut bottom = inner.bottom();
+        if self.watch.disconnected && inner.height > 0 {
+            bottom -= 1;
+            let line = Line::styled(self.texts.spectate_closed, self.theme.info);
+            buf.set_line(inner.x + 1, bottom, &line, inner.width.saturating_sub(1));
+        }
+        let area = Rect {
+            height: bottom - inner.y,
+            ..inner
+        };
+
+        if self.watch.players.is_empty() {
+            let line = Line::from(self.texts.rival_waiting);
+            buf.set_line(area.x + 1, area.y, &line, area.width.saturating_sub(1));
+            return;
+        }
+
+        // As many boards on a line as fit, the lines centered
+        let per_line = (area.width.saturating_sub(1) / BOARD_WIDTH).max(1) as usize;
+        for (line, players) in self.watch.players.chunks(per_line).enumerate() {
+            let y = area.y + line as u16 * (BOARD_HEIGHT + 1);
+            if y >= area.bottom() {
+                return;
+            }
+            let width = players.len() as u16 * BOARD_WIDTH - 3;
+            let x = area.x + area.width.saturating_sub(width) / 2;
+            for (index, player) in players.iter().enumerate() {
+                self.render_board(player, x + index as u16 * BOARD_WIDTH, y, area, buf);
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use ratatui::{
+    prelude::*,
+    widgets::{block::Title, Block, Paragraph},
+};
+use unicode_width::UnicodeWidthStr;
+
+use super::menu::{category_name, difficulty_name};
+use crate::app::App;
+use crate::history::Source;
+use crate::leaderboard::Category;
+use crate::mode::Mode;
+
+/// Between the items of the status bar.
+const SEPARATOR: &str = " · ";
+
+/// The footer: the instructions or the message of the moment, with a
+/// status bar of short items after them. Framed, the items go on the top
+/// edge of the frame; on a single line they take its right end when the
+/// whole line fits, and are left out otherwise.
+pub struct StatusBarWidget<'a> {
+    message: Line<'a>,
+    items: Vec<String>,
+    style: Style,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> StatusBarWidget<'a> {
+    pub fn new(message: Line<'a>) -> Self {
+        StatusBarWidget {
+            message,
+            items: Vec::new(),
+            style: Style::default(),
+            block: None,
+        }
+    }
+
+    /// Adds every one of `items`, in order.
+    pub fn items(mut self, items: impl IntoIterator<Item = String>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Style of the status bar items.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Frames the footer in `block`.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl<'a> Widget for StatusBarWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let status = self.items.join(SEPARATOR);
+        let message_width = self.message.width() as u16;
+        let message = Paragraph::new(self.message).alignment(Alignment::Center);
+        if let Some(block) = self.block {
+            let block = if status.is_empty() {
+                block
+            } else {
+                block.title(Title::from(Span::styled(
+                    format!(" {} ", status),
+                    self.style,
+                )))
+            };
+            message.block(block).render(area, buf);
+            return;
+        }
+
+        // A space at least between the message and the items
+        let status_width = status.width() as u16;
+        let fits = !status.is_empty() && message_width + status_width < area.width;
+        if !fits {
+            message.render(area, buf);
+            return;
+        }
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(status_width)])
+            .split(area);
+        message.render(columns[0], buf);
+        Line::styled(status, self.style).render(columns[1], buf);
+    }
+}
+
+/// Where the game on screen stands: the guess being played out of those
+/// allowed, its mode, the time it has taken and the wins in a row.
+pub fn game_status(app: &App) -> Vec<String> {
+    let texts = app.texts();
+    let game = &app.game;
+    let guess = if game.status.is_over() {
+        game.submitted().count()
+    } else {
+        game.current_attempt + 1
+    };
+    let mut items = vec![if game.config.endless {
+        format!("{} {}", texts.status_guess, guess)
+    } else {
+        format!(
+            "{} {}/{}",
+            texts.status_guess,
+            guess.min(game.config.max_attempts),
+            game.config.max_attempts
+        )
+    }];
+    items.extend(mode_name(app));
+    if let Some(elapsed) = app.game_id.and_then(|id| app.history.elapsed(id)) {
+        let seconds = elapsed.as_secs();
+        items.push(format!("{}:{:02}", seconds / 60, seconds % 60));
+    }
+    items.push(format!("{} {}", texts.status_streak, app.stats.streak()));
+    items
+}
+
+// The mode of the game on screen, with the difficulty of a word drawn at
+// random when one was chosen
+fn mode_name(app: &App) -> Option<String> {
+    let texts = app.texts();
+    let category = Category::of(&app.mode, app.source, app.game.config.feedback);
+    let name = match (category, &app.mode, app.source) {
+        (Some(category), _, _) => category_name(category, texts),
+        (None, Mode::Hotseat(_), _) => texts.mode_versus,
+        (None, Mode::Pair(_), _) => texts.pair_label,
+        (None, _, Source::Practice) => texts.mode_practice,
+        (None, _, Source::Challenge | Source::Sent) => texts.mode_challenge,
+        (None, _, _) => return None,
+    };
+    Some(match app.difficulty {
+        Some(tier) if category == Some(Category::Classic) && app.source == Source::Random => {
+            format!("{} ({})", name, difficulty_name(Some(tier), texts))
+        }
+        _ => name.to_string(),
+    })
+}
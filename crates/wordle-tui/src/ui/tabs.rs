@@ -0,0 +1,45 @@
+use ratatui::{prelude::*, widgets::Tabs};
+
+use crate::app::App;
+use crate::game::{Game, GameStatus};
+
+/// One line listing the open games, with the active one highlighted.
+pub struct TabBar<'a> {
+    app: &'a App,
+}
+
+impl<'a> TabBar<'a> {
+    pub fn new(app: &'a App) -> Self {
+        TabBar { app }
+    }
+}
+
+impl<'a> Widget for TabBar<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let titles: Vec<String> = self
+            .app
+            .tab_games()
+            .enumerate()
+            .map(|(index, game)| format!("{} {}", index + 1, progress(game)))
+            .collect();
+
+        Tabs::new(titles)
+            .select(self.app.active_tab)
+            .highlight_style(self.app.theme().title)
+            .render(area, buf);
+    }
+}
+
+// How far a game got: attempts used, or how it ended
+fn progress(game: &Game) -> String {
+    match game.status {
+        GameStatus::Won => "✓".to_string(),
+        GameStatus::Lost => "✗".to_string(),
+        GameStatus::Playing | GameStatus::Quitting if game.config.endless => {
+            format!("{}/∞", game.submitted().count())
+        }
+        GameStatus::Playing | GameStatus::Quitting => {
+            format!("{}/{}", game.submitted().count(), game.config.max_attempts)
+        }
+    }
+}
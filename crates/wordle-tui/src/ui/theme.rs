@@ -0,0 +1,245 @@
+//! Named color themes. Every color the interface draws with comes from the
+//! active [`Theme`], so switching themes recolors the whole screen.
+
+use ratatui::prelude::*;
+
+use crate::config::ThemeName;
+use crate::game::LetterStatus;
+
+/// Colors of the interface.
+pub struct Theme {
+    /// Fills the whole screen before anything else is drawn.
+    pub background: Style,
+    /// Grid tiles, indexed by `LetterStatus` discriminant.
+    pub tiles: [Style; 4],
+    /// Virtual keyboard keys, indexed by `LetterStatus` discriminant.
+    pub keys: [Style; 4],
+    pub current_row: Style,
+    pub empty_row: Style,
+    /// Row being typed when a guess is refused.
+    pub rejected_row: Style,
+    /// Borders of the title, instructions and panels.
+    pub border: Style,
+    /// Game title, table headers and the active tab.
+    pub title: Style,
+    /// Temporary messages.
+    pub message: Style,
+    /// Neutral figures, like the assistant's remaining words.
+    pub info: Style,
+    pub success: Style,
+    pub error: Style,
+}
+
+impl Theme {
+    /// The built-in theme called `name`. `Auto` stands for the theme its
+    /// schedule picks, which the app works out; on its own it is the
+    /// classic one.
+    pub fn named(name: ThemeName) -> &'static Theme {
+        match name {
+            ThemeName::Classic | ThemeName::Auto => &CLASSIC,
+            ThemeName::Dark => &DARK,
+            ThemeName::Light => &LIGHT,
+            ThemeName::Solarized => &SOLARIZED,
+            ThemeName::Sepia => &SEPIA,
+        }
+    }
+
+    /// Colorblind-friendly theme: orange and blue instead of green and
+    /// yellow, bold letters on pure black and white.
+    pub fn high_contrast() -> &'static Theme {
+        &HIGH_CONTRAST
+    }
+
+    pub fn tile(&self, status: LetterStatus) -> Style {
+        self.tiles[status as usize]
+    }
+
+    pub fn key(&self, status: LetterStatus) -> Style {
+        self.keys[status as usize]
+    }
+}
+
+const fn on(bg: Color, fg: Color) -> Style {
+    Style::new().bg(bg).fg(fg)
+}
+
+const fn fg(color: Color) -> Style {
+    Style::new().fg(color)
+}
+
+// The terminal's own colors with the basic palette
+static CLASSIC: Theme = Theme {
+    background: Style::new(),
+    tiles: CLASSIC_TILES,
+    keys: CLASSIC_TILES,
+    current_row: on(Color::Black, Color::White),
+    empty_row: on(Color::Black, Color::DarkGray),
+    rejected_row: on(Color::Black, Color::Red),
+    border: Style::new(),
+    title: fg(Color::Yellow).add_modifier(Modifier::BOLD),
+    message: fg(Color::Yellow),
+    info: fg(Color::Cyan),
+    success: fg(Color::Green),
+    error: fg(Color::Red),
+};
+
+const CLASSIC_TILES: [Style; 4] = [
+    on(Color::Green, Color::Black),    // Correct
+    on(Color::Yellow, Color::Black),   // Present
+    on(Color::DarkGray, Color::White), // Absent
+    on(Color::Black, Color::White),    // Unused
+];
+
+const DARK_BG: Color = Color::Rgb(18, 18, 19);
+const DARK_FG: Color = Color::Rgb(215, 218, 220);
+const DARK_GREEN: Color = Color::Rgb(83, 141, 78);
+const DARK_YELLOW: Color = Color::Rgb(181, 159, 59);
+const DARK_GRAY: Color = Color::Rgb(58, 58, 60);
+
+static DARK: Theme = Theme {
+    background: on(DARK_BG, DARK_FG),
+    tiles: [
+        on(DARK_GREEN, Color::White),
+        on(DARK_YELLOW, Color::White),
+        on(DARK_GRAY, Color::White),
+        on(DARK_BG, Color::White),
+    ],
+    keys: [
+        on(DARK_GREEN, Color::White),
+        on(DARK_YELLOW, Color::White),
+        on(DARK_GRAY, Color::White),
+        on(Color::Rgb(129, 131, 132), Color::White),
+    ],
+    current_row: on(DARK_BG, Color::White),
+    empty_row: on(DARK_BG, DARK_GRAY),
+    rejected_row: on(DARK_BG, Color::Rgb(230, 80, 80)),
+    border: fg(DARK_GRAY),
+    title: fg(DARK_YELLOW).add_modifier(Modifier::BOLD),
+    message: fg(DARK_YELLOW),
+    info: fg(Color::Rgb(120, 170, 220)),
+    success: fg(DARK_GREEN),
+    error: fg(Color::Rgb(230, 80, 80)),
+};
+
+const LIGHT_BORDER: Color = Color::Rgb(211, 214, 218);
+
+static LIGHT: Theme = Theme {
+    background: on(Color::White, Color::Black),
+    tiles: [
+        on(Color::Rgb(106, 170, 100), Color::White),
+        on(Color::Rgb(201, 180, 88), Color::White),
+        on(Color::Rgb(120, 124, 126), Color::White),
+        on(Color::White, Color::Black),
+    ],
+    keys: [
+        on(Color::Rgb(106, 170, 100), Color::White),
+        on(Color::Rgb(201, 180, 88), Color::White),
+        on(Color::Rgb(120, 124, 126), Color::White),
+        on(LIGHT_BORDER, Color::Black),
+    ],
+    current_row: on(Color::White, Color::Black),
+    empty_row: on(Color::White, LIGHT_BORDER),
+    rejected_row: on(Color::White, Color::Rgb(200, 30, 30)),
+    border: fg(Color::Rgb(135, 138, 140)),
+    title: fg(Color::Black).add_modifier(Modifier::BOLD),
+    message: fg(Color::Rgb(150, 110, 0)),
+    info: fg(Color::Rgb(30, 90, 170)),
+    success: fg(Color::Rgb(60, 130, 60)),
+    error: fg(Color::Rgb(200, 30, 30)),
+};
+
+// Ethan Schoonover's palette
+const BASE03: Color = Color::Rgb(0, 43, 54);
+const BASE02: Color = Color::Rgb(7, 54, 66);
+const BASE01: Color = Color::Rgb(88, 110, 117);
+const BASE0: Color = Color::Rgb(131, 148, 150);
+const BASE1: Color = Color::Rgb(147, 161, 161);
+const BASE3: Color = Color::Rgb(253, 246, 227);
+const SOLAR_YELLOW: Color = Color::Rgb(181, 137, 0);
+const SOLAR_RED: Color = Color::Rgb(220, 50, 47);
+const SOLAR_CYAN: Color = Color::Rgb(42, 161, 152);
+const SOLAR_GREEN: Color = Color::Rgb(133, 153, 0);
+
+static SOLARIZED: Theme = Theme {
+    background: on(BASE03, BASE0),
+    tiles: [
+        on(SOLAR_GREEN, BASE03),
+        on(SOLAR_YELLOW, BASE03),
+        on(BASE01, BASE3),
+        on(BASE03, BASE1),
+    ],
+    keys: [
+        on(SOLAR_GREEN, BASE03),
+        on(SOLAR_YELLOW, BASE03),
+        on(BASE01, BASE3),
+        on(BASE02, BASE1),
+    ],
+    current_row: on(BASE03, BASE1),
+    empty_row: on(BASE03, BASE01),
+    rejected_row: on(BASE03, SOLAR_RED),
+    border: fg(BASE01),
+    title: fg(SOLAR_YELLOW).add_modifier(Modifier::BOLD),
+    message: fg(SOLAR_YELLOW),
+    info: fg(SOLAR_CYAN),
+    success: fg(SOLAR_GREEN),
+    error: fg(SOLAR_RED),
+};
+
+const SEPIA_PAPER: Color = Color::Rgb(244, 236, 216);
+const SEPIA_INK: Color = Color::Rgb(67, 52, 34);
+const SEPIA_GREEN: Color = Color::Rgb(110, 138, 76);
+const SEPIA_OCHRE: Color = Color::Rgb(196, 150, 66);
+const SEPIA_BROWN: Color = Color::Rgb(140, 118, 92);
+const SEPIA_EDGE: Color = Color::Rgb(214, 200, 170);
+const SEPIA_RED: Color = Color::Rgb(170, 60, 40);
+
+static SEPIA: Theme = Theme {
+    background: on(SEPIA_PAPER, SEPIA_INK),
+    tiles: [
+        on(SEPIA_GREEN, SEPIA_PAPER),
+        on(SEPIA_OCHRE, SEPIA_PAPER),
+        on(SEPIA_BROWN, SEPIA_PAPER),
+        on(SEPIA_PAPER, SEPIA_INK),
+    ],
+    keys: [
+        on(SEPIA_GREEN, SEPIA_PAPER),
+        on(SEPIA_OCHRE, SEPIA_PAPER),
+        on(SEPIA_BROWN, SEPIA_PAPER),
+        on(SEPIA_EDGE, SEPIA_INK),
+    ],
+    current_row: on(SEPIA_PAPER, SEPIA_INK),
+    empty_row: on(SEPIA_PAPER, SEPIA_EDGE),
+    rejected_row: on(SEPIA_PAPER, SEPIA_RED),
+    border: fg(SEPIA_BROWN),
+    title: fg(SEPIA_INK).add_modifier(Modifier::BOLD),
+    message: fg(Color::Rgb(150, 95, 20)),
+    info: fg(Color::Rgb(70, 100, 120)),
+    success: fg(SEPIA_GREEN),
+    error: fg(SEPIA_RED),
+};
+
+const CONTRAST_ORANGE: Color = Color::Rgb(245, 121, 58);
+const CONTRAST_BLUE: Color = Color::Rgb(133, 192, 249);
+const CONTRAST_TILES: [Style; 4] = [
+    on(CONTRAST_ORANGE, Color::Black).add_modifier(Modifier::BOLD),
+    on(CONTRAST_BLUE, Color::Black).add_modifier(Modifier::BOLD),
+    on(Color::Rgb(90, 90, 90), Color::White).add_modifier(Modifier::BOLD),
+    on(Color::Black, Color::White).add_modifier(Modifier::BOLD),
+];
+
+static HIGH_CONTRAST: Theme = Theme {
+    background: on(Color::Black, Color::White),
+    tiles: CONTRAST_TILES,
+    keys: CONTRAST_TILES,
+    current_row: on(Color::Black, Color::White).add_modifier(Modifier::BOLD),
+    empty_row: on(Color::Black, Color::Gray),
+    rejected_row: on(Color::Black, CONTRAST_ORANGE).add_modifier(Modifier::BOLD),
+    border: fg(Color::White),
+    title: fg(Color::White).add_modifier(Modifier::BOLD),
+    message: fg(CONTRAST_BLUE).add_modifier(Modifier::BOLD),
+    info: fg(CONTRAST_BLUE),
+    success: fg(CONTRAST_ORANGE).add_modifier(Modifier::BOLD),
+    error: fg(Color::White)
+        .add_modifier(Modifier::BOLD)
+        .add_modifier(Modifier::UNDERLINED),
+};
@@ -0,0 +1,203 @@
+use ratatui::prelude::*;
+
+use super::theme::Theme;
+use crate::hotseat::Hotseat;
+use crate::i18n::Texts;
+use crate::mode::{Blitz, CatchUp, Mode, Polyglot, Speedrun};
+use crate::net::Pair;
+use crate::records::format_time;
+
+/// Time left before the clock turns red.
+const LOW_SECONDS: u64 = 30;
+
+/// One line with the clock of a timed mode and the score so far, the
+/// score of a versus game with the player at the keyboard marked, who
+/// submits next in a pair and what the partner is typing, the languages of
+/// the polyglot mode with the one in play marked, or the days of a
+/// catch-up with how each went.
+///
+/// While the clock of a timed mode goes, a dot in the right corner pulses
+/// with each second, to keep track of time without reading the digits.
+pub struct TimerWidget<'a> {
+    mode: &'a Mode,
+    texts: &'a Texts,
+    theme: &'a Theme,
+    metronome: bool,
+}
+
+impl<'a> TimerWidget<'a> {
+    pub fn new(mode: &'a Mode, texts: &'a Texts, theme: &'a Theme) -> Self {
+        TimerWidget {
+            mode,
+            texts,
+            theme,
+            metronome: false,
+        }
+    }
+
+    /// Pulses the dot of the metronome with the clock.
+    pub fn metronome(mut self, metronome: bool) -> Self {
+        self.metronome = metronome;
+        self
+    }
+
+    fn blitz_line(&self, blitz: &Blitz) -> Line<'a> {
+        // Rounded up, so the clock reads 0:00 only once time is up
+        let remaining = blitz.remaining();
+        let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+        let clock_style = if seconds <= LOW_SECONDS {
+            self.theme.error
+        } else {
+            self.theme.title
+        };
+        Line::from(vec![
+            Span::raw(format!("{}  ", self.texts.blitz_label)),
+            Span::styled(
+                format!("⏱ {}:{:02}", seconds / 60, seconds % 60),
+                clock_style,
+            ),
+            Span::raw(format!(
+                "  {} {}  ✗ {}",
+                self.texts.blitz_solved, blitz.solved, blitz.missed
+            )),
+        ])
+    }
+
+    fn speedrun_line(&self, run: &Speedrun) -> Line<'a> {
+        let mut spans = vec![
+            Span::raw(format!("{}  ", self.texts.speedrun_label)),
+            Span::styled(
+                format!("⏱ {}", format_time(run.elapsed())),
+                self.theme.title,
+            ),
+        ];
+        if let Some(best) = run.best {
+            spans.push(Span::raw(format!(
+                "  {} {}",
+                self.texts.speedrun_best,
+                format_time(best)
+            )));
+        }
+        if run.new_record {
+            spans.push(Span::styled(
+                format!("  {}", self.texts.speedrun_record),
+                self.theme.success,
+            ));
+        }
+        Line::from(spans)
+    }
+
+    fn hotseat_line(&self, hotseat: &Hotseat) -> Line<'a> {
+        let player = |index: usize| {
+            let label = format!("{} {}", self.texts.hotseat_player, index + 1);
+            if index == hotseat.turn {
+                Span::styled(
+                    format!("▸ {} ◂", label),
+                    self.theme.title.add_modifier(Modifier::REVERSED),
+                )
+            } else {
+                Span::raw(format!("  {}  ", label))
+            }
+        };
+        let mut spans = vec![player(0)];
+        spans.push(Span::styled(
+            format!(" {} – {} ", hotseat.scores[0], hotseat.scores[1]),
+            self.theme.title,
+        ));
+        spans.push(player(1));
+        if hotseat.draws > 0 {
+            spans.push(Span::raw(format!(
+                "  {} {}",
+                self.texts.hotseat_draws, hotseat.draws
+            )));
+        }
+        Line::from(spans)
+    }
+
+    fn pair_line(&self, pair: &Pair) -> Line<'a> {
+        let (turn, style) = if pair.our_turn {
+            (
+                self.texts.pair_your_turn,
+                self.theme.title.add_modifier(Modifier::REVERSED),
+            )
+        } else {
+            (self.texts.pair_partner_turn, self.theme.title)
+        };
+        let mut spans = vec![
+            Span::raw(format!("{}  ", self.texts.pair_label)),
+            Span::styled(format!("▸ {} ◂", turn), style),
+        ];
+        if pair.disconnected {
+            spans.push(Span::styled(
+                format!("  {}", self.texts.pair_left),
+                self.theme.error,
+            ));
+        } else if !pair.typing.is_empty() {
+            spans.push(Span::raw(format!("  {} ", self.texts.pair_partner)));
+            spans.push(Span::styled(
+                pair.typing.iter().collect::<String>(),
+                self.theme.title.add_modifier(Modifier::DIM),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    fn polyglot_line(&self, polyglot: &Polyglot) -> Line<'a> {
+        let mut spans = vec![Span::raw(format!("{} ", self.texts.menu_polyglot))];
+        for (index, language) in polyglot.languages.iter().enumerate() {
+            let code = language.code().to_uppercase();
+            spans.push(if index == polyglot.current {
+                Span::styled(format!(" ▸ {} ◂", code), self.theme.title)
+            } else {
+                Span::raw(format!("   {}  ", code))
+            });
+        }
+        Line::from(spans)
+    }
+
+    fn catch_up_line(&self, catch_up: &CatchUp) -> Line<'a> {
+        let mut spans = vec![Span::raw(format!("{} ", self.texts.catch_up_label))];
+        for (index, day) in catch_up.days.iter().enumerate() {
+            spans.push(match catch_up.results.get(index) {
+                Some((true, guesses)) => {
+                    Span::styled(format!("  #{} ✓{}", day, guesses), self.theme.success)
+                }
+                Some((false, _)) => Span::styled(format!("  #{} ✗", day), self.theme.error),
+                None if index == catch_up.current => {
+                    Span::styled(format!(" ▸ #{} ◂", day), self.theme.title)
+                }
+                None => Span::raw(format!("  #{}", day)),
+            });
+        }
+        Line::from(spans)
+    }
+}
+
+impl<'a> Widget for TimerWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if let Some(beat) = self.mode.beat().filter(|_| self.metronome) {
+            let (dot, style) = if beat {
+                ("●", self.theme.title)
+            } else {
+                ("○", self.theme.border)
+            };
+            buf.set_string(area.right().saturating_sub(2), area.y, dot, style);
+        }
+        let line = match self.mode {
+            Mode::Blitz(blitz) => self.blitz_line(blitz),
+            Mode::Speedrun(run) => self.speedrun_line(run),
+            Mode::Hotseat(hotseat) => self.hotseat_line(hotseat),
+            Mode::Pair(pair) => self.pair_line(pair),
+            Mode::Polyglot(polyglot) => self.polyglot_line(polyglot),
+            Mode::CatchUp(catch_up) => self.catch_up_line(catch_up),
+            // The clock of the countdown is on the board
+            Mode::Classic | Mode::Countdown(_) | Mode::Race(_) => return,
+        };
+        buf.set_line(
+            area.x + area.width.saturating_sub(line.width() as u16) / 2,
+            area.y,
+            &line,
+            area.width,
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear},
+};
+
+use super::menu::achievement_name;
+use crate::achievements::Achievement;
+use crate::app::App;
+
+/// A badge just earned, in a small box in the top right corner of the
+/// area, until it times out.
+pub struct ToastWidget<'a> {
+    app: &'a App,
+    achievement: Achievement,
+}
+
+impl<'a> ToastWidget<'a> {
+    pub fn new(app: &'a App, achievement: Achievement) -> Self {
+        ToastWidget { app, achievement }
+    }
+}
+
+impl<'a> Widget for ToastWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let texts = self.app.texts();
+        let theme = self.app.theme();
+        let (name, _) = achievement_name(self.achievement, texts);
+        let line = Line::styled(format!("★ {}", name), theme.success);
+        let width = (line.width().max(texts.achievement_unlocked.chars().count()) as u16 + 4)
+            .min(area.width);
+        let rect = Rect::new(area.right() - width, area.y, width, 3.min(area.height));
+
+        Clear.render(rect, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border)
+            .style(theme.background)
+            .title(texts.achievement_unlocked);
+        let inner = block.inner(rect);
+        block.render(rect, buf);
+        line.alignment(Alignment::Center).render(inner, buf);
+    }
+}
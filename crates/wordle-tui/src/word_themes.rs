@@ -0,0 +1,155 @@
+//! Themed word lists: animals, food, the jargon of the 42 schools, or any
+//! list of one's own, played instead of the whole word list.
+//!
+//! A theme is a word list in a `themes/` directory of any of the places
+//! word packs are looked up (see [`crate::paths`]), one word per line with
+//! `#` for comments, and a TOML file of the same name next to it with its
+//! metadata:
+//!
+//! ```toml
+//! name = "Animals"
+//! language = "en"
+//!
+//! [names]
+//! pt = "Animais"
+//! ```
+//!
+//! The name is shown in the title bar, in the language of the interface if
+//! `names` has it. A theme with a `language` is only offered in that
+//! language; without one, in all of them. Without metadata the theme is
+//! named after its file. Themes found earlier in the search order hide
+//! those of the same name further down, and the built-in ones last.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+use crate::game::{Game, WORD_LENGTH};
+use crate::i18n::Language;
+use crate::paths;
+
+/// Themes built into the binary: name, word list and metadata.
+const BUILT_IN: [(&str, &str, &str); 3] = [
+    (
+        "42",
+        include_str!("../../../data/themes/42.txt"),
+        include_str!("../../../data/themes/42.toml"),
+    ),
+    (
+        "animals",
+        include_str!("../../../data/themes/animals.txt"),
+        include_str!("../../../data/themes/animals.toml"),
+    ),
+    (
+        "food",
+        include_str!("../../../data/themes/food.txt"),
+        include_str!("../../../data/themes/food.toml"),
+    ),
+];
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Metadata {
+    name: Option<String>,
+    names: HashMap<String, String>,
+    language: Option<String>,
+}
+
+/// A themed word list and what its metadata says of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordTheme {
+    /// Name of its files, as written in `word_theme` in the config.
+    pub id: String,
+    name: String,
+    names: HashMap<String, String>,
+    /// Language of its words, `None` when they suit any.
+    pub language: Option<Language>,
+    /// The words, as spelled in the list.
+    pub words: Vec<String>,
+}
+
+impl WordTheme {
+    /// Reads the theme `id` from its word list and metadata. A theme
+    /// needs words, and a language the game knows if it names one.
+    pub fn parse(id: &str, words: &str, metadata: &str) -> anyhow::Result<WordTheme> {
+        let metadata: Metadata = toml::from_str(metadata).context("reading the metadata")?;
+        let language = match metadata.language {
+            Some(code) => Some(
+                Language::from_code(&code)
+                    .with_context(|| format!("unknown language '{}'", code))?,
+            ),
+            None => None,
+        };
+        let words = Game::parse_words(
+            &words
+                .lines()
+                .filter(|line| !line.trim_start().starts_with('#'))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            WORD_LENGTH,
+        );
+        anyhow::ensure!(!words.is_empty(), "no {} letter words", WORD_LENGTH);
+        Ok(WordTheme {
+            id: id.to_string(),
+            name: metadata.name.unwrap_or_else(|| id.to_string()),
+            names: metadata.names,
+            language,
+            words,
+        })
+    }
+
+    /// Reads the theme whose word list is at `path`, with the metadata
+    /// next to it if there is some.
+    pub fn load(path: &Path) -> anyhow::Result<WordTheme> {
+        let id = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .context("no theme name")?;
+        let words =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let metadata = fs::read_to_string(path.with_extension("toml")).unwrap_or_default();
+        Self::parse(id, &words, &metadata).with_context(|| format!("in {}", path.display()))
+    }
+
+    /// Its name in `language`, or the one of the metadata.
+    pub fn name(&self, language: Language) -> &str {
+        self.names
+            .get(language.code())
+            .map_or(&self.name, |name| name)
+    }
+
+    /// Whether it can be played in `language`.
+    pub fn fits(&self, language: Language) -> bool {
+        self.language.is_none_or(|own| own == language)
+    }
+}
+
+/// Every theme that can be played, by name, from the files found and the
+/// built-in ones. Themes that can't be read are left out.
+pub fn available() -> Vec<WordTheme> {
+    let mut themes = BTreeMap::new();
+    for dir in paths::asset_dirs() {
+        let Ok(entries) = fs::read_dir(dir.join("themes")) else {
+            continue;
+        };
+        let mut lists: Vec<_> = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+            .collect();
+        lists.sort();
+        for path in lists {
+            if let Ok(theme) = WordTheme::load(&path) {
+                themes.entry(theme.id.clone()).or_insert(theme);
+            }
+        }
+    }
+    for (id, words, metadata) in BUILT_IN {
+        if let Ok(theme) = WordTheme::parse(id, words, metadata) {
+            themes.entry(theme.id.clone()).or_insert(theme);
+        }
+    }
+    themes.into_values().collect()
+}
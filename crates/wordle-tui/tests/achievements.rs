@@ -0,0 +1,127 @@
+//! Badges: which games earn them, the toast when one is earned, the screen
+//! listing them and their keeping across sessions.
+
+mod common;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::{press, type_word};
+use wordle::achievements::Achievement;
+use wordle::app::{App, MenuItem, View};
+use wordle::GameStatus;
+
+fn open(app: &mut App, item: MenuItem) {
+    let index = app.menu_items().iter().position(|&i| i == item).unwrap();
+    app.view = View::Menu(index);
+    press(app, KeyCode::Enter);
+}
+
+fn screen(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..40)
+        .map(|y| {
+            (0..100)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn wins_earn_badges_kept_across_sessions() {
+    common::data_home("achievements");
+    let config = common::english();
+    let mut app = App::new(&config);
+    assert!(Achievement::ALL
+        .iter()
+        .all(|&a| app.achievements.unlocked(a).is_none()));
+
+    // A loss earns nothing
+    app.play_seed(3);
+    for _ in 0..6 {
+        type_word(&mut app, "XXXXX");
+    }
+    assert_eq!(app.game.status, GameStatus::Lost);
+    assert!(app.toasts.is_empty());
+
+    app.play_seed(4);
+    let word = app.game.target_word.clone();
+    type_word(&mut app, "XXXXX");
+    type_word(&mut app, &word);
+    assert_eq!(app.game.status, GameStatus::Won);
+    assert_eq!(
+        Vec::from(app.toasts.clone()),
+        vec![
+            Achievement::FirstWin,
+            Achievement::InTwo,
+            Achievement::NoHints
+        ]
+    );
+    assert_eq!(app.achievements.streak(), 1);
+    let shown = screen(&app);
+    assert!(shown.contains("Achievement unlocked"));
+    assert!(shown.contains("★ First win"));
+
+    // Toasts go one after the other, each for a while
+    app.on_tick();
+    assert_eq!(app.toasts.len(), 3);
+
+    open(&mut app, MenuItem::Mastermind);
+    let word = app.game.target_word.clone();
+    type_word(&mut app, "XXXXX");
+    type_word(&mut app, "XXXXX");
+    type_word(&mut app, &word);
+    assert_eq!(app.game.status, GameStatus::Won);
+    assert_eq!(app.toasts.back(), Some(&Achievement::HardMode));
+    assert_eq!(app.achievements.streak(), 2);
+
+    // Three guesses in a row each finding a new green, shown as they come
+    app.play_seed(5);
+    let word = app.game.target_word.clone();
+    let filler = ['Z', 'Q', 'X', 'J', 'K']
+        .into_iter()
+        .find(|&c| !word.contains(c))
+        .unwrap();
+    let known = |letters: usize| -> String {
+        word.chars()
+            .take(letters)
+            .chain(std::iter::repeat(filler))
+            .take(5)
+            .collect()
+    };
+    type_word(&mut app, &known(1));
+    assert!(!screen(&app).contains("Momentum"));
+    type_word(&mut app, &known(2));
+    assert!(screen(&app).contains("Momentum ×2"));
+    type_word(&mut app, &word);
+    assert_eq!(app.toasts.back(), Some(&Achievement::Momentum));
+
+    let restarted = App::new(&config);
+    assert!(restarted
+        .achievements
+        .unlocked(Achievement::FirstWin)
+        .is_some());
+    assert!(restarted
+        .achievements
+        .unlocked(Achievement::HardMode)
+        .is_some());
+    assert!(restarted
+        .achievements
+        .unlocked(Achievement::Streak)
+        .is_none());
+    assert_eq!(restarted.achievements.streak(), 3);
+
+    let mut app = restarted;
+    open(&mut app, MenuItem::Achievements);
+    assert_eq!(app.view, View::Achievements);
+    let shown = screen(&app);
+    assert!(shown.contains("★ Sharp shooter"));
+    assert!(shown.contains("☆ On a roll: Win 10 games in a row"));
+    assert!(shown.contains("Earned: 5/6"));
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.view, View::Menu(0));
+}
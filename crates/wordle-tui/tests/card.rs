@@ -0,0 +1,239 @@
+//! The result card: its text, its image, and the PNG and Sixel encodings
+//! of the image.
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+
+use std::time::Duration;
+use wordle::app::{App, EndAction, Screen};
+
+use wordle::card::{Card, Palette};
+use wordle::config::{Config, ShareConfig, ShareGlyphs};
+use wordle::raster::Image;
+use wordle::Game;
+
+fn finished(guesses: &[&str]) -> Game {
+    let mut game = Game::with_word("CRANE");
+    for guess in guesses {
+        game.guess(guess).unwrap();
+    }
+    game
+}
+
+// The rows of pixels of a PNG written by `Image::to_png`, read back from
+// its stored deflate blocks
+fn png_rows(png: &[u8]) -> (u32, u32, Vec<Vec<u8>>) {
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    let mut at = 8;
+    let (mut width, mut height, mut data) = (0, 0, Vec::new());
+    while at < png.len() {
+        let length = u32::from_be_bytes(png[at..at + 4].try_into().unwrap()) as usize;
+        let kind = &png[at + 4..at + 8];
+        let body = &png[at + 8..at + 8 + length];
+        match kind {
+            b"IHDR" => {
+                width = u32::from_be_bytes(body[0..4].try_into().unwrap());
+                height = u32::from_be_bytes(body[4..8].try_into().unwrap());
+                assert_eq!(&body[8..], &[8, 2, 0, 0, 0]);
+            }
+            b"IDAT" => data.extend(body),
+            _ => {}
+        }
+        at += 12 + length;
+    }
+    // The CRC of an empty IEND chunk is well known
+    assert_eq!(&png[png.len() - 4..], &[0xae, 0x42, 0x60, 0x82]);
+
+    let mut raw = Vec::new();
+    let mut at = 2;
+    loop {
+        let last = data[at] & 1 == 1;
+        let length = u16::from_le_bytes([data[at + 1], data[at + 2]]) as usize;
+        raw.extend(&data[at + 5..at + 5 + length]);
+        at += 5 + length;
+        if last {
+            break;
+        }
+    }
+    let rows = raw
+        .chunks(1 + width as usize * 3)
+        .map(|row| {
+            assert_eq!(row[0], 0);
+            row[1..].to_vec()
+        })
+        .collect();
+    (width, height, rows)
+}
+
+#[test]
+fn the_card_text_has_a_square_per_letter() {
+    let card = Card::new(
+        "WORDLE #20391".to_string(),
+        &finished(&["slate", "crane"]),
+        3,
+    );
+    assert_eq!(card.result, "2/6");
+    assert_eq!(
+        card.lines(false),
+        vec![
+            "WORDLE #20391 2/6",
+            "",
+            "⬛⬛🟩⬛🟩",
+            "🟩🟩🟩🟩🟩",
+            "",
+            "STREAK 3",
+        ]
+    );
+    let lost = Card::new(
+        "WORDLE".to_string(),
+        &finished(&["slate", "slate", "slate", "slate", "slate", "slate"]),
+        0,
+    );
+    assert_eq!(lost.result, "X/6");
+    assert_eq!(lost.lines(true)[2], "⬛⬛🟧⬛🟧");
+}
+
+#[test]
+fn the_card_text_follows_the_share_config() {
+    let mut game = finished(&["slate"]);
+    game.hint(&["CRANE".to_string()]);
+    game.guess("crane").unwrap();
+    let card = Card::new("WORDLE".to_string(), &game, 1).timed(Some(Duration::from_secs(83)));
+
+    let ascii = ShareConfig {
+        glyphs: ShareGlyphs::Ascii,
+        time: true,
+        ..ShareConfig::default()
+    };
+    assert_eq!(
+        card.text(&ascii, true),
+        vec!["WORDLE 2/6* 1:23", "", "..#.#", "#####", "", "STREAK 1"]
+    );
+
+    // Missing custom symbols stay emoji, and markers can be left out
+    let config: Config = toml::from_str(
+        "[share]\nglyphs = \"custom\"\ncorrect = \"O\"\nabsent = \"_\"\nmarkers = false\n",
+    )
+    .unwrap();
+    assert_eq!(
+        card.text(&config.share, false),
+        vec!["WORDLE 2/6", "", "__O_O", "OOOOO", "", "STREAK 1"]
+    );
+    assert_eq!(card.lines(false)[0], "WORDLE 2/6*");
+}
+
+#[test]
+fn copies_go_through_the_terminal() {
+    assert_eq!(
+        wordle::term::copy_sequence("WORDLE 1/6"),
+        "\x1b]52;c;V09SRExFIDEvNg==\x07"
+    );
+}
+
+#[test]
+fn the_image_survives_the_png_encoding() {
+    let card = Card::new("WORDLE".to_string(), &finished(&["crane"]), 1);
+    let image = card.render(&Palette::CLASSIC);
+    let (width, height, rows) = png_rows(&image.to_png());
+    assert_eq!(
+        (width as usize, height as usize),
+        (image.width, image.height)
+    );
+    for (y, row) in rows.iter().enumerate() {
+        for x in 0..image.width {
+            assert_eq!(&row[x * 3..x * 3 + 3], &image.pixel(x, y));
+        }
+    }
+    // The winning row is there, in green
+    let greens = (0..image.height)
+        .flat_map(|y| (0..image.width).map(move |x| (x, y)))
+        .filter(|&(x, y)| image.pixel(x, y) == Palette::CLASSIC.correct)
+        .count();
+    assert_eq!(greens, 5 * 40 * 40);
+}
+
+#[test]
+fn large_images_span_several_deflate_blocks() {
+    let image = Image::new(300, 100, [1, 2, 3]);
+    let (_, height, rows) = png_rows(&image.to_png());
+    assert_eq!(height, 100);
+    assert!(rows
+        .iter()
+        .flatten()
+        .eq([1, 2, 3].iter().cycle().take(300 * 3 * 100)));
+}
+
+#[test]
+fn sixels_have_a_register_per_color() {
+    let mut image = Image::new(8, 7, [0, 0, 0]);
+    image.fill_rect(0, 0, 8, 6, [255, 0, 0]);
+    let sixel = image.to_sixel();
+    assert!(sixel.starts_with("\x1bPq\"1;1;8;7#0;2;100;0;0#1;2;0;0;0"));
+    assert!(sixel.ends_with("\x1b\\"));
+    // Registers go by first appearance. The first band is all red, as a
+    // repeat of the full sixel; the last row is black, the first bit of the
+    // second band
+    assert!(sixel.contains("#0!8~$-"));
+    assert!(sixel.ends_with("-#1!8@$-\x1b\\"));
+}
+
+#[test]
+fn s_shares_a_finished_game() {
+    std::env::set_var(
+        "XDG_DATA_HOME",
+        std::env::temp_dir().join(format!("wordle-card-{}", std::process::id())),
+    );
+    let config = Config {
+        language: Some("en".to_string()),
+        ..Config::default()
+    };
+    let mut app = App::new(&config);
+    let press = |app: &mut App, code| {
+        app.handle_event(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+    };
+    press(&mut app, KeyCode::Enter);
+    app.game = Game::from_seed(app.dictionary.spellings(), 42);
+    let answer = app.game.target_word.to_lowercase();
+    for c in answer.chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('s'));
+
+    let Screen::Card(shared) = &app.screen else {
+        panic!("no card");
+    };
+    let path = shared.saved.clone().expect("the card wasn't saved");
+    assert!(path.ends_with("cards/wordle-16.png"));
+    assert!(std::fs::read(&path).unwrap().starts_with(b"\x89PNG"));
+    // Without Sixel graphics the card is shown as text
+    assert!(shared.sixel.is_none());
+
+    let mut terminal = Terminal::new(TestBackend::new(90, 40)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, &app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let screen: Vec<String> = (0..40)
+        .map(|y| (0..90).map(|x| buffer.get(x, y).symbol()).collect())
+        .collect();
+    assert!(screen.iter().any(|line| line.contains("Result card")));
+    assert!(screen.iter().any(|line| line.contains("WORDLE 16 1/6")));
+    assert!(screen.iter().any(|line| line.contains("Saved to")));
+    assert!(screen.iter().any(|line| line.contains("[C] Copy")));
+
+    press(&mut app, KeyCode::Char('c'));
+    assert_eq!(
+        app.clipboard.as_deref(),
+        Some("WORDLE 16 1/6\n\n🟩🟩🟩🟩🟩\n\nSTREAK 1")
+    );
+    let Screen::Card(shared) = &app.screen else {
+        panic!("no card");
+    };
+    assert!(shared.copied);
+
+    // Back to the menu of the finished game, on sharing
+    press(&mut app, KeyCode::Esc);
+    assert!(matches!(
+        app.screen,
+        Screen::Finished(selected) if EndAction::ALL[selected] == EndAction::Share
+    ));
+}
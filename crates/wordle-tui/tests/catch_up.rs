@@ -0,0 +1,115 @@
+//! Catching up with the words of the day missed: which days are missed,
+//! and playing them back to back as archive games with a summary at the
+//! end.
+
+mod common;
+
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::{press, type_word};
+use wordle::app::{App, MenuItem};
+use wordle::history::{Entry, Source, Start};
+use wordle::mode::Mode;
+use wordle::GameStatus;
+
+fn today() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        / 86_400
+}
+
+fn daily(id: u64, day: u64, language: &str) -> String {
+    let start = Entry::Start(Start {
+        id,
+        at: day * 86_400,
+        source: Source::Daily,
+        language: language.to_string(),
+        word: "CRANE".to_string(),
+        seed: Some(day),
+        words: 10,
+        checksum: 7,
+    });
+    serde_json::to_string(&start).unwrap() + "\n"
+}
+
+fn screen(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..40)
+        .map(|y| {
+            (0..100)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn missed_days_are_played_back_to_back() {
+    let dir = common::data_home("catch-up");
+    fs::create_dir_all(dir.join("wordle")).unwrap();
+    let today = today();
+    // Days before the first word of the day played, and those played in
+    // another language, don't count
+    let history =
+        daily(1, today - 5, "en") + &daily(2, today - 2, "en") + &daily(3, today - 1, "pt");
+    fs::write(dir.join("wordle/history.jsonl"), history).unwrap();
+
+    let config = common::english();
+    let mut app = App::new(&config);
+    let missed = vec![today - 4, today - 3, today - 1];
+    assert_eq!(app.missed_dailies, missed);
+    let items = app.menu_items();
+    let daily = items
+        .iter()
+        .position(|&item| item == MenuItem::Daily)
+        .unwrap();
+    assert_eq!(items[daily + 1], MenuItem::CatchUp);
+
+    app.start_catch_up();
+    assert_eq!(app.source, Source::Archive);
+    assert_eq!(app.game.seed, Some(today - 4));
+    let word = app.game.target_word.clone();
+    type_word(&mut app, &word);
+    assert_eq!(app.game.status, GameStatus::Won);
+    press(&mut app, KeyCode::Esc);
+
+    assert_eq!(app.game.seed, Some(today - 3));
+    for _ in 0..6 {
+        type_word(&mut app, "XXXXX");
+    }
+    assert_eq!(app.game.status, GameStatus::Lost);
+    press(&mut app, KeyCode::Esc);
+
+    assert_eq!(app.game.seed, Some(today - 1));
+    assert_eq!(app.source, Source::Archive);
+    let shown = screen(&app);
+    assert!(shown.contains(&format!("#{} ✓1", today - 4)));
+    assert!(shown.contains(&format!("▸ #{} ◂", today - 1)));
+    let word = app.game.target_word.clone();
+    type_word(&mut app, "XXXXX");
+    type_word(&mut app, &word);
+    assert_eq!(app.game.status, GameStatus::Won);
+    let shown = screen(&app);
+    assert!(shown.contains("Caught up! Found 2/3, 3 guesses in all"));
+    assert!(shown.contains(&format!("#{} ✗", today - 3)));
+    let Mode::CatchUp(catch_up) = &app.mode else {
+        panic!("not a catch-up");
+    };
+    assert_eq!(catch_up.results, vec![(true, 1), (false, 6), (true, 2)]);
+    assert!(app.missed_dailies.is_empty());
+
+    // Past the summary, a plain game
+    press(&mut app, KeyCode::Esc);
+    assert!(matches!(app.mode, Mode::Classic));
+    assert_eq!(app.source, Source::Random);
+    assert!(App::new(&config).missed_dailies.is_empty());
+}
@@ -0,0 +1,103 @@
+//! Challenge codes: a word and its language in eight letters and digits,
+//! given on the command line or pasted in the guest challenge.
+
+use crossterm::event::Event;
+
+use wordle::app::{App, View};
+use wordle::challenge::ChallengeCode;
+use wordle::cli::{Args, Command};
+use wordle::config::Config;
+use wordle::history::Source;
+use wordle::i18n::Language;
+
+fn args(line: &str) -> anyhow::Result<Args> {
+    Args::parse_from(line.split_whitespace().map(str::to_string))
+}
+
+#[test]
+fn a_code_gives_back_its_word_and_language() {
+    let code = ChallengeCode::new("avião", Language::Pt).unwrap();
+    assert_eq!(code.word(), "AVIAO");
+    for salt in 0..64 {
+        let written = code.encode_with(salt);
+        assert_eq!(written.len(), 9);
+        assert_eq!(&written[4..5], "-");
+        assert!(!written.contains("AVIAO"));
+        assert_eq!(ChallengeCode::decode(&written).unwrap(), code);
+    }
+    // Salts change the code, not the word
+    assert_ne!(code.encode_with(1), code.encode_with(2));
+    assert_eq!(ChallengeCode::decode(&code.encode()).unwrap(), code);
+
+    assert!(ChallengeCode::new("cat", Language::En).is_err());
+    assert!(ChallengeCode::new("cr4ne", Language::En).is_err());
+}
+
+#[test]
+fn codes_are_read_the_way_people_paste_them() {
+    let code = ChallengeCode::new("CRANE", Language::En).unwrap();
+    let written = code.encode_with(7);
+    for pasted in [
+        written.to_lowercase(),
+        written.replace('-', ""),
+        format!("  {} \n", written),
+        written.replace('0', "O").replace('1', "l"),
+    ] {
+        assert_eq!(ChallengeCode::decode(&pasted).unwrap(), code, "{}", pasted);
+    }
+
+    assert!(ChallengeCode::decode("CRANE").is_err());
+    assert!(ChallengeCode::decode(&format!("{}0", written)).is_err());
+    assert!(ChallengeCode::decode("ABCD-EFG!").is_err());
+    // A mistyped digit is caught by the check
+    let mistyped: usize = (0..64)
+        .map(|salt| code.encode_with(salt))
+        .filter(|written| {
+            let last = if written.ends_with('Z') { "Y" } else { "Z" };
+            let mistyped = format!("{}{}", &written[..8], last);
+            ChallengeCode::decode(&mistyped).is_ok_and(|other| other == code)
+        })
+        .count();
+    assert_eq!(mistyped, 0);
+}
+
+#[test]
+fn codes_are_made_and_played_from_the_command_line() {
+    assert_eq!(
+        args("challenge code crane --any-word").unwrap().command,
+        Some(Command::ChallengeCode {
+            word: "crane".to_string(),
+            any_word: true,
+        })
+    );
+    assert!(args("challenge code").is_err());
+
+    let code = ChallengeCode::new("CRANE", Language::En).unwrap();
+    let parsed = args(&format!("--challenge {}", code.encode())).unwrap();
+    assert_eq!(parsed.code, Some(code));
+    assert!(args("--challenge nonsense").is_err());
+    assert!(args(&format!("--challenge {} --word crane", code.encode())).is_err());
+}
+
+#[test]
+fn a_pasted_code_starts_the_guest_challenge_in_its_language() {
+    std::env::set_var(
+        "XDG_DATA_HOME",
+        std::env::temp_dir().join(format!("wordle-challenge-code-{}", std::process::id())),
+    );
+    let config = Config {
+        language: Some("en".to_string()),
+        ..Config::default()
+    };
+    let mut app = App::new(&config);
+    app.view = View::Challenge;
+    let code = ChallengeCode::new("AVIAO", Language::Pt).unwrap();
+    app.handle_event(Event::Paste(code.encode()));
+
+    assert_eq!(app.view, View::Game);
+    assert_eq!(app.language, Language::Pt);
+    assert_eq!(app.source, Source::Challenge);
+    assert_eq!(app.game.target_word, "AVIAO");
+    // Spelled as in the word list
+    assert_eq!(app.game.display_word, "AVIÃO");
+}
@@ -0,0 +1,177 @@
+//! Challenges sent as files: the subcommands, the hidden word, and a
+//! challenge played through to its reply.
+
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::type_word;
+use wordle::app::App;
+use wordle::challenge::{ChallengeFile, Playing, Replies, Reply};
+use wordle::cli::{Args, Command, PlayChallenge};
+use wordle::i18n::Language;
+use wordle::{FeedbackStyle, GameStatus};
+
+// A directory of its own for the files of `test`, which is also where the
+// data directory goes
+fn dir(test: &str) -> PathBuf {
+    let dir = common::data_home("challenge").join(test);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn shows(app: &App, text: &str) -> bool {
+    let (width, height) = (120, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height).any(|y| {
+        (0..width)
+            .map(|x| buffer.get(x, y).symbol())
+            .collect::<String>()
+            .contains(text)
+    })
+}
+
+#[test]
+fn challenge_is_parsed() {
+    let args = |line: &str| Args::parse_from(line.split_whitespace().map(str::to_string));
+    assert_eq!(
+        args("challenge create crane --out c.json --name ana --attempts 4 --mastermind")
+            .unwrap()
+            .command,
+        Some(Command::CreateChallenge {
+            word: Some("crane".to_string()),
+            out: Some(PathBuf::from("c.json")),
+            name: Some("ana".to_string()),
+            attempts: 4,
+            mastermind: true,
+        })
+    );
+    assert!(matches!(
+        args("challenge create").unwrap().command,
+        Some(Command::CreateChallenge {
+            word: None,
+            attempts: 6,
+            ..
+        })
+    ));
+    assert_eq!(
+        args("challenge play c.json --name bia").unwrap().challenge,
+        Some(PlayChallenge {
+            file: PathBuf::from("c.json"),
+            name: Some("bia".to_string()),
+        })
+    );
+    assert_eq!(
+        args("challenge import a.json b.json").unwrap().command,
+        Some(Command::ImportReplies(vec![
+            PathBuf::from("a.json"),
+            PathBuf::from("b.json")
+        ]))
+    );
+
+    assert!(args("challenge").is_err());
+    assert!(args("challenge play").is_err());
+    assert!(args("challenge import").is_err());
+    assert!(args("challenge create --attempts 0").is_err());
+    assert!(args("challenge play c.json --mastermind").is_err());
+}
+
+#[test]
+fn the_word_is_hidden_and_comes_back() {
+    let dir = dir("hidden");
+    let challenge = ChallengeFile::new("AVIÃO", "ana", Language::Pt, 6, false);
+    let path = dir.join("challenge.json");
+    challenge.save(&path).unwrap();
+
+    let text = fs::read_to_string(&path).unwrap();
+    assert!(!text.to_uppercase().contains("AVI"), "{}", text);
+    let loaded = ChallengeFile::load(&path).unwrap();
+    assert_eq!(loaded, challenge);
+    assert_eq!(loaded.word().unwrap(), "AVIÃO");
+    assert_eq!(loaded.config().max_attempts, 6);
+
+    // The same word hides differently in every challenge
+    let hidden =
+        |challenge: &ChallengeFile| serde_json::to_value(challenge).unwrap()["word"].clone();
+    let again = ChallengeFile::new("AVIÃO", "ana", Language::Pt, 6, false);
+    assert_ne!(hidden(&again), hidden(&challenge));
+
+    // Nothing but a five-letter word is played
+    let damaged = text.replace(&challenge.id, "0000000000000000");
+    fs::write(&path, damaged).unwrap();
+    assert!(Playing::open(path.clone(), "bia".to_string()).is_err());
+    fs::write(&path, text.replace("\"attempts\": 6", "\"attempts\": 0")).unwrap();
+    assert!(ChallengeFile::load(&path).is_err());
+    fs::write(&path, "{}").unwrap();
+    assert!(ChallengeFile::load(&path).is_err());
+
+    // An id too short to name the replies, or not hexadecimal, is refused
+    // before anything slices it
+    for id in ["abc", "ééééééééé", "../../../etc/xx"] {
+        fs::write(&path, text.replace(&challenge.id, id)).unwrap();
+        let error = ChallengeFile::load(&path).unwrap_err().to_string();
+        assert!(error.contains("damaged"), "{}", error);
+    }
+}
+
+#[test]
+fn a_challenge_is_played_and_answered() {
+    let dir = dir("played");
+    let path = dir.join("challenge.json");
+    ChallengeFile::new("CRANE", "ana", Language::En, 4, true)
+        .save(&path)
+        .unwrap();
+    let playing = Playing::open(path, "bia".to_string()).unwrap();
+    let reply_path = playing.reply_path();
+    let id = playing.challenge.id.clone();
+
+    let config = common::english();
+    let mut app = App::new(&config);
+    app.play_sent(playing);
+    assert!(shows(&app, "Challenge from ana"));
+    // The rules come with the challenge
+    assert_eq!(app.game.config.max_attempts, 4);
+    assert_eq!(app.game.config.feedback, FeedbackStyle::Counts);
+
+    type_word(&mut app, "slate");
+    type_word(&mut app, "crane");
+    assert_eq!(app.game.status, GameStatus::Won);
+    assert!(shows(&app, "Reply for ana"));
+
+    let reply = Reply::load(&reply_path).unwrap();
+    assert_eq!(reply.id, id);
+    assert_eq!(reply.player, "bia");
+    assert!(reply.won);
+    assert_eq!(reply.rows, vec!["AACAC", "CCCCC"]);
+    assert_eq!(reply.summary(), "2/4 ⬛⬛🟩⬛🟩 🟩🟩🟩🟩🟩");
+
+    // Kept here too, and ranked with the others
+    let mut replies = Replies::load();
+    assert!(replies.ranking(&id).contains(&&reply));
+    assert!(!replies.record(reply.clone()).unwrap());
+    let lost = Reply {
+        player: "caio".to_string(),
+        won: false,
+        guesses: 4,
+        ..reply.clone()
+    };
+    let faster = Reply {
+        player: "davi".to_string(),
+        guesses: 1,
+        at: reply.at + 60,
+        ..reply.clone()
+    };
+    assert!(replies.record(lost).unwrap());
+    assert!(replies.record(faster).unwrap());
+    let ranking: Vec<&str> = replies
+        .ranking(&id)
+        .iter()
+        .map(|reply| reply.player.as_str())
+        .collect();
+    assert_eq!(ranking, vec!["davi", "bia", "caio"]);
+}
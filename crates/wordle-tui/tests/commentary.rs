@@ -0,0 +1,38 @@
+//! Commentary of a finished game, from its analysis.
+
+use std::sync::Arc;
+
+use wordle::commentary;
+use wordle::i18n::Language;
+
+fn words() -> Arc<[String]> {
+    ["CRANE", "SLATE", "PLATE", "CRATE", "GRATE", "TRACE"]
+        .iter()
+        .map(|word| word.to_string())
+        .collect()
+}
+
+fn narrate(answer: &str, guesses: &[&str]) -> String {
+    let guesses: Vec<String> = guesses.iter().map(|guess| guess.to_string()).collect();
+    let report = commentary::replay(&words(), answer, &guesses);
+    commentary::narrate(&report, answer, Language::En.texts())
+}
+
+#[test]
+fn a_game_is_told_guess_by_guess() {
+    let told = narrate("GRATE", &["SLATE", "SLATE", "CRATE", "GRATE"]);
+    assert_eq!(
+        told,
+        "Opened with SLATE, which left 2 words. Guess 2, SLATE, told nothing new. \
+         Guess 3, CRATE, the solver's own pick: 2 words down to 1. Found it on guess 4."
+    );
+}
+
+#[test]
+fn lucky_wins_and_losses_are_told() {
+    assert_eq!(
+        narrate("TRACE", &["TRACE"]),
+        "Found it on guess 1, a lucky pick among 6 words."
+    );
+    assert!(narrate("TRACE", &["SLATE", "PLATE"]).ends_with("Out of guesses: the word was TRACE."));
+}
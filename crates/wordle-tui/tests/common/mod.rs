@@ -0,0 +1,62 @@
+//! Helpers shared by the tests that play an [`App`] through its keys.
+
+// Each test file builds its own copy and uses only part of it
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+use wordle::app::{App, View};
+use wordle::config::Config;
+
+/// Points the data directory (history, autosave, stats) at one for the
+/// tests of `name`, so they never touch the player's own.
+pub fn data_home(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wordle-{}-{}", name, std::process::id()));
+    std::env::set_var("XDG_DATA_HOME", &dir);
+    dir
+}
+
+/// The default config, in English.
+pub fn english() -> Config {
+    Config {
+        language: Some("en".to_string()),
+        ..Config::default()
+    }
+}
+
+/// An app on `config`, with its data kept under [`data_home`].
+pub fn app_with(name: &str, config: &Config) -> App {
+    data_home(name);
+    App::new(config)
+}
+
+/// An app on the [`english`] config, with its data kept under [`data_home`].
+pub fn app(name: &str) -> App {
+    app_with(name, &english())
+}
+
+/// Like [`app`], in a new game rather than on the menu.
+pub fn in_game(name: &str) -> App {
+    let mut app = app(name);
+    app.new_game();
+    app.view = View::Game;
+    app
+}
+
+pub fn press(app: &mut App, code: KeyCode) {
+    press_with(app, code, KeyModifiers::NONE);
+}
+
+pub fn press_with(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+    app.handle_event(Event::Key(KeyEvent::new(code, modifiers)));
+}
+
+/// Types `word` and plays it.
+pub fn type_word(app: &mut App, word: &str) {
+    for c in word.to_lowercase().chars() {
+        press(app, KeyCode::Char(c));
+    }
+    press(app, KeyCode::Enter);
+}
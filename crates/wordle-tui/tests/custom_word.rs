@@ -0,0 +1,65 @@
+//! A word set on the command line for a friend to find: what `--word`
+//! takes, and the guest challenge it starts.
+
+use wordle::app::App;
+use wordle::cli::{challenge_word, Args};
+use wordle::config::Config;
+use wordle::history::Source;
+use wordle::i18n::Language;
+use wordle::GameStatus;
+
+fn args(line: &str) -> anyhow::Result<Args> {
+    Args::parse_from(line.split_whitespace().map(str::to_string))
+}
+
+#[test]
+fn the_word_needs_five_letters() {
+    let parsed = args("--word crane").unwrap();
+    assert_eq!(parsed.word.as_deref(), Some("crane"));
+    assert!(!parsed.any_word);
+    assert!(args("--word=avião").is_ok());
+    assert!(args("--word crane --any-word").unwrap().any_word);
+
+    assert!(args("--word cranes").is_err());
+    assert!(args("--word cat").is_err());
+    assert!(args("--word cr4ne").is_err());
+    assert!(args("--word").is_err());
+    assert!(args("--any-word").is_err());
+}
+
+#[test]
+fn the_word_is_checked_against_the_list() {
+    assert_eq!(
+        challenge_word("Crane", Language::En, false).unwrap(),
+        "CRANE"
+    );
+    // Spelled as in the list, accents and all
+    assert_eq!(
+        challenge_word("aviao", Language::Pt, false).unwrap(),
+        "AVIÃO"
+    );
+
+    let refused = challenge_word("qzxjv", Language::En, false).unwrap_err();
+    assert!(refused.to_string().contains("--any-word"));
+    assert_eq!(
+        challenge_word("qzxjv", Language::En, true).unwrap(),
+        "QZXJV"
+    );
+}
+
+#[test]
+fn the_guest_plays_the_word() {
+    std::env::set_var(
+        "XDG_DATA_HOME",
+        std::env::temp_dir().join(format!("wordle-custom-word-{}", std::process::id())),
+    );
+    let config = Config {
+        language: Some("en".to_string()),
+        ..Config::default()
+    };
+    let mut app = App::new(&config);
+    app.play_challenge("QZXJV");
+    assert_eq!(app.game.target_word, "QZXJV");
+    assert_eq!(app.source, Source::Challenge);
+    assert_eq!(app.game.status, GameStatus::Playing);
+}
@@ -0,0 +1,176 @@
+//! Definitions of the word just played: the dictionary next to the word
+//! list, the dictionary API and its cache, and the panel showing them.
+
+// The lookups served from here need the `http` feature, and so do their
+// helpers
+#![cfg_attr(not(feature = "http"), allow(unused))]
+
+mod common;
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::{press, type_word};
+use wordle::app::App;
+use wordle::config::Config;
+use wordle::definitions::{self, Definitions};
+use wordle::ui::definition::wrapped_lines;
+
+const ANSWER: &str = r#"[{"word": "slate", "meanings": [
+    {"partOfSpeech": "noun", "definitions": [
+        {"definition": "A fine-grained rock."}, {"definition": "A list of candidates."}]},
+    {"partOfSpeech": "verb", "definitions": [{"definition": "To schedule."}]},
+    {"partOfSpeech": "adjective", "definitions": []}
+]}]"#;
+
+fn screen(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..40)
+        .map(|y| {
+            (0..100)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn dictionaries_are_read_and_answers_parsed() {
+    let dictionary = Definitions::parse("AVIÃO\tVeículo que voa.\nno tab here\nCASA\t\n");
+    assert_eq!(dictionary.get("aviao"), Some("Veículo que voa."));
+    assert_eq!(dictionary.get("AVIÃO"), Some("Veículo que voa."));
+    assert_eq!(dictionary.get("CASA"), None);
+    assert_eq!(
+        Definitions::path_for(Path::new("data/words-pt.txt")),
+        Path::new("data/words-pt.definitions")
+    );
+
+    assert_eq!(
+        definitions::parse_answer(ANSWER).as_deref(),
+        Some("(noun) A fine-grained rock.\n(verb) To schedule.")
+    );
+    assert_eq!(
+        definitions::parse_answer(r#"{"title": "No Definitions Found"}"#),
+        None
+    );
+    assert_eq!(
+        definitions::api_url("https://dict/{language}/{word}", "pt", "AVIÃO"),
+        "https://dict/pt/avião"
+    );
+
+    assert_eq!(wrapped_lines("one two\nthree"), 2);
+    assert_eq!(wrapped_lines(&"word ".repeat(20)), 4);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn the_word_played_is_defined_once_the_game_ends() {
+    // Answers one lookup; the next comes from the cache
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!(
+        "http://{}/{{language}}/{{word}}",
+        listener.local_addr().unwrap()
+    );
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+            if header.trim().is_empty() {
+                break;
+            }
+        }
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            ANSWER.len(),
+            ANSWER
+        )
+        .unwrap();
+        line
+    });
+
+    let dir = std::env::temp_dir().join(format!("wordle-definitions-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("words.txt"), "crane\nslate\n").unwrap();
+    let long = "A bird with a long neck that wades in marshes. ".repeat(6);
+    fs::write(dir.join("words.definitions"), format!("CRANE\t{}\n", long)).unwrap();
+    std::env::set_var("WORDLE_DATA_DIR", &dir);
+    std::env::set_var("XDG_DATA_HOME", dir.join("home"));
+    let config: Config = toml::from_str(&format!(
+        "language = \"en\"\n[definitions]\napi_url = \"{}\"\n",
+        url
+    ))
+    .unwrap();
+
+    let mut app = App::new(&config);
+    let play = |app: &mut App, word: &str| {
+        let seed = (0..)
+            .find(|&seed| {
+                app.play_seed(seed);
+                app.game.target_word == word
+            })
+            .unwrap();
+        app.play_seed(seed);
+        type_word(app, word);
+    };
+
+    // From the dictionary of the word list
+    play(&mut app, "CRANE");
+    let lookup = app.definition.clone().unwrap();
+    assert_eq!(lookup.text.as_deref(), Some(long.trim()));
+    assert!(!lookup.loading);
+    let shown = screen(&app);
+    assert!(shown.contains("Definition · CRANE"));
+    assert!(shown.contains("↑↓ scroll"));
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    assert_eq!(app.definition.as_ref().unwrap().scroll, 2);
+    for _ in 0..20 {
+        press(&mut app, KeyCode::Down);
+    }
+    let last = wrapped_lines(long.trim()) - 8;
+    assert_eq!(app.definition.as_ref().unwrap().scroll, last);
+    press(&mut app, KeyCode::Up);
+    assert_eq!(app.definition.as_ref().unwrap().scroll, last - 1);
+
+    // From the API, while it answers
+    play(&mut app, "SLATE");
+    assert!(app.definition.as_ref().unwrap().loading);
+    assert!(screen(&app).contains("Looking it up..."));
+    let start = Instant::now();
+    while app.definition.as_ref().unwrap().loading {
+        assert!(start.elapsed() < Duration::from_secs(10), "no answer");
+        thread::sleep(Duration::from_millis(10));
+        app.on_tick();
+    }
+    assert!(server.join().unwrap().starts_with("GET /en/slate "));
+    assert!(screen(&app).contains("(noun) A fine-grained rock."));
+
+    // ... and then from the cache, the server being gone
+    let mut app = App::new(&config);
+    play(&mut app, "SLATE");
+    let lookup = app.definition.clone().unwrap();
+    assert!(!lookup.loading);
+    assert_eq!(
+        lookup.text.as_deref(),
+        Some("(noun) A fine-grained rock.\n(verb) To schedule.")
+    );
+
+    // Nothing to show while playing
+    app.new_game();
+    assert!(!screen(&app).contains("Definition"));
+}
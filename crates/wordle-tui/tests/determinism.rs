@@ -0,0 +1,40 @@
+//! The word of the day of every built-in word list, against the vectors in
+//! `tests/vectors/daily.txt`: the same day gives everyone the same word, on
+//! every platform and in every release.
+
+use wordle::game::{word_list_checksum, Game, WORD_LENGTH};
+use wordle::i18n::Language;
+
+const VECTORS: &str = include_str!("vectors/daily.txt");
+
+#[test]
+fn every_language_draws_the_pinned_word_of_the_day() {
+    let mut checked = 0;
+    for (number, line) in VECTORS.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let at = format!("vectors/daily.txt:{}", number + 1);
+        let ["daily", code, checksum, day, word] = line.split_whitespace().collect::<Vec<_>>()[..]
+        else {
+            panic!("{}: unknown vector '{}'", at, line);
+        };
+        let language = Language::from_code(code).unwrap();
+        let words = Game::parse_words(language.embedded_words(), WORD_LENGTH);
+        assert_eq!(
+            word_list_checksum(&words),
+            checksum.parse::<u64>().unwrap(),
+            "{}: the {} word list changed; its vectors need to be drawn again",
+            at,
+            code
+        );
+        assert_eq!(
+            Game::for_day(&words, day.parse().unwrap()).display_word,
+            word,
+            "{}",
+            at
+        );
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
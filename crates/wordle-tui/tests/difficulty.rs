@@ -0,0 +1,91 @@
+//! The difficulty of new games: chosen on the menu or in the config, and
+//! left out of games drawn from a seed.
+
+mod common;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::press;
+use wordle::app::{App, View};
+use wordle::config::{Config, Difficulty};
+use wordle::game::dictionary::Tier;
+use wordle::Game;
+
+fn app(difficulty: Difficulty) -> App {
+    common::data_home("difficulty");
+    let config = Config {
+        language: Some("en".to_string()),
+        difficulty,
+        ..Config::default()
+    };
+    App::new(&config)
+}
+
+fn shows(app: &App, text: &str) -> bool {
+    let (width, height) = (90, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height).any(|y| {
+        let line: String = (0..width).map(|x| buffer.get(x, y).symbol()).collect();
+        line.contains(text)
+    })
+}
+
+#[test]
+fn the_difficulty_is_chosen_on_the_new_game_entry() {
+    let mut app = app(Difficulty::Any);
+    app.view = View::Menu(0);
+    assert_eq!(app.difficulty, None);
+    assert!(shows(&app, "New game ◂ Any ▸"));
+
+    press(&mut app, KeyCode::Right);
+    press(&mut app, KeyCode::Right);
+    assert_eq!(app.difficulty, Some(Tier::Medium));
+    assert!(shows(&app, "New game ◂ Medium ▸"));
+    press(&mut app, KeyCode::Left);
+    press(&mut app, KeyCode::Left);
+    press(&mut app, KeyCode::Left);
+    assert_eq!(app.difficulty, Some(Tier::Hard));
+
+    // Elsewhere on the menu, the arrows do nothing and the entry says it
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Right);
+    assert_eq!(app.difficulty, Some(Tier::Hard));
+    assert!(shows(&app, "New game (Hard)"));
+
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Game);
+    assert_eq!(app.dictionary.tier(&app.game.target_word), Some(Tier::Hard));
+}
+
+#[test]
+fn new_games_are_of_the_configured_tier() {
+    let mut app = app(Difficulty::Easy);
+    assert_eq!(app.difficulty, Some(Tier::Easy));
+    for _ in 0..20 {
+        app.view = View::Menu(0);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.dictionary.tier(&app.game.target_word), Some(Tier::Easy));
+        let seed = app.game.seed.unwrap();
+        assert_eq!(
+            Game::from_seed(app.dictionary.spellings(), seed).target_word,
+            app.game.target_word
+        );
+    }
+}
+
+#[test]
+fn seeds_draw_the_same_word_whatever_the_difficulty() {
+    let mut app = app(Difficulty::Hard);
+    for seed in 0..20 {
+        app.play_seed(seed);
+        assert_eq!(app.game.seed, Some(seed));
+        assert_eq!(
+            app.game.target_word,
+            Game::from_seed(app.dictionary.spellings(), seed).target_word
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! The terminal report of `wordle doctor`: what it tells of what was found,
+//! and the settings it suggests for it.
+
+use wordle::cli::{Args, Command};
+use wordle::config::Config;
+use wordle::doctor::{ColorDepth, Report, PATTERNS};
+
+fn good_terminal() -> Report {
+    Report {
+        size: Some((100, 40)),
+        term: Some("xterm-256color".to_string()),
+        colors: Some(ColorDepth::TrueColor),
+        no_color: false,
+        widths: PATTERNS.iter().map(|&(_, _, width)| Some(width)).collect(),
+        mouse: Some(true),
+        keyboard_protocol: Some(true),
+        synchronized_output: Some(true),
+        sixel: Some(false),
+    }
+}
+
+#[test]
+fn doctor_is_a_subcommand() {
+    let args = Args::parse_from(["doctor".to_string()]).unwrap();
+    assert_eq!(args.command, Some(Command::Doctor));
+}
+
+#[test]
+fn color_depth_comes_from_the_environment() {
+    let depth = |term, colorterm| ColorDepth::from_env(Some(term), colorterm);
+    assert_eq!(
+        depth("xterm-256color", Some("truecolor")),
+        ColorDepth::TrueColor
+    );
+    assert_eq!(depth("xterm", Some("24bit")), ColorDepth::TrueColor);
+    assert_eq!(depth("screen-256color", None), ColorDepth::Indexed);
+    assert_eq!(depth("linux", None), ColorDepth::Basic);
+    assert_eq!(depth("dumb", None), ColorDepth::Monochrome);
+    assert_eq!(ColorDepth::from_env(None, None), ColorDepth::Monochrome);
+}
+
+#[test]
+fn a_capable_terminal_needs_no_change() {
+    let report = good_terminal();
+    assert!(report.suggestions(&Config::default()).is_empty());
+    let text = report.render(&Config::default());
+    assert!(text.contains("Size:                 100x40"));
+    assert!(text.contains("Colors:               true color (24-bit)"));
+    assert!(text.contains("Sixel graphics:       no"));
+    assert!(text.contains("  Emoji               🟩🟨⬛  |  6 (ok)"));
+    assert!(text.contains("Nothing to change"));
+}
+
+#[test]
+fn shortcomings_come_with_suggestions() {
+    let mut report = good_terminal();
+    report.size = Some((60, 20));
+    report.colors = Some(ColorDepth::Indexed);
+    report.widths[2] = Some(10);
+    report.widths[4] = None;
+    report.mouse = Some(false);
+    report.keyboard_protocol = None;
+    let mut config: Config =
+        toml::from_str("[display]\ntheme = \"dark\"\nsixel = \"on\"\n").unwrap();
+
+    let suggestions = report.suggestions(&config);
+    assert_eq!(suggestions.len(), 5, "{:#?}", suggestions);
+    assert!(suggestions[0].contains("keyboard is hidden"));
+    assert!(suggestions[1].contains("theme = \"classic\""));
+    assert!(suggestions[2].contains("ambiguous-width"));
+    assert!(suggestions[3].contains("keyboard_audit = true"));
+    assert!(suggestions[4].contains("sixel = \"auto\""));
+    let text = report.render(&config);
+    assert!(text.contains("  Marks               ✓✗★☆•   |  10 (expected 5)"));
+    assert!(text.contains("  Emoji               🟩🟨⬛  |  no answer"));
+    assert!(text.contains("Keyboard protocol:    no answer"));
+    assert!(text.contains("Suggestions:\n  - The keyboard is hidden"));
+
+    // Settings already right aren't suggested again
+    config.display.keyboard_audit = true;
+    config.display.theme = wordle::config::ThemeName::Classic;
+    assert_eq!(report.suggestions(&config).len(), 3);
+
+    report.size = Some((10, 5));
+    report.no_color = true;
+    let suggestions = report.suggestions(&config);
+    assert!(suggestions[0].contains("too small to play"));
+    assert!(suggestions
+        .iter()
+        .any(|s| s.contains("high_contrast = true")));
+}
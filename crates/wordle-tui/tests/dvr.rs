@@ -0,0 +1,110 @@
+//! The DVR of the game in progress: the boards it keeps, and the read-only
+//! screen scrubbing back through them.
+
+mod common;
+
+use crossterm::event::{Event, KeyCode, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::{press, press_with};
+use wordle::app::{App, Screen};
+use wordle::dvr::{Dvr, DVR_FRAMES};
+use wordle::game::Game;
+
+fn ctrl(app: &mut App, c: char) {
+    press_with(app, KeyCode::Char(c), KeyModifiers::CONTROL);
+}
+
+fn screen(app: &App) -> String {
+    let (width, height) = (90, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn the_last_boards_are_kept_for_one_word() {
+    let mut dvr = Dvr::default();
+    let mut game = Game::with_word("CRANE");
+    dvr.record(&game);
+    // Nothing changed, nothing kept
+    dvr.record(&game);
+    assert_eq!(dvr.len(), 1);
+    game.input_letter('S');
+    dvr.record(&game);
+    assert_eq!(dvr.len(), 2);
+    assert_eq!(
+        game.at(dvr.frame(0).unwrap()).current_row(),
+        Some(&['S'][..])
+    );
+    assert_eq!(game.at(dvr.frame(1).unwrap()).current_row(), Some(&[][..]));
+    assert!(dvr.frame(2).is_none());
+
+    for _ in 0..DVR_FRAMES {
+        game.input_letter('A');
+        dvr.record(&game);
+        game.delete_letter();
+        dvr.record(&game);
+    }
+    assert_eq!(dvr.len(), DVR_FRAMES);
+
+    dvr.record(&Game::with_word("SLATE"));
+    assert_eq!(dvr.len(), 1);
+}
+
+#[test]
+fn the_dvr_scrubs_back_without_touching_the_game() {
+    let mut app = common::in_game("dvr");
+    let answer = app.game.target_word.clone();
+    let miss = app
+        .words
+        .iter()
+        .find(|word| **word != answer)
+        .unwrap()
+        .to_lowercase();
+    for c in miss.chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('a'));
+    let live = app.game.snapshot();
+    // Five letters, the guess and the letter after it
+    assert_eq!(app.dvr.len(), 7);
+
+    ctrl(&mut app, 'r');
+    assert!(matches!(app.screen, Screen::Dvr(0)));
+    assert!(screen(&app).contains("DVR 7/7"));
+    press(&mut app, KeyCode::Left);
+    press(&mut app, KeyCode::Left);
+    assert!(matches!(app.screen, Screen::Dvr(2)));
+    assert!(screen(&app).contains("DVR 5/7"));
+    press(&mut app, KeyCode::Home);
+    assert!(screen(&app).contains("DVR 1/7"));
+    press(&mut app, KeyCode::Left);
+    assert!(matches!(app.screen, Screen::Dvr(6)));
+    press(&mut app, KeyCode::End);
+    assert!(matches!(app.screen, Screen::Dvr(0)));
+
+    // Read-only: typing, deleting, undoing or pasting change nothing
+    press(&mut app, KeyCode::Char('z'));
+    press(&mut app, KeyCode::Backspace);
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.screen, Screen::Board));
+    ctrl(&mut app, 'r');
+    ctrl(&mut app, 'z');
+    app.handle_event(Event::Paste("xyz".to_string()));
+    assert_eq!(app.game.snapshot(), live);
+
+    ctrl(&mut app, 'r');
+    assert!(matches!(app.screen, Screen::Board));
+    press(&mut app, KeyCode::Char('b'));
+    assert_eq!(app.game.current_row(), Some(&['A', 'B'][..]));
+}
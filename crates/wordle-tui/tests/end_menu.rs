@@ -0,0 +1,112 @@
+//! The menu of a finished game: what it offers, how it is walked, and
+//! where each entry leads.
+
+mod common;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::{press, type_word};
+use wordle::app::{App, EndAction, Screen, View};
+use wordle::history::Source;
+use wordle::{FeedbackStyle, Game, GameStatus};
+
+fn app() -> App {
+    let mut app = common::app("end-menu");
+    app.view = View::Game;
+    app.game = Game::with_word("CRANE");
+    app
+}
+
+fn shows(app: &App, text: &str) -> bool {
+    let (width, height) = (120, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height).any(|y| {
+        let line: String = (0..width).map(|x| buffer.get(x, y).symbol()).collect();
+        line.contains(text)
+    })
+}
+
+fn selected(app: &App) -> Option<EndAction> {
+    match app.screen {
+        Screen::Finished(selected) => Some(EndAction::ALL[selected]),
+        _ => None,
+    }
+}
+
+#[test]
+fn the_menu_comes_up_when_the_game_ends() {
+    let mut app = app();
+    type_word(&mut app, "slate");
+    assert_eq!(selected(&app), None);
+    type_word(&mut app, "crane");
+    assert_eq!(selected(&app), Some(EndAction::PlayAgain));
+    assert!(shows(
+        &app,
+        "You won! [P] Play again | [M] Change mode | [A] Analysis"
+    ));
+
+    // The arrows walk it, wrapping around, and Enter runs the entry
+    press(&mut app, KeyCode::Left);
+    assert_eq!(selected(&app), Some(EndAction::Quit));
+    press(&mut app, KeyCode::Right);
+    press(&mut app, KeyCode::Right);
+    press(&mut app, KeyCode::Right);
+    assert_eq!(selected(&app), Some(EndAction::Analysis));
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.screen, Screen::Analysis(_)));
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(selected(&app), Some(EndAction::Analysis));
+
+    // Or its letter
+    press(&mut app, KeyCode::Char('S'));
+    assert!(matches!(app.screen, Screen::Card(_)));
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Char('m'));
+    assert!(matches!(app.view, View::Menu(_)));
+    app.view = View::Game;
+    press(&mut app, KeyCode::Char('q'));
+    assert!(app.should_quit);
+}
+
+#[test]
+fn playing_again_keeps_the_mode() {
+    let mut app = app();
+    app.game.config = app.game.config.with_feedback(FeedbackStyle::Counts);
+    type_word(&mut app, "crane");
+    press(&mut app, KeyCode::Char('p'));
+    assert!(matches!(app.screen, Screen::Board));
+    assert_eq!(app.game.status, GameStatus::Playing);
+    assert_eq!(app.game.config.feedback, FeedbackStyle::Counts);
+
+    // Esc too, the way it always did
+    app.game = Game::with_word("CRANE");
+    app.source = Source::Zen;
+    type_word(&mut app, "crane");
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.source, Source::Zen);
+    assert!(app.game.config.endless);
+}
+
+#[test]
+fn a_lost_game_can_go_on() {
+    let mut app = app();
+    for _ in 0..6 {
+        type_word(&mut app, "slate");
+    }
+    assert_eq!(app.game.status, GameStatus::Lost);
+    assert_eq!(selected(&app), Some(EndAction::PlayAgain));
+    assert!(shows(
+        &app,
+        "The word was CRANE. [Space] Keep trying [P] Play again"
+    ));
+
+    press(&mut app, KeyCode::Char(' '));
+    assert!(matches!(app.screen, Screen::Board));
+    assert_eq!(app.game.status, GameStatus::Playing);
+    // Losing again brings the menu back
+    type_word(&mut app, "slate");
+    assert_eq!(selected(&app), Some(EndAction::PlayAgain));
+}
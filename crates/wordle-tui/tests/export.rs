@@ -0,0 +1,196 @@
+//! `wordle stats export`: its options, and the history as JSON and CSV.
+
+use std::fs;
+use std::path::PathBuf;
+
+use wordle::cli::{Args, Command};
+use wordle::config::Config;
+use wordle::export::{Export, CSV_HEADER, VERSION};
+use wordle::history::{Entry, Finish, Source, Start};
+
+fn start(id: u64, source: Source, language: &str, word: &str) -> Entry {
+    Entry::Start(Start {
+        id,
+        at: 1000 * id,
+        source,
+        language: language.to_string(),
+        word: word.to_string(),
+        seed: Some(id),
+        words: 10,
+        checksum: 7,
+    })
+}
+
+fn finish(id: u64, word: &str, guesses: &[&str], won: bool) -> Entry {
+    Entry::Finish(Finish {
+        id,
+        at: 1000 * id + 60,
+        word: word.to_string(),
+        guesses: guesses.iter().map(|guess| guess.to_string()).collect(),
+        won,
+        seconds: 60,
+        accepted_by: Vec::new(),
+    })
+}
+
+fn history() -> Vec<Entry> {
+    vec![
+        start(1, Source::Daily, "en", "CRANE"),
+        finish(1, "CRANE", &["SLATE", "CRANE"], true),
+        start(2, Source::Random, "en", "PIANO"),
+        finish(2, "PIANO", &["SLATE"; 6], false),
+        start(3, Source::Random, "pt", "TERMO"),
+        finish(3, "TERMO", &["TERMO"], true),
+        start(4, Source::Daily, "en", "HOUSE"),
+        finish(4, "HOUSE", &["SLATE", "CRANE"], true),
+        // Never finished
+        start(5, Source::Random, "en", "MOUSE"),
+    ]
+}
+
+#[test]
+fn export_is_parsed() {
+    let args = |line: &str| Args::parse_from(line.split_whitespace().map(str::to_string));
+    assert_eq!(
+        args("stats export --json games.json").unwrap().command,
+        Some(Command::ExportStats {
+            json: Some(PathBuf::from("games.json")),
+            csv: None,
+            commentary: false,
+        })
+    );
+    assert_eq!(
+        args("stats export --csv=games.csv --json games.json")
+            .unwrap()
+            .command,
+        Some(Command::ExportStats {
+            json: Some(PathBuf::from("games.json")),
+            csv: Some(PathBuf::from("games.csv")),
+            commentary: false,
+        })
+    );
+    assert_eq!(
+        args("stats export").unwrap().command,
+        Some(Command::ExportStats {
+            json: None,
+            csv: None,
+            commentary: false,
+        })
+    );
+    assert_eq!(
+        args("stats export --commentary").unwrap().command,
+        Some(Command::ExportStats {
+            json: None,
+            csv: None,
+            commentary: true,
+        })
+    );
+    assert!(args("stats").is_err());
+    assert!(args("print --commentary").is_err());
+    assert!(args("stats export --json").is_err());
+    assert!(args("stats export --csv").is_err());
+    assert!(args("print --json games.json").is_err());
+    assert!(args("print --csv games.csv").is_err());
+}
+
+#[test]
+fn games_and_totals_are_exported() {
+    let export = Export::new(&history(), 99);
+    assert_eq!(export.version, VERSION);
+    assert_eq!(export.games.len(), 5);
+    assert_eq!(export.games[1].won, Some(false));
+    assert_eq!(export.games[4].won, None);
+    assert!(export.games[4].guesses.is_empty());
+
+    let totals = &export.totals;
+    assert_eq!((totals.started, totals.finished, totals.wins), (5, 4, 3));
+    assert_eq!(totals.win_rate, Some(75.0));
+    assert_eq!(totals.average_guesses, Some(5.0 / 3.0));
+    assert_eq!((totals.current_streak, totals.best_streak), (2, 2));
+    assert_eq!(
+        totals.guess_distribution.iter().collect::<Vec<_>>(),
+        vec![(&1, &1), (&2, &2)]
+    );
+
+    let daily = &export.by_source["daily"];
+    assert_eq!((daily.finished, daily.wins), (2, 2));
+    assert_eq!(export.by_source["random"].started, 3);
+    assert_eq!(export.by_language["pt"].wins, 1);
+    assert_eq!(export.by_language["en"].current_streak, 1);
+
+    // The schema as written
+    let json: serde_json::Value = serde_json::from_str(&export.to_json()).unwrap();
+    assert_eq!(json["version"], 1);
+    assert_eq!(json["exported_at"], 99);
+    assert_eq!(json["games"][0]["source"], "daily");
+    assert_eq!(json["games"][0]["guesses"][1], "CRANE");
+    assert_eq!(json["games"][4]["finished_at"], serde_json::Value::Null);
+    assert_eq!(json["totals"]["guess_distribution"]["2"], 2);
+    assert_eq!(json["by_language"]["en"]["best_streak"], 1);
+}
+
+#[test]
+fn commentary_is_added_when_asked() {
+    let mut export = Export::new(&history(), 99);
+    let json: serde_json::Value = serde_json::from_str(&export.to_json()).unwrap();
+    assert!(json["games"][0].get("commentary").is_none());
+
+    export.add_commentary();
+    let told = |index: usize| export.games[index].commentary.clone().unwrap();
+    assert!(told(0).starts_with("Opened with SLATE, which left "));
+    assert!(told(0).contains("Found it on guess 2"));
+    assert!(told(1).contains("Guess 2, SLATE, told nothing new."));
+    assert!(told(1).ends_with("Out of guesses: the word was PIANO."));
+    // In the language the game was played in
+    assert!(told(2).starts_with("Acertou no palpite 1"));
+    assert_eq!(export.games[4].commentary, None);
+}
+
+#[test]
+fn games_are_flattened_to_csv_rows() {
+    let csv = Export::new(&history(), 99).to_csv();
+    let rows: Vec<&str> = csv.lines().collect();
+    assert_eq!(rows.len(), 6);
+    assert_eq!(rows[0], CSV_HEADER);
+    assert_eq!(rows[1], "1970-01-01,daily,en,CRANE,2,won,60,SLATE CRANE");
+    assert_eq!(
+        rows[2],
+        "1970-01-01,random,en,PIANO,6,lost,60,SLATE SLATE SLATE SLATE SLATE SLATE"
+    );
+    assert_eq!(rows[5], "1970-01-01,random,en,MOUSE,,unfinished,,");
+
+    // Fields are quoted when they have to be
+    let mut odd = history();
+    if let Entry::Start(start) = &mut odd[0] {
+        start.language = "en,\"gb\"".to_string();
+    }
+    let csv = Export::new(&odd, 99).to_csv();
+    assert!(csv.contains(",\"en,\"\"gb\"\"\",CRANE,"));
+}
+
+#[test]
+fn the_history_is_written_to_the_file() {
+    let dir = std::env::temp_dir().join(format!("wordle-export-{}", std::process::id()));
+    std::env::set_var("XDG_DATA_HOME", &dir);
+    fs::create_dir_all(dir.join("wordle")).unwrap();
+    let lines: String = history()
+        .iter()
+        .map(|entry| serde_json::to_string(entry).unwrap() + "\n")
+        .collect();
+    fs::write(dir.join("wordle/history.jsonl"), lines).unwrap();
+
+    let path = dir.join("games.json");
+    let csv = dir.join("games.csv");
+    Command::ExportStats {
+        json: Some(path.clone()),
+        csv: Some(csv.clone()),
+        commentary: false,
+    }
+    .run(&Config::default())
+    .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(json["games"].as_array().unwrap().len(), 5);
+    assert_eq!(json["totals"]["wins"], 3);
+    assert_eq!(fs::read_to_string(&csv).unwrap().lines().count(), 6);
+}
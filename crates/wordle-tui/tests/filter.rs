@@ -0,0 +1,122 @@
+//! The word filter: its lists, the words it keeps from being drawn, and
+//! the guesses it refuses.
+
+mod common;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::{press, type_word};
+use wordle::app::{App, View};
+use wordle::config::{Config, WordFilterMode};
+use wordle::filter::WordFilter;
+use wordle::i18n::Language;
+use wordle::{Game, GameStatus};
+
+fn app(word_filter: WordFilterMode) -> App {
+    common::data_home("filter");
+    let config = Config {
+        language: Some("en".to_string()),
+        word_filter,
+        ..Config::default()
+    };
+    App::new(&config)
+}
+
+fn shows(app: &App, text: &str) -> bool {
+    let (width, height) = (90, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height).any(|y| {
+        (0..width)
+            .map(|x| buffer.get(x, y).symbol())
+            .collect::<String>()
+            .contains(text)
+    })
+}
+
+#[test]
+fn lists_skip_comments_and_fold_accents() {
+    let filter = WordFilter::parse("# A comment\n\n  Café  # and another\nbitch\n");
+    assert_eq!(filter.len(), 2);
+    assert!(filter.blocks("CAFE"));
+    assert!(filter.blocks("café"));
+    assert!(filter.blocks("Bitch"));
+    assert!(!filter.blocks("crane"));
+
+    // The built-in lists only hold words of their language's list
+    for language in [Language::En, Language::Pt, Language::Es, Language::Fr] {
+        let words = language.load_words();
+        let filter = WordFilter::parse(language.embedded_filter());
+        let listed = words.iter().filter(|word| filter.blocks(word)).count();
+        assert_eq!(listed, filter.len(), "{}", language.code());
+    }
+    assert!(!WordFilter::parse(Language::En.embedded_filter()).is_empty());
+}
+
+#[test]
+fn filtered_words_are_never_drawn() {
+    let mut app = app(WordFilterMode::Answers);
+    let seed = 42;
+    let word = Game::from_seed(app.dictionary.spellings(), seed).display_word;
+    app.filter = WordFilter::parse(&word);
+
+    // The seed moves on to one whose word is allowed, and that seed gives
+    // the same word to anyone
+    app.play_seed(seed);
+    assert_ne!(app.game.display_word, word);
+    let moved = app.game.seed.unwrap();
+    assert_eq!(moved, seed + 1);
+    assert_eq!(
+        Game::from_seed(app.dictionary.spellings(), moved).display_word,
+        app.game.display_word
+    );
+
+    // The filter off, the seed draws its word
+    app.word_filter = WordFilterMode::Off;
+    app.play_seed(seed);
+    assert_eq!(app.game.display_word, word);
+}
+
+#[test]
+fn filtered_guesses_are_refused_if_asked() {
+    let mut app = app(WordFilterMode::Answers);
+    press(&mut app, KeyCode::Enter);
+    app.game = Game::with_word("CRANE");
+    app.filter = WordFilter::parse("slate");
+    type_word(&mut app, "slate");
+    assert_eq!(app.game.submitted().count(), 1);
+
+    app.word_filter = WordFilterMode::Guesses;
+    type_word(&mut app, "slate");
+    assert_eq!(app.game.submitted().count(), 1);
+    assert!(shows(&app, "That word isn't allowed here"));
+    for _ in 0..5 {
+        press(&mut app, KeyCode::Backspace);
+    }
+    type_word(&mut app, "crane");
+    assert_eq!(app.game.status, GameStatus::Won);
+}
+
+#[test]
+fn the_filter_is_set_in_the_config_and_the_settings() {
+    let config: Config = toml::from_str("word_filter = \"guesses\"").unwrap();
+    assert_eq!(config.word_filter, WordFilterMode::Guesses);
+    assert!(toml::from_str::<Config>("word_filter = \"sometimes\"").is_err());
+
+    // Settings, third from the end of the menu; the filter comes before
+    // the quit confirmation, the last
+    let mut app = app(WordFilterMode::Off);
+    for _ in 0..3 {
+        press(&mut app, KeyCode::Up);
+    }
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Settings(0));
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Up);
+    assert!(shows(&app, "Word filter: off"));
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.word_filter, WordFilterMode::Answers);
+    assert!(shows(&app, "Word filter: answers"));
+}
@@ -0,0 +1,109 @@
+//! Guess dictionaries: the chain of lists of each language in the config,
+//! guesses none of them has refused, and the list that took each guess
+//! kept in the history.
+
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+
+use crossterm::event::KeyCode;
+
+use common::{press, type_word};
+use wordle::app::{App, View};
+use wordle::config::Config;
+use wordle::guesses::{GuessChain, GuessSource};
+use wordle::history::History;
+use wordle::i18n::Language;
+
+// A list of `words` in a file of its own
+fn list(name: &str, words: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wordle-guesses-lists-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    fs::write(&path, words).unwrap();
+    path
+}
+
+#[test]
+fn the_chain_is_read_from_the_config() {
+    let config: Config =
+        toml::from_str("[guesses]\nen = [\"pack\", \"base\", \"system\", \"/lists/mine.txt\"]")
+            .unwrap();
+    assert_eq!(
+        config.guesses["en"],
+        vec![
+            GuessSource::Pack,
+            GuessSource::Base,
+            GuessSource::System,
+            GuessSource::File(PathBuf::from("/lists/mine.txt")),
+        ]
+    );
+    assert_eq!(
+        GuessSource::File("/lists/mine.txt".into()).label(),
+        "/lists/mine.txt"
+    );
+    assert!(Config::default().guesses.is_empty());
+}
+
+#[test]
+fn the_first_list_with_the_guess_takes_it() {
+    let first = list("first.txt", "# mine\nqajaq\nCRANE\ntoolong\n");
+    let second = list("second.txt", "crane\nslate # a comment\nfaçon\n");
+    let chain = GuessChain::load(
+        &[
+            GuessSource::File(first.clone()),
+            GuessSource::File("/nowhere/at/all.txt".into()),
+            GuessSource::File(second.clone()),
+            GuessSource::Base,
+        ],
+        Language::En,
+        &["PIANO".to_string()],
+    );
+    assert!(!chain.is_empty());
+    assert_eq!(chain.find("crane"), Some(&GuessSource::File(first.clone())));
+    assert_eq!(chain.find("QAJAQ"), Some(&GuessSource::File(first)));
+    assert_eq!(
+        chain.find("slate"),
+        Some(&GuessSource::File(second.clone()))
+    );
+    // Accents or not
+    assert_eq!(chain.find("FACON"), Some(&GuessSource::File(second)));
+    assert_eq!(chain.find("piano"), Some(&GuessSource::Base));
+    assert_eq!(chain.find("TOOLO"), None);
+    assert_eq!(chain.find("zzzzz"), None);
+
+    assert!(GuessChain::load(&[], Language::En, &[]).is_empty());
+}
+
+#[test]
+fn guesses_no_list_has_are_refused_and_the_others_logged() {
+    common::data_home("guesses");
+    let mine = list("mine.txt", "qzxjv\n");
+    let config: Config = toml::from_str(&format!(
+        "language = \"en\"\n[guesses]\nen = [\"base\", \"{}\"]",
+        mine.display()
+    ))
+    .unwrap();
+    let mut app = App::new(&config);
+    app.new_game();
+    app.view = View::Game;
+    let answer = app.game.target_word.to_lowercase();
+
+    type_word(&mut app, "zzzzz");
+    assert_eq!(app.game.submitted().count(), 0);
+    assert_eq!(app.game.message.as_deref(), Some("Not in the word list"));
+    for _ in 0..5 {
+        press(&mut app, KeyCode::Backspace);
+    }
+
+    type_word(&mut app, "qzxjv");
+    type_word(&mut app, &answer);
+    assert_eq!(app.game.submitted().count(), 2);
+
+    let finished = History::open().finished();
+    assert_eq!(
+        finished[0].accepted_by,
+        vec![mine.display().to_string(), "base".to_string()]
+    );
+}
@@ -0,0 +1,694 @@
+//! Walks every screen of the game with keyboard events only, checking the
+//! focus path the keyboard audit mode shows in the footer.
+
+mod common;
+
+use std::time::Duration;
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::{press, type_word};
+use wordle::app::{App, Screen, View};
+use wordle::cli::Args;
+use wordle::config::Config;
+use wordle::game::seed_code;
+use wordle::history::Source;
+use wordle::mode::{Blitz, Countdown, Mode};
+use wordle::{FeedbackStyle, Game, GameStatus, LetterStatus};
+
+fn audit_config() -> Config {
+    // The game history goes to a scratch directory, not the real one
+    common::data_home("walk");
+    let mut config = common::english();
+    config.display.keyboard_audit = true;
+    config
+}
+
+fn audit_app() -> App {
+    let mut app = App::new(&audit_config());
+    // Past the menu, into a known game
+    press(&mut app, KeyCode::Enter);
+    app.game = Game::with_word("CRANE");
+    app
+}
+
+fn ctrl(app: &mut App, c: char) {
+    app.handle_event(Event::Key(KeyEvent::new(
+        KeyCode::Char(c),
+        KeyModifiers::CONTROL,
+    )));
+}
+
+// The rendered screen, one string per line
+fn screen(app: &App) -> Vec<String> {
+    let (width, height) = (90, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..width).map(|x| buffer.get(x, y).symbol()).collect())
+        .collect()
+}
+
+fn shows(app: &App, text: &str) -> bool {
+    screen(app).iter().any(|line| line.contains(text))
+}
+
+#[test]
+fn the_menu_leads_everywhere() {
+    let mut app = App::new(&audit_config());
+    assert!(shows(&app, "Focus: Menu"));
+    assert!(shows(&app, "New game"));
+
+    // New game, Word of the day, Warm-up letter, Blitz, Timed guesses,
+    // Speedrun, Mastermind, Zen, Practice, Versus, Polyglot, Challenge a
+    // guest, Statistics
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Stats);
+    assert!(shows(&app, "No game finished yet"));
+    press(&mut app, KeyCode::Esc);
+
+    // Settings, before What's new and Quit at the end; Enter changes the
+    // theme
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Settings(0));
+    assert!(shows(&app, "Theme: classic"));
+    press(&mut app, KeyCode::Enter);
+    assert!(shows(&app, "Theme: dark"));
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.view, View::Menu(0));
+
+    // Into a game and back through the menu
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Game);
+    assert!(shows(&app, "Focus: Board"));
+    press(&mut app, KeyCode::F(10));
+    assert!(shows(&app, "Continue"));
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.view, View::Game);
+
+    // Quit is the last entry, and asks first with the game in progress
+    press(&mut app, KeyCode::F(10));
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Enter);
+    assert!(app.confirming_quit);
+    press(&mut app, KeyCode::Enter);
+    assert!(app.should_quit);
+}
+
+#[test]
+fn a_guest_plays_the_owners_secret_word() {
+    let mut app = App::new(&audit_config());
+    let before = app.challenges.challenges.len();
+
+    // Challenge a guest is the twelfth entry
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Challenge);
+    assert!(shows(&app, "Focus: Challenge a guest"));
+
+    // Words off the list are refused, and the word is never on screen
+    type_word(&mut app, "zzzzz");
+    assert_eq!(app.view, View::Challenge);
+    assert!(shows(&app, "Not in the word list"));
+    for _ in 0..5 {
+        press(&mut app, KeyCode::Backspace);
+    }
+    for c in "crane".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    assert!(shows(&app, "● ● ● ● ●"));
+    assert!(!screen(&app).iter().any(|line| line.contains("CRANE")));
+
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Game);
+    assert_eq!(app.game.target_word, "CRANE");
+    assert!(shows(&app, "Guest's turn"));
+
+    // The result goes to the challenges, not to the owner's stats
+    type_word(&mut app, "crane");
+    assert_eq!(app.game.status, GameStatus::Won);
+    assert_eq!(app.stats.games(), 0);
+    assert_eq!(app.challenges.challenges.len(), before + 1);
+    assert!(app.challenges.challenges.last().unwrap().won);
+}
+
+#[test]
+fn finished_games_are_listed_and_replayed_from_the_history() {
+    let mut app = audit_app();
+    type_word(&mut app, "slate");
+    type_word(&mut app, "crane");
+    assert_eq!(app.game.status, GameStatus::Won);
+
+    // History comes before Settings, What's new and Quit, at the end of
+    // the menu
+    press(&mut app, KeyCode::F(10));
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::History(0));
+    assert!(shows(&app, "Focus: History"));
+    let latest = &app.past_games[0];
+    assert_eq!(latest.word, "CRANE");
+    assert_eq!(latest.guesses, ["SLATE", "CRANE"]);
+    assert!(latest.won);
+    assert!(shows(&app, "CRANE  2/6"));
+    assert!(shows(&app, "SLATE CRANE"));
+
+    // Enter replays it, a letter per tick
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Replay(0));
+    assert!(shows(&app, "Focus: History › Replay"));
+    for _ in 0..6 {
+        app.on_tick();
+    }
+    let replay = app.replay.as_ref().unwrap();
+    assert_eq!(replay.game.submitted().count(), 1);
+    press(&mut app, KeyCode::Char(' '));
+    app.on_tick();
+    assert_eq!(app.replay.as_ref().unwrap().game.attempts[1].len(), 0);
+    press(&mut app, KeyCode::Right);
+    let replay = app.replay.as_ref().unwrap();
+    assert!(replay.is_done());
+    assert_eq!(replay.game.status, GameStatus::Won);
+
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.view, View::History(0));
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.view, View::Menu(0));
+}
+
+#[test]
+fn a_blitz_goes_from_word_to_word_until_the_clock_runs_out() {
+    let mut app = App::new(&audit_config());
+    // Blitz is the fourth entry
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.mode, Mode::Blitz(_)));
+    assert!(shows(&app, "⏱ 3:00"));
+
+    // Solving a word brings the next one
+    let answer = app.game.target_word.to_lowercase();
+    type_word(&mut app, &answer);
+    assert_eq!(app.game.status, GameStatus::Playing);
+    assert_eq!(app.game.current_attempt, 0);
+    assert!(shows(&app, "Solved 1"));
+
+    // Once time is up nothing more can be typed
+    let mut ended = Blitz::start(Duration::ZERO);
+    ended.solved = 1;
+    app.mode = Mode::Blitz(ended);
+    press(&mut app, KeyCode::Char('a'));
+    assert!(app.game.attempts[0].is_empty());
+    assert!(shows(&app, "Time's up! Words solved: 1"));
+    assert!(shows(&app, "Focus: Board › Game over"));
+
+    // Esc starts another
+    press(&mut app, KeyCode::Esc);
+    assert!(!app.mode.is_over());
+    assert!(shows(&app, "Solved 0"));
+}
+
+#[test]
+fn the_warmup_letter_leads_to_the_word_of_the_day() {
+    let mut app = App::new(&audit_config());
+    // Warm-up letter comes right after Word of the day
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.source, Source::Warmup);
+    assert_eq!(app.game.config.word_length, 1);
+
+    // Missing every other letter scrolls the board down to the row typed
+    let letter = app.game.target_word.clone();
+    for c in ('a'..='z').filter(|c| c.to_ascii_uppercase().to_string() != letter) {
+        type_word(&mut app, &c.to_string());
+    }
+    assert_eq!(app.game.status, GameStatus::Playing);
+    assert_eq!(app.game.current_attempt, 25);
+    type_word(&mut app, &letter.to_lowercase());
+    assert_eq!(app.game.status, GameStatus::Won);
+    assert!(shows(&app, &format!("The letter was {}", letter)));
+    assert_eq!(app.stats.games(), 0);
+
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.source, Source::Daily);
+    assert_eq!(app.game.config.word_length, 5);
+}
+
+#[test]
+fn a_speedrun_is_timed_from_the_first_letter_to_the_win() {
+    let mut app = App::new(&audit_config());
+    // Speedrun comes right after Timed guesses
+    for _ in 0..5 {
+        press(&mut app, KeyCode::Down);
+    }
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.mode, Mode::Speedrun(_)));
+    assert!(shows(&app, "⏱ 0:00.000"));
+    assert!(!app.clock_running());
+
+    press(&mut app, KeyCode::Char('a'));
+    assert!(app.clock_running());
+    press(&mut app, KeyCode::Backspace);
+
+    // The first win is a record, and the clock stops on it
+    let answer = app.game.target_word.to_lowercase();
+    type_word(&mut app, &answer);
+    assert_eq!(app.game.status, GameStatus::Won);
+    assert!(!app.clock_running());
+    assert!(shows(&app, "New record!"));
+    let best = app.records.best(5).unwrap();
+    let Mode::Speedrun(run) = &app.mode else {
+        panic!("not a speedrun");
+    };
+    assert_eq!(run.elapsed().as_millis(), best.as_millis());
+}
+
+#[test]
+fn mastermind_rows_only_show_counts() {
+    let mut app = App::new(&audit_config());
+    // Mastermind comes right after Speedrun
+    for _ in 0..6 {
+        press(&mut app, KeyCode::Down);
+    }
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.game.config.feedback, FeedbackStyle::Counts);
+
+    app.game = Game::with_word("CRANE");
+    app.game.config = app.game.config.with_feedback(FeedbackStyle::Counts);
+    type_word(&mut app, "trace");
+    // Three letters in place and one elsewhere, in badges beside the row
+    assert!(shows(&app, " 3   1 "));
+    assert!(app
+        .game
+        .get_keyboard_status()
+        .iter()
+        .all(|&status| status == LetterStatus::Unused));
+    assert!(app.candidates.iter().any(|word| word == "CRANE"));
+}
+
+#[test]
+fn status_symbols_mark_tiles_keys_and_legend() {
+    let mut config: Config = toml::from_str(
+        r##"
+        [display.status]
+        correct = { color = "#1e90ff", symbol = "✓" }
+        present = { color = "208", symbol = "~", placement = "before" }
+        "##,
+    )
+    .unwrap();
+    config.language = Some("en".to_string());
+    config.display.keyboard_audit = true;
+    audit_config();
+    let mut app = App::new(&config);
+    press(&mut app, KeyCode::Enter);
+    app.game = Game::with_word("CRANE");
+
+    type_word(&mut app, "trace");
+    let lines = screen(&app);
+    // R, A and E are in place, C is elsewhere, T has no symbol
+    assert!(lines
+        .iter()
+        .any(|line| line.contains("R✓") && line.contains("~C")));
+    assert!(!lines
+        .iter()
+        .any(|line| line.contains("T✓") || line.contains("~T")));
+    ctrl(&mut app, 'l');
+    assert!(shows(&app, " A✓ "));
+    assert!(shows(&app, "~A  "));
+
+    assert!(toml::from_str::<Config>("[display.status]\nabsent = { color = \"mauve\" }").is_err());
+}
+
+#[test]
+fn status_markers_are_switched_on_in_the_settings() {
+    let mut config: Config =
+        toml::from_str("[display.status]\npresent = { symbol = \"?\" }").unwrap();
+    config.language = Some("en".to_string());
+    config.display.keyboard_audit = true;
+    audit_config();
+    let mut app = App::new(&config);
+    press(&mut app, KeyCode::Enter);
+    app.game = Game::with_word("CRANE");
+    type_word(&mut app, "trace");
+    assert!(!shows(&app, "R*"));
+
+    app.view = View::Settings(2);
+    assert!(shows(&app, "Status markers: off"));
+    press(&mut app, KeyCode::Enter);
+    assert!(app.status_markers);
+    assert!(shows(&app, "Status markers: on"));
+
+    // Every status gets a marker, the one set in the config or the default
+    app.view = View::Game;
+    let lines = screen(&app);
+    assert!(lines
+        .iter()
+        .any(|line| line.contains("T-") && line.contains("R*") && line.contains("C?")));
+    ctrl(&mut app, 'l');
+    assert!(shows(&app, " A- "));
+}
+
+#[test]
+fn a_zen_game_goes_on_until_the_word_is_found() {
+    let mut app = App::new(&audit_config());
+    // Zen comes right after Mastermind
+    for _ in 0..7 {
+        press(&mut app, KeyCode::Down);
+    }
+    press(&mut app, KeyCode::Enter);
+    assert!(app.game.config.endless);
+    assert_eq!(app.source, Source::Zen);
+
+    let answer = app.game.target_word.to_lowercase();
+    let miss = if answer == "slate" { "crane" } else { "slate" };
+    for _ in 0..12 {
+        type_word(&mut app, miss);
+    }
+    assert_eq!(app.game.status, GameStatus::Playing);
+    // The first rows scrolled out, the one being typed is in view
+    assert!(shows(&app, "↑"));
+    type_word(&mut app, &answer);
+    assert_eq!(app.game.status, GameStatus::Won);
+    assert_eq!(app.game.submitted().count(), 13);
+    assert_eq!(app.stats.games(), 0);
+}
+
+#[test]
+fn practice_guesses_are_taken_back_with_ctrl_z() {
+    let mut app = App::new(&audit_config());
+    // Practice comes right after Zen
+    for _ in 0..8 {
+        press(&mut app, KeyCode::Down);
+    }
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.source, Source::Practice);
+    assert!(app.game.config.undo);
+
+    let mut game = Game::with_word("CRANE");
+    game.config = app.game.config;
+    app.game = game;
+    type_word(&mut app, "slate");
+    let after_slate = app.candidates.len();
+    type_word(&mut app, "crane");
+    assert_eq!(app.game.status, GameStatus::Won);
+
+    // The win is taken back, with the guess left in the row to change
+    ctrl(&mut app, 'z');
+    assert_eq!(app.game.status, GameStatus::Playing);
+    assert_eq!(app.game.current_attempt, 1);
+    assert_eq!(app.candidates.len(), after_slate);
+    assert!(shows(&app, "Guess taken back"));
+    ctrl(&mut app, 'z');
+    ctrl(&mut app, 'z');
+    assert!(shows(&app, "No guess to take back"));
+    assert_eq!(app.game.current_attempt, 0);
+    assert_eq!(app.candidates.len(), app.words.len());
+
+    // Practice doesn't count
+    for _ in 0..5 {
+        press(&mut app, KeyCode::Backspace);
+    }
+    type_word(&mut app, "crane");
+    assert_eq!(app.game.status, GameStatus::Won);
+    assert_eq!(app.stats.games(), 0);
+}
+
+#[test]
+fn versus_players_take_turns_and_the_faster_one_scores() {
+    let mut app = App::new(&audit_config());
+    // Versus comes right after Practice
+    for _ in 0..9 {
+        press(&mut app, KeyCode::Down);
+    }
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.source, Source::Hotseat);
+    assert!(shows(&app, "Pass the keyboard to Player 1"));
+    assert!(shows(&app, "Each player has a word of their own"));
+
+    // Before the first guess both can agree on the same word
+    press(&mut app, KeyCode::Char('s'));
+    let Mode::Hotseat(hotseat) = &mut app.mode else {
+        panic!("not a versus game");
+    };
+    assert!(hotseat.same_word);
+    assert_eq!(hotseat.waiting.game.target_word, app.game.target_word);
+    hotseat.waiting.game = Game::with_word("CRANE");
+    app.game = Game::with_word("CRANE");
+
+    // Player 1 misses, and the keyboard goes to player 2 with the boards
+    // hidden
+    press(&mut app, KeyCode::Enter);
+    type_word(&mut app, "slate");
+    assert!(shows(&app, "Pass the keyboard to Player 2"));
+    assert!(app.keyboard_keys.borrow().is_empty());
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.game.submitted().count(), 0);
+
+    // Player 2 finds it in as many guesses: player 1 can't catch up
+    type_word(&mut app, "crane");
+    assert!(shows(&app, "Player 2 wins the round!"));
+    let Mode::Hotseat(hotseat) = &app.mode else {
+        panic!("not a versus game");
+    };
+    assert_eq!(hotseat.scores, [0, 1]);
+
+    // The next round is started by player 2, and nothing counts
+    press(&mut app, KeyCode::Enter);
+    assert!(shows(&app, "Pass the keyboard to Player 2"));
+    assert_eq!(app.game.submitted().count(), 0);
+    assert_eq!(app.stats.games(), 0);
+}
+
+#[test]
+fn the_keymap_types_the_letters_printed_on_the_keys() {
+    let mut config: Config = toml::from_str(
+        r#"
+        [keymap]
+        system = "qwerty"
+        printed = "azerty"
+        remap = { "1" = "c" }
+        "#,
+    )
+    .unwrap();
+    config.language = Some("en".to_string());
+    audit_config();
+    let mut app = App::new(&config);
+    press(&mut app, KeyCode::Enter);
+    app.game = Game::with_word("SLATE");
+
+    // Q is where AZERTY has A, and the 1 key stands for C
+    for c in "1rqne".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    assert_eq!(app.game.attempts[0], ['C', 'R', 'A', 'N', 'E']);
+    // Pasted text is taken as it is
+    press(&mut app, KeyCode::Enter);
+    app.handle_event(Event::Paste("qa".to_string()));
+    assert_eq!(app.game.attempts[1], ['Q', 'A']);
+    // Shortcuts keep their keys
+    ctrl(&mut app, 'l');
+    assert!(app.show_legend);
+}
+
+#[test]
+fn a_shared_seed_gives_everyone_the_same_word() {
+    let args = Args::parse_from(["--seed".to_string(), "friday-race".to_string()]).unwrap();
+    let seed = args.seed.unwrap();
+    assert!(Args::parse_from(["--seed=".to_string()]).is_err());
+
+    let mut mine = App::new(&audit_config());
+    let mut theirs = App::new(&audit_config());
+    mine.play_seed(seed);
+    theirs.play_seed(seed);
+    assert_eq!(mine.game.target_word, theirs.game.target_word);
+    // The code is shown to be passed on
+    let code = seed_code(seed);
+    assert!(shows(&mine, &format!("Seed {}", code)));
+    assert_eq!(
+        Args::parse_from(["--seed".to_string(), code]).unwrap().seed,
+        Some(seed)
+    );
+}
+
+#[test]
+fn a_slow_guess_forfeits_its_row() {
+    let mut app = App::new(&audit_config());
+    // Timed guesses comes right after Blitz
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.mode, Mode::Countdown(_)));
+    assert!(shows(&app, "⏱ 20"));
+
+    // A guess in time starts the clock again on the next row
+    let miss = if app.game.target_word == "SLATE" {
+        "crane"
+    } else {
+        "slate"
+    };
+    type_word(&mut app, miss);
+    assert_eq!(app.game.current_attempt, 1);
+
+    // Once the clock runs out, the next tick gives up the row
+    press(&mut app, KeyCode::Char('c'));
+    app.mode = Mode::Countdown(Countdown::start(Duration::ZERO));
+    app.on_tick();
+    assert_eq!(app.game.current_attempt, 2);
+    assert_eq!(app.game.submitted().count(), 2);
+    assert!(shows(&app, "Too slow! Row lost"));
+}
+
+#[test]
+fn every_screen_is_reachable_from_the_keyboard() {
+    let mut app = audit_app();
+    assert!(shows(&app, "Focus: Board"));
+
+    // Side panels
+    ctrl(&mut app, 'l');
+    assert!(shows(&app, "Right letter, right spot"));
+    ctrl(&mut app, 'a');
+    assert!(shows(&app, "Possible words"));
+    ctrl(&mut app, 'l');
+    ctrl(&mut app, 'a');
+    assert!(!shows(&app, "Right letter, right spot"));
+    assert!(app.assistant.is_none());
+
+    // Hint
+    press(&mut app, KeyCode::Tab);
+    assert_eq!(app.game.hints_used, 1);
+
+    // Play to the end
+    type_word(&mut app, "react");
+    type_word(&mut app, "crane");
+    assert_eq!(app.game.status, GameStatus::Won);
+    assert!(shows(&app, "Focus: Board › Game over"));
+    // Scored as a common word, with a point for the momentum of CRANE
+    // after REACT's green
+    let played = app.stats.played.last().unwrap();
+    assert_eq!(played.momentum, 2);
+    assert_eq!(played.score(), 7 - played.guesses as u32 + 1);
+
+    // Analysis, from the menu of the finished game, and back to it
+    press(&mut app, KeyCode::Right);
+    press(&mut app, KeyCode::Right);
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.screen, Screen::Analysis(_)));
+    assert!(shows(&app, "Focus: Game analysis"));
+    assert!(shows(&app, "This word is  common "));
+    press(&mut app, KeyCode::Esc);
+    assert!(matches!(app.screen, Screen::Finished(2)));
+
+    // New game
+    press(&mut app, KeyCode::Char('p'));
+    assert_eq!(app.game.status, GameStatus::Playing);
+    assert_eq!(app.game.current_attempt, 0);
+
+    // Quit prompt, cancelled, then confirmed
+    press(&mut app, KeyCode::Esc);
+    assert!(shows(&app, "Focus: Board › Quit?"));
+    press(&mut app, KeyCode::Esc);
+    assert!(shows(&app, "Focus: Board"));
+    assert!(!app.should_quit);
+    press(&mut app, KeyCode::Esc);
+    press(&mut app, KeyCode::Enter);
+    assert!(app.should_quit);
+}
+
+#[test]
+fn focus_path_is_in_the_footer_of_every_layout() {
+    let app = audit_app();
+    for (width, height) in [(90, 40), (100, 20), (40, 22), (30, 8)] {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|f| wordle::ui::draw(f, &app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let footer: String = (0..width)
+            .map(|x| buffer.get(x, height - 1).symbol())
+            .collect();
+        assert!(
+            footer.contains("Focus: Board"),
+            "{}x{}: {:?}",
+            width,
+            height,
+            footer
+        );
+    }
+}
+
+#[test]
+fn polyglot_games_take_turns_with_the_languages() {
+    let mut config = audit_config();
+    config.polyglot = vec!["pt".to_string(), "xx".to_string(), "es".to_string()];
+    let mut app = App::new(&config);
+    // Polyglot comes right after Versus
+    for _ in 0..10 {
+        press(&mut app, KeyCode::Down);
+    }
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.mode, Mode::Polyglot(_)));
+
+    // Each word is drawn from the list of its language, with the
+    // interface in it
+    for code in ["PT", "ES", "PT"] {
+        assert_eq!(app.language.code().to_uppercase(), code);
+        assert!(shows(&app, &format!("▸ {} ◂", code)));
+        assert!(app.dictionary.spellings().contains(&app.game.display_word));
+        let answer = app.game.target_word.to_lowercase();
+        type_word(&mut app, &answer);
+        assert_eq!(app.game.status, GameStatus::Won);
+        press(&mut app, KeyCode::Esc);
+    }
+    assert_eq!(app.language.code(), "es");
+
+    // Another game goes back to the usual language
+    press(&mut app, KeyCode::F(10));
+    press(&mut app, KeyCode::Down);
+    press(&mut app, KeyCode::Enter);
+    assert!(matches!(app.mode, Mode::Classic));
+    assert_eq!(app.language.code(), "en");
+    assert!(app.dictionary.spellings().contains(&app.game.display_word));
+
+    // Statistics, seventh from the end, count each language apart
+    press(&mut app, KeyCode::F(10));
+    for _ in 0..7 {
+        press(&mut app, KeyCode::Up);
+    }
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Stats);
+    assert!(shows(&app, "By language"));
+    assert!(shows(&app, "PT: 2/2"));
+    assert!(shows(&app, "ES: 1/1"));
+}
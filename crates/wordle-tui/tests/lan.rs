@@ -0,0 +1,152 @@
+//! Races over the network, host and guest both in this process on the
+//! loopback interface.
+
+mod common;
+
+use std::net::TcpListener;
+use std::thread;
+use std::time::Duration;
+
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::type_word;
+use wordle::app::App;
+use wordle::cli::{Args, Lan};
+use wordle::config::Config;
+use wordle::i18n::Language;
+use wordle::mode::Mode;
+use wordle::net::{self, Race, RivalResult, DEFAULT_PORT};
+use wordle::GameStatus;
+
+fn config() -> Config {
+    common::data_home("lan");
+    common::english()
+}
+
+// A host and a guest connected to each other, the guest speaking `guest`
+fn connect(guest: Language) -> (anyhow::Result<Race>, anyhow::Result<Race>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let host = thread::spawn(move || net::host(&listener, Language::En));
+    let guest = net::join(&address, guest, net::DEFAULT_ROOM, "guest");
+    (host.join().unwrap(), guest)
+}
+
+fn race(app: &App) -> &Race {
+    match &app.mode {
+        Mode::Race(race) => race,
+        _ => panic!("not a race"),
+    }
+}
+
+// Ticks `app` until `done` holds of its race, for up to two seconds
+fn wait_for(app: &mut App, done: impl Fn(&Race) -> bool) {
+    for _ in 0..100 {
+        app.on_tick();
+        if done(race(app)) {
+            return;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    panic!("the rival never got there");
+}
+
+fn shows(app: &App, text: &str) -> bool {
+    let (width, height) = (90, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height).any(|y| {
+        (0..width)
+            .map(|x| buffer.get(x, y).symbol())
+            .collect::<String>()
+            .contains(text)
+    })
+}
+
+#[test]
+fn host_and_join_are_parsed() {
+    let args = |line: &str| Args::parse_from(line.split_whitespace().map(str::to_string));
+    assert_eq!(
+        args("host").unwrap().lan,
+        Some(Lan::Host { port: DEFAULT_PORT })
+    );
+    assert_eq!(
+        args("host --port 5000").unwrap().lan,
+        Some(Lan::Host { port: 5000 })
+    );
+    assert_eq!(
+        args("join 192.168.1.20").unwrap().lan,
+        Some(Lan::Join {
+            address: "192.168.1.20".to_string(),
+            room: None,
+            name: None,
+        })
+    );
+    assert_eq!(
+        args("join 192.168.1.20:5000 --room friday --name ana")
+            .unwrap()
+            .lan,
+        Some(Lan::Join {
+            address: "192.168.1.20:5000".to_string(),
+            room: Some("friday".to_string()),
+            name: Some("ana".to_string()),
+        })
+    );
+    assert!(args("join").is_err());
+    assert!(args("host --room friday").is_err());
+    assert!(args("--port 5000").is_err());
+}
+
+#[test]
+fn a_guest_with_another_word_list_cant_join() {
+    let (_, guest) = connect(Language::Pt);
+    let error = guest.unwrap_err().to_string();
+    assert!(error.contains("--lang en"), "{}", error);
+}
+
+#[test]
+fn rivals_see_each_others_colors_but_not_letters() {
+    let (host, guest) = connect(Language::En);
+    let (host, guest) = (host.unwrap(), guest.unwrap());
+    assert_eq!(host.seed, guest.seed);
+
+    let config = config();
+    let mut host_app = App::new(&config);
+    let mut guest_app = App::new(&config);
+    host_app.play_race(host);
+    guest_app.play_race(guest);
+    let answer = host_app.game.target_word.clone();
+    assert_eq!(guest_app.game.target_word, answer);
+
+    // The host misses: the guest sees the row, not the word typed
+    let miss = host_app
+        .words
+        .iter()
+        .find(|word| **word != answer)
+        .unwrap()
+        .clone();
+    type_word(&mut host_app, &miss);
+    wait_for(&mut guest_app, |race| race.rivals[0].rows.len() == 1);
+    assert!(shows(&guest_app, "Rival"));
+    assert!(shows(&guest_app, "Still guessing"));
+    assert!(!shows(&guest_app, &miss));
+
+    // The guest finds it at once
+    type_word(&mut guest_app, &answer);
+    assert_eq!(guest_app.game.status, GameStatus::Won);
+    wait_for(&mut host_app, |race| race.rivals[0].result.is_some());
+    assert_eq!(
+        race(&host_app).rivals[0].result,
+        Some(RivalResult {
+            won: true,
+            guesses: 1
+        })
+    );
+    assert!(shows(&host_app, "Found it in 1"));
+
+    // Leaving the race closes the connection
+    drop(guest_app);
+    wait_for(&mut host_app, |race| race.disconnected);
+    assert!(shows(&host_app, "Found it in 1"));
+}
@@ -0,0 +1,130 @@
+//! The leaderboard: which games count where, how bests are kept and
+//! sorted, and its screen.
+
+mod common;
+
+use std::time::Duration;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::press;
+use wordle::app::{App, View};
+use wordle::history::Source;
+use wordle::leaderboard::{Category, Column, Leaderboard, Sort};
+use wordle::mode::Mode;
+use wordle::{FeedbackStyle, Game, GameStatus};
+
+fn shows(app: &App, text: &str) -> bool {
+    let (width, height) = (90, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height).any(|y| {
+        (0..width)
+            .map(|x| buffer.get(x, y).symbol())
+            .collect::<String>()
+            .contains(text)
+    })
+}
+
+#[test]
+fn games_count_by_mode() {
+    let letters = FeedbackStyle::Letters;
+    let of = |source| Category::of(&Mode::Classic, source, letters);
+    assert_eq!(of(Source::Random), Some(Category::Classic));
+    assert_eq!(of(Source::Community), Some(Category::Classic));
+    assert_eq!(of(Source::Daily), Some(Category::Daily));
+    assert_eq!(of(Source::Zen), Some(Category::Zen));
+    assert_eq!(
+        Category::of(&Mode::Classic, Source::Random, FeedbackStyle::Counts),
+        Some(Category::Mastermind)
+    );
+    // Nothing that can be undone, nor a word someone else chose
+    assert_eq!(of(Source::Practice), None);
+    assert_eq!(of(Source::Challenge), None);
+    assert_eq!(of(Source::Sent), None);
+}
+
+#[test]
+fn bests_are_kept_and_sorted() {
+    let mut board = Leaderboard::default();
+    let seconds = |s| Some(Duration::from_secs(s));
+    board.submit(Category::Classic, 5, 4, seconds(90)).unwrap();
+    board.submit(Category::Classic, 5, 3, seconds(120)).unwrap();
+    board.submit(Category::Classic, 5, 5, seconds(60)).unwrap();
+    board.submit(Category::Warmup, 1, 7, None).unwrap();
+    board.submit(Category::Daily, 5, 2, seconds(30)).unwrap();
+
+    let classic = &board.entries[0];
+    assert_eq!(classic.wins, 3);
+    assert_eq!(classic.fewest_guesses, 3);
+    assert_eq!(classic.best_time(), seconds(60));
+
+    let order =
+        |sort: Sort| -> Vec<Category> { board.sorted(sort).iter().map(|e| e.category).collect() };
+    let by = |column, reversed| Sort { column, reversed };
+    assert_eq!(
+        order(Sort::default()),
+        vec![Category::Classic, Category::Daily, Category::Warmup]
+    );
+    assert_eq!(
+        order(by(Column::Wins, false)),
+        vec![Category::Classic, Category::Daily, Category::Warmup]
+    );
+    assert_eq!(
+        order(by(Column::Guesses, false)),
+        vec![Category::Daily, Category::Classic, Category::Warmup]
+    );
+    assert_eq!(
+        order(by(Column::Length, false)),
+        vec![Category::Warmup, Category::Classic, Category::Daily]
+    );
+    // Untimed bests stay last either way
+    assert_eq!(
+        order(by(Column::Time, false)),
+        vec![Category::Daily, Category::Classic, Category::Warmup]
+    );
+    assert_eq!(
+        order(by(Column::Time, true)),
+        vec![Category::Classic, Category::Daily, Category::Warmup]
+    );
+}
+
+#[test]
+fn won_games_reach_the_leaderboard_screen() {
+    let mut app = common::app("leaderboard");
+    press(&mut app, KeyCode::Enter);
+    app.game = Game::with_word("CRANE");
+    for c in "crane".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.game.status, GameStatus::Won);
+    let entry = app
+        .leaderboard
+        .entries
+        .iter()
+        .find(|entry| entry.category == Category::Classic)
+        .unwrap();
+    assert_eq!(entry.fewest_guesses, 1);
+    assert!(entry.best_time().is_some());
+
+    // Fifth from the end of the menu
+    press(&mut app, KeyCode::F(10));
+    for _ in 0..5 {
+        press(&mut app, KeyCode::Up);
+    }
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Leaderboard(Sort::default()));
+    assert!(shows(&app, "Mode ▼"));
+    assert!(shows(&app, "Classic"));
+    press(&mut app, KeyCode::Right);
+    press(&mut app, KeyCode::Right);
+    assert!(shows(&app, "Wins ▼"));
+    press(&mut app, KeyCode::Char(' '));
+    assert!(shows(&app, "Wins ▲"));
+    assert!(shows(&app, "[Space] Reverse"));
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.view, View::Menu(0));
+}
@@ -0,0 +1,98 @@
+//! Linear mode for screen readers: guesses read a line at a time, and
+//! what became of each told in words.
+
+mod common;
+
+use std::io::Cursor;
+
+use wordle::app::App;
+use wordle::cli::Args;
+use wordle::linear;
+use wordle::LetterStatus;
+
+fn app() -> App {
+    let mut app = common::app("linear");
+    app.play_random();
+    app
+}
+
+fn play(app: &mut App, input: &str) -> Vec<String> {
+    let mut output = Vec::new();
+    linear::play(app, Cursor::new(input.to_string()), &mut output).unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn no_tui_is_parsed() {
+    let args = |line: &str| Args::parse_from(line.split_whitespace().map(str::to_string));
+    assert!(args("--no-tui").unwrap().no_tui);
+    assert!(!args("--lang en").unwrap().no_tui);
+    assert!(args("--no-tui host").is_err());
+    assert!(args("join 192.168.1.20 --no-tui").is_err());
+}
+
+#[test]
+fn each_letter_is_told_in_words() {
+    let mut app = app();
+    let answer = app.game.target_word.clone();
+    let miss = app
+        .words
+        .iter()
+        .find(|word| **word != answer)
+        .unwrap()
+        .clone();
+    let lines = play(&mut app, &format!("{}\nquit\n", miss.to_lowercase()));
+    assert_eq!(
+        lines[0],
+        "Guess the word: type a guess and press Enter. Type quit to leave."
+    );
+    let told: Vec<_> = lines[1]
+        .strip_prefix("Guess 1/6: ")
+        .unwrap()
+        .split(", ")
+        .collect();
+    assert_eq!(told.len(), 5);
+    for ((said, letter), status) in told
+        .iter()
+        .zip(miss.chars())
+        .zip(app.game.row_statuses(0).unwrap())
+    {
+        let status = match status {
+            LetterStatus::Correct => "correct",
+            LetterStatus::Present => "present",
+            _ => "absent",
+        };
+        assert_eq!(*said, format!("{} {}", letter, status));
+    }
+    assert_eq!(lines[2], "Guess 2/6: ");
+}
+
+#[test]
+fn refused_guesses_say_why_and_the_game_goes_on() {
+    let mut app = app();
+    let answer = app.game.target_word.clone();
+    let lines = play(
+        &mut app,
+        &format!("abc\ntoolong\n{}\nn\n", answer.to_lowercase()),
+    );
+    assert_eq!(lines[1], "Guess 1/6: Not enough letters");
+    assert_eq!(lines[2], "Guess 1/6: Too many letters");
+    let told: Vec<_> = answer.chars().map(|c| format!("{} correct", c)).collect();
+    assert_eq!(lines[3], format!("Guess 1/6: {}", told.join(", ")));
+    assert_eq!(lines[4], "You won!");
+    assert_eq!(lines[5], "Play again? (y/n) ");
+    assert_eq!(app.game.submitted().count(), 1);
+}
+
+#[test]
+fn another_game_starts_on_yes() {
+    let mut app = app();
+    let answer = app.game.target_word.clone();
+    let lines = play(&mut app, &format!("{}\ny\n", answer.to_lowercase()));
+    assert_eq!(lines.last().unwrap(), "Play again? (y/n) Guess 1/6: ");
+    assert_eq!(app.game.submitted().count(), 0);
+}
@@ -0,0 +1,114 @@
+//! The idle lock: the game in progress saved and hidden after a while
+//! without input, the passphrase that unlocks it, and the saved game played
+//! again on the next start.
+
+mod common;
+
+use std::time::Duration;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::press;
+use wordle::app::{App, View};
+use wordle::config::Config;
+use wordle::history::Source;
+
+fn config(lock: &str) -> Config {
+    // The saved game goes to a scratch directory, not the real one
+    common::data_home("lock");
+    toml::from_str(&format!("language = \"en\"\n[lock]\n{}", lock)).unwrap()
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        press(app, KeyCode::Char(c));
+    }
+}
+
+// The rendered screen, as one string
+fn screen(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(90, 40)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..40)
+        .map(|y| {
+            (0..90)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+// Every app is made in the one test, as each start picks up the saved
+// game
+#[test]
+fn an_idle_game_is_saved_locked_and_played_again() {
+    let app = App::new(&config("passphrase = \"x\"\n"));
+    assert_eq!(app.idle_timeout, None);
+    assert!(app.has_passphrase());
+
+    let config = config("idle_minutes = 1\npassphrase = \"open sesame\"\n");
+    let mut app = App::new(&config);
+    assert_eq!(app.idle_timeout, Some(Duration::from_secs(60)));
+    app.play_seed(42);
+    let word = app.game.display_word.clone();
+    type_text(&mut app, "slate");
+    press(&mut app, KeyCode::Enter);
+    type_text(&mut app, "cr");
+
+    app.on_tick();
+    assert!(app.lock.is_none());
+    app.idle_timeout = Some(Duration::ZERO);
+    app.on_tick();
+    let lock = app.lock.clone().expect("the screen didn't lock");
+    assert_eq!(lock.saved, Some(true));
+    let shown = screen(&app);
+    assert!(shown.contains("Locked"));
+    assert!(shown.contains("The game was saved"));
+    assert!(!shown.contains(app.texts().title));
+    assert_eq!(app.focus_path(), vec!["Locked"]);
+
+    // Keys go to the passphrase, not to the game
+    type_text(&mut app, "wrong");
+    assert!(screen(&app).contains("•••••"));
+    press(&mut app, KeyCode::Enter);
+    assert!(app.lock.as_ref().unwrap().wrong);
+    assert_eq!(app.game.attempts[1], vec!['C', 'R']);
+
+    // Started again, the game is back as it was left
+    let mut restarted = App::new(&config);
+    assert_eq!(restarted.view, View::Game);
+    assert_eq!(restarted.source, Source::Random);
+    assert_eq!(restarted.game.display_word, word);
+    assert_eq!(restarted.game.seed, Some(42));
+    assert_eq!(restarted.game.current_attempt, 1);
+    assert_eq!(
+        restarted.game.attempts[0],
+        "SLATE".chars().collect::<Vec<_>>()
+    );
+    assert_eq!(restarted.game.attempts[1], vec!['C', 'R']);
+    assert_eq!(restarted.candidate_history.len(), 1);
+    type_text(&mut restarted, "a");
+    assert_eq!(restarted.game.attempts[1], vec!['C', 'R', 'A']);
+    // ... and only once
+    assert_eq!(App::new(&config).game.attempts[0], Vec::<char>::new());
+
+    type_text(&mut app, "open sesame");
+    press(&mut app, KeyCode::Enter);
+    assert!(app.lock.is_none());
+    assert_eq!(app.view, View::Game);
+
+    let mut app = App::new(&common::english());
+    assert!(!app.has_passphrase());
+    app.start_blitz();
+    app.lock_screen();
+    // Timed modes aren't saved
+    assert_eq!(app.lock.as_ref().unwrap().saved, None);
+    assert!(screen(&app).contains("Press any key to resume"));
+
+    press(&mut app, KeyCode::Char('q'));
+    assert!(app.lock.is_none());
+    assert!(app.game.attempts[0].is_empty());
+}
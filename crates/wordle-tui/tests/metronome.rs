@@ -0,0 +1,69 @@
+//! The metronome of the timed modes: a dot in the corner of the clock line
+//! pulsing with each second, kept still by reduced motion.
+
+mod common;
+
+use std::time::Duration;
+
+use ratatui::{backend::TestBackend, Terminal};
+
+use wordle::app::App;
+use wordle::mode::{Blitz, Countdown, Mode, Speedrun};
+
+fn screen(app: &App) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..40)
+        .map(|y| (0..100).map(|x| buffer.get(x, y).symbol()).collect())
+        .collect()
+}
+
+// The dot at the end of the clock line, if there is one
+fn dot(app: &App) -> Option<String> {
+    screen(app)
+        .into_iter()
+        .map(|line| line.trim_end().to_string())
+        .find(|line| line.ends_with('●') || line.ends_with('○'))
+        .map(|line| line.chars().last().unwrap().to_string())
+}
+
+#[test]
+fn the_beat_follows_the_clock() {
+    // The digits of a countdown change as it goes past a whole second
+    let beat = |remaining: u64| Mode::Blitz(Blitz::start(Duration::from_millis(remaining))).beat();
+    assert_eq!(beat(60_900), Some(true));
+    assert_eq!(beat(60_300), Some(false));
+    assert_eq!(beat(0), None);
+    let countdown = Mode::Countdown(Countdown::start(Duration::from_millis(9_800)));
+    assert_eq!(countdown.beat(), Some(true));
+
+    let mut run = Speedrun::new(None);
+    assert_eq!(Mode::Speedrun(run.clone()).beat(), None);
+    run.start();
+    assert_eq!(Mode::Speedrun(run.clone()).beat(), Some(true));
+    run.stop();
+    assert_eq!(Mode::Speedrun(run).beat(), None);
+    assert_eq!(Mode::Classic.beat(), None);
+}
+
+#[test]
+fn the_dot_pulses_in_timed_modes_only() {
+    let mut config = common::english();
+    let mut app = common::app_with("metronome", &config);
+    assert_eq!(dot(&app), None);
+    app.start_blitz();
+    assert!(dot(&app).is_some());
+    app.start_countdown();
+    assert!(dot(&app).is_some());
+    app.start_speedrun();
+    // Still until the first letter starts the clock
+    assert_eq!(dot(&app), None);
+
+    config.display.reduced_motion = Some(true);
+    let mut app = common::app_with("metronome", &config);
+    app.start_blitz();
+    assert_eq!(dot(&app), None);
+    app.start_countdown();
+    assert_eq!(dot(&app), None);
+}
@@ -0,0 +1,169 @@
+//! The online leaderboard: opting in, reading its answer, and (with the
+//! `online-leaderboard` feature) a daily result shared with a server on
+//! the loopback interface.
+
+use wordle::config::{Config, OnlineConfig};
+use wordle::online::{self, Online};
+
+#[test]
+fn nothing_is_shared_without_opting_in() {
+    let config: Config = toml::from_str(
+        "[online]\nleaderboard_url = \"http://127.0.0.1:9/daily\"\nname = \"ana\"\n",
+    )
+    .unwrap();
+    assert!(!config.online.submit_daily);
+    assert_eq!(Online::from_config(&config.online), None);
+    assert_eq!(Online::from_config(&OnlineConfig::default()), None);
+
+    let opted_in = OnlineConfig {
+        submit_daily: true,
+        ..config.online
+    };
+    let expected = online::AVAILABLE.then(|| Online {
+        url: "http://127.0.0.1:9/daily".to_string(),
+        name: "ana".to_string(),
+    });
+    assert_eq!(Online::from_config(&opted_in), expected);
+}
+
+#[test]
+fn the_best_ten_are_kept() {
+    let mut json = String::from("[");
+    for guesses in (1..=6).rev() {
+        json += &format!(
+            r#"{{"name": "p{}", "won": true, "guesses": {}, "time_ms": {}}},"#,
+            guesses,
+            guesses,
+            1000 * guesses
+        );
+        json += &format!(
+            r#"{{"name": "s{}", "won": true, "guesses": {}}},"#,
+            guesses, guesses
+        );
+    }
+    json += r#"{"name": "lost", "won": false, "guesses": 6, "time_ms": 1}]"#;
+
+    let top = online::top(&json).unwrap();
+    let names: Vec<&str> = top.iter().map(|score| score.name.as_str()).collect();
+    // Untimed results go after the timed ones with as many guesses
+    assert_eq!(
+        names,
+        vec!["p1", "s1", "p2", "s2", "p3", "s3", "p4", "s4", "p5", "s5"]
+    );
+    assert!(online::top("{}").is_err());
+}
+
+#[cfg(feature = "online-leaderboard")]
+#[test]
+fn a_daily_result_is_shared() {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    use wordle::GameStatus;
+
+    // Answers one POST and one GET, then hands back what was posted and
+    // the query of the GET
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/daily", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for stream in listener.incoming().take(2) {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let mut length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some(value) = header.to_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let answer = if line.starts_with("POST") {
+                String::new()
+            } else {
+                r#"[{"name": "bia", "won": true, "guesses": 2, "time_ms": 30000},
+                    {"name": "ana", "won": true, "guesses": 1, "time_ms": 9000}]"#
+                    .to_string()
+            };
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                answer.len(),
+                answer
+            )
+            .unwrap();
+            requests.push((line, String::from_utf8(body).unwrap()));
+        }
+        requests
+    });
+
+    std::env::set_var(
+        "XDG_DATA_HOME",
+        std::env::temp_dir().join(format!("wordle-online-{}", std::process::id())),
+    );
+    let config = Config {
+        language: Some("en".to_string()),
+        online: OnlineConfig {
+            leaderboard_url: Some(url),
+            submit_daily: true,
+            name: Some("ana".to_string()),
+        },
+        ..Config::default()
+    };
+    let mut app = wordle::app::App::new(&config);
+    app.play_daily();
+    let answer = app.game.target_word.to_lowercase();
+    for c in answer.chars() {
+        app.handle_event(Event::Key(KeyEvent::new(
+            KeyCode::Char(c),
+            KeyModifiers::NONE,
+        )));
+    }
+    app.handle_event(Event::Key(KeyEvent::new(
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+    )));
+    assert_eq!(app.game.status, GameStatus::Won);
+
+    let started = Instant::now();
+    while app.online_top.is_none() && started.elapsed() < Duration::from_secs(5) {
+        app.on_tick();
+        thread::sleep(Duration::from_millis(10));
+    }
+    let top = app.online_top.clone().unwrap();
+    assert_eq!(top[0].name, "ana");
+    assert_eq!(top[1].name, "bia");
+
+    let requests = server.join().unwrap();
+    let (post, body) = &requests[0];
+    assert!(post.starts_with("POST /daily "), "{}", post);
+    let sent: serde_json::Value = serde_json::from_str(body).unwrap();
+    assert_eq!(sent["puzzle"], app.game.seed.unwrap());
+    assert_eq!(sent["language"], "en");
+    assert_eq!(sent["name"], "ana");
+    assert_eq!(sent["won"], true);
+    assert_eq!(sent["guesses"], 1);
+    assert_eq!(
+        sent["typing"][0]["intervals_ms"].as_array().unwrap().len(),
+        4
+    );
+    let (get, _) = &requests[1];
+    assert!(
+        get.starts_with(&format!(
+            "GET /daily?puzzle={}&language=en ",
+            sent["puzzle"]
+        )),
+        "{}",
+        get
+    );
+}
@@ -0,0 +1,110 @@
+//! The status line for streaming overlays: what it says of the game, and
+//! the file it is kept in.
+
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+
+use common::type_word;
+use wordle::app::View;
+use wordle::cli::Args;
+use wordle::overlay::{self, Overlay};
+
+fn file(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wordle-overlay-files-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+#[test]
+fn overlay_file_is_parsed() {
+    let args = |line: &str| Args::parse_from(line.split_whitespace().map(str::to_string));
+    assert_eq!(
+        args("--overlay-file /tmp/line.txt").unwrap().overlay_file,
+        Some(PathBuf::from("/tmp/line.txt"))
+    );
+    assert!(args("--overlay-file").is_err());
+}
+
+#[test]
+fn the_line_follows_each_guess_without_giving_letters_away() {
+    let path = file("daily.txt");
+    let mut app = common::app("overlay");
+    app.overlay = Some(Overlay::create(path.clone()).unwrap());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "\n");
+
+    app.play_daily();
+    app.view = View::Game;
+    let day = app.game.seed.unwrap();
+    app.on_tick();
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        format!("Daily #{} — attempt 1/6\n", day)
+    );
+
+    let answer = app.game.target_word.clone();
+    let miss = app
+        .words
+        .iter()
+        .find(|word| **word != answer)
+        .unwrap()
+        .clone();
+    type_word(&mut app, &miss);
+    let line = fs::read_to_string(&path).unwrap();
+    assert!(
+        line.starts_with(&format!("Daily #{} — attempt 2/6 — ", day)),
+        "{}",
+        line
+    );
+    assert_eq!(
+        line.trim_end()
+            .rsplit(" — ")
+            .next()
+            .unwrap()
+            .chars()
+            .count(),
+        5
+    );
+    assert!(!line.contains(&miss) && !line.contains(&answer));
+
+    type_word(&mut app, &answer);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        format!("Daily #{} — solved 2/6 — 🟩🟩🟩🟩🟩\n", day)
+    );
+    // Nothing left half written beside it
+    assert!(!file("daily.txt.tmp").exists());
+}
+
+#[test]
+fn games_on_a_seed_keep_it_to_themselves() {
+    let path = file("seed.txt");
+    let mut app = common::app("overlay");
+    app.overlay = Some(Overlay::create(path.clone()).unwrap());
+    app.play_seed(1234);
+    app.on_tick();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "Wordle — attempt 1/6\n");
+
+    assert!(Overlay::create(PathBuf::from("/nowhere/at/all/line.txt")).is_err());
+}
+
+#[test]
+fn the_attempt_is_the_guess_being_played() {
+    let mut app = common::app("overlay");
+    app.play_seed(1234);
+    let answer = app.game.target_word.clone();
+    let misses: Vec<String> = app
+        .words
+        .iter()
+        .filter(|word| **word != answer)
+        .take(2)
+        .cloned()
+        .collect();
+    for miss in &misses {
+        type_word(&mut app, miss);
+    }
+    // Two guesses made, the third being played
+    let line = overlay::line("Wordle", &app.game, false);
+    assert!(line.starts_with("Wordle — attempt 3/6 — "), "{}", line);
+}
@@ -0,0 +1,238 @@
+//! Packs installed from an index, served from this process: their
+//! signatures, the trusted keys and the trust policy.
+
+// The lookups served from here need the `http` feature, and so do their
+// helpers
+#![cfg_attr(not(feature = "http"), allow(unused))]
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread;
+
+use wordle::cli::{Args, Command};
+use wordle::config::{Config, PacksConfig, TrustPolicy};
+use wordle::packs::{self, Verdict};
+use wordle::word_themes;
+
+const WORDS: &str = "# birds\nrobin\nheron\nstork\n";
+const METADATA: &str = "name = \"Birds\"\nlanguage = \"en\"\n";
+
+fn temp(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wordle-packs-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+// A signed pack in files of its own, and the public key that signed it
+fn signed(name: &str, key: &str) -> (String, String) {
+    let words = temp(&format!("{}.txt", name));
+    fs::write(&words, WORDS).unwrap();
+    fs::write(words.with_extension("toml"), METADATA).unwrap();
+    packs::sign(&words, &temp(key)).unwrap()
+}
+
+// Serves `files` by path over HTTP for as long as the tests run
+fn serve(listener: TcpListener, files: HashMap<&'static str, String>) {
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+            }
+            let path = line.split_whitespace().nth(1).unwrap_or("");
+            match files.get(path) {
+                Some(body) => write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                ),
+                None => write!(
+                    stream,
+                    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                ),
+            }
+            .unwrap();
+        }
+    });
+}
+
+#[test]
+fn the_trust_policy_is_read_from_the_config() {
+    let config: Config = toml::from_str(
+        "[packs]\nindex_url = \"https://example.org/packs.json\"\ntrusted_keys = [\"ab\"]\ntrust = \"require\"",
+    )
+    .unwrap();
+    assert_eq!(config.packs.trust, TrustPolicy::Require);
+    assert_eq!(config.packs.trusted_keys, vec!["ab".to_string()]);
+    assert_eq!(Config::default().packs.trust, TrustPolicy::Warn);
+    assert!(toml::from_str::<Config>("[packs]\ntrust = \"maybe\"").is_err());
+
+    let args = |line: &str| Args::parse_from(line.split_whitespace().map(str::to_string));
+    assert_eq!(
+        args("pack install birds").unwrap().command,
+        Some(Command::InstallPack("birds".to_string()))
+    );
+    assert_eq!(args("pack list").unwrap().command, Some(Command::ListPacks));
+    assert!(args("pack install").is_err());
+    assert!(args("pack sign birds.txt").is_err());
+}
+
+#[test]
+fn signatures_are_checked_against_the_trusted_keys() {
+    let (signature, key) = signed("checked", "checked.key");
+    let (_, other_key) = signed("other", "other.key");
+    let config = |keys: &[&str], trust| PacksConfig {
+        index_url: None,
+        trusted_keys: keys.iter().map(|key| key.to_string()).collect(),
+        trust,
+    };
+    let verdict = |config: &PacksConfig, words: &str, signature: Option<&str>| {
+        packs::verify(
+            config,
+            "checked",
+            words.as_bytes(),
+            METADATA.as_bytes(),
+            signature,
+        )
+        .unwrap()
+    };
+
+    let trusting = config(&[&other_key, &key], TrustPolicy::Warn);
+    assert_eq!(
+        verdict(&trusting, WORDS, Some(&signature)),
+        Verdict::Trusted(1)
+    );
+    assert_eq!(verdict(&trusting, WORDS, None), Verdict::Unsigned);
+    // Changed on the way, or signed by someone else
+    assert_eq!(
+        verdict(&trusting, "robin\nraven\n", Some(&signature)),
+        Verdict::Untrusted
+    );
+    assert_eq!(
+        verdict(
+            &config(&[&other_key], TrustPolicy::Warn),
+            WORDS,
+            Some(&signature)
+        ),
+        Verdict::Untrusted
+    );
+    assert_eq!(
+        verdict(&trusting, WORDS, Some("not hex at all")),
+        Verdict::Untrusted
+    );
+    assert_eq!(
+        verdict(&config(&[], TrustPolicy::Off), WORDS, Some("whatever")),
+        Verdict::Unchecked
+    );
+    assert!(packs::verify(
+        &config(&["0123"], TrustPolicy::Warn),
+        "checked",
+        WORDS.as_bytes(),
+        b"",
+        None
+    )
+    .is_err());
+}
+
+#[test]
+fn the_signature_holds_the_files_apart_and_names_the_pack() {
+    let (signature, key) = signed("framed", "framed.key");
+    let config = PacksConfig {
+        index_url: None,
+        trusted_keys: vec![key],
+        trust: TrustPolicy::Warn,
+    };
+    let verdict = |name: &str, words: &str, metadata: &str| {
+        packs::verify(
+            &config,
+            name,
+            words.as_bytes(),
+            metadata.as_bytes(),
+            Some(&signature),
+        )
+        .unwrap()
+    };
+    assert_eq!(verdict("framed", WORDS, METADATA), Verdict::Trusted(0));
+    // The same bytes, split elsewhere between the two files
+    let (words, metadata) = WORDS.split_at(WORDS.len() - 6);
+    assert_eq!(
+        verdict("framed", words, &format!("{}{}", metadata, METADATA)),
+        Verdict::Untrusted
+    );
+    assert_eq!(
+        verdict("framed", &format!("{}{}", WORDS, METADATA), ""),
+        Verdict::Untrusted
+    );
+    // The same files under another name
+    assert_eq!(verdict("birds", WORDS, METADATA), Verdict::Untrusted);
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn packs_are_installed_as_the_policy_says() {
+    std::env::set_var(
+        "XDG_DATA_HOME",
+        std::env::temp_dir().join(format!("wordle-packs-data-{}", std::process::id())),
+    );
+    let (signature, key) = signed("birds", "birds.key");
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let index = format!(
+        r#"{{"packs": [
+            {{"name": "birds", "words": "{0}/birds.txt", "metadata": "{0}/birds.toml",
+              "signature": "{1}"}},
+            {{"name": "loose", "words": "{0}/birds.txt"}},
+            {{"name": "../escape", "words": "{0}/birds.txt"}}
+        ]}}"#,
+        address, signature
+    );
+    serve(
+        listener,
+        HashMap::from([
+            ("/index.json", index),
+            ("/birds.txt", WORDS.to_string()),
+            ("/birds.toml", METADATA.to_string()),
+        ]),
+    );
+    let config = |trust| PacksConfig {
+        index_url: Some(format!("{}/index.json", address)),
+        trusted_keys: vec![key.clone()],
+        trust,
+    };
+
+    let names: Vec<_> = packs::fetch_index(&config(TrustPolicy::Warn))
+        .unwrap()
+        .into_iter()
+        .map(|pack| pack.name)
+        .collect();
+    assert_eq!(names, ["birds", "loose", "../escape"]);
+
+    let error = packs::install(&config(TrustPolicy::Require), "loose")
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("trusted key"), "{}", error);
+    assert!(packs::install(&config(TrustPolicy::Warn), "../escape").is_err());
+    assert!(packs::install(&config(TrustPolicy::Warn), "missing").is_err());
+
+    let installed = packs::install(&config(TrustPolicy::Require), "birds").unwrap();
+    assert_eq!(installed.verdict, Verdict::Trusted(0));
+    assert_eq!(fs::read_to_string(&installed.path).unwrap(), WORDS);
+    let loose = packs::install(&config(TrustPolicy::Warn), "loose").unwrap();
+    assert_eq!(loose.verdict, Verdict::Unsigned);
+
+    let themes = word_themes::available();
+    let birds = themes.iter().find(|theme| theme.id == "birds").unwrap();
+    assert_eq!(birds.name(wordle::i18n::Language::En), "Birds");
+    assert!(themes.iter().any(|theme| theme.id == "loose"));
+}
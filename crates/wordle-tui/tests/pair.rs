@@ -0,0 +1,181 @@
+//! Pairs over the network, host and partner both in this process on the
+//! loopback interface.
+
+mod common;
+
+use std::net::TcpListener;
+use std::thread;
+use std::time::Duration;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::press;
+use wordle::app::App;
+use wordle::cli::{Args, Lan};
+use wordle::config::Config;
+use wordle::i18n::Language;
+use wordle::mode::Mode;
+use wordle::net::{self, Pair, DEFAULT_PORT};
+use wordle::GameStatus;
+
+fn config() -> Config {
+    common::data_home("pair");
+    common::english()
+}
+
+fn type_letters(app: &mut App, letters: &str) {
+    for c in letters.chars() {
+        press(app, KeyCode::Char(c));
+    }
+}
+
+fn pair(app: &App) -> &Pair {
+    match &app.mode {
+        Mode::Pair(pair) => pair,
+        _ => panic!("not a pair"),
+    }
+}
+
+// Ticks `app` until `done` holds of it, for up to two seconds
+fn wait_for(app: &mut App, done: impl Fn(&App) -> bool) {
+    for _ in 0..100 {
+        app.on_tick();
+        if done(app) {
+            return;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    panic!("the partner never got there");
+}
+
+fn shows(app: &App, text: &str) -> bool {
+    let (width, height) = (90, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height).any(|y| {
+        (0..width)
+            .map(|x| buffer.get(x, y).symbol())
+            .collect::<String>()
+            .contains(text)
+    })
+}
+
+#[test]
+fn pair_host_and_join_are_parsed() {
+    let args = |line: &str| Args::parse_from(line.split_whitespace().map(str::to_string));
+    assert_eq!(
+        args("pair host --port 5000").unwrap().lan,
+        Some(Lan::PairHost { port: 5000 })
+    );
+    assert_eq!(
+        args("pair host").unwrap().lan,
+        Some(Lan::PairHost { port: DEFAULT_PORT })
+    );
+    assert_eq!(
+        args("pair join 192.168.1.20").unwrap().lan,
+        Some(Lan::PairJoin {
+            address: "192.168.1.20".to_string(),
+        })
+    );
+    assert!(args("pair").is_err());
+    assert!(args("pair join").is_err());
+    assert!(args("pair join 192.168.1.20 --port 5000").is_err());
+}
+
+#[test]
+fn races_and_pairs_dont_mix() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let host = thread::spawn(move || net::pair_host(&listener, Language::En));
+    let error = net::join(&address, Language::En, net::DEFAULT_ROOM, "guest")
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("pair join"), "{}", error);
+    host.join().unwrap().unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let host = thread::spawn(move || net::host(&listener, Language::En));
+    let error = net::pair_join(&address, Language::En)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("wordle join"), "{}", error);
+    host.join().unwrap().unwrap();
+}
+
+#[test]
+fn partners_see_each_others_typing_and_take_turns_to_submit() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let host = thread::spawn(move || net::pair_host(&listener, Language::En));
+    let partner = net::pair_join(&address, Language::En).unwrap();
+    let host = host.join().unwrap().unwrap();
+    assert_eq!(host.seed, partner.seed);
+
+    let config = config();
+    let mut host_app = App::new(&config);
+    let mut partner_app = App::new(&config);
+    host_app.play_pair(host);
+    partner_app.play_pair(partner);
+    let answer = host_app.game.target_word.clone();
+    assert_eq!(partner_app.game.target_word, answer);
+    assert!(pair(&host_app).our_turn);
+    assert!(!pair(&partner_app).our_turn);
+    assert!(shows(&host_app, "You submit"));
+    assert!(shows(&partner_app, "Partner submits"));
+
+    // What the host types shows on the partner's board as it is typed
+    let miss = host_app
+        .words
+        .iter()
+        .find(|word| **word != answer)
+        .unwrap()
+        .to_lowercase();
+    type_letters(&mut host_app, &miss[..2]);
+    wait_for(&mut partner_app, |app| pair(app).typing.len() == 2);
+    assert_eq!(
+        pair(&partner_app).typing,
+        miss[..2].to_uppercase().chars().collect::<Vec<_>>()
+    );
+    assert!(shows(
+        &partner_app,
+        &format!("Partner {}", miss[..2].to_uppercase())
+    ));
+
+    // Without the turn, the partner can type but not submit
+    type_letters(&mut partner_app, &answer.to_lowercase());
+    press(&mut partner_app, KeyCode::Enter);
+    assert_eq!(partner_app.game.submitted().count(), 0);
+    assert_eq!(
+        partner_app.game.message.as_deref(),
+        Some("Your partner submits this guess")
+    );
+
+    // The host submits: the guess is played on both boards, and the turn
+    // goes over
+    type_letters(&mut host_app, &miss[2..]);
+    press(&mut host_app, KeyCode::Enter);
+    assert_eq!(host_app.game.submitted().count(), 1);
+    assert!(!pair(&host_app).our_turn);
+    wait_for(&mut partner_app, |app| app.game.submitted().count() == 1);
+    assert_eq!(
+        partner_app.game.row(0),
+        host_app.game.row(0),
+        "both boards have the row"
+    );
+    assert!(pair(&partner_app).our_turn);
+
+    // The partner finds the word, for both of them
+    type_letters(&mut partner_app, &answer.to_lowercase());
+    press(&mut partner_app, KeyCode::Enter);
+    assert_eq!(partner_app.game.status, GameStatus::Won);
+    wait_for(&mut host_app, |app| app.game.status == GameStatus::Won);
+    assert_eq!(host_app.game.submitted().count(), 2);
+
+    // Alone, the one left submits
+    drop(partner_app);
+    wait_for(&mut host_app, |app| pair(app).disconnected);
+    assert!(pair(&host_app).our_turn);
+}
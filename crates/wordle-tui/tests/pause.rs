@@ -0,0 +1,94 @@
+//! Pausing a timed game: its clock stops, its board is hidden, and any key
+//! resumes it with the pause left out of its time.
+
+mod common;
+
+use std::thread;
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::{press, press_with};
+use wordle::app::App;
+use wordle::mode::{Mode, Stopwatch};
+
+fn screen(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..40)
+        .map(|y| {
+            (0..100)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+fn blitz_left(app: &App) -> Duration {
+    match &app.mode {
+        Mode::Blitz(blitz) => blitz.remaining(),
+        _ => panic!("not a blitz"),
+    }
+}
+
+#[test]
+fn a_stopwatch_leaves_out_the_time_paused() {
+    let mut clock = Stopwatch::start();
+    clock.pause();
+    assert!(clock.is_paused());
+    let paused = clock.elapsed();
+    thread::sleep(Duration::from_millis(30));
+    assert_eq!(clock.elapsed(), paused);
+
+    // Pausing again doesn't move where the pause began
+    clock.pause();
+    clock.resume();
+    assert!(!clock.is_paused());
+    assert!(clock.elapsed() < paused + Duration::from_millis(30));
+}
+
+#[test]
+fn a_paused_blitz_hides_its_board_until_a_key() {
+    let mut app = common::app("pause");
+    app.start_blitz();
+    assert!(screen(&app).contains("[Ctrl+P] Pause"));
+    press(&mut app, KeyCode::Char('c'));
+    assert!(screen(&app).contains(" C "));
+
+    press_with(&mut app, KeyCode::Char('p'), KeyModifiers::CONTROL);
+    assert!(app.paused);
+    assert_eq!(app.focus_path(), vec!["Paused"]);
+    let left = blitz_left(&app);
+    thread::sleep(Duration::from_millis(30));
+    assert_eq!(blitz_left(&app), left);
+    let shown = screen(&app);
+    assert!(shown.contains("Paused"));
+    assert!(shown.contains("Press any key to resume"));
+    assert!(!shown.contains(" C "));
+
+    // The key resuming the game doesn't reach it
+    press(&mut app, KeyCode::Char('x'));
+    assert!(!app.paused);
+    assert_eq!(app.game.current_row(), Some(&['C'][..]));
+    assert!(blitz_left(&app) <= left);
+    assert!(!screen(&app).contains("Paused"));
+}
+
+#[test]
+fn games_without_a_clock_dont_pause() {
+    let mut app = common::app("pause");
+    app.play_seed(42);
+    press_with(&mut app, KeyCode::Char('p'), KeyModifiers::CONTROL);
+    assert!(!app.paused);
+    assert!(!screen(&app).contains("[Ctrl+P] Pause"));
+
+    // A speedrun pauses before its first letter starts the clock too
+    app.start_speedrun();
+    app.pause();
+    assert!(app.paused);
+    press(&mut app, KeyCode::Enter);
+    assert!(!app.paused);
+}
@@ -0,0 +1,36 @@
+//! Render profiling, only built with `--features profiling`.
+#![cfg(feature = "profiling")]
+
+mod common;
+
+use ratatui::{backend::TestBackend, Terminal};
+
+use wordle::ui::{self, profile};
+
+#[test]
+fn every_widget_drawn_gets_a_folded_stack() {
+    let app = common::app("profile");
+    let mut terminal = Terminal::new(TestBackend::new(90, 40)).unwrap();
+
+    // Nothing is recorded before profiling starts
+    terminal.draw(|f| ui::draw(f, &app)).unwrap();
+    assert!(profile::folded().is_empty());
+
+    profile::enable();
+    for _ in 0..3 {
+        profile::span("frame", || terminal.draw(|f| ui::draw(f, &app)).unwrap());
+    }
+    let folded = profile::folded();
+    let stacks: Vec<&str> = folded
+        .lines()
+        .map(|line| line.rsplit_once(' ').unwrap().0)
+        .collect();
+    for stack in ["frame", "frame;menu", "frame;title", "frame;footer"] {
+        assert!(stacks.contains(&stack), "no {} in\n{}", stack, folded);
+    }
+    // One line per stack, with whole microseconds
+    for line in folded.lines() {
+        let (_, micros) = line.rsplit_once(' ').unwrap();
+        assert!(micros.parse::<u64>().is_ok(), "{}", line);
+    }
+}
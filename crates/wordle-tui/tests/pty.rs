@@ -0,0 +1,268 @@
+//! Runs the real binary in a pseudo-terminal and plays through it with
+//! keystrokes, checking what ends up on the screen and that the terminal is
+//! set up and restored properly.
+//!
+//! Each test runs in its own directory with a one-word dictionary, so the
+//! answer is known: CRANE.
+
+use std::fs;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::process;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+
+const ROWS: u16 = 40;
+const COLS: u16 = 100;
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h";
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?1049l";
+const ENABLE_BRACKETED_PASTE: &str = "\x1b[?2004h";
+const DISABLE_BRACKETED_PASTE: &str = "\x1b[?2004l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+struct Session {
+    child: Box<dyn Child + Send + Sync>,
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    screen: Arc<Mutex<vt100::Parser>>,
+    output: Arc<Mutex<Vec<u8>>>,
+    dir: PathBuf,
+}
+
+impl Session {
+    fn start(name: &str) -> Session {
+        Session::start_with(name, "off")
+    }
+
+    // With `sixel` as the display setting: "auto" asks the terminal, which
+    // here never answers
+    fn start_with(name: &str, sixel: &str) -> Session {
+        let dir = std::env::temp_dir().join(format!("wordle-pty-{}-{}", process::id(), name));
+        fs::create_dir_all(dir.join("data")).unwrap();
+        fs::write(dir.join("data/words.txt"), "crane\n").unwrap();
+        fs::write(
+            dir.join("config.toml"),
+            format!(
+                "language = \"en\"\n\
+                 [display]\nsynchronized_output = \"off\"\nsixel = \"{}\"\n\
+                 [solver]\npattern_cache = \"off\"\n",
+                sixel
+            ),
+        )
+        .unwrap();
+
+        let pair = native_pty_system()
+            .openpty(PtySize {
+                rows: ROWS,
+                cols: COLS,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .unwrap();
+        let mut command = CommandBuilder::new(env!("CARGO_BIN_EXE_wordle"));
+        command.cwd(&dir);
+        command.env("WORDLE_CONFIG", dir.join("config.toml"));
+        // Whatever the game records stays in the test's directory
+        command.env("XDG_DATA_HOME", &dir);
+        command.env("TERM", "xterm-256color");
+        let child = pair.slave.spawn_command(command).unwrap();
+        drop(pair.slave);
+
+        let screen = Arc::new(Mutex::new(vt100::Parser::new(ROWS, COLS, 0)));
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let mut reader = pair.master.try_clone_reader().unwrap();
+        {
+            let screen = screen.clone();
+            let output = output.clone();
+            thread::spawn(move || {
+                let mut buf = [0; 4096];
+                while let Ok(n) = reader.read(&mut buf) {
+                    if n == 0 {
+                        break;
+                    }
+                    screen.lock().unwrap().process(&buf[..n]);
+                    output.lock().unwrap().extend_from_slice(&buf[..n]);
+                }
+            });
+        }
+        let writer = pair.master.take_writer().unwrap();
+
+        Session {
+            child,
+            master: pair.master,
+            writer,
+            screen,
+            output,
+            dir,
+        }
+    }
+
+    // Past the menu, into a new game
+    fn start_game(name: &str) -> Session {
+        let mut session = Session::start(name);
+        session.wait_for("New game");
+        session.send("\r");
+        session.wait_for("[Enter] Submit");
+        session
+    }
+
+    fn send(&mut self, keys: &str) {
+        self.writer.write_all(keys.as_bytes()).unwrap();
+        self.writer.flush().unwrap();
+    }
+
+    // Keys one at a time, so an Esc is never read as the start of a
+    // sequence
+    fn type_keys(&mut self, keys: &str) {
+        for key in keys.chars() {
+            self.send(&key.to_string());
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    // As when the window is dragged to a new size
+    fn resize(&self, cols: u16, rows: u16) {
+        self.screen.lock().unwrap().set_size(rows, cols);
+        self.master
+            .resize(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .unwrap();
+    }
+
+    fn contents(&self) -> String {
+        self.screen.lock().unwrap().screen().contents()
+    }
+
+    fn output(&self) -> String {
+        String::from_utf8_lossy(&self.output.lock().unwrap()).into_owned()
+    }
+
+    fn wait_for(&self, text: &str) {
+        let start = Instant::now();
+        while !self.contents().contains(text) {
+            if start.elapsed() > TIMEOUT {
+                panic!("{:?} never showed up; screen:\n{}", text, self.contents());
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+
+    // Raw output can trail the screen (or the exit) a little
+    fn wait_for_output(&self, text: &str) {
+        let start = Instant::now();
+        while !self.output().contains(text) {
+            if start.elapsed() > TIMEOUT {
+                panic!("{:?} was never written", text);
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+
+    fn wait_for_exit(&mut self) -> bool {
+        let start = Instant::now();
+        loop {
+            if let Some(status) = self.child.try_wait().unwrap() {
+                return status.success();
+            }
+            if start.elapsed() > TIMEOUT {
+                panic!("the game did not exit; screen:\n{}", self.contents());
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[test]
+fn starts_in_the_alternate_screen() {
+    let session = Session::start("start");
+    session.wait_for("WORDLE");
+    session.wait_for("New game");
+
+    session.wait_for_output(ENTER_ALTERNATE_SCREEN);
+    session.wait_for_output(ENABLE_BRACKETED_PASTE);
+}
+
+#[test]
+fn a_terminal_that_never_answers_keeps_the_keys_typed_next() {
+    let mut session = Session::start_with("silent", "auto");
+    session.wait_for("New game");
+    // Past the query's timeout, nothing reads the terminal but the game
+    thread::sleep(Duration::from_millis(300));
+    session.send("\r");
+    session.wait_for("[Enter] Submit");
+}
+
+#[test]
+fn refuses_incomplete_guesses_and_accepts_full_ones() {
+    let mut session = Session::start_game("guess");
+
+    session.type_keys("cr\r");
+    session.wait_for("Not enough letters");
+
+    // CRATE is wrong but complete, so it takes the first row and CRANE
+    // can win on the second
+    session.type_keys("ate\r");
+    session.type_keys("crane\r");
+    session.wait_for("You won!");
+}
+
+#[test]
+fn winning_shows_the_result() {
+    let mut session = Session::start_game("win");
+
+    session.type_keys("crane\r");
+    session.wait_for("You won!");
+}
+
+#[test]
+fn quitting_asks_first_and_restores_the_terminal() {
+    let mut session = Session::start_game("quit");
+
+    session.type_keys("\x1b");
+    session.wait_for("Are you sure you want to exit?");
+    // Esc again goes back to the game
+    session.type_keys("\x1b");
+    session.wait_for("[Enter] Submit");
+
+    session.type_keys("\x1b");
+    session.wait_for("Are you sure you want to exit?");
+    session.type_keys("\r");
+    assert!(session.wait_for_exit());
+
+    session.wait_for_output(LEAVE_ALTERNATE_SCREEN);
+    session.wait_for_output(DISABLE_BRACKETED_PASTE);
+    session.wait_for_output(SHOW_CURSOR);
+}
+
+#[test]
+fn a_terminal_too_small_waits_for_it_to_grow() {
+    let mut session = Session::start_game("resize");
+    session.type_keys("cr");
+
+    session.resize(30, 6);
+    session.wait_for("Terminal too small");
+    // Typed blind, so held
+    session.type_keys("a");
+
+    session.resize(COLS, ROWS);
+    session.wait_for("[Enter] Submit");
+    // CR is still typed and the A went nowhere, so this is CRANE
+    session.type_keys("ane\r");
+    session.wait_for("You won!");
+}
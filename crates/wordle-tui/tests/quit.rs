@@ -0,0 +1,102 @@
+//! Quitting: when it asks first, in the config and the settings. The
+//! shortcut that quits at once is in `quit_shortcut.rs`, since the game it
+//! saves would be played again by the apps of the tests here.
+
+mod common;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::press;
+use wordle::app::{App, View};
+use wordle::config::{Config, QuitConfirm};
+use wordle::{Game, GameStatus};
+
+fn start(quit_confirm: QuitConfirm) -> App {
+    common::data_home("quit");
+    let config = Config {
+        language: Some("en".to_string()),
+        quit_confirm,
+        ..Config::default()
+    };
+    App::new(&config)
+}
+
+fn shows(app: &App, text: &str) -> bool {
+    let (width, height) = (90, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height).any(|y| {
+        let line: String = (0..width).map(|x| buffer.get(x, y).symbol()).collect();
+        line.contains(text)
+    })
+}
+
+// Quit, the last entry of the menu
+fn quit_from_menu(app: &mut App) {
+    app.view = View::Menu(0);
+    press(app, KeyCode::Up);
+    press(app, KeyCode::Enter);
+}
+
+#[test]
+fn the_policy_says_when_quitting_asks() {
+    let config: Config = toml::from_str("quit_confirm = \"always\"").unwrap();
+    assert_eq!(config.quit_confirm, QuitConfirm::Always);
+    assert_eq!(Config::default().quit_confirm, QuitConfirm::Playing);
+    assert!(toml::from_str::<Config>("quit_confirm = \"maybe\"").is_err());
+
+    // Without a game in progress, only always asks
+    let mut app = start(QuitConfirm::Playing);
+    quit_from_menu(&mut app);
+    assert!(app.should_quit);
+
+    let mut app = start(QuitConfirm::Always);
+    quit_from_menu(&mut app);
+    assert!(!app.should_quit);
+    assert!(app.confirming_quit);
+    assert!(shows(&app, "Are you sure you want to exit?"));
+    // Anything but Enter stays
+    press(&mut app, KeyCode::Esc);
+    assert!(!app.confirming_quit);
+    assert!(!app.should_quit);
+    quit_from_menu(&mut app);
+    press(&mut app, KeyCode::Enter);
+    assert!(app.should_quit);
+}
+
+#[test]
+fn a_game_in_progress_asks_unless_never() {
+    // Esc in the game asks through its status
+    let mut app = start(QuitConfirm::Playing);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Game);
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.game.status, GameStatus::Quitting);
+    press(&mut app, KeyCode::Esc);
+
+    // From the menu, the game left there counts
+    quit_from_menu(&mut app);
+    assert!(app.confirming_quit);
+    press(&mut app, KeyCode::Char('x'));
+
+    let mut app = start(QuitConfirm::Never);
+    app.view = View::Game;
+    app.game = Game::with_word("CRANE");
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.game.status, GameStatus::Playing);
+    assert!(app.should_quit);
+}
+
+#[test]
+fn the_policy_is_in_the_settings() {
+    let mut app = start(QuitConfirm::Playing);
+    app.view = View::Settings(0);
+    press(&mut app, KeyCode::Up);
+    assert!(shows(&app, "Confirm quitting: playing"));
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.quit_confirm, QuitConfirm::Always);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.quit_confirm, QuitConfirm::Never);
+}
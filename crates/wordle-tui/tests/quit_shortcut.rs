@@ -0,0 +1,59 @@
+//! The shortcut that quits at once, saving the game in progress.
+
+mod common;
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+use common::press;
+use wordle::app::{App, View};
+use wordle::config::{Config, QuitConfirm};
+use wordle::Game;
+
+fn start(quit_confirm: QuitConfirm) -> App {
+    common::data_home("quit-shortcut");
+    let config = Config {
+        language: Some("en".to_string()),
+        quit_confirm,
+        ..Config::default()
+    };
+    App::new(&config)
+}
+
+#[test]
+fn the_quit_shortcut_saves_and_quits_at_once() {
+    let config: Config = toml::from_str("[keymap]\nquit = \"alt+x\"").unwrap();
+    assert_eq!(
+        config.keymap.quit,
+        Some(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT))
+    );
+    let config: Config = toml::from_str("[keymap]\nquit = \"F12\"").unwrap();
+    assert_eq!(
+        config.keymap.quit,
+        Some(KeyEvent::new(KeyCode::F(12), KeyModifiers::NONE))
+    );
+    let config: Config = toml::from_str("[keymap]\nquit = \"none\"").unwrap();
+    assert_eq!(config.keymap.quit, None);
+    for wrong in ["q", "shift+q", "ctrl+", "f13"] {
+        let text = format!("[keymap]\nquit = \"{}\"", wrong);
+        assert!(toml::from_str::<Config>(&text).is_err(), "{}", wrong);
+    }
+
+    // Ctrl+Q by default, whatever the policy, with the game kept for the
+    // next start
+    let mut app = start(QuitConfirm::Always);
+    press(&mut app, KeyCode::Enter);
+    app.game = Game::with_word("CRANE");
+    for c in "slate".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Enter);
+    app.handle_event(Event::Key(KeyEvent::new(
+        KeyCode::Char('q'),
+        KeyModifiers::CONTROL,
+    )));
+    assert!(app.should_quit);
+    assert!(!app.confirming_quit);
+    let again = start(QuitConfirm::Always);
+    assert_eq!(again.game.submitted().count(), 1);
+    assert_eq!(again.view, View::Game);
+}
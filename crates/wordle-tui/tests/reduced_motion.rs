@@ -0,0 +1,64 @@
+//! Reduced motion: set in the config, or asked for by the environment when
+//! the config doesn't say.
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+use wordle::app::{App, View};
+use wordle::config::Config;
+use wordle::doctor::Report;
+
+fn config(toml: &str) -> Config {
+    let mut config: Config = toml::from_str(toml).unwrap();
+    config.language = Some("en".to_string());
+    config
+}
+
+// Refuses a guess, which shakes the row unless motion is reduced
+fn shakes(config: &Config) -> bool {
+    let mut app = App::new(config);
+    app.new_game();
+    app.view = View::Game;
+    app.handle_event(Event::Key(KeyEvent::new(
+        KeyCode::Enter,
+        KeyModifiers::NONE,
+    )));
+    app.animations.shake().is_some()
+}
+
+// Every test here changes the environment, so they go one after the other
+#[test]
+fn the_environment_is_followed_unless_the_config_says() {
+    std::env::set_var(
+        "XDG_DATA_HOME",
+        std::env::temp_dir().join(format!("wordle-reduced-motion-{}", std::process::id())),
+    );
+    std::env::remove_var("NO_COLOR");
+    std::env::remove_var("REDUCED_MOTION");
+    let unset = config("");
+    let on = config("[display]\nreduced_motion = true");
+    let off = config("[display]\nreduced_motion = false");
+    assert_eq!(unset.display.reduced_motion, None);
+    assert!(!unset.display.reduced_motion());
+    assert!(on.display.reduced_motion());
+    assert!(shakes(&unset));
+    assert!(!shakes(&on));
+
+    for name in ["NO_COLOR", "REDUCED_MOTION"] {
+        std::env::set_var(name, "1");
+        assert!(unset.display.reduced_motion(), "{}", name);
+        assert!(!off.display.reduced_motion(), "{}", name);
+        assert!(!shakes(&unset));
+        assert!(shakes(&off));
+        let report = Report::default().render(&unset);
+        assert!(report.contains("Animations:           reduced (asked by the environment)"));
+        std::env::remove_var(name);
+    }
+
+    // Set but empty doesn't count
+    std::env::set_var("NO_COLOR", "");
+    assert!(!unset.display.reduced_motion());
+    std::env::remove_var("NO_COLOR");
+    assert!(Report::default()
+        .render(&on)
+        .contains("Animations:           reduced\n"));
+}
@@ -0,0 +1,56 @@
+//! Reminder of the word of the day: pending until it is played to the end,
+//! with a dot next to it in the menu meanwhile.
+
+mod common;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::press;
+use wordle::app::{App, View};
+use wordle::config::Config;
+use wordle::GameStatus;
+
+fn menu(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..40)
+        .map(|y| {
+            (0..100)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn the_daily_is_pending_until_played_to_the_end() {
+    common::data_home("reminder");
+    let config: Config = toml::from_str("language = \"en\"\n[reminder]\nnotify = true").unwrap();
+    assert!(config.reminder.notify);
+    assert!(!Config::default().reminder.notify);
+
+    let mut app = App::new(&config);
+    assert!(app.daily_pending);
+    assert!(menu(&app).contains("Word of the day •"));
+
+    // Started isn't enough
+    app.play_daily();
+    assert!(App::new(&config).daily_pending);
+
+    app.view = View::Game;
+    for c in app.game.target_word.clone().to_lowercase().chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.game.status, GameStatus::Won);
+    assert!(!app.daily_pending);
+
+    let app = App::new(&config);
+    assert!(!app.daily_pending);
+    let shown = menu(&app);
+    assert!(shown.contains("Word of the day"));
+    assert!(!shown.contains("•"));
+}
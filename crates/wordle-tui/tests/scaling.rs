@@ -0,0 +1,69 @@
+//! Boards drawn bigger on big terminals: the cells of the grid and the
+//! keys of the keyboard grow with the room there is, and clicks still land
+//! on the keys drawn.
+
+mod common;
+
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{backend::TestBackend, Terminal};
+
+use wordle::app::App;
+
+fn screen(app: &App, width: u16, height: u16) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..width).map(|x| buffer.get(x, y).symbol()).collect())
+        .collect()
+}
+
+// Width of the widest cell or key border drawn on `screen`, short of the
+// frame around everything
+fn widest_tile(screen: &[String]) -> usize {
+    screen
+        .iter()
+        .flat_map(|line| line.split('┐'))
+        .filter_map(|part| part.rsplit_once('┌'))
+        .map(|(_, border)| border.chars().count() + 2)
+        .filter(|width| *width < 20)
+        .max()
+        .unwrap_or(0)
+}
+
+#[test]
+fn the_board_grows_with_the_terminal() {
+    let app = common::in_game("scaling");
+    let small = widest_tile(&screen(&app, 80, 34));
+    assert_eq!(small, 5);
+    let small_key = app.keyboard_keys.borrow()[0].1;
+    assert_eq!((small_key.width, small_key.height), (3, 3));
+
+    let big = widest_tile(&screen(&app, 200, 80));
+    assert!(big > small, "{} wide at most", big);
+    let big_key = app.keyboard_keys.borrow()[0].1;
+    assert!(big_key.width > 3 && big_key.height > 3);
+}
+
+#[test]
+fn clicks_land_on_the_bigger_keys() {
+    let mut app = common::in_game("scaling");
+    let lines = screen(&app, 200, 80);
+    let (key, area) = app.keyboard_keys.borrow()[0];
+    // The letter is drawn in the middle of its key
+    let middle = &lines[(area.y + area.height / 2) as usize];
+    assert!(middle.contains(key));
+
+    for kind in [
+        MouseEventKind::Down(MouseButton::Left),
+        MouseEventKind::Up(MouseButton::Left),
+    ] {
+        app.handle_event(Event::Mouse(MouseEvent {
+            kind,
+            column: area.x + area.width - 1,
+            row: area.y + area.height - 1,
+            modifiers: KeyModifiers::NONE,
+        }));
+    }
+    assert_eq!(app.game.row(0), Some(&[key][..]));
+}
@@ -0,0 +1,405 @@
+//! The race server: its rooms, scores and spectators, then whole races
+//! through it with the players in this process on the loopback interface.
+
+mod common;
+
+use std::net::TcpListener;
+use std::thread;
+use std::time::Duration;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::{press, type_word};
+use wordle::app::{App, View};
+use wordle::cli::{Args, Command, Lan};
+use wordle::config::Config;
+use wordle::i18n::Language;
+use wordle::mode::Mode;
+use wordle::net::{self, Message, Race, Score, Watch, DEFAULT_PORT, PROTOCOL_VERSION};
+use wordle::server::{self, ClientId, Server};
+use wordle::GameStatus;
+
+fn join(
+    server: &mut Server,
+    client: ClientId,
+    room: &str,
+    name: &str,
+    language: &str,
+) -> Vec<(ClientId, Message)> {
+    server.handle(
+        client,
+        Message::Join {
+            version: PROTOCOL_VERSION,
+            room: room.to_string(),
+            name: name.to_string(),
+            language: language.to_string(),
+        },
+    )
+}
+
+fn done(
+    server: &mut Server,
+    client: ClientId,
+    won: bool,
+    guesses: usize,
+) -> Vec<(ClientId, Message)> {
+    server.handle(
+        client,
+        Message::Done {
+            player: None,
+            won,
+            guesses,
+        },
+    )
+}
+
+fn watch(server: &mut Server, client: ClientId, room: &str) -> Vec<(ClientId, Message)> {
+    server.handle(
+        client,
+        Message::Watch {
+            version: PROTOCOL_VERSION,
+            room: room.to_string(),
+        },
+    )
+}
+
+fn row(server: &mut Server, client: ClientId, feedback: &str) -> Vec<(ClientId, Message)> {
+    server.handle(
+        client,
+        Message::Row {
+            player: None,
+            feedback: feedback.to_string(),
+        },
+    )
+}
+
+// The seed `client` was sent to start on
+fn seed_for(outgoing: &[(ClientId, Message)], client: ClientId) -> Option<u64> {
+    outgoing.iter().find_map(|(to, message)| match message {
+        Message::Start { seed, .. } if *to == client => Some(*seed),
+        _ => None,
+    })
+}
+
+// The latest scores `client` was sent
+fn scores(outgoing: &[(ClientId, Message)], client: ClientId) -> Option<Vec<(String, u32)>> {
+    outgoing
+        .iter()
+        .rev()
+        .find_map(|(to, message)| match message {
+            Message::Scores { scores } if *to == client => Some(
+                scores
+                    .iter()
+                    .map(|Score { name, points }| (name.clone(), *points))
+                    .collect(),
+            ),
+            _ => None,
+        })
+}
+
+#[test]
+fn serve_is_parsed() {
+    let args = |line: &str| Args::parse_from(line.split_whitespace().map(str::to_string));
+    assert_eq!(
+        args("serve").unwrap().command,
+        Some(Command::Serve { port: DEFAULT_PORT })
+    );
+    assert_eq!(
+        args("serve --port 5000").unwrap().command,
+        Some(Command::Serve { port: 5000 })
+    );
+    assert!(args("serve --name ana").is_err());
+}
+
+#[test]
+fn watch_is_parsed() {
+    let args = |line: &str| Args::parse_from(line.split_whitespace().map(str::to_string));
+    assert_eq!(
+        args("watch example.org --room friday").unwrap().lan,
+        Some(Lan::Watch {
+            address: "example.org".to_string(),
+            room: Some("friday".to_string())
+        })
+    );
+    assert!(args("watch").is_err());
+    assert!(args("watch example.org --name ana").is_err());
+}
+
+#[test]
+fn rooms_keep_to_themselves() {
+    let mut server = Server::new(server::word_list);
+    let ana = seed_for(&join(&mut server, 1, "friday", "ana", "en"), 1).unwrap();
+    let bia = join(&mut server, 2, "friday", "bia", "en");
+    assert_eq!(seed_for(&bia, 2), Some(ana));
+    // Everyone in the room hears of the newcomer
+    assert_eq!(
+        scores(&bia, 1),
+        Some(vec![("ana".to_string(), 0), ("bia".to_string(), 0)])
+    );
+    let elsewhere = join(&mut server, 3, "monday", "ana", "en");
+    assert!(seed_for(&elsewhere, 3).is_some());
+    assert_eq!(server.room_count(), 2);
+
+    // Rows go to the rest of the room, with the name of their player
+    let row = server.handle(
+        1,
+        Message::Row {
+            player: None,
+            feedback: "APACC".to_string(),
+        },
+    );
+    assert_eq!(
+        row,
+        vec![(
+            2,
+            Message::Row {
+                player: Some("ana".to_string()),
+                feedback: "APACC".to_string()
+            }
+        )]
+    );
+
+    let refused = |outgoing: Vec<(ClientId, Message)>| {
+        matches!(&outgoing[..], [(_, Message::Refused { .. })])
+    };
+    assert!(refused(join(&mut server, 4, "friday", "ana", "en")));
+    assert!(refused(join(&mut server, 5, "friday", "caio", "pt")));
+    assert!(refused(join(&mut server, 6, "friday", "caio", "xx")));
+
+    assert!(server.leave(3).is_empty());
+    assert_eq!(server.room_count(), 1);
+}
+
+#[test]
+fn the_fewest_guesses_score_and_the_next_round_starts() {
+    let mut server = Server::new(server::word_list);
+    let first = seed_for(&join(&mut server, 1, "lobby", "ana", "en"), 1).unwrap();
+    join(&mut server, 2, "lobby", "bia", "en");
+    join(&mut server, 3, "lobby", "caio", "en");
+
+    assert!(seed_for(&done(&mut server, 1, true, 3), 1).is_none());
+    assert!(seed_for(&done(&mut server, 2, false, 6), 2).is_none());
+    let end = done(&mut server, 3, true, 3);
+    // Ana and Caio both took three guesses; Ana was first
+    assert_eq!(
+        scores(&end, 2),
+        Some(vec![
+            ("ana".to_string(), 1),
+            ("bia".to_string(), 0),
+            ("caio".to_string(), 0)
+        ])
+    );
+    let second = seed_for(&end, 1).unwrap();
+    assert_ne!(second, first);
+    assert_eq!(seed_for(&end, 2), Some(second));
+
+    // A round waiting on a player who leaves ends without them
+    done(&mut server, 1, true, 4);
+    done(&mut server, 2, true, 2);
+    let end = server.leave(3);
+    let left = Message::Left {
+        player: "caio".to_string(),
+    };
+    assert!(end.contains(&(1, left)));
+    assert_eq!(
+        scores(&end, 1).unwrap().last(),
+        Some(&("bia".to_string(), 1))
+    );
+    assert!(seed_for(&end, 1).is_some());
+}
+
+#[test]
+fn spectators_catch_up_then_hear_the_room() {
+    let mut server = Server::new(server::word_list);
+    join(&mut server, 1, "friday", "ana", "en");
+    join(&mut server, 2, "friday", "bia", "en");
+    row(&mut server, 1, "APACC");
+    done(&mut server, 2, false, 6);
+
+    let refused = watch(&mut server, 3, "monday");
+    assert!(matches!(&refused[..], [(3, Message::Refused { .. })]));
+
+    // The round so far, for the spectator alone
+    let snapshot = watch(&mut server, 4, "friday");
+    assert!(snapshot.iter().all(|(to, _)| *to == 4));
+    assert!(seed_for(&snapshot, 4).is_some());
+    assert!(snapshot.contains(&(
+        4,
+        Message::Row {
+            player: Some("ana".to_string()),
+            feedback: "APACC".to_string()
+        }
+    )));
+    assert!(snapshot.contains(&(
+        4,
+        Message::Done {
+            player: Some("bia".to_string()),
+            won: false,
+            guesses: 6
+        }
+    )));
+
+    // Then every row, and the rounds go on without waiting for them
+    let next = row(&mut server, 1, "CCCCC");
+    assert_eq!(next.len(), 2);
+    assert!(next.iter().any(|(to, _)| *to == 4));
+    assert!(row(&mut server, 4, "CCCCC").is_empty());
+    let end = done(&mut server, 1, true, 2);
+    assert_eq!(
+        scores(&end, 4),
+        Some(vec![("ana".to_string(), 1), ("bia".to_string(), 0)])
+    );
+    assert!(seed_for(&end, 4).is_some());
+
+    // The room stays open while watched
+    server.leave(1);
+    server.leave(2);
+    assert_eq!(server.room_count(), 1);
+    server.leave(4);
+    assert_eq!(server.room_count(), 0);
+}
+
+fn config() -> Config {
+    common::data_home("server");
+    common::english()
+}
+
+fn race(app: &App) -> &Race {
+    match &app.mode {
+        Mode::Race(race) => race,
+        _ => panic!("not a race"),
+    }
+}
+
+// Ticks `app` until `done` holds of its race, for up to two seconds
+fn wait_for(app: &mut App, done: impl Fn(&Race) -> bool) {
+    for _ in 0..100 {
+        app.on_tick();
+        if done(race(app)) {
+            return;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    panic!("the server never got there");
+}
+
+fn shows(app: &App, text: &str) -> bool {
+    let (width, height) = (90, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height).any(|y| {
+        (0..width)
+            .map(|x| buffer.get(x, y).symbol())
+            .collect::<String>()
+            .contains(text)
+    })
+}
+
+#[test]
+fn a_room_races_round_after_round() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    thread::spawn(move || server::serve(listener));
+
+    let config = config();
+    let mut ana = App::new(&config);
+    ana.play_race(net::join(&address, Language::En, "friday", "ana").unwrap());
+    let mut bia = App::new(&config);
+    bia.play_race(net::join(&address, Language::En, "friday", "bia").unwrap());
+    assert_eq!(race(&ana).room.as_deref(), Some("friday"));
+    let answer = ana.game.target_word.clone();
+    assert_eq!(bia.game.target_word, answer);
+
+    // Names are taken per room
+    let error = net::join(&address, Language::En, "friday", "ana")
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("already in room"), "{}", error);
+
+    wait_for(&mut ana, |race| race.rivals.len() == 1);
+    assert!(shows(&ana, "friday"));
+    assert!(shows(&ana, "bia      ·· ·· ·· ·· ·· 0/6"));
+
+    type_word(&mut ana, &answer);
+    wait_for(&mut bia, |race| race.rivals[0].result.is_some());
+    assert!(shows(&bia, "✓ 1"));
+    type_word(&mut bia, &answer);
+    assert_eq!(bia.game.status, GameStatus::Won);
+
+    // Both found it at once: the first one to do so scores
+    wait_for(&mut ana, |race| race.next_seed.is_some());
+    assert_eq!(
+        race(&ana).scores,
+        vec![
+            Score {
+                name: "ana".to_string(),
+                points: 1
+            },
+            Score {
+                name: "bia".to_string(),
+                points: 0
+            }
+        ]
+    );
+    assert!(shows(&ana, "Next round"));
+
+    let next = race(&ana).next_seed.unwrap();
+    press(&mut ana, KeyCode::Esc);
+    assert!(matches!(ana.mode, Mode::Race(_)));
+    assert_eq!(race(&ana).seed, next);
+    assert_eq!(ana.game.status, GameStatus::Playing);
+    assert!(race(&ana).rivals[0].rows.is_empty());
+}
+
+// Ticks `app` until `done` holds of the room it watches, for up to two
+// seconds
+fn wait_for_room(app: &mut App, done: impl Fn(&Watch) -> bool) {
+    for _ in 0..100 {
+        app.on_tick();
+        if done(app.watching.as_ref().unwrap()) {
+            return;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    panic!("the server never got there");
+}
+
+#[test]
+fn a_spectator_watches_the_boards() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    thread::spawn(move || server::serve(listener));
+
+    let error = net::watch(&address, "friday").unwrap_err().to_string();
+    assert!(error.contains("nobody is playing"), "{}", error);
+
+    let config = config();
+    let mut ana = App::new(&config);
+    ana.play_race(net::join(&address, Language::En, "friday", "ana").unwrap());
+    let answer = ana.game.target_word.to_lowercase();
+    let guess = if answer == "crane" { "slate" } else { "crane" };
+    type_word(&mut ana, guess);
+    ana.on_tick();
+
+    let mut spectator = App::new(&config);
+    spectator.watch(net::watch(&address, "friday").unwrap());
+    assert_eq!(spectator.view, View::Spectate);
+    wait_for_room(&mut spectator, |watch| {
+        watch.players.len() == 1 && watch.players[0].rows.len() == 1
+    });
+    assert!(shows(&spectator, "Watching friday | Round 1"));
+    assert!(shows(&spectator, "1/6"));
+    assert!(shows(&spectator, "[Esc] Stop watching"));
+
+    // Then the rest of the game, live
+    type_word(&mut ana, &answer);
+    wait_for_room(&mut spectator, |watch| watch.round == 2);
+    assert!(shows(&spectator, "Round 2"));
+    assert_eq!(spectator.watching.as_ref().unwrap().scores[0].points, 1);
+
+    press(&mut spectator, KeyCode::Esc);
+    assert_eq!(spectator.view, View::Menu(0));
+    assert!(spectator.watching.is_none());
+}
@@ -0,0 +1,78 @@
+//! Sound cues: the events that make a sound, and the bell rung for them.
+
+mod common;
+
+use crossterm::event::KeyCode;
+
+use common::{press, type_word};
+use wordle::app::{App, View};
+use wordle::config::Config;
+use wordle::sound::{self, Cue};
+
+fn app(toml: &str) -> App {
+    common::data_home("sound");
+    let mut config: Config = toml::from_str(toml).unwrap();
+    config.language = Some("en".to_string());
+    let mut app = App::new(&config);
+    app.new_game();
+    app.view = View::Game;
+    app
+}
+
+#[test]
+fn sounds_are_off_unless_enabled() {
+    let config = Config::default().sound;
+    assert!(!config.enabled);
+    assert!(!config.plays(Cue::Win));
+
+    let mut app = app("");
+    press(&mut app, KeyCode::Enter);
+    assert!(app.sound.take().is_empty());
+}
+
+#[test]
+fn each_event_has_its_flag() {
+    let config: Config = toml::from_str(
+        "[sound]\nenabled = true\nguess = true\nloss = false\n\
+         [sound.commands]\nwin = \"true\"",
+    )
+    .unwrap();
+    let sound = &config.sound;
+    assert!(sound.plays(Cue::Invalid));
+    assert!(sound.plays(Cue::Guess));
+    assert!(sound.plays(Cue::Win));
+    assert!(!sound.plays(Cue::Loss));
+    assert_eq!(sound.command(Cue::Win), Some("true"));
+    assert_eq!(sound.command(Cue::Guess), None);
+}
+
+#[test]
+fn refused_taken_and_winning_guesses_are_cued() {
+    let mut app = app("[sound]\nenabled = true\nguess = true");
+    let answer = app.game.target_word.clone();
+    let miss = app
+        .words
+        .iter()
+        .find(|word| **word != answer)
+        .unwrap()
+        .clone();
+
+    type_word(&mut app, "ab");
+    assert_eq!(app.sound.take(), [Cue::Invalid]);
+    for _ in 0..2 {
+        press(&mut app, KeyCode::Backspace);
+    }
+    type_word(&mut app, &miss.to_lowercase());
+    assert_eq!(app.sound.take(), [Cue::Guess]);
+    type_word(&mut app, &answer.to_lowercase());
+    assert_eq!(app.sound.take(), [Cue::Win]);
+    assert!(app.sound.take().is_empty());
+}
+
+#[test]
+fn the_bell_rings_without_a_command() {
+    let config: Config = toml::from_str("[sound]\nenabled = true").unwrap();
+    let mut terminal = Vec::new();
+    sound::play(Cue::Invalid, &config.sound, &mut terminal).unwrap();
+    assert_eq!(terminal, b"\x07");
+}
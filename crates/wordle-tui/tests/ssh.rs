@@ -0,0 +1,431 @@
+//! The SSH server: the keys it reads from a remote terminal, then whole
+//! games through it with the system's ssh client in a pseudo-terminal.
+//! Those are skipped where there is no ssh client.
+//!
+//! The server plays with a one-word dictionary, so the answer is known:
+//! CRANE.
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::process::{self, Command};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+
+use wordle::cli::{Args, Command as Subcommand};
+use wordle::config::Config;
+use wordle::ssh::transport::{
+    self, Writer, MSG_IGNORE, MSG_KEXINIT, MSG_KEX_ECDH_INIT, MSG_KEX_ECDH_REPLY, MSG_NEWKEYS,
+};
+use wordle::ssh::{self, input::Input, DEFAULT_SSH_PORT};
+
+const ROWS: u16 = 40;
+const COLS: u16 = 100;
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?1049l";
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new(code, modifiers))
+}
+
+#[test]
+fn ssh_is_parsed() {
+    let args = |line: &str| Args::parse_from(line.split_whitespace().map(str::to_string));
+    assert_eq!(
+        args("ssh").unwrap().command,
+        Some(Subcommand::Ssh {
+            port: DEFAULT_SSH_PORT
+        })
+    );
+    assert_eq!(
+        args("ssh --port 2200").unwrap().command,
+        Some(Subcommand::Ssh { port: 2200 })
+    );
+    assert!(args("ssh --room lobby").is_err());
+}
+
+#[test]
+fn remote_input_becomes_events() {
+    let none = KeyModifiers::NONE;
+    let mut input = Input::new();
+    assert_eq!(
+        input.feed("cé\r\x7f\t".as_bytes()),
+        vec![
+            key(KeyCode::Char('c'), none),
+            key(KeyCode::Char('é'), none),
+            key(KeyCode::Enter, none),
+            key(KeyCode::Backspace, none),
+            key(KeyCode::Tab, none),
+        ]
+    );
+    assert_eq!(
+        input.feed(b"\x1b[A\x1bOB\x1b[6;5~\x1b[1;3D\x1b[15~\x1b[Z\x01"),
+        vec![
+            key(KeyCode::Up, none),
+            key(KeyCode::Down, none),
+            key(KeyCode::PageDown, KeyModifiers::CONTROL),
+            key(KeyCode::Left, KeyModifiers::ALT),
+            key(KeyCode::F(5), none),
+            key(KeyCode::BackTab, KeyModifiers::SHIFT),
+            key(KeyCode::Char('a'), KeyModifiers::CONTROL),
+        ]
+    );
+    // A lone Esc is the key; Esc before a key is Alt
+    assert_eq!(input.feed(b"\x1b"), vec![key(KeyCode::Esc, none)]);
+    assert_eq!(
+        input.feed(b"\x1bx"),
+        vec![key(KeyCode::Char('x'), KeyModifiers::ALT)]
+    );
+
+    // Sequences and characters split across reads wait for their end
+    assert!(input.feed(b"\x1b[1;").is_empty());
+    assert_eq!(
+        input.feed(b"5C"),
+        vec![key(KeyCode::Right, KeyModifiers::CONTROL)]
+    );
+    assert!(input.feed(&"ç".as_bytes()[..1]).is_empty());
+    assert_eq!(
+        input.feed(&"ç".as_bytes()[1..]),
+        vec![key(KeyCode::Char('ç'), none)]
+    );
+
+    assert_eq!(
+        input.feed(b"\x1b[200~cra"),
+        Vec::<Event>::new(),
+        "a paste waits for its end"
+    );
+    assert_eq!(
+        input.feed(b"ne\x1b[20"),
+        Vec::<Event>::new(),
+        "the end marker can be split"
+    );
+    assert_eq!(
+        input.feed(b"1~x"),
+        vec![
+            Event::Paste("crane".to_string()),
+            key(KeyCode::Char('x'), none)
+        ]
+    );
+
+    // Clicks count, the wheel doesn't
+    assert_eq!(
+        input.feed(b"\x1b[<0;12;5M\x1b[<64;1;1M\x1b[<0;12;5m"),
+        vec![
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column: 11,
+                row: 4,
+                modifiers: none,
+            }),
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Up(MouseButton::Left),
+                column: 11,
+                row: 4,
+                modifiers: none,
+            }),
+        ]
+    );
+}
+
+// A client's KEXINIT offering `kex` as its key exchanges
+fn kex_init(kex: &str) -> Vec<u8> {
+    let mut init = Writer::new(MSG_KEXINIT);
+    for _ in 0..16 {
+        init = init.u8(0);
+    }
+    let cipher = b"chacha20-poly1305@openssh.com";
+    init.string(kex.as_bytes())
+        .string(b"ssh-ed25519")
+        .string(cipher)
+        .string(cipher)
+        .string(b"hmac-sha2-256")
+        .string(b"hmac-sha2-256")
+        .string(b"none")
+        .string(b"none")
+        .string(b"")
+        .string(b"")
+        .bool(false)
+        .u32(0)
+        .finish()
+}
+
+// Sends the client's version then `payloads` as plain packets, and says
+// how the server's side of the key exchange ended, with the types of the
+// messages it sent
+fn key_exchange(payloads: Vec<Vec<u8>>) -> (Result<(), String>, Vec<u8>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let client = thread::spawn(move || {
+        let mut stream = std::net::TcpStream::connect(address).unwrap();
+        stream.write_all(b"SSH-2.0-test\r\n").unwrap();
+        for payload in payloads {
+            let padding = 8 - (5 + payload.len()) % 8 + 8;
+            let mut packet = ((1 + payload.len() + padding) as u32)
+                .to_be_bytes()
+                .to_vec();
+            packet.push(padding as u8);
+            packet.extend(payload);
+            packet.extend(vec![0; padding]);
+            stream.write_all(&packet).unwrap();
+        }
+        stream.shutdown(std::net::Shutdown::Write).unwrap();
+        let mut received = Vec::new();
+        let _ = stream.read_to_end(&mut received);
+        // Past the version line, packets in the clear
+        let start = received.windows(2).position(|w| w == b"\r\n").unwrap() + 2;
+        let mut rest = &received[start..];
+        let mut kinds = Vec::new();
+        while rest.len() > 5 {
+            let length = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            kinds.push(rest[5]);
+            rest = &rest[(4 + length).min(rest.len())..];
+        }
+        kinds
+    });
+    let (stream, _) = listener.accept().unwrap();
+    let host_key = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+    let result = transport::accept(stream, &host_key)
+        .map(drop)
+        .map_err(|error| error.to_string());
+    (result, client.join().unwrap())
+}
+
+#[test]
+fn the_key_exchange_is_strict() {
+    let strict = "curve25519-sha256,kex-strict-c-v00@openssh.com";
+    let refused = |payloads: Vec<Vec<u8>>, reason: &str| {
+        let (result, sent) = key_exchange(payloads);
+        let error = result.unwrap_err();
+        assert!(error.contains(reason), "{}", error);
+        // The server never got as far as its key
+        assert_eq!(sent, [MSG_KEXINIT]);
+    };
+    // Nothing comes before the KEXINIT, not even a message to ignore
+    refused(
+        vec![vec![MSG_IGNORE], kex_init(strict)],
+        "expected the key exchange",
+    );
+    // Nor a second KEXINIT instead of the client's key
+    refused(
+        vec![kex_init(strict), kex_init(strict)],
+        "expected the client's key",
+    );
+    refused(
+        vec![kex_init(strict), vec![MSG_IGNORE]],
+        "expected the client's key",
+    );
+    // Clients that don't do strict key exchange aren't let in
+    refused(vec![kex_init("curve25519-sha256")], "strict key exchange");
+
+    // In order, the server answers with its key and its NEWKEYS
+    let (result, sent) = key_exchange(vec![
+        kex_init(strict),
+        Writer::new(MSG_KEX_ECDH_INIT).string(&[9; 32]).finish(),
+    ]);
+    assert!(result.is_err());
+    assert_eq!(sent, [MSG_KEXINIT, MSG_KEX_ECDH_REPLY, MSG_NEWKEYS]);
+}
+
+fn has_ssh_client() -> bool {
+    Command::new("ssh").arg("-V").output().is_ok()
+}
+
+// A server on a port of its own, with the one-word dictionary. Every test
+// points the data directories to the same place, so they can run side by
+// side
+fn start_server() -> u16 {
+    let dir = std::env::temp_dir().join(format!("wordle-ssh-{}", process::id()));
+    fs::create_dir_all(dir.join("data")).unwrap();
+    fs::write(dir.join("data/words.txt"), "crane\n").unwrap();
+    std::env::set_var("XDG_DATA_HOME", &dir);
+    std::env::set_var("WORDLE_DATA_DIR", dir.join("data"));
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let config: Config = toml::from_str(
+        "language = \"en\"\n\
+         [display]\nsynchronized_output = \"off\"\n\
+         [solver]\npattern_cache = \"off\"\n",
+    )
+    .unwrap();
+    thread::spawn(move || ssh::serve(listener, config));
+    port
+}
+
+struct Player {
+    child: Box<dyn Child + Send + Sync>,
+    writer: Box<dyn Write + Send>,
+    master: Box<dyn MasterPty + Send>,
+    screen: Arc<Mutex<vt100::Parser>>,
+    output: Arc<Mutex<Vec<u8>>>,
+}
+
+impl Player {
+    // Connects with ssh from a terminal of its own
+    fn connect() -> Player {
+        let port = start_server();
+
+        let pair = native_pty_system()
+            .openpty(PtySize {
+                rows: ROWS,
+                cols: COLS,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .unwrap();
+        let mut command = CommandBuilder::new("ssh");
+        let port = port.to_string();
+        // Nothing from the user's own ssh setup
+        command.args([
+            "-tt",
+            "-F",
+            "/dev/null",
+            "-p",
+            &port,
+            "-o",
+            "StrictHostKeyChecking=no",
+            "-o",
+            "UserKnownHostsFile=/dev/null",
+            "-o",
+            "BatchMode=yes",
+            "-o",
+            "LogLevel=ERROR",
+            "play@127.0.0.1",
+        ]);
+        command.env("TERM", "xterm-256color");
+        let child = pair.slave.spawn_command(command).unwrap();
+        drop(pair.slave);
+
+        let screen = Arc::new(Mutex::new(vt100::Parser::new(ROWS, COLS, 0)));
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let mut reader = pair.master.try_clone_reader().unwrap();
+        {
+            let screen = screen.clone();
+            let output = output.clone();
+            thread::spawn(move || {
+                let mut buf = [0; 4096];
+                while let Ok(n) = reader.read(&mut buf) {
+                    if n == 0 {
+                        break;
+                    }
+                    screen.lock().unwrap().process(&buf[..n]);
+                    output.lock().unwrap().extend_from_slice(&buf[..n]);
+                }
+            });
+        }
+        let writer = pair.master.take_writer().unwrap();
+
+        Player {
+            child,
+            writer,
+            master: pair.master,
+            screen,
+            output,
+        }
+    }
+
+    // Keys one at a time, so an Esc is never read as the start of a
+    // sequence
+    fn type_keys(&mut self, keys: &str) {
+        for key in keys.chars() {
+            self.writer.write_all(key.to_string().as_bytes()).unwrap();
+            self.writer.flush().unwrap();
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    fn resize(&self, cols: u16, rows: u16) {
+        self.screen.lock().unwrap().set_size(rows, cols);
+        self.master
+            .resize(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .unwrap();
+    }
+
+    fn contents(&self) -> String {
+        self.screen.lock().unwrap().screen().contents()
+    }
+
+    fn wait_for(&self, text: &str) {
+        let start = Instant::now();
+        while !self.contents().contains(text) {
+            if start.elapsed() > TIMEOUT {
+                panic!("{:?} never showed up; screen:\n{}", text, self.contents());
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+
+    fn wait_for_exit(&mut self) -> bool {
+        let start = Instant::now();
+        loop {
+            if let Some(status) = self.child.try_wait().unwrap() {
+                return status.success();
+            }
+            if start.elapsed() > TIMEOUT {
+                panic!("ssh did not exit; screen:\n{}", self.contents());
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+}
+
+impl Drop for Player {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+    }
+}
+
+#[test]
+fn a_game_is_played_over_ssh() {
+    if !has_ssh_client() {
+        eprintln!("no ssh client, skipped");
+        return;
+    }
+    let mut player = Player::connect();
+    player.wait_for("New game");
+    player.type_keys("\r");
+    player.wait_for("[Enter] Submit");
+    player.type_keys("crane\r");
+    player.wait_for("You won!");
+
+    // The game went in the player's own records, not the host user's
+    let host = std::env::temp_dir().join(format!("wordle-ssh-{}/wordle", process::id()));
+    assert!(!host.join("history.jsonl").exists());
+    assert!(!host.join("leaderboard.json").exists());
+}
+
+#[test]
+fn the_game_follows_the_terminal_size_and_quits_cleanly() {
+    if !has_ssh_client() {
+        eprintln!("no ssh client, skipped");
+        return;
+    }
+    let mut player = Player::connect();
+    player.wait_for("New game");
+    player.type_keys("\r");
+    player.wait_for("[Enter] Submit");
+
+    player.resize(16, 5);
+    player.wait_for("small (16x5)");
+    player.resize(COLS, ROWS);
+    player.wait_for("[Enter] Submit");
+
+    player.type_keys("\x1b");
+    player.wait_for("Are you sure you want to exit?");
+    player.type_keys("\r");
+    assert!(player.wait_for_exit());
+    let output = String::from_utf8_lossy(&player.output.lock().unwrap()).into_owned();
+    assert!(output.contains(LEAVE_ALTERNATE_SCREEN));
+}
@@ -0,0 +1,67 @@
+//! The status bar of the footer: the guess being played, the mode, the
+//! time and the streak, on the frame of the footer or at the end of its
+//! line.
+
+mod common;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::press;
+use wordle::app::{App, View};
+
+fn screen(app: &App, width: u16, height: u16) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..width).map(|x| buffer.get(x, y).symbol()).collect())
+        .collect()
+}
+
+#[test]
+fn the_bar_follows_the_game() {
+    let mut app = common::app("status-bar");
+    // Nothing to tell in the menu
+    assert!(!screen(&app, 100, 40).join("\n").contains("Streak"));
+
+    app.new_game();
+    app.view = View::Game;
+    let lines = screen(&app, 100, 40);
+    // On the top edge of the footer, above the instructions
+    let bar = lines
+        .iter()
+        .position(|line| line.contains("Guess 1/6 · Classic"))
+        .expect("no status bar");
+    assert!(lines[bar].contains("Streak 0"), "{}", lines[bar]);
+    assert!(lines[bar].contains("0:0"), "{}", lines[bar]);
+    assert!(bar >= 37, "the bar is on line {}", bar);
+
+    for c in "react".chars() {
+        press(&mut app, KeyCode::Char(c));
+    }
+    press(&mut app, KeyCode::Enter);
+    let shown = screen(&app, 100, 40).join("\n");
+    assert!(shown.contains("Guess 2/6"), "{}", shown);
+
+    app.play_daily();
+    app.view = View::Game;
+    assert!(screen(&app, 100, 40)
+        .join("\n")
+        .contains("Guess 1/6 · Daily"));
+}
+
+#[test]
+fn a_single_line_keeps_the_bar_only_when_it_fits() {
+    let mut app = common::app("status-bar");
+    app.new_game();
+    app.view = View::Game;
+    // The compact layout, a line for the footer
+    let wide = screen(&app, 200, 14);
+    let footer = wide.last().unwrap();
+    assert!(footer.trim_end().ends_with("Streak 0"), "{}", footer);
+
+    // Too narrow for both: the instructions win
+    let narrow = screen(&app, 40, 14);
+    assert!(!narrow.last().unwrap().contains("Streak"));
+}
@@ -0,0 +1,81 @@
+//! The `auto` theme: its schedule in the config, and the theme changing
+//! with the next game rather than in the middle of one.
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+use wordle::app::App;
+use wordle::config::{Config, ThemeName};
+
+fn config(toml: &str) -> anyhow::Result<Config> {
+    Ok(toml::from_str(toml)?)
+}
+
+#[test]
+fn the_schedule_gives_the_theme_of_each_time_of_day() {
+    let config = config(
+        "[display.theme_schedule]\n\"21:30\" = \"dark\"\n\"07:00\" = \"light\"\n\"18:00\" = \"sepia\"",
+    )
+    .unwrap();
+    let schedule = &config.display.theme_schedule;
+    let at = |hours: u16, minutes: u16| schedule.theme_at(hours * 60 + minutes);
+    assert_eq!(at(7, 0), ThemeName::Light);
+    assert_eq!(at(17, 59), ThemeName::Light);
+    assert_eq!(at(18, 0), ThemeName::Sepia);
+    assert_eq!(at(23, 0), ThemeName::Dark);
+    // Before the first change, the last one of the day before goes on
+    assert_eq!(at(3, 0), ThemeName::Dark);
+
+    assert_eq!(schedule, &Config::default().display.theme_schedule);
+}
+
+#[test]
+fn the_schedule_needs_times_and_real_themes() {
+    assert!(config("[display.theme_schedule]\n\"7h\" = \"light\"").is_err());
+    assert!(config("[display.theme_schedule]\n\"24:00\" = \"light\"").is_err());
+    assert!(config("[display.theme_schedule]\n\"07:00\" = \"auto\"").is_err());
+    assert!(config("[display.theme_schedule]\n\"07:00\" = \"purple\"").is_err());
+    assert_eq!(
+        config("[display]\ntheme = \"sepia\"")
+            .unwrap()
+            .display
+            .theme,
+        ThemeName::Sepia
+    );
+}
+
+#[test]
+fn auto_changes_theme_with_the_next_game() {
+    std::env::set_var(
+        "XDG_DATA_HOME",
+        std::env::temp_dir().join(format!("wordle-theme-schedule-{}", std::process::id())),
+    );
+    let mut config =
+        config("[display]\ntheme = \"auto\"\n[display.theme_schedule]\n\"00:00\" = \"sepia\"")
+            .unwrap();
+    config.language = Some("en".to_string());
+    let mut app = App::new(&config);
+    assert_eq!(app.shown_theme(), ThemeName::Sepia);
+    assert_eq!(app.theme_label(), "auto (sepia)");
+
+    // Time moves on mid-game: the theme waits for the next one
+    app.theme_schedule = toml::from_str::<Config>("[display.theme_schedule]\n\"00:00\" = \"dark\"")
+        .unwrap()
+        .display
+        .theme_schedule;
+    app.game.input_letter('A');
+    assert_eq!(app.shown_theme(), ThemeName::Sepia);
+    app.new_game();
+    assert_eq!(app.shown_theme(), ThemeName::Dark);
+
+    // F2 goes on from auto to the first theme, and back round to auto
+    let press = |app: &mut App| {
+        app.handle_event(Event::Key(KeyEvent::new(KeyCode::F(2), KeyModifiers::NONE)));
+    };
+    press(&mut app);
+    assert_eq!(app.shown_theme(), ThemeName::Classic);
+    for _ in 0..5 {
+        press(&mut app);
+    }
+    assert_eq!(app.theme_name, ThemeName::Auto);
+    assert_eq!(app.game.message.as_deref(), Some("Theme: auto (dark)"));
+}
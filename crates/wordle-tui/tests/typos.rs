@@ -0,0 +1,66 @@
+//! The heatmap of typing corrections: letters deleted after typing them,
+//! counted across games and shown on the keyboard of the stats screen.
+
+mod common;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::press;
+use wordle::app::{App, View};
+use wordle::GameStatus;
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        press(app, KeyCode::Char(c));
+    }
+}
+
+fn screen(app: &App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..40)
+        .map(|y| {
+            (0..100)
+                .map(|x| buffer.get(x, y).symbol())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn deleted_letters_heat_up_their_keys() {
+    common::data_home("typos");
+    let config = common::english();
+    let mut app = App::new(&config);
+    assert!(app.typos.is_empty());
+    app.play_seed(7);
+    type_text(&mut app, "slatx");
+    press(&mut app, KeyCode::Backspace);
+    // Nothing left to delete past the start of the row
+    for _ in 0..6 {
+        press(&mut app, KeyCode::Backspace);
+    }
+    type_text(&mut app, "slate");
+    press(&mut app, KeyCode::Enter);
+    for _ in 0..5 {
+        type_text(&mut app, "xxxxx");
+        press(&mut app, KeyCode::Enter);
+    }
+    assert_eq!(app.game.status, GameStatus::Lost);
+    assert_eq!(app.typos.rate('S'), 0.5);
+    assert_eq!(app.typos.rate('X'), 1.0 / 26.0);
+    assert_eq!(app.typos.rate('Q'), 0.0);
+    assert_eq!(app.typos.worst(2)[0], ('A', 0.5));
+
+    // Saved as the game ended
+    let mut app = App::new(&config);
+    assert_eq!(app.typos.rate('T'), 0.5);
+    app.view = View::Stats;
+    let shown = screen(&app);
+    assert!(shown.contains("Letters corrected"));
+    assert!(shown.contains(" Q  W  E  R  T  Y  U  I  O  P "));
+    assert!(shown.contains("Most corrected: A 50%, L 50%, S 50%"));
+}
@@ -0,0 +1,70 @@
+//! The "What's new" screen: shown once after an upgrade, then on demand
+//! from the menu. Its own test binary, since it changes the version seen
+//! in the data directory every `App` reads at start.
+
+mod common;
+
+use std::fs;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::press;
+use wordle::app::{App, View};
+use wordle::changelog::{self, VERSION};
+
+#[test]
+fn the_changelog_lists_this_version_first() {
+    let releases = changelog::releases();
+    assert_eq!(releases[0].version, VERSION);
+    assert!(releases.iter().all(|release| !(release.modes.is_empty()
+        && release.keys.is_empty()
+        && release.other.is_empty())));
+    assert!(changelog::since(VERSION).is_empty());
+    assert_eq!(changelog::since("0.0.9")[0].version, VERSION);
+}
+
+#[test]
+fn whats_new_shows_once_after_an_upgrade() {
+    let data = common::data_home("whats-new");
+    let _ = fs::remove_dir_all(&data);
+    let config = common::english();
+
+    // A first install starts on the menu, remembering its version
+    let app = App::new(&config);
+    assert_eq!(app.view, View::Menu(0));
+    assert_eq!(changelog::seen_version().as_deref(), Some(VERSION));
+
+    // Coming from an older version opens on the changes since
+    fs::write(data.join("wordle/seen-version"), "0.0.9\n").unwrap();
+    let mut app = App::new(&config);
+    assert_eq!(app.view, View::WhatsNew(0));
+    assert_eq!(app.focus_path(), vec!["What's new"]);
+    // Closing it keeps it for the next start
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.view, View::Menu(0));
+    let mut app = App::new(&config);
+    assert_eq!(app.view, View::WhatsNew(0));
+    // Until it is dismissed for good
+    press(&mut app, KeyCode::Char('d'));
+    assert_eq!(app.view, View::Menu(0));
+    assert_eq!(changelog::seen_version().as_deref(), Some(VERSION));
+    let mut app = App::new(&config);
+    assert_eq!(app.view, View::Menu(0));
+
+    // The menu shows every release, the last entry before Quit
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Up);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::WhatsNew(0));
+    assert_eq!(app.releases, changelog::releases());
+    let mut terminal = Terminal::new(TestBackend::new(90, 40)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, &app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let screen: Vec<String> = (0..40)
+        .map(|y| (0..90).map(|x| buffer.get(x, y).symbol()).collect())
+        .collect();
+    let title = format!("What's new in {}", VERSION);
+    assert!(screen.iter().any(|line| line.contains(&title)));
+    assert!(screen.iter().any(|line| line.contains("• Zen")));
+}
@@ -0,0 +1,137 @@
+//! Themed word lists: the built-in ones, those found in the data
+//! directories with their metadata, and playing one.
+
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+
+use crossterm::event::KeyCode;
+use ratatui::{backend::TestBackend, Terminal};
+
+use common::press;
+use wordle::app::{App, View};
+use wordle::config::Config;
+use wordle::i18n::Language;
+use wordle::word_themes::{self, WordTheme};
+
+// A data directory with a theme of its own and one over a built-in one,
+// the same for every test since they share the environment
+fn data_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wordle-themes-{}", std::process::id()));
+    let themes = dir.join("themes");
+    fs::create_dir_all(&themes).unwrap();
+    fs::write(
+        themes.join("birds.txt"),
+        "# Birds\nrobin\nheron\nowl\nfinch\n",
+    )
+    .unwrap();
+    fs::write(themes.join("food.txt"), "SALSA\n").unwrap();
+    fs::write(themes.join("food.toml"), "name = \"Snacks\"\n").unwrap();
+    fs::write(themes.join("broken.txt"), "CRANE\n").unwrap();
+    fs::write(themes.join("broken.toml"), "language = \"xx\"\n").unwrap();
+    std::env::set_var("WORDLE_DATA_DIR", &dir);
+    std::env::set_var("XDG_DATA_HOME", dir.join("home"));
+    dir
+}
+
+fn app(word_theme: Option<&str>) -> App {
+    data_dir();
+    let config = Config {
+        language: Some("en".to_string()),
+        word_theme: word_theme.map(str::to_string),
+        ..Config::default()
+    };
+    App::new(&config)
+}
+
+fn shows(app: &App, text: &str) -> bool {
+    let (width, height) = (90, 40);
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| wordle::ui::draw(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height).any(|y| {
+        let line: String = (0..width).map(|x| buffer.get(x, y).symbol()).collect();
+        line.contains(text)
+    })
+}
+
+#[test]
+fn metadata_names_a_theme_and_its_language() {
+    let theme = WordTheme::parse(
+        "animals",
+        "# comment\ntiger\nant\nzebra\n",
+        "name = \"Animals\"\nlanguage = \"en\"\n[names]\npt = \"Animais\"\n",
+    )
+    .unwrap();
+    assert_eq!(theme.words, ["TIGER", "ZEBRA"]);
+    assert_eq!(theme.name(Language::En), "Animals");
+    assert_eq!(theme.name(Language::Pt), "Animais");
+    assert!(theme.fits(Language::En));
+    assert!(!theme.fits(Language::Pt));
+
+    // Without metadata it is named after its file, and fits any language
+    let plain = WordTheme::parse("birds", "robin\n", "").unwrap();
+    assert_eq!(plain.name(Language::Fr), "birds");
+    assert!(plain.fits(Language::Fr));
+
+    assert!(WordTheme::parse("none", "ant\n", "").is_err());
+    assert!(WordTheme::parse("odd", "tiger\n", "language = \"xx\"").is_err());
+    assert!(WordTheme::parse("odd", "tiger\n", "name = ").is_err());
+}
+
+#[test]
+fn themes_come_from_every_directory_and_the_binary() {
+    data_dir();
+    let themes = word_themes::available();
+    let ids: Vec<&str> = themes.iter().map(|theme| theme.id.as_str()).collect();
+    // The broken one is left out
+    assert_eq!(ids, ["42", "animals", "birds", "food"]);
+    // Files come before the built-in theme of the same name
+    let food = &themes[3];
+    assert_eq!(food.name(Language::En), "Snacks");
+    assert_eq!(food.words, ["SALSA"]);
+    assert_eq!(themes[0].name(Language::Pt), "Jargão da 42");
+    assert!(themes[1].words.contains(&"TIGER".to_string()));
+}
+
+#[test]
+fn a_theme_is_played_and_shown_in_the_title() {
+    let mut app = app(Some("animals"));
+    let animals = app.word_theme.clone().unwrap();
+    assert_eq!(
+        app.dictionary.spellings().as_ref(),
+        animals.words.as_slice()
+    );
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.view, View::Game);
+    assert!(animals.words.contains(&app.game.display_word));
+    assert!(shows(&app, "WORDLE · Animals"));
+
+    // Not in another language
+    app.switch_language(Language::Pt);
+    assert!(app.dictionary.len() > animals.words.len());
+    assert!(!shows(&app, "Animals"));
+}
+
+#[test]
+fn the_theme_is_chosen_in_the_settings() {
+    let mut app = app(None);
+    let whole = app.dictionary.len();
+    app.view = View::Settings(5);
+    assert!(shows(&app, "Word theme: off"));
+    press(&mut app, KeyCode::Enter);
+    assert!(shows(&app, "Word theme: 42 jargon"));
+    assert_eq!(app.dictionary.len(), 20);
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Enter);
+    assert!(shows(&app, "Word theme: birds"));
+    assert_eq!(
+        app.dictionary.spellings().as_ref(),
+        ["ROBIN", "HERON", "FINCH"]
+    );
+    press(&mut app, KeyCode::Enter);
+    press(&mut app, KeyCode::Enter);
+    assert!(shows(&app, "Word theme: off"));
+    assert_eq!(app.dictionary.len(), whole);
+}